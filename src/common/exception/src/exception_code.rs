@@ -253,7 +253,7 @@ build_exceptions! {
     UnsupportedClusterType(4013),
 }
 
-// Table Structure and Operation Errors [1102-1103, 1106-1111, 1113-1118, 1121-1122, 1130-1133]
+// Table Structure and Operation Errors [1102-1103, 1106-1111, 1113-1118, 1121-1122, 1130-1135]
 build_exceptions! {
     /// Index out of bounds
     IndexOutOfBounds(1102),
@@ -293,6 +293,8 @@ build_exceptions! {
     ConstraintError(1133),
     /// Unknown row policy
     UnknownMaskPolicy(1134),
+    /// Unknown tag
+    UnknownTag(1135),
 }
 
 // Sequence Errors [1124-1126, 3101-3102]
@@ -317,7 +319,7 @@ build_exceptions! {
     VirtualColumnTooMany(1128),
 }
 
-// Table Engine Errors [1301-1303, 2701-2703]
+// Table Engine Errors [1301-1305, 2701-2703]
 build_exceptions! {
     /// Table option is invalid
     TableOptionInvalid(1301),
@@ -325,6 +327,10 @@ build_exceptions! {
     TableEngineNotSupported(1302),
     /// Table schema mismatch
     TableSchemaMismatch(1303),
+    /// Unknown table branch or tag
+    UnknownTableRef(1304),
+    /// Table branch or tag already exists
+    TableRefAlreadyExists(1305),
     /// Unknown database engine
     UnknownDatabaseEngine(2701),
     /// Unknown table engine
@@ -537,6 +543,8 @@ build_exceptions! {
     RowAccessPolicyAlreadyExists(2324),
     /// General failures met while garbage collecting database meta
     GeneralDbGcFailure(2325),
+    /// Tag already exists
+    TagAlreadyExists(2327),
 }
 
 // Stage and Connection Errors [2501-2505, 2510-2512]
@@ -692,6 +700,14 @@ build_exceptions! {
     UnknownWorkloadQuotas(3144),
 }
 
+// Plan Baseline Errors [3150-3151]
+build_exceptions! {
+    /// Unknown plan baseline
+    UnknownPlanBaseline(3150),
+    /// Plan baseline already exists
+    PlanBaselineAlreadyExists(3151),
+}
+
 // Transaction and Processing Errors [4001-4004, 4012]
 build_exceptions! {
     /// Unresolvable conflict
@@ -227,6 +227,8 @@ fn is_custom_type(name: &str) -> bool {
             | "Utc"
             | "MetaHLL"
             | "Histogram"
+            | "MostCommonValues"
+            | "MultiColumnStatistics"
             | "RawBlockHLL"
             | "VariantDataType"
             | "DateTime"
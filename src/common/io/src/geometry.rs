@@ -276,6 +276,17 @@ pub fn ewkb_to_geo<B: AsRef<[u8]>>(ewkb: &mut Ewkb<B>) -> Result<(Geometry<f64>,
     Ok((geo, srid))
 }
 
+/// Process EWKB input and return the bounding box `(min_x, min_y, max_x, max_y)`,
+/// or `None` if the geometry has no finite coordinates.
+pub fn read_bounding_box<B: AsRef<[u8]>>(
+    ewkb: &mut Ewkb<B>,
+) -> Result<Option<(f64, f64, f64, f64)>> {
+    let mut bbox_processor = BoundingBoxProcessor::new();
+    ewkb.process_geom(&mut bbox_processor)?;
+
+    Ok(bbox_processor.bbox)
+}
+
 struct SridProcessor {
     srid: Option<i32>,
 }
@@ -293,6 +304,32 @@ impl GeomProcessor for SridProcessor {
     }
 }
 
+struct BoundingBoxProcessor {
+    bbox: Option<(f64, f64, f64, f64)>,
+}
+
+impl BoundingBoxProcessor {
+    fn new() -> Self {
+        Self { bbox: None }
+    }
+}
+
+impl GeomProcessor for BoundingBoxProcessor {
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> geozero::error::Result<()> {
+        // Empty points are encoded with NaN coordinates.
+        if !x.is_finite() || !y.is_finite() {
+            return Ok(());
+        }
+        self.bbox = Some(match self.bbox {
+            Some((min_x, min_y, max_x, max_y)) => {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            }
+            None => (x, y, x, y),
+        });
+        Ok(())
+    }
+}
+
 struct EwkbProcessor {
     geo_writer: GeoWriter,
     srid: Option<i32>,
@@ -62,6 +62,7 @@ pub use geometry::geometry_format;
 pub use geometry::geometry_from_ewkt;
 pub use geometry::geometry_type_name;
 pub use geometry::parse_bytes_to_ewkb;
+pub use geometry::read_bounding_box;
 pub use geometry::read_srid;
 pub use geometry::Axis;
 pub use geometry::Extremum;
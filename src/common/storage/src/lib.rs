@@ -73,6 +73,8 @@ mod histogram;
 mod merge;
 mod meta_hll;
 mod metrics_layer;
+mod most_common_values;
+mod multi_column_statistics;
 mod multi_table_insert;
 mod statistics;
 
@@ -86,6 +88,9 @@ pub use histogram::DEFAULT_HISTOGRAM_BUCKETS;
 pub use merge::MutationStatus;
 pub use meta_hll::MetaHLL;
 pub use meta_hll::MetaHLL12;
+pub use most_common_values::MostCommonValues;
+pub use most_common_values::DEFAULT_MOST_COMMON_VALUES;
+pub use multi_column_statistics::MultiColumnStatistics;
 pub use multi_table_insert::MultiTableInsertStatus;
 pub use statistics::Datum;
 pub use statistics::F64;
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Datum;
+
+pub const DEFAULT_MOST_COMMON_VALUES: usize = 20;
+
+/// The most common values of a column with their frequencies.
+///
+/// Frequencies are the fraction of the rows of the table holding the value,
+/// so the selectivity of an equality predicate on a listed value is exact at
+/// the time of `ANALYZE`, and the remaining rows are assumed to be evenly
+/// distributed over the other distinct values.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MostCommonValues {
+    pub values: Vec<(Datum, f64)>,
+}
+
+impl MostCommonValues {
+    pub fn new(values: Vec<(Datum, f64)>) -> Self {
+        Self { values }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Get the frequency of the value, `None` if the value is not a most common value.
+    pub fn frequency(&self, value: &Datum) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(v, _)| {
+                v.can_compare(value) && matches!(v.compare(value), Ok(std::cmp::Ordering::Equal))
+            })
+            .map(|(_, freq)| *freq)
+    }
+
+    /// Get the total frequency of all the most common values.
+    pub fn total_frequency(&self) -> f64 {
+        self.values
+            .iter()
+            .map(|(_, freq)| freq)
+            .sum::<f64>()
+            .min(1.0)
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::ColumnId;
+
+/// Statistics of a group of columns created by `CREATE STATISTICS`.
+///
+/// Single column statistics assume columns are independent, which heavily
+/// underestimates predicates and groupings on correlated columns, e.g. city and zip code.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MultiColumnStatistics {
+    /// Columns of the group.
+    pub column_ids: Vec<ColumnId>,
+    /// Number of distinct combinations of values of the columns.
+    pub ndv: u64,
+    /// Functional dependency degree of each column on the other columns of the group,
+    /// that is the fraction of rows whose value of the column is determined by the
+    /// values of the other columns, in `[0, 1]`.
+    pub dependencies: Vec<f64>,
+}
+
+impl MultiColumnStatistics {
+    pub fn new(column_ids: Vec<ColumnId>, ndv: u64, dependencies: Vec<f64>) -> Self {
+        Self {
+            column_ids,
+            ndv,
+            dependencies,
+        }
+    }
+}
@@ -61,6 +61,8 @@ mod row_access_policy_api;
 mod row_access_policy_api_impl;
 mod sequence_api_impl;
 pub(crate) mod sequence_nextval_impl;
+mod tag_api;
+mod tag_api_impl;
 
 pub use auto_increment_api::AutoIncrementApi;
 pub use auto_increment_api_test_suite::AutoIncrementApiTestSuite;
@@ -98,6 +100,7 @@ pub use serialization_util::{
     deserialize_struct, deserialize_u64, serialize_struct, serialize_u64,
 };
 pub use table_api::TableApi;
+pub use tag_api::TagApi;
 // Re-export from new txn_condition_util module for backward compatibility
 pub use txn_condition_util::txn_cond_eq_seq;
 pub use txn_condition_util::txn_cond_seq;
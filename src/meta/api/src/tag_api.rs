@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::tag::tag_name_ident;
+use databend_common_meta_app::tag::CreateTagReply;
+use databend_common_meta_app::tag::CreateTagReq;
+use databend_common_meta_app::tag::TagId;
+use databend_common_meta_app::tag::TagMeta;
+use databend_common_meta_app::tag::TagNameIdent;
+use databend_common_meta_app::tag::TagReference;
+use databend_common_meta_app::tag::TaggableObject;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_app::tenant_key::errors::ExistError;
+use databend_common_meta_app::tenant_key::errors::UnknownError;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::SeqV;
+
+use crate::meta_txn_error::MetaTxnError;
+
+#[async_trait::async_trait]
+pub trait TagApi: Send + Sync {
+    async fn create_tag(
+        &self,
+        req: CreateTagReq,
+    ) -> Result<Result<CreateTagReply, ExistError<tag_name_ident::Resource>>, MetaTxnError>;
+
+    /// Drop a tag and remove it from every object it is set on.
+    ///
+    /// On success, returns the dropped id and tag meta.
+    /// Returning None, means nothing is removed.
+    async fn drop_tag(
+        &self,
+        name_ident: &TagNameIdent,
+    ) -> Result<Option<(SeqV<TagId>, SeqV<TagMeta>)>, MetaTxnError>;
+
+    async fn get_tag(
+        &self,
+        name_ident: &TagNameIdent,
+    ) -> Result<Option<(SeqV<TagId>, SeqV<TagMeta>)>, MetaError>;
+
+    /// List all tags of a tenant as `(tag_name, tag_id, tag_meta)`.
+    async fn list_tags(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<Vec<(String, TagId, SeqV<TagMeta>)>, MetaError>;
+
+    /// Bind a masking policy to a tag, or unbind it if `policy_id` is None.
+    async fn set_tag_masking_policy(
+        &self,
+        name_ident: &TagNameIdent,
+        policy_id: Option<u64>,
+    ) -> Result<Result<(), UnknownError<tag_name_ident::Resource>>, MetaTxnError>;
+
+    /// Set tags on an object, the value of a tag already set on it is replaced.
+    async fn set_object_tags(
+        &self,
+        tenant: &Tenant,
+        object: TaggableObject,
+        tags: Vec<(u64, String)>,
+    ) -> Result<(), MetaTxnError>;
+
+    /// Remove tags from an object, tags that are not set on it are ignored.
+    async fn unset_object_tags(
+        &self,
+        tenant: &Tenant,
+        object: TaggableObject,
+        tag_ids: Vec<u64>,
+    ) -> Result<(), MetaTxnError>;
+
+    /// List the tags set on an object.
+    ///
+    /// For a table, the tags set on its columns are listed too.
+    async fn get_object_tags(
+        &self,
+        tenant: &Tenant,
+        object: TaggableObject,
+    ) -> Result<Vec<TagReference>, MetaError>;
+
+    /// List the tags set on any object of a tenant.
+    async fn list_tag_references(&self, tenant: &Tenant) -> Result<Vec<TagReference>, MetaError>;
+}
@@ -0,0 +1,341 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tag::tag_name_ident;
+use databend_common_meta_app::tag::CreateTagReply;
+use databend_common_meta_app::tag::CreateTagReq;
+use databend_common_meta_app::tag::ObjectTagId;
+use databend_common_meta_app::tag::ObjectTagIdIdent;
+use databend_common_meta_app::tag::ObjectTagValue;
+use databend_common_meta_app::tag::TagId;
+use databend_common_meta_app::tag::TagIdIdent;
+use databend_common_meta_app::tag::TagMeta;
+use databend_common_meta_app::tag::TagNameIdent;
+use databend_common_meta_app::tag::TagReference;
+use databend_common_meta_app::tag::TaggableObject;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_app::tenant_key::errors::ExistError;
+use databend_common_meta_app::tenant_key::errors::UnknownError;
+use databend_common_meta_app::KeyWithTenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::TxnRequest;
+use fastrace::func_name;
+use log::debug;
+
+use crate::fetch_id;
+use crate::kv_pb_api::KVPbApi;
+use crate::meta_txn_error::MetaTxnError;
+use crate::tag_api::TagApi;
+use crate::txn_backoff::txn_backoff;
+use crate::txn_condition_util::txn_cond_eq_seq;
+use crate::txn_core_util::send_txn;
+use crate::txn_core_util::txn_delete_exact;
+use crate::txn_core_util::txn_replace_exact;
+use crate::txn_op_builder_util::txn_op_del;
+use crate::txn_op_builder_util::txn_op_put_pb;
+
+/// TagApi is implemented upon kvapi::KVApi.
+/// Thus every type that impl kvapi::KVApi impls TagApi.
+#[tonic::async_trait]
+impl<KV: kvapi::KVApi<Error = MetaError>> TagApi for KV {
+    async fn create_tag(
+        &self,
+        req: CreateTagReq,
+    ) -> Result<Result<CreateTagReply, ExistError<tag_name_ident::Resource>>, MetaTxnError> {
+        debug!(req :? =(&req); "TagApi: {}", func_name!());
+
+        let name_ident = &req.name;
+
+        let mut trials = txn_backoff(None, func_name!());
+        let id = loop {
+            trials.next().unwrap()?.await;
+
+            let mut txn = TxnRequest::default();
+
+            let res = self.get_id_and_value(name_ident).await?;
+            debug!(res :? = res, name_key :? =(name_ident); "create_tag");
+
+            let mut curr_seq = 0;
+
+            if let Some((seq_id, seq_meta)) = res {
+                match req.create_option {
+                    CreateOption::Create => {
+                        return Ok(Err(name_ident.exist_error(func_name!())));
+                    }
+                    CreateOption::CreateIfNotExists => {
+                        return Ok(Ok(CreateTagReply { id: *seq_id.data }));
+                    }
+                    CreateOption::CreateOrReplace => {
+                        // The references to the replaced tag are left behind,
+                        // they are ignored because the tag id no longer exists.
+                        let id_ident = seq_id.data.into_t_ident(name_ident.tenant());
+
+                        txn_delete_exact(&mut txn, &id_ident, seq_meta.seq);
+
+                        curr_seq = seq_id.seq;
+                    }
+                }
+            }
+
+            // Create tag by inserting these record:
+            // name -> id
+            // id -> meta
+
+            let id = fetch_id(self, IdGenerator::tag_id()).await?;
+            let id = TagId::new(id);
+            let id_ident = TagIdIdent::new_generic(name_ident.tenant(), id);
+
+            debug!(
+                id :? =(&id_ident),
+                name_key :? =(name_ident);
+                "new tag id"
+            );
+
+            txn.condition.push(txn_cond_eq_seq(name_ident, curr_seq));
+            txn.if_then.extend(vec![
+                txn_op_put_pb(name_ident, &id, None)?,      // name -> tag_id
+                txn_op_put_pb(&id_ident, &req.meta, None)?, // id -> meta
+            ]);
+
+            let (succ, _responses) = send_txn(self, txn).await?;
+
+            debug!(
+                name :? =(name_ident),
+                id :? =(&id_ident),
+                succ = succ;
+                "create_tag"
+            );
+
+            if succ {
+                break id;
+            }
+        };
+
+        Ok(Ok(CreateTagReply { id: *id }))
+    }
+
+    async fn drop_tag(
+        &self,
+        name_ident: &TagNameIdent,
+    ) -> Result<Option<(SeqV<TagId>, SeqV<TagMeta>)>, MetaTxnError> {
+        debug!(name_ident :? =(name_ident); "TagApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let mut txn = TxnRequest::default();
+
+            let res = self.get_id_and_value(name_ident).await?;
+            debug!(res :? = res, name_key :? =(name_ident); "{}", func_name!());
+
+            let Some((seq_id, seq_meta)) = res else {
+                return Ok(None);
+            };
+
+            let id_ident = seq_id.data.into_t_ident(name_ident.tenant());
+
+            txn_delete_exact(&mut txn, name_ident, seq_id.seq);
+            txn_delete_exact(&mut txn, &id_ident, seq_meta.seq);
+
+            let tenant = name_ident.tenant();
+            let references = self.list_pb_vec(&tenant_object_tag_dir(tenant)).await?;
+            for (ident, _) in references {
+                if ident.name().tag_id == *seq_id.data {
+                    txn.if_then.push(txn_op_del(&ident));
+                }
+            }
+
+            let (succ, _responses) = send_txn(self, txn).await?;
+            debug!(succ = succ;"{}", func_name!());
+
+            if succ {
+                return Ok(Some((seq_id, seq_meta)));
+            }
+        }
+    }
+
+    async fn get_tag(
+        &self,
+        name_ident: &TagNameIdent,
+    ) -> Result<Option<(SeqV<TagId>, SeqV<TagMeta>)>, MetaError> {
+        debug!(req :? =(&name_ident); "TagApi: {}", func_name!());
+
+        let res = self.get_id_and_value(name_ident).await?;
+
+        Ok(res)
+    }
+
+    async fn list_tags(
+        &self,
+        tenant: &Tenant,
+    ) -> Result<Vec<(String, TagId, SeqV<TagMeta>)>, MetaError> {
+        debug!(req :? =(&tenant); "TagApi: {}", func_name!());
+
+        let dir = DirName::new(TagNameIdent::new(tenant, "dummy"));
+        let name_ids = self.list_pb_vec(&dir).await?;
+
+        let id_idents = name_ids
+            .iter()
+            .map(|(_, seq_id)| TagIdIdent::new_generic(tenant, seq_id.data))
+            .collect::<Vec<_>>();
+        let metas = self.get_pb_values_vec(id_idents).await?;
+
+        let tags = name_ids
+            .into_iter()
+            .zip(metas)
+            .filter_map(|((name_ident, seq_id), seq_meta)| {
+                seq_meta.map(|seq_meta| (name_ident.tag_name().to_string(), seq_id.data, seq_meta))
+            })
+            .collect();
+
+        Ok(tags)
+    }
+
+    async fn set_tag_masking_policy(
+        &self,
+        name_ident: &TagNameIdent,
+        policy_id: Option<u64>,
+    ) -> Result<Result<(), UnknownError<tag_name_ident::Resource>>, MetaTxnError> {
+        debug!(name_ident :? =(name_ident), policy_id :? =(policy_id); "TagApi: {}", func_name!());
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let Some((seq_id, seq_meta)) = self.get_id_and_value(name_ident).await? else {
+                return Ok(Err(name_ident.unknown_error(func_name!())));
+            };
+
+            let id_ident = seq_id.data.into_t_ident(name_ident.tenant());
+            let mut meta = seq_meta.data;
+            meta.masking_policy_id = policy_id;
+            meta.updated_on = Some(Utc::now());
+
+            let mut txn = TxnRequest::default();
+            txn_replace_exact(&mut txn, &id_ident, seq_meta.seq, &meta)?;
+
+            let (succ, _responses) = send_txn(self, txn).await?;
+            debug!(succ = succ;"{}", func_name!());
+
+            if succ {
+                return Ok(Ok(()));
+            }
+        }
+    }
+
+    async fn set_object_tags(
+        &self,
+        tenant: &Tenant,
+        object: TaggableObject,
+        tags: Vec<(u64, String)>,
+    ) -> Result<(), MetaTxnError> {
+        debug!(object :? =(&object), tags :? =(&tags); "TagApi: {}", func_name!());
+
+        let mut txn = TxnRequest::default();
+        for (tag_id, tag_value) in tags {
+            let ident = ObjectTagIdIdent::new_generic(tenant, ObjectTagId { object, tag_id });
+            txn.if_then
+                .push(txn_op_put_pb(&ident, &ObjectTagValue { tag_value }, None)?);
+        }
+
+        send_txn(self, txn).await?;
+        Ok(())
+    }
+
+    async fn unset_object_tags(
+        &self,
+        tenant: &Tenant,
+        object: TaggableObject,
+        tag_ids: Vec<u64>,
+    ) -> Result<(), MetaTxnError> {
+        debug!(object :? =(&object), tag_ids :? =(&tag_ids); "TagApi: {}", func_name!());
+
+        let mut txn = TxnRequest::default();
+        for tag_id in tag_ids {
+            let ident = ObjectTagIdIdent::new_generic(tenant, ObjectTagId { object, tag_id });
+            txn.if_then.push(txn_op_del(&ident));
+        }
+
+        send_txn(self, txn).await?;
+        Ok(())
+    }
+
+    async fn get_object_tags(
+        &self,
+        tenant: &Tenant,
+        object: TaggableObject,
+    ) -> Result<Vec<TagReference>, MetaError> {
+        debug!(object :? =(&object); "TagApi: {}", func_name!());
+
+        let mut dirs = vec![object_tag_dir(tenant, object, 1)];
+        if let TaggableObject::Table { table_id } = object {
+            let column = TaggableObject::Column {
+                table_id,
+                column_id: 0,
+            };
+            dirs.push(object_tag_dir(tenant, column, 2));
+        }
+
+        let mut references = vec![];
+        for dir in dirs {
+            let kvs = self.list_pb_vec(&dir).await?;
+            references.extend(kvs.into_iter().map(to_tag_reference));
+        }
+        Ok(references)
+    }
+
+    async fn list_tag_references(&self, tenant: &Tenant) -> Result<Vec<TagReference>, MetaError> {
+        debug!(req :? =(&tenant); "TagApi: {}", func_name!());
+
+        let kvs = self.list_pb_vec(&tenant_object_tag_dir(tenant)).await?;
+        Ok(kvs.into_iter().map(to_tag_reference).collect())
+    }
+}
+
+/// The dir of the tags set on `object`, `level` is the number of trailing
+/// segments to strip from a full key of `object`.
+fn object_tag_dir(
+    tenant: &Tenant,
+    object: TaggableObject,
+    level: usize,
+) -> DirName<ObjectTagIdIdent> {
+    let ident = ObjectTagIdIdent::new_generic(tenant, ObjectTagId { object, tag_id: 0 });
+    DirName::new_with_level(ident, level)
+}
+
+/// The dir of the tags set on any object of a tenant:
+/// `__fd_object_tag/<tenant>/`.
+fn tenant_object_tag_dir(tenant: &Tenant) -> DirName<ObjectTagIdIdent> {
+    let column = TaggableObject::Column {
+        table_id: 0,
+        column_id: 0,
+    };
+    object_tag_dir(tenant, column, 4)
+}
+
+fn to_tag_reference((ident, seq_value): (ObjectTagIdIdent, SeqV<ObjectTagValue>)) -> TagReference {
+    let ObjectTagId { object, tag_id } = *ident.name();
+    TagReference {
+        object,
+        tag_id,
+        tag_value: seq_value.data.tag_value,
+    }
+}
@@ -28,6 +28,7 @@ use crate::schema::dictionary_name_ident;
 use crate::schema::index_name_ident;
 use crate::schema::DictionaryIdentity;
 use crate::schema::SequenceRsc;
+use crate::tag::tag_name_ident;
 use crate::tenant_key::errors::ExistError;
 use crate::tenant_key::errors::UnknownError;
 use crate::tenant_key::ident::TIdent;
@@ -1155,6 +1156,12 @@ pub enum AppError {
     #[error(transparent)]
     UnknownRowAccessPolicy(#[from] UnknownError<row_access_policy_name_ident::Resource>),
 
+    #[error(transparent)]
+    TagAlreadyExists(#[from] ExistError<tag_name_ident::Resource>),
+
+    #[error(transparent)]
+    UnknownTag(#[from] UnknownError<tag_name_ident::Resource>),
+
     #[error(transparent)]
     UnmatchColumnDataType(#[from] UnmatchColumnDataType),
 
@@ -1709,6 +1716,8 @@ impl From<AppError> for ErrorCode {
             AppError::UnknownRowAccessPolicy(err) => {
                 ErrorCode::UnknownRowAccessPolicy(err.message())
             }
+            AppError::TagAlreadyExists(err) => ErrorCode::TagAlreadyExists(err.message()),
+            AppError::UnknownTag(err) => ErrorCode::UnknownTag(err.message()),
 
             AppError::UnmatchColumnDataType(err) => ErrorCode::UnmatchColumnDataType(err.message()),
             AppError::UnmatchMaskPolicyReturnType(err) => {
@@ -28,6 +28,7 @@ pub(crate) const ID_GEN_SHARE_ENDPOINT: &str = "share_endpoint_id";
 
 pub(crate) const ID_GEN_DATA_MASK: &str = "data_mask";
 pub(crate) const ID_GEN_ROW_POLICY: &str = "row_access";
+pub(crate) const ID_GEN_TAG: &str = "tag_id";
 pub(crate) const ID_GEN_BACKGROUND_JOB: &str = "background_job";
 
 pub(crate) const ID_GEN_PROCEDURE: &str = "procedure_id";
@@ -102,6 +103,13 @@ impl IdGenerator {
         }
     }
 
+    /// Create a key for generating tag id with kvapi::KVApi
+    pub fn tag_id() -> Self {
+        Self {
+            resource: ID_GEN_TAG.to_string(),
+        }
+    }
+
     pub fn table_lock_id() -> Self {
         Self {
             resource: ID_GEN_TABLE_LOCK.to_string(),
@@ -241,6 +249,16 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        // Tag id generator
+        {
+            let g = IdGenerator::tag_id();
+            let k = g.to_string_key();
+            assert_eq!("__fd_id_gen/tag_id", k);
+
+            let t2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g, t2);
+        }
+
         // Procedure id generator
         {
             let g = IdGenerator::procedure_id();
@@ -29,6 +29,7 @@ pub mod primitive;
 pub mod principal;
 pub mod schema;
 pub mod storage;
+pub mod tag;
 pub mod tenant;
 pub mod tenant_key;
 
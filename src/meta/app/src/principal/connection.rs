@@ -18,6 +18,9 @@ use itertools::Itertools;
 use serde::Deserialize;
 use serde::Serialize;
 
+/// The storage type of connections which only hold a secret value, such as an encryption key.
+pub const SECRET_STORAGE_TYPE: &str = "secret";
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
 #[serde(default)]
 pub struct UserDefinedConnection {
@@ -38,6 +41,15 @@ impl UserDefinedConnection {
         }
     }
 
+    /// Returns the secret value if this is a secret connection.
+    pub fn secret(&self) -> Option<&str> {
+        if self.storage_type.eq_ignore_ascii_case(SECRET_STORAGE_TYPE) {
+            self.storage_params.get("secret").map(|s| s.as_str())
+        } else {
+            None
+        }
+    }
+
     pub fn storage_params_display(&self) -> String {
         self.storage_params
             .iter()
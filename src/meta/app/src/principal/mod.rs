@@ -19,6 +19,7 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod plan_baseline;
 mod principal_identity;
 pub mod role_ident;
 mod role_info;
@@ -43,6 +44,7 @@ pub mod client_session_ident;
 pub mod connection_ident;
 pub mod network_policy_ident;
 pub mod password_policy_ident;
+pub mod plan_baseline_ident;
 pub mod procedure;
 pub mod procedure_id_ident;
 pub mod procedure_id_to_name;
@@ -69,6 +71,8 @@ pub use ownership_info::OwnershipInfo;
 pub use ownership_object::OwnershipObject;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use plan_baseline::PlanBaseline;
+pub use plan_baseline_ident::PlanBaselineIdent;
 pub use principal_identity::PrincipalIdentity;
 pub use procedure::CreateProcedureReply;
 pub use procedure::CreateProcedureReq;
@@ -0,0 +1,31 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A known-good plan of a query, keyed by the hash of the normalized query text
+/// and the catalog and database it is planned in.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PlanBaseline {
+    /// Hash of the normalized query text, the current catalog and database.
+    pub sql_hash: String,
+    /// The normalized query text.
+    pub query_text: String,
+    /// The pinned plan shape, serialized by the planner.
+    pub plan: String,
+    /// Human readable form of the pinned plan shape.
+    pub plan_display: String,
+    pub created_on: DateTime<Utc>,
+}
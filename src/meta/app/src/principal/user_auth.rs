@@ -23,6 +23,8 @@ const NO_PASSWORD_STR: &str = "no_password";
 const SHA256_PASSWORD_STR: &str = "sha256_password";
 const DOUBLE_SHA1_PASSWORD_STR: &str = "double_sha1_password";
 const JWT_AUTH_STR: &str = "jwt";
+const KEY_PAIR_AUTH_STR: &str = "key_pair";
+const LDAP_AUTH_STR: &str = "ldap";
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum AuthType {
@@ -30,6 +32,12 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    /// Authenticated by a JWT signed with the private key of one of the
+    /// public keys set on the user, see `UserOption::rsa_public_key`.
+    KeyPair,
+    /// Authenticated by binding to the LDAP server configured in `[query.ldap]`
+    /// with the password of the user.
+    Ldap,
 }
 
 impl FromStr for AuthType {
@@ -41,6 +49,8 @@ impl FromStr for AuthType {
             DOUBLE_SHA1_PASSWORD_STR => Ok(AuthType::DoubleSha1Password),
             NO_PASSWORD_STR => Ok(AuthType::NoPassword),
             JWT_AUTH_STR => Ok(AuthType::JWT),
+            KEY_PAIR_AUTH_STR => Ok(AuthType::KeyPair),
+            LDAP_AUTH_STR => Ok(AuthType::Ldap),
             _ => Err(ErrorCode::AuthenticateFailure(AuthType::bad_auth_types(s))),
         }
     }
@@ -53,6 +63,8 @@ impl AuthType {
             AuthType::Sha256Password => SHA256_PASSWORD_STR,
             AuthType::DoubleSha1Password => DOUBLE_SHA1_PASSWORD_STR,
             AuthType::JWT => JWT_AUTH_STR,
+            AuthType::KeyPair => KEY_PAIR_AUTH_STR,
+            AuthType::Ldap => LDAP_AUTH_STR,
         }
     }
 
@@ -62,6 +74,8 @@ impl AuthType {
             SHA256_PASSWORD_STR,
             DOUBLE_SHA1_PASSWORD_STR,
             JWT_AUTH_STR,
+            KEY_PAIR_AUTH_STR,
+            LDAP_AUTH_STR,
         ];
         let all = all
             .iter()
@@ -87,6 +101,8 @@ impl From<databend_common_ast::ast::AuthType> for AuthType {
             databend_common_ast::ast::AuthType::Sha256Password => AuthType::Sha256Password,
             databend_common_ast::ast::AuthType::DoubleSha1Password => AuthType::DoubleSha1Password,
             databend_common_ast::ast::AuthType::JWT => AuthType::JWT,
+            databend_common_ast::ast::AuthType::KeyPair => AuthType::KeyPair,
+            databend_common_ast::ast::AuthType::Ldap => AuthType::Ldap,
         }
     }
 }
@@ -103,6 +119,8 @@ pub enum AuthInfo {
         need_change: bool,
     },
     JWT,
+    KeyPair,
+    Ldap,
 }
 
 fn calc_sha1(v: &[u8]) -> [u8; 20] {
@@ -124,6 +142,8 @@ impl AuthInfo {
         match auth_type {
             AuthType::NoPassword => Ok(AuthInfo::None),
             AuthType::JWT => Ok(AuthInfo::JWT),
+            AuthType::KeyPair => Ok(AuthInfo::KeyPair),
+            AuthType::Ldap => Ok(AuthInfo::Ldap),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => match auth_string {
                 Some(p) => {
                     let method = auth_type.get_password_type().unwrap();
@@ -204,6 +224,8 @@ impl AuthInfo {
         match self {
             AuthInfo::None => AuthType::NoPassword,
             AuthInfo::JWT => AuthType::JWT,
+            AuthInfo::KeyPair => AuthType::KeyPair,
+            AuthInfo::Ldap => AuthType::Ldap,
             AuthInfo::Password { hash_method: t, .. } => match t {
                 PasswordHashMethod::Sha256 => AuthType::Sha256Password,
                 PasswordHashMethod::DoubleSha1 => AuthType::DoubleSha1Password,
@@ -215,6 +237,8 @@ impl AuthInfo {
         match self {
             AuthInfo::None => false,
             AuthInfo::JWT => false,
+            AuthInfo::KeyPair => false,
+            AuthInfo::Ldap => false,
             AuthInfo::Password { need_change, .. } => *need_change,
         }
     }
@@ -226,7 +250,7 @@ impl AuthInfo {
                 hash_method: t,
                 ..
             } => t.to_string(p),
-            AuthInfo::None | AuthInfo::JWT => "".to_string(),
+            AuthInfo::None | AuthInfo::JWT | AuthInfo::KeyPair | AuthInfo::Ldap => "".to_string(),
         }
     }
 
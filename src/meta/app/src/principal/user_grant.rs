@@ -57,6 +57,8 @@ pub enum GrantObject {
     DatabaseById(String, u64),
     Table(String, String, String),
     TableById(String, u64, u64),
+    /// A column of a table, identified by catalog name, database id, table id and column id.
+    ColumnById(String, u64, u64, u32),
     UDF(String),
     Stage(String),
     Warehouse(String),
@@ -67,7 +69,8 @@ pub enum GrantObject {
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects, and a Table object contains all the
+    /// Column objects of it.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -81,6 +84,9 @@ impl GrantObject {
             (GrantObject::DatabaseById(lcat, ldb), GrantObject::TableById(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (GrantObject::DatabaseById(lcat, ldb), GrantObject::ColumnById(rcat, rdb, _, _)) => {
+                lcat == rcat && ldb == rdb
+            }
             (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
@@ -92,6 +98,19 @@ impl GrantObject {
                 GrantObject::TableById(lcat, lhs_db, lhs_table),
                 GrantObject::TableById(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::TableById(lcat, lhs_db, lhs_table),
+                GrantObject::ColumnById(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::ColumnById(lcat, lhs_db, lhs_table, lhs_column),
+                GrantObject::ColumnById(rcat, rhs_db, rhs_table, rhs_column),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && (lhs_column == rhs_column)
+            }
             (GrantObject::Table(_, _, _), _) => false,
             (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
             (GrantObject::UDF(udf), GrantObject::UDF(rudf)) => udf == rudf,
@@ -113,6 +132,9 @@ impl GrantObject {
             GrantObject::Table(_, _, _) | GrantObject::TableById(_, _, _) => {
                 UserPrivilegeSet::available_privileges_on_table(available_ownership)
             }
+            GrantObject::ColumnById(_, _, _, _) => {
+                UserPrivilegeSet::available_privileges_on_column()
+            }
             GrantObject::UDF(_) => {
                 UserPrivilegeSet::available_privileges_on_udf(available_ownership)
             }
@@ -144,7 +166,9 @@ impl GrantObject {
             | GrantObject::Procedure(_)
             | GrantObject::Connection(_) => None,
             GrantObject::Database(cat, _) | GrantObject::DatabaseById(cat, _) => Some(cat.clone()),
-            GrantObject::Table(cat, _, _) | GrantObject::TableById(cat, _, _) => Some(cat.clone()),
+            GrantObject::Table(cat, _, _)
+            | GrantObject::TableById(cat, _, _)
+            | GrantObject::ColumnById(cat, _, _, _) => Some(cat.clone()),
         }
     }
 }
@@ -161,6 +185,9 @@ impl fmt::Display for GrantObject {
             GrantObject::TableById(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::ColumnById(ref cat, ref db, ref table, ref column) => {
+                write!(f, "'{}'.'{}'.'{}'.'{}'", cat, db, table, column)
+            }
             GrantObject::UDF(udf) => write!(f, "UDF {udf}"),
             GrantObject::Stage(stage) => write!(f, "STAGE {stage}"),
             GrantObject::Warehouse(w) => write!(f, "WAREHOUSE {w}"),
@@ -194,6 +194,10 @@ pub struct UserOption {
     workload_group: Option<String>,
     disabled: Option<bool>,
     must_change_password: Option<bool>,
+    /// PEM encoded public keys for key-pair authentication.
+    /// Two slots are kept so that a key can be rotated without downtime.
+    rsa_public_key: Option<String>,
+    rsa_public_key_2: Option<String>,
 }
 
 impl UserOption {
@@ -206,6 +210,8 @@ impl UserOption {
             workload_group: None,
             disabled: None,
             must_change_password: None,
+            rsa_public_key: None,
+            rsa_public_key_2: None,
         }
     }
 
@@ -248,6 +254,16 @@ impl UserOption {
         self
     }
 
+    pub fn with_rsa_public_key(mut self, rsa_public_key: Option<String>) -> Self {
+        self.rsa_public_key = rsa_public_key;
+        self
+    }
+
+    pub fn with_rsa_public_key_2(mut self, rsa_public_key_2: Option<String>) -> Self {
+        self.rsa_public_key_2 = rsa_public_key_2;
+        self
+    }
+
     pub fn with_set_flag(mut self, flag: UserOptionFlag) -> Self {
         self.flags.insert(flag);
         self
@@ -282,6 +298,21 @@ impl UserOption {
         self.must_change_password.as_ref()
     }
 
+    pub fn rsa_public_key(&self) -> Option<&String> {
+        self.rsa_public_key.as_ref()
+    }
+
+    pub fn rsa_public_key_2(&self) -> Option<&String> {
+        self.rsa_public_key_2.as_ref()
+    }
+
+    /// All the public keys set on the user.
+    pub fn rsa_public_keys(&self) -> impl Iterator<Item = &String> {
+        self.rsa_public_key
+            .iter()
+            .chain(self.rsa_public_key_2.iter())
+    }
+
     pub fn set_default_role(&mut self, default_role: Option<String>) {
         self.default_role = default_role;
     }
@@ -344,6 +375,10 @@ impl UserOption {
             UserOptionItem::MustChangePassword(v) => self.must_change_password = Some(*v),
             UserOptionItem::SetWorkloadGroup(v) => self.workload_group = Some(v.clone()),
             UserOptionItem::UnsetWorkloadGroup => self.workload_group = None,
+            UserOptionItem::SetRsaPublicKey(v) => self.rsa_public_key = Some(v.clone()),
+            UserOptionItem::UnsetRsaPublicKey => self.rsa_public_key = None,
+            UserOptionItem::SetRsaPublicKey2(v) => self.rsa_public_key_2 = Some(v.clone()),
+            UserOptionItem::UnsetRsaPublicKey2 => self.rsa_public_key_2 = None,
         }
     }
 }
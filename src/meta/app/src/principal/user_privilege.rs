@@ -277,6 +277,11 @@ impl UserPrivilegeSet {
         }
     }
 
+    /// The privileges which can be granted on a column, columns have no ownership.
+    pub fn available_privileges_on_column() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Select | Update }).into()
+    }
+
     pub fn available_privileges_on_stage(available_ownership: bool) -> Self {
         if available_ownership {
             make_bitflags!(UserPrivilegeType::{  Read | Write | Ownership }).into()
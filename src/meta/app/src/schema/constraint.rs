@@ -12,21 +12,80 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::ColumnId;
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
 pub enum Constraint {
     Check(String),
+    PrimaryKey(KeyConstraint),
+    Unique(KeyConstraint),
+    ForeignKey(ForeignKeyConstraint),
+}
+
+/// A primary key or unique constraint.
+///
+/// Key constraints are not enforced on write. When `rely` is set, the
+/// optimizer is allowed to assume the key columns are unique.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct KeyConstraint {
+    pub column_ids: Vec<ColumnId>,
+    pub rely: bool,
+}
+
+/// A foreign key referencing a primary key or unique constraint of another table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ForeignKeyConstraint {
+    pub column_ids: Vec<ColumnId>,
+    pub ref_database_id: u64,
+    pub ref_table_id: u64,
+    pub ref_column_ids: Vec<ColumnId>,
+    pub rely: bool,
 }
 
 impl Constraint {
     pub fn type_name(&self) -> &'static str {
         match self {
             Constraint::Check(_) => "check",
+            Constraint::PrimaryKey(_) => "primary key",
+            Constraint::Unique(_) => "unique",
+            Constraint::ForeignKey(_) => "foreign key",
+        }
+    }
+
+    /// The check expression, `None` for key constraints.
+    pub fn check_expr(&self) -> Option<&str> {
+        match self {
+            Constraint::Check(expr) => Some(expr),
+            _ => None,
+        }
+    }
+
+    /// Columns covered by a key constraint, empty for check constraints.
+    pub fn key_column_ids(&self) -> &[ColumnId] {
+        match self {
+            Constraint::Check(_) => &[],
+            Constraint::PrimaryKey(key) | Constraint::Unique(key) => &key.column_ids,
+            Constraint::ForeignKey(fk) => &fk.column_ids,
+        }
+    }
+
+    /// Whether the optimizer may rely on this constraint.
+    pub fn rely(&self) -> bool {
+        match self {
+            Constraint::Check(_) => false,
+            Constraint::PrimaryKey(key) | Constraint::Unique(key) => key.rely,
+            Constraint::ForeignKey(fk) => fk.rely,
         }
     }
 
-    pub fn expr(&self) -> String {
+    /// Returns the key columns if this is a primary key or unique constraint
+    /// the optimizer may rely on.
+    pub fn unique_key(&self) -> Option<&[ColumnId]> {
         match self {
-            Constraint::Check(expr) => expr.clone(),
+            Constraint::PrimaryKey(key) | Constraint::Unique(key) if key.rely => {
+                Some(&key.column_ids)
+            }
+            _ => None,
         }
     }
 }
@@ -60,6 +60,8 @@ pub use catalog_id_ident::CatalogIdIdent;
 pub use catalog_id_to_name_ident::CatalogIdToNameIdent;
 pub use catalog_name_ident::CatalogNameIdent;
 pub use constraint::Constraint;
+pub use constraint::ForeignKeyConstraint;
+pub use constraint::KeyConstraint;
 pub use create_option::CreateOption;
 pub use database::CreateDatabaseReply;
 pub use database::CreateDatabaseReq;
@@ -117,6 +119,7 @@ pub use table::ListDroppedTableReq;
 pub use table::ListDroppedTableResp;
 pub use table::ListTableCopiedFileReply;
 pub use table::ListTableReq;
+pub use table::MultiColumnStatisticsDef;
 pub use table::RenameTableReply;
 pub use table::RenameTableReq;
 pub use table::SecurityPolicyColumnMap;
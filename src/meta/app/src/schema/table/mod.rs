@@ -184,6 +184,9 @@ pub struct TableMeta {
     pub row_access_policy_columns_ids: Option<SecurityPolicyColumnMap>,
     pub indexes: BTreeMap<String, TableIndex>,
     pub constraints: BTreeMap<String, Constraint>,
+    /// Column groups to collect multi-column statistics for on `ANALYZE`,
+    /// keyed by statistics name.
+    pub multi_column_statistics: BTreeMap<String, MultiColumnStatisticsDef>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -233,6 +236,12 @@ pub struct TableIndex {
     pub options: BTreeMap<String, String>,
 }
 
+/// A column group created by `CREATE STATISTICS`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MultiColumnStatisticsDef {
+    pub column_ids: Vec<ColumnId>,
+}
+
 impl TableInfo {
     /// Create a TableInfo with only db, table, schema
     pub fn simple(db: &str, table: &str, schema: Arc<TableSchema>) -> TableInfo {
@@ -359,6 +368,7 @@ impl Default for TableMeta {
             row_access_policy_columns_ids: None,
             indexes: BTreeMap::new(),
             constraints: BTreeMap::new(),
+            multi_column_statistics: BTreeMap::new(),
         }
     }
 }
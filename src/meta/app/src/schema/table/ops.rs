@@ -22,6 +22,7 @@ use databend_common_expression::ColumnId;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::TableField;
 
+use super::MultiColumnStatisticsDef;
 use super::TableMeta;
 use crate::schema::constraint::Constraint;
 
@@ -71,6 +72,18 @@ impl TableMeta {
                 constraint_name
             )));
         }
+        if matches!(constraint, Constraint::PrimaryKey(_)) {
+            if let Some((name, _)) = self
+                .constraints
+                .iter()
+                .find(|(_, c)| matches!(c, Constraint::PrimaryKey(_)))
+            {
+                return Err(ErrorCode::AlterTableError(format!(
+                    "table already has primary key constraint {}",
+                    name
+                )));
+            }
+        }
         self.constraints.insert(constraint_name, constraint);
         Ok(())
     }
@@ -85,6 +98,31 @@ impl TableMeta {
         Ok(())
     }
 
+    pub fn add_multi_column_statistics(
+        &mut self,
+        name: String,
+        def: MultiColumnStatisticsDef,
+    ) -> Result<()> {
+        if self.multi_column_statistics.contains_key(&name) {
+            return Err(ErrorCode::AlterTableError(format!(
+                "statistics {} already exists",
+                name
+            )));
+        }
+        self.multi_column_statistics.insert(name, def);
+        Ok(())
+    }
+
+    pub fn drop_multi_column_statistics(&mut self, name: &str) -> Result<()> {
+        if self.multi_column_statistics.remove(name).is_none() {
+            return Err(ErrorCode::AlterTableError(format!(
+                "statistics {} not exists",
+                name
+            )));
+        };
+        Ok(())
+    }
+
     /// Check if a column reference a security policy.
     pub fn is_column_reference_policy(&self, column_id: &ColumnId) -> bool {
         self.column_mask_policy_columns_ids.contains_key(column_id)
@@ -106,6 +144,7 @@ mod tests {
     use databend_common_expression::TableSchema;
 
     use super::*;
+    use crate::schema::constraint::KeyConstraint;
 
     fn create_test_table_meta() -> TableMeta {
         let schema = TableSchema::new(vec![
@@ -202,6 +241,25 @@ mod tests {
         assert_eq!(err.code(), ErrorCode::ALTER_TABLE_ERROR);
     }
 
+    #[test]
+    fn test_add_second_primary_key() {
+        let mut meta = create_test_table_meta();
+        let pk = |column_ids| {
+            Constraint::PrimaryKey(KeyConstraint {
+                column_ids,
+                rely: true,
+            })
+        };
+
+        meta.add_constraint("pk_id".to_string(), pk(vec![0]))
+            .unwrap();
+        let result = meta.add_constraint("pk_name".to_string(), pk(vec![1]));
+
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ALTER_TABLE_ERROR);
+    }
+
     #[test]
     fn test_drop_constraint() {
         let mut meta = create_test_table_meta();
@@ -215,6 +273,26 @@ mod tests {
         assert_eq!(meta.constraints.len(), 0);
     }
 
+    #[test]
+    fn test_multi_column_statistics() {
+        let mut meta = create_test_table_meta();
+        let def = MultiColumnStatisticsDef {
+            column_ids: vec![0, 1],
+        };
+
+        meta.add_multi_column_statistics("s1".to_string(), def.clone())
+            .unwrap();
+        let err = meta
+            .add_multi_column_statistics("s1".to_string(), def)
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ALTER_TABLE_ERROR);
+
+        meta.drop_multi_column_statistics("s1").unwrap();
+        assert!(meta.multi_column_statistics.is_empty());
+        let err = meta.drop_multi_column_statistics("s1").unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ALTER_TABLE_ERROR);
+    }
+
     #[test]
     fn test_drop_nonexistent_constraint() {
         let mut meta = create_test_table_meta();
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod object_tag_ident;
+mod tag_id_ident;
+pub mod tag_name_ident;
+
+use chrono::DateTime;
+use chrono::Utc;
+pub use object_tag_ident::ObjectTagId;
+pub use object_tag_ident::ObjectTagIdIdent;
+pub use object_tag_ident::TaggableObject;
+pub use tag_id_ident::TagId;
+pub use tag_id_ident::TagIdIdent;
+pub use tag_name_ident::TagNameIdent;
+
+use crate::schema::CreateOption;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagMeta {
+    /// The values this tag may be set to, `None` means any value is allowed.
+    pub allowed_values: Option<Vec<String>>,
+    pub comment: String,
+    /// The masking policy applied to the columns this tag is set on.
+    pub masking_policy_id: Option<u64>,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: Option<DateTime<Utc>>,
+}
+
+impl TagMeta {
+    pub fn is_allowed_value(&self, value: &str) -> bool {
+        match &self.allowed_values {
+            None => true,
+            Some(values) => values.iter().any(|v| v == value),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObjectTagValue {
+    pub tag_value: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTagReq {
+    pub create_option: CreateOption,
+    pub name: TagNameIdent,
+    pub meta: TagMeta,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateTagReply {
+    pub id: u64,
+}
+
+/// A tag set on an object, as listed by `system.tag_references`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagReference {
+    pub object: TaggableObject,
+    pub tag_id: u64,
+    pub tag_value: String,
+}
+
+/// A masking policy that reaches `object` through a tag set on it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TagMaskPolicy {
+    pub object: TaggableObject,
+    pub tag_id: u64,
+    pub policy_id: u64,
+}
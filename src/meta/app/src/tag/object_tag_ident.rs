@@ -0,0 +1,198 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_kvapi::kvapi::KeyBuilder;
+use databend_common_meta_kvapi::kvapi::KeyCodec;
+use databend_common_meta_kvapi::kvapi::KeyError;
+use databend_common_meta_kvapi::kvapi::KeyParser;
+
+use crate::tenant_key::ident::TIdent;
+use crate::tenant_key::raw::TIdentRaw;
+
+/// An object that a tag can be set on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaggableObject {
+    Database { db_id: u64 },
+    Table { table_id: u64 },
+    Column { table_id: u64, column_id: u32 },
+}
+
+impl TaggableObject {
+    pub fn domain(&self) -> &'static str {
+        match self {
+            TaggableObject::Database { .. } => "DATABASE",
+            TaggableObject::Table { .. } => "TABLE",
+            TaggableObject::Column { .. } => "COLUMN",
+        }
+    }
+}
+
+impl KeyCodec for TaggableObject {
+    fn encode_key(&self, b: KeyBuilder) -> KeyBuilder {
+        match self {
+            TaggableObject::Database { db_id } => b.push_raw("database").push_u64(*db_id),
+            TaggableObject::Table { table_id } => b.push_raw("table").push_u64(*table_id),
+            TaggableObject::Column {
+                table_id,
+                column_id,
+            } => b
+                .push_raw("column")
+                .push_u64(*table_id)
+                .push_u64(*column_id as u64),
+        }
+    }
+
+    fn decode_key(parser: &mut KeyParser) -> Result<Self, KeyError>
+    where Self: Sized {
+        let kind = parser.next_raw()?;
+        let i = parser.index();
+        match kind {
+            "database" => Ok(TaggableObject::Database {
+                db_id: parser.next_u64()?,
+            }),
+            "table" => Ok(TaggableObject::Table {
+                table_id: parser.next_u64()?,
+            }),
+            "column" => {
+                let table_id = parser.next_u64()?;
+                let column_id = parser.next_u64()?;
+                let column_id = u32::try_from(column_id).map_err(|e| KeyError::InvalidId {
+                    s: column_id.to_string(),
+                    reason: e.to_string(),
+                })?;
+                Ok(TaggableObject::Column {
+                    table_id,
+                    column_id,
+                })
+            }
+            _ => Err(KeyError::InvalidSegment {
+                i,
+                expect: "database|table|column".to_string(),
+                got: kind.to_string(),
+            }),
+        }
+    }
+}
+
+/// The tag `tag_id` set on `object`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjectTagId {
+    pub object: TaggableObject,
+    pub tag_id: u64,
+}
+
+impl KeyCodec for ObjectTagId {
+    fn encode_key(&self, b: KeyBuilder) -> KeyBuilder {
+        self.object.encode_key(b).push_u64(self.tag_id)
+    }
+
+    fn decode_key(parser: &mut KeyParser) -> Result<Self, KeyError>
+    where Self: Sized {
+        let object = TaggableObject::decode_key(parser)?;
+        let tag_id = parser.next_u64()?;
+        Ok(Self { object, tag_id })
+    }
+}
+
+/// Records the value of a tag set on an object:
+/// `__fd_object_tag/<tenant>/<object>/<tag_id> -> ObjectTagValue`.
+///
+/// The object comes first so that all tags of a database, a table or
+/// the columns of a table can be listed with a single prefix scan.
+pub type ObjectTagIdIdent = TIdent<Resource, ObjectTagId>;
+pub type ObjectTagIdIdentRaw = TIdentRaw<Resource, ObjectTagId>;
+
+pub use kvapi_impl::Resource;
+
+mod kvapi_impl {
+
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::tag::ObjectTagIdIdent;
+    use crate::tag::ObjectTagValue;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct Resource;
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_object_tag";
+        const TYPE: &'static str = "ObjectTagIdIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = ObjectTagValue;
+    }
+
+    impl kvapi::Value for ObjectTagValue {
+        type KeyType = ObjectTagIdIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::DirName;
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::tag::ObjectTagId;
+    use crate::tag::ObjectTagIdIdent;
+    use crate::tag::TaggableObject;
+    use crate::tenant::Tenant;
+
+    #[test]
+    fn test_ident() {
+        let tenant = Tenant::new_literal("tenant1");
+
+        for (object, want) in [
+            (
+                TaggableObject::Database { db_id: 1 },
+                "__fd_object_tag/tenant1/database/1/7",
+            ),
+            (
+                TaggableObject::Table { table_id: 2 },
+                "__fd_object_tag/tenant1/table/2/7",
+            ),
+            (
+                TaggableObject::Column {
+                    table_id: 2,
+                    column_id: 3,
+                },
+                "__fd_object_tag/tenant1/column/2/3/7",
+            ),
+        ] {
+            let ident =
+                ObjectTagIdIdent::new_generic(tenant.clone(), ObjectTagId { object, tag_id: 7 });
+            assert_eq!(want, ident.to_string_key());
+
+            let got = ObjectTagIdIdent::from_str_key(&ident.to_string_key()).unwrap();
+            assert_eq!(ident, got);
+        }
+
+        assert!(ObjectTagIdIdent::from_str_key("__fd_object_tag/tenant1/schema/2/7").is_err());
+    }
+
+    #[test]
+    fn test_dir_name() {
+        let tenant = Tenant::new_literal("tenant1");
+        let ident = ObjectTagIdIdent::new_generic(tenant, ObjectTagId {
+            object: TaggableObject::Column {
+                table_id: 2,
+                column_id: 3,
+            },
+            tag_id: 7,
+        });
+
+        let dir = DirName::new_with_level(ident, 2);
+        assert_eq!("__fd_object_tag/tenant1/column/2", dir.to_string_key());
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tenant_key::ident::TIdent;
+use crate::tenant_key::raw::TIdentRaw;
+
+pub type TagId = DataId<Resource>;
+
+pub type TagIdIdent = TIdent<Resource, TagId>;
+pub type TagIdIdentRaw = TIdentRaw<Resource, TagId>;
+
+pub use kvapi_impl::Resource;
+
+use crate::data_id::DataId;
+use crate::tenant::ToTenant;
+
+impl TagIdIdent {
+    pub fn new(tenant: impl ToTenant, tag_id: u64) -> Self {
+        Self::new_generic(tenant, TagId::new(tag_id))
+    }
+
+    pub fn tag_id(&self) -> TagId {
+        *self.name()
+    }
+}
+
+impl TagIdIdentRaw {
+    pub fn tag_id(&self) -> TagId {
+        *self.name()
+    }
+}
+
+mod kvapi_impl {
+
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::tag::TagIdIdent;
+    use crate::tag::TagMeta;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct Resource;
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_tag_by_id";
+        const TYPE: &'static str = "TagIdIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = TagMeta;
+    }
+
+    impl kvapi::Value for TagMeta {
+        type KeyType = TagIdIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use super::TagIdIdent;
+    use crate::tenant::Tenant;
+
+    #[test]
+    fn test_tag_id_ident() {
+        let tenant = Tenant::new_literal("tenant1");
+        let ident = TagIdIdent::new(tenant, 3);
+
+        let key = ident.to_string_key();
+        assert_eq!(key, "__fd_tag_by_id/tenant1/3");
+
+        assert_eq!(ident, TagIdIdent::from_str_key(&key).unwrap());
+    }
+}
@@ -107,6 +107,36 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::DatabaseById("default".into(), 1),
+            rhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::TableById("default".into(), 1, 2),
+            rhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::TableById("default".into(), 1, 3),
+            rhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            rhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            rhs: GrantObject::ColumnById("default".into(), 1, 2, 1),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::ColumnById("default".into(), 1, 2, 0),
+            rhs: GrantObject::TableById("default".into(), 1, 2),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
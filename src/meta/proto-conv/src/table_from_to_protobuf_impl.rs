@@ -210,6 +210,10 @@ impl FromToProto for mt::TableMeta {
         for (constraint_name, constraint) in p.constraints {
             constraints.insert(constraint_name, mt::Constraint::from_pb(constraint)?);
         }
+        let mut multi_column_statistics = BTreeMap::new();
+        for (name, def) in p.multi_column_statistics {
+            multi_column_statistics.insert(name, mt::MultiColumnStatisticsDef::from_pb(def)?);
+        }
         let v = Self {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
             engine: p.engine,
@@ -254,6 +258,7 @@ impl FromToProto for mt::TableMeta {
             indexes,
             virtual_schema,
             constraints,
+            multi_column_statistics,
         };
         Ok(v)
     }
@@ -267,6 +272,10 @@ impl FromToProto for mt::TableMeta {
         for (constraint_name, constraint) in &self.constraints {
             constraints.insert(constraint_name.clone(), constraint.to_pb()?);
         }
+        let mut multi_column_statistics = BTreeMap::new();
+        for (name, def) in &self.multi_column_statistics {
+            multi_column_statistics.insert(name.clone(), def.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -315,6 +324,7 @@ impl FromToProto for mt::TableMeta {
                 .map(VirtualDataSchema::to_pb)
                 .transpose()?,
             constraints,
+            multi_column_statistics,
         };
         Ok(p)
     }
@@ -365,12 +375,24 @@ impl FromToProto for mt::Constraint {
         })?;
         Ok(match constraint {
             pb::constraint::Constraint::Check(expr) => mt::Constraint::Check(expr),
+            pb::constraint::Constraint::PrimaryKey(key) => {
+                mt::Constraint::PrimaryKey(mt::KeyConstraint::from_pb(key)?)
+            }
+            pb::constraint::Constraint::Unique(key) => {
+                mt::Constraint::Unique(mt::KeyConstraint::from_pb(key)?)
+            }
+            pb::constraint::Constraint::ForeignKey(fk) => {
+                mt::Constraint::ForeignKey(mt::ForeignKeyConstraint::from_pb(fk)?)
+            }
         })
     }
 
     fn to_pb(&self) -> Result<Self::PB, Incompatible> {
         let constraint = match self {
             mt::Constraint::Check(expr) => pb::constraint::Constraint::Check(expr.clone()),
+            mt::Constraint::PrimaryKey(key) => pb::constraint::Constraint::PrimaryKey(key.to_pb()?),
+            mt::Constraint::Unique(key) => pb::constraint::Constraint::Unique(key.to_pb()?),
+            mt::Constraint::ForeignKey(fk) => pb::constraint::Constraint::ForeignKey(fk.to_pb()?),
         };
         Ok(pb::Constraint {
             ver: VER,
@@ -380,6 +402,91 @@ impl FromToProto for mt::Constraint {
     }
 }
 
+impl FromToProto for mt::KeyConstraint {
+    type PB = pb::KeyConstraint;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::KeyConstraint {
+            column_ids: p.column_ids,
+            rely: p.rely,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::KeyConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_ids: self.column_ids.clone(),
+            rely: self.rely,
+        })
+    }
+}
+
+impl FromToProto for mt::ForeignKeyConstraint {
+    type PB = pb::ForeignKeyConstraint;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::ForeignKeyConstraint {
+            column_ids: p.column_ids,
+            ref_database_id: p.ref_database_id,
+            ref_table_id: p.ref_table_id,
+            ref_column_ids: p.ref_column_ids,
+            rely: p.rely,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::ForeignKeyConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_ids: self.column_ids.clone(),
+            ref_database_id: self.ref_database_id,
+            ref_table_id: self.ref_table_id,
+            ref_column_ids: self.ref_column_ids.clone(),
+            rely: self.rely,
+        })
+    }
+}
+
+impl FromToProto for mt::MultiColumnStatisticsDef {
+    type PB = pb::MultiColumnStatisticsDef;
+
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(mt::MultiColumnStatisticsDef {
+            column_ids: p.column_ids,
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(pb::MultiColumnStatisticsDef {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            column_ids: self.column_ids.clone(),
+        })
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
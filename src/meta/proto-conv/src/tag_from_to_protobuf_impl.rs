@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::tag as mt;
+use databend_common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::TagMeta {
+    type PB = pb::TagMeta;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TagMeta) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            allowed_values: if p.allowed_values.is_empty() {
+                None
+            } else {
+                Some(p.allowed_values)
+            },
+            comment: p.comment,
+            masking_policy_id: p.masking_policy_id,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+            updated_on: match p.updated_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TagMeta, Incompatible> {
+        let p = pb::TagMeta {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            allowed_values: self.allowed_values.clone().unwrap_or_default(),
+            comment: self.comment.clone(),
+            masking_policy_id: self.masking_policy_id,
+            created_on: self.created_on.to_pb()?,
+            updated_on: match &self.updated_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::ObjectTagValue {
+    type PB = pb::ObjectTagValue;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ObjectTagValue) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        Ok(Self {
+            tag_value: p.tag_value,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::ObjectTagValue, Incompatible> {
+        Ok(pb::ObjectTagValue {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            tag_value: self.tag_value.clone(),
+        })
+    }
+}
@@ -47,6 +47,12 @@ impl FromToProto for mt::principal::AuthInfo {
             Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})) => {
                 Ok(mt::principal::AuthInfo::JWT)
             }
+            Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {})) => {
+                Ok(mt::principal::AuthInfo::KeyPair)
+            }
+            Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {})) => {
+                Ok(mt::principal::AuthInfo::Ldap)
+            }
             Some(pb::auth_info::Info::Password(pb::auth_info::Password {
                 hash_value,
                 hash_method,
@@ -68,6 +74,12 @@ impl FromToProto for mt::principal::AuthInfo {
                 Some(pb::auth_info::Info::None(pb::auth_info::None {}))
             }
             mt::principal::AuthInfo::JWT => Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})),
+            mt::principal::AuthInfo::KeyPair => {
+                Some(pb::auth_info::Info::KeyPair(pb::auth_info::KeyPair {}))
+            }
+            mt::principal::AuthInfo::Ldap => {
+                Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {}))
+            }
             mt::principal::AuthInfo::Password {
                 hash_value,
                 hash_method,
@@ -105,7 +117,9 @@ impl FromToProto for mt::principal::UserOption {
             .with_password_policy(p.password_policy)
             .with_workload_group(p.workload_group)
             .with_disabled(p.disabled)
-            .with_must_change_password(p.must_change_password))
+            .with_must_change_password(p.must_change_password)
+            .with_rsa_public_key(p.rsa_public_key)
+            .with_rsa_public_key_2(p.rsa_public_key_2))
     }
 
     fn to_pb(&self) -> Result<pb::UserOption, Incompatible> {
@@ -119,6 +133,8 @@ impl FromToProto for mt::principal::UserOption {
             workload_group: self.workload_group().cloned(),
             disabled: self.disabled().cloned(),
             must_change_password: self.must_change_password().cloned(),
+            rsa_public_key: self.rsa_public_key().cloned(),
+            rsa_public_key_2: self.rsa_public_key_2().cloned(),
         })
     }
 }
@@ -185,6 +201,14 @@ impl FromToProto for mt::principal::GrantObject {
                 db,
                 table,
             }) => Ok(mt::principal::GrantObject::TableById(catalog, db, table)),
+            pb::grant_object::Object::Columnbyid(pb::grant_object::GrantColumnIdObject {
+                catalog,
+                db,
+                table,
+                column,
+            }) => Ok(mt::principal::GrantObject::ColumnById(
+                catalog, db, table, column,
+            )),
             pb::grant_object::Object::Udf(pb::grant_object::GrantUdfObject { udf }) => {
                 Ok(mt::principal::GrantObject::UDF(udf))
             }
@@ -237,6 +261,14 @@ impl FromToProto for mt::principal::GrantObject {
                     table: *table,
                 }),
             ),
+            mt::principal::GrantObject::ColumnById(catalog, db, table, column) => Some(
+                pb::grant_object::Object::Columnbyid(pb::grant_object::GrantColumnIdObject {
+                    catalog: catalog.clone(),
+                    db: *db,
+                    table: *table,
+                    column: *column,
+                }),
+            ),
             mt::principal::GrantObject::UDF(udf) => Some(pb::grant_object::Object::Udf(
                 pb::grant_object::GrantUdfObject { udf: udf.clone() },
             )),
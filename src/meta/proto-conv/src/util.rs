@@ -187,6 +187,12 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (155, "2025-10-24: Add: RowAccessPolicyMeta::RowAccessPolicyArg"),
     (156, "2025-10-22: Add: DataMaskMeta add DataMaskArg"),
     (157, "2025-10-22: Add: TableDataType TimestampTz"),
+    (158, "2025-10-28: Add: GrantColumnIdObject"),
+    (159, "2025-10-30: Add: AuthInfo::KeyPair, UserOption::rsa_public_key and rsa_public_key_2"),
+    (160, "2025-11-03: Add: AuthInfo::Ldap"),
+    (161, "2025-11-05: Add: tag.proto/TagMeta, ObjectTagValue"),
+    (162, "2025-11-07: Add: table.proto/KeyConstraint, ForeignKeyConstraint"),
+    (163, "2025-11-10: Add: table.proto/MultiColumnStatisticsDef, TableMeta::multi_column_statistics"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -149,3 +149,9 @@ mod v154_vacuum_watermark;
 mod v155_row_access_policy_args;
 mod v156_data_mask_args;
 mod v157_type_timestamp_tz;
+mod v158_grant_object_column;
+mod v159_user_key_pair;
+mod v160_auth_info_ldap;
+mod v161_tag_meta;
+mod v162_key_constraint;
+mod v163_multi_column_statistics;
@@ -220,6 +220,7 @@ fn test_decode_v80_table_meta() -> anyhow::Result<()> {
         indexes: btreemap! {},
         row_access_policy_columns_ids: None,
         constraints: btreemap! {},
+        multi_column_statistics: btreemap! {},
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), table_meta_v80.as_slice(), 80, want())?;
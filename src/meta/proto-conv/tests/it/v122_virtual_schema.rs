@@ -175,6 +175,7 @@ fn test_decode_v122_table_meta() -> anyhow::Result<()> {
         row_access_policy_columns_ids: None,
         indexes: btreemap! {},
         constraints: btreemap! {},
+        multi_column_statistics: btreemap! {},
     };
     common::test_load_old(func_name!(), table_meta_v122.as_slice(), 122, want())?;
     common::test_pb_from_to(func_name!(), want())?;
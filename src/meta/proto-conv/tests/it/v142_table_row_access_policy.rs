@@ -56,6 +56,7 @@ fn test_decode_v142_table_meta() -> anyhow::Result<()> {
         row_access_policy_columns_ids: None,
         indexes: BTreeMap::default(),
         constraints: BTreeMap::default(),
+        multi_column_statistics: BTreeMap::default(),
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), table_meta_v142.as_slice(), 142, want())?;
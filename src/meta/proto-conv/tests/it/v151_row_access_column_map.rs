@@ -60,6 +60,7 @@ fn test_decode_v151_table_meta() -> anyhow::Result<()> {
         }),
         indexes: BTreeMap::default(),
         constraints: BTreeMap::default(),
+        multi_column_statistics: BTreeMap::default(),
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), table_meta_v151.as_slice(), 151, want())?;
@@ -0,0 +1,56 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v159_user_option() -> anyhow::Result<()> {
+    let user_option_v159 = vec![
+        8, 1, 18, 5, 114, 111, 108, 101, 49, 66, 4, 107, 101, 121, 49, 74, 4, 107, 101, 121, 50,
+        160, 6, 159, 1, 168, 6, 24,
+    ];
+
+    let want = || {
+        databend_common_meta_app::principal::UserOption::default()
+            .with_set_flag(databend_common_meta_app::principal::UserOptionFlag::TenantSetting)
+            .with_default_role(Some("role1".into()))
+            .with_rsa_public_key(Some("key1".to_string()))
+            .with_rsa_public_key_2(Some("key2".to_string()))
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), user_option_v159.as_slice(), 159, want())
+}
+
+#[test]
+fn test_decode_v159_auth_info() -> anyhow::Result<()> {
+    let auth_info_v159 = vec![34, 0, 160, 6, 159, 1, 168, 6, 24];
+
+    let want = || databend_common_meta_app::principal::AuthInfo::KeyPair;
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), auth_info_v159.as_slice(), 159, want())
+}
@@ -0,0 +1,62 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v161_tag_meta() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 5, 101, 109, 97, 105, 108, 10, 5, 112, 104, 111, 110, 101, 18, 13, 112, 101, 114, 115,
+        111, 110, 97, 108, 32, 100, 97, 116, 97, 24, 5, 34, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 42, 23, 50, 48, 49, 52, 45, 49, 49,
+        45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 161, 1, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::tag::TagMeta {
+        allowed_values: Some(vec!["email".to_string(), "phone".to_string()]),
+        comment: "personal data".to_string(),
+        masking_policy_id: Some(5),
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 161, want())
+}
+
+#[test]
+fn test_decode_v161_object_tag_value() -> anyhow::Result<()> {
+    let bytes = vec![10, 5, 101, 109, 97, 105, 108, 160, 6, 161, 1, 168, 6, 24];
+
+    let want = || databend_common_meta_app::tag::ObjectTagValue {
+        tag_value: "email".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 161, want())
+}
@@ -0,0 +1,103 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_expression as ce;
+use databend_common_meta_app::schema as mt;
+use databend_common_meta_app::schema::Constraint;
+use fastrace::func_name;
+use maplit::btreemap;
+use maplit::btreeset;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v162_key_constraint() -> anyhow::Result<()> {
+    let table_meta_v162 = vec![
+        10, 7, 160, 6, 162, 1, 168, 6, 24, 64, 0, 162, 1, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+        56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 170, 1, 23, 50, 48, 49, 52, 45, 49,
+        49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 49, 48, 32, 85, 84, 67, 186, 1, 7, 160, 6, 162,
+        1, 168, 6, 24, 226, 1, 1, 1, 146, 2, 22, 10, 2, 99, 107, 18, 16, 18, 7, 99, 49, 32, 62, 32,
+        49, 48, 160, 6, 162, 1, 168, 6, 24, 146, 2, 34, 10, 2, 102, 107, 18, 28, 42, 19, 10, 1, 1,
+        16, 1, 24, 2, 34, 1, 0, 40, 1, 160, 6, 162, 1, 168, 6, 24, 160, 6, 162, 1, 168, 6, 24, 146,
+        2, 27, 10, 2, 112, 107, 18, 21, 26, 12, 10, 1, 0, 16, 1, 160, 6, 162, 1, 168, 6, 24, 160,
+        6, 162, 1, 168, 6, 24, 146, 2, 26, 10, 2, 117, 107, 18, 20, 34, 11, 10, 2, 1, 2, 160, 6,
+        162, 1, 168, 6, 24, 160, 6, 162, 1, 168, 6, 24, 160, 6, 162, 1, 168, 6, 24,
+    ];
+
+    let want = || mt::TableMeta {
+        schema: Arc::new(ce::TableSchema::default()),
+        engine: s(""),
+        storage_params: None,
+        part_prefix: s(""),
+        engine_options: BTreeMap::default(),
+        options: BTreeMap::default(),
+        cluster_key: None,
+        cluster_key_seq: 0,
+        created_on: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_on: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 10).unwrap(),
+        comment: s(""),
+        field_comments: vec![],
+        virtual_schema: None,
+        drop_on: None,
+        statistics: Default::default(),
+        shared_by: btreeset! {1},
+        column_mask_policy: None,
+        column_mask_policy_columns_ids: BTreeMap::new(),
+        row_access_policy: None,
+        row_access_policy_columns_ids: None,
+        indexes: BTreeMap::default(),
+        constraints: btreemap! {
+            "ck".to_string() => Constraint::Check("c1 > 10".to_string()),
+            "fk".to_string() => Constraint::ForeignKey(mt::ForeignKeyConstraint {
+                column_ids: vec![1],
+                ref_database_id: 1,
+                ref_table_id: 2,
+                ref_column_ids: vec![0],
+                rely: true,
+            }),
+            "pk".to_string() => Constraint::PrimaryKey(mt::KeyConstraint {
+                column_ids: vec![0],
+                rely: true,
+            }),
+            "uk".to_string() => Constraint::Unique(mt::KeyConstraint {
+                column_ids: vec![1, 2],
+                rely: false,
+            }),
+        },
+        multi_column_statistics: BTreeMap::default(),
+    };
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), table_meta_v162.as_slice(), 162, want())?;
+
+    Ok(())
+}
+
+fn s(ss: impl ToString) -> String {
+    ss.to_string()
+}
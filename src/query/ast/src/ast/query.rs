@@ -571,6 +571,8 @@ pub enum TimeTravelPoint {
         database: Option<Identifier>,
         name: Identifier,
     },
+    Branch(Identifier),
+    Tag(Identifier),
 }
 
 impl Display for TimeTravelPoint {
@@ -597,6 +599,12 @@ impl Display for TimeTravelPoint {
                 )?;
                 write!(f, ")")?;
             }
+            TimeTravelPoint::Branch(name) => {
+                write!(f, "(BRANCH => {name})")?;
+            }
+            TimeTravelPoint::Tag(name) => {
+                write!(f, "(TAG => {name})")?;
+            }
         }
 
         Ok(())
@@ -18,11 +18,36 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::Expr;
+use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum ConstraintType {
     Check(Expr),
+    /// Informational key constraints: they are never enforced on write,
+    /// but the optimizer may rely on them when `rely` is set.
+    PrimaryKey {
+        columns: Vec<Identifier>,
+        rely: bool,
+    },
+    Unique {
+        columns: Vec<Identifier>,
+        rely: bool,
+    },
+    ForeignKey {
+        columns: Vec<Identifier>,
+        ref_database: Option<Identifier>,
+        ref_table: Identifier,
+        ref_columns: Vec<Identifier>,
+        rely: bool,
+    },
+}
+
+impl ConstraintType {
+    pub fn is_key(&self) -> bool {
+        !matches!(self, ConstraintType::Check(_))
+    }
 }
 
 impl Display for ConstraintType {
@@ -31,6 +56,44 @@ impl Display for ConstraintType {
             ConstraintType::Check(expr) => {
                 write!(f, "CHECK ({})", expr)
             }
+            ConstraintType::PrimaryKey { columns, rely } => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") NOT ENFORCED")?;
+                write_rely(f, *rely)
+            }
+            ConstraintType::Unique { columns, rely } => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") NOT ENFORCED")?;
+                write_rely(f, *rely)
+            }
+            ConstraintType::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+                rely,
+            } => {
+                write!(f, "FOREIGN KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") REFERENCES ")?;
+                if let Some(ref_database) = ref_database {
+                    write!(f, "{ref_database}.")?;
+                }
+                write!(f, "{ref_table} (")?;
+                write_comma_separated_list(f, ref_columns)?;
+                write!(f, ") NOT ENFORCED")?;
+                write_rely(f, *rely)
+            }
         }
     }
 }
+
+fn write_rely(f: &mut Formatter, rely: bool) -> std::fmt::Result {
+    if rely {
+        write!(f, " RELY")
+    } else {
+        write!(f, " NORELY")
+    }
+}
@@ -19,10 +19,12 @@ use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use crate::ast::statements::show::ShowLimit;
+use crate::ast::write_comma_separated_list;
 use crate::ast::write_dot_separated_list;
 use crate::ast::CreateOption;
 use crate::ast::DatabaseRef;
 use crate::ast::Identifier;
+use crate::ast::TagSetItem;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct ShowDatabasesStmt {
@@ -90,6 +92,8 @@ pub struct CreateDatabaseStmt {
     pub database: DatabaseRef,
     pub engine: Option<DatabaseEngine>,
     pub options: Vec<SQLProperty>,
+    /// Clone all the tables of another database with `CLONE`.
+    pub clone_from: Option<DatabaseRef>,
 }
 
 impl Display for CreateDatabaseStmt {
@@ -105,6 +109,10 @@ impl Display for CreateDatabaseStmt {
 
         write!(f, "{}", self.database)?;
 
+        if let Some(clone_from) = &self.clone_from {
+            write!(f, " CLONE {clone_from}")?;
+        }
+
         if let Some(engine) = &self.engine {
             write!(f, " ENGINE = {engine}")?;
         }
@@ -169,6 +177,14 @@ impl Display for AlterDatabaseStmt {
             AlterDatabaseAction::RefreshDatabaseCache => {
                 write!(f, " REFRESH CACHE")?;
             }
+            AlterDatabaseAction::SetTags { tags } => {
+                write!(f, " SET TAG ")?;
+                write_comma_separated_list(f, tags)?;
+            }
+            AlterDatabaseAction::UnsetTags { tags } => {
+                write!(f, " UNSET TAG ")?;
+                write_comma_separated_list(f, tags)?;
+            }
         }
 
         Ok(())
@@ -179,6 +195,8 @@ impl Display for AlterDatabaseStmt {
 pub enum AlterDatabaseAction {
     RenameDatabase { new_db: Identifier },
     RefreshDatabaseCache,
+    SetTags { tags: Vec<TagSetItem> },
+    UnsetTags { tags: Vec<Identifier> },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
@@ -24,6 +24,7 @@ use crate::ast::Identifier;
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct Hint {
     pub hints_list: Vec<HintItem>,
+    pub optimizer_hints: Vec<OptimizerHint>,
 }
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -32,6 +33,44 @@ pub struct HintItem {
     pub expr: Expr,
 }
 
+/// Hints that steer join ordering and join execution, e.g. `/*+ LEADING(a b) BROADCAST(b) */`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub enum OptimizerHint {
+    /// Join the listed tables first, in the given order.
+    Leading(Vec<Identifier>),
+    /// Broadcast the listed tables as the build side of their joins.
+    Broadcast(Vec<Identifier>),
+    /// Hash shuffle both sides of the joins that involve the listed tables.
+    Shuffle(Vec<Identifier>),
+    /// Use hash join for the join between the listed tables.
+    HashJoin(Vec<Identifier>),
+    /// Do not push runtime filters into scans of the listed tables.
+    NoRuntimeFilter(Vec<Identifier>),
+    /// Do not rewrite subqueries into semi/anti joins.
+    NoDecorrelate,
+}
+
+impl Display for OptimizerHint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let (name, tables) = match self {
+            OptimizerHint::Leading(tables) => ("LEADING", tables),
+            OptimizerHint::Broadcast(tables) => ("BROADCAST", tables),
+            OptimizerHint::Shuffle(tables) => ("SHUFFLE", tables),
+            OptimizerHint::HashJoin(tables) => ("HASH_JOIN", tables),
+            OptimizerHint::NoRuntimeFilter(tables) => ("NO_RUNTIME_FILTER", tables),
+            OptimizerHint::NoDecorrelate => return write!(f, "NO_DECORRELATE"),
+        };
+        write!(f, "{name}(")?;
+        for (i, table) in tables.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{table}")?;
+        }
+        write!(f, ")")
+    }
+}
+
 impl Display for Hint {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "/*+ ")?;
@@ -42,6 +81,9 @@ impl Display for Hint {
             write!(f, "{}", hint.expr)?;
             write!(f, ")")?;
         }
+        for hint in &self.optimizer_hints {
+            write!(f, "{hint} ")?;
+        }
         write!(f, "*/")
     }
 }
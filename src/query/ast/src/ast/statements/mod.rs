@@ -35,6 +35,7 @@ mod network_policy;
 mod notification;
 pub(crate) mod password_policy;
 mod pipe;
+mod plan_baseline;
 mod presign;
 mod principal;
 mod priority;
@@ -52,6 +53,7 @@ mod statement;
 mod stream;
 mod system_action;
 mod table;
+mod tag;
 mod task;
 mod udf;
 mod update;
@@ -84,6 +86,7 @@ pub use network_policy::*;
 pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
+pub use plan_baseline::*;
 pub use presign::*;
 pub use principal::*;
 pub use priority::*;
@@ -101,6 +104,7 @@ pub use statement::*;
 pub use stream::*;
 pub use system_action::*;
 pub use table::*;
+pub use tag::*;
 pub use task::*;
 pub use udf::*;
 pub use update::*;
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::quote::QuotedString;
+use crate::ast::CreateOption;
+use crate::ast::Query;
+
+// CREATE [ OR REPLACE ] PLAN BASELINE [ IF NOT EXISTS ] FOR <query>
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreatePlanBaselineStmt {
+    pub create_option: CreateOption,
+    pub query: Box<Query>,
+}
+
+impl Display for CreatePlanBaselineStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, " OR REPLACE")?;
+        }
+        write!(f, " PLAN BASELINE")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " FOR {}", self.query)
+    }
+}
+
+// DROP PLAN BASELINE [ IF EXISTS ] '<sql_hash>'
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct DropPlanBaselineStmt {
+    pub if_exists: bool,
+    pub sql_hash: String,
+}
+
+impl Display for DropPlanBaselineStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP PLAN BASELINE ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", QuotedString(&self.sql_hash, '\''))
+    }
+}
@@ -78,6 +78,8 @@ pub enum AuthType {
     Sha256Password,
     DoubleSha1Password,
     JWT,
+    KeyPair,
+    Ldap,
 }
 
 impl Display for AuthType {
@@ -87,6 +89,8 @@ impl Display for AuthType {
             AuthType::Sha256Password => "sha256_password",
             AuthType::DoubleSha1Password => "double_sha1_password",
             AuthType::JWT => "jwt",
+            AuthType::KeyPair => "key_pair",
+            AuthType::Ldap => "ldap",
         })
     }
 }
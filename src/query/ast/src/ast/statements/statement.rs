@@ -193,6 +193,8 @@ pub enum Statement {
     AnalyzeTable(AnalyzeTableStmt),
     ExistsTable(ExistsTableStmt),
     ShowStatistics(ShowStatisticsStmt),
+    CreateStatistics(CreateStatisticsStmt),
+    DropStatistics(DropStatisticsStmt),
 
     // Dictionaries
     CreateDictionary(CreateDictionaryStmt),
@@ -321,6 +323,15 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // tag
+    CreateTag(CreateTagStmt),
+    DropTag(DropTagStmt),
+    AlterTag(AlterTagStmt),
+
+    // plan baseline
+    CreatePlanBaseline(CreatePlanBaselineStmt),
+    DropPlanBaseline(DropPlanBaselineStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -427,6 +438,11 @@ impl Statement {
                 attach_clone.uri_location.connection = attach_clone.uri_location.connection.mask();
                 format!("{}", Statement::AttachTable(attach_clone))
             }
+            Statement::CreateConnection(conn) => {
+                let mut conn_clone = conn.clone();
+                conn_clone.storage_params = Connection::new(conn_clone.storage_params).mask().conns;
+                format!("{}", Statement::CreateConnection(conn_clone))
+            }
             _ => format!("{}", self),
         }
     }
@@ -562,6 +578,8 @@ impl Statement {
             | Statement::DropStream(..)
             | Statement::CreateTableIndex(..)
             | Statement::DropTableIndex(..)
+            | Statement::CreateStatistics(..)
+            | Statement::DropStatistics(..)
             | Statement::CreateUser(..)
             | Statement::DropUser { .. }
             | Statement::CreateRole { .. }
@@ -579,6 +597,11 @@ impl Statement {
             | Statement::DropFileFormat { .. }
             | Statement::CreateDatamaskPolicy(..)
             | Statement::DropDatamaskPolicy(..)
+            | Statement::CreateTag(..)
+            | Statement::DropTag(..)
+            | Statement::AlterTag(..)
+            | Statement::CreatePlanBaseline(..)
+            | Statement::DropPlanBaseline(..)
             | Statement::CreateNetworkPolicy(..)
             | Statement::AlterNetworkPolicy(..)
             | Statement::DropNetworkPolicy(..)
@@ -837,6 +860,8 @@ impl Display for Statement {
             Statement::VacuumTemporaryFiles(stmt) => write!(f, "{stmt}")?,
             Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStatistics(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStatistics(stmt) => write!(f, "{stmt}")?,
             Statement::CreateDictionary(stmt) => write!(f, "{stmt}")?,
             Statement::DropDictionary(stmt) => write!(f, "{stmt}")?,
             Statement::ShowCreateDictionary(stmt) => write!(f, "{stmt}")?,
@@ -1000,6 +1025,11 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateTag(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTag(stmt) => write!(f, "{stmt}")?,
+            Statement::AlterTag(stmt) => write!(f, "{stmt}")?,
+            Statement::CreatePlanBaseline(stmt) => write!(f, "{stmt}")?,
+            Statement::DropPlanBaseline(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -33,6 +33,7 @@ use crate::ast::Identifier;
 use crate::ast::Query;
 use crate::ast::TableIndexType;
 use crate::ast::TableReference;
+use crate::ast::TagSetItem;
 use crate::ast::TimeTravelPoint;
 use crate::ast::TypeName;
 use crate::ast::UriLocation;
@@ -186,6 +187,7 @@ pub struct CreateTableStmt {
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
     pub cluster_by: Option<ClusterOption>,
+    pub ttl: Option<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub iceberg_table_partition: Option<Vec<Identifier>>,
     pub table_properties: Option<BTreeMap<String, String>>,
@@ -240,6 +242,10 @@ impl Display for CreateTableStmt {
             write!(f, " {cluster_by}")?;
         }
 
+        if let Some(ttl) = &self.ttl {
+            write!(f, " TTL {ttl}")?;
+        }
+
         // Format table options
         if !self.table_options.is_empty() {
             write!(f, " ")?;
@@ -311,6 +317,13 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    /// Zero-copy clone of a fuse table, optionally at a historical point.
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -343,6 +356,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -472,6 +498,12 @@ pub enum AlterTableAction {
         policy: Identifier,
     },
     DropAllRowAccessPolicies,
+    SetTags {
+        tags: Vec<TagSetItem>,
+    },
+    UnsetTags {
+        tags: Vec<Identifier>,
+    },
     DropColumn {
         column: Identifier,
     },
@@ -479,6 +511,10 @@ pub enum AlterTableAction {
         cluster_by: ClusterOption,
     },
     DropTableClusterKey,
+    ModifyTableTTL {
+        ttl: Expr,
+    },
+    DropTableTTL,
     ReclusterTable {
         is_final: bool,
         selection: Option<Expr>,
@@ -497,6 +533,15 @@ pub enum AlterTableAction {
     ModifyConnection {
         new_connection: BTreeMap<String, String>,
     },
+    CreateTableRef {
+        kind: TableRefKind,
+        name: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropTableRef {
+        kind: TableRefKind,
+        name: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -544,6 +589,12 @@ impl Display for AlterTableAction {
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")?;
             }
+            AlterTableAction::ModifyTableTTL { ttl } => {
+                write!(f, "TTL {ttl}")?;
+            }
+            AlterTableAction::DropTableTTL => {
+                write!(f, "DROP TTL")?;
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -594,11 +645,45 @@ impl Display for AlterTableAction {
             AlterTableAction::DropAllRowAccessPolicies => {
                 write!(f, "DROP ALL ROW ACCESS POLICIES")?
             }
+            AlterTableAction::SetTags { tags } => {
+                write!(f, "SET TAG ")?;
+                write_comma_separated_list(f, tags)?
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                write!(f, "UNSET TAG ")?;
+                write_comma_separated_list(f, tags)?
+            }
+            AlterTableAction::CreateTableRef {
+                kind,
+                name,
+                travel_point,
+            } => {
+                write!(f, "CREATE {kind} {name}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+            }
+            AlterTableAction::DropTableRef { kind, name } => write!(f, "DROP {kind} {name}")?,
         };
         Ok(())
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum TableRefKind {
+    Branch,
+    Tag,
+}
+
+impl Display for TableRefKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableRefKind::Branch => write!(f, "BRANCH"),
+            TableRefKind::Tag => write!(f, "TAG"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum AddColumnOption {
     End,
@@ -800,6 +885,66 @@ impl Display for AnalyzeTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateStatisticsStmt {
+    pub create_option: CreateOption,
+    pub name: Identifier,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub columns: Vec<Identifier>,
+}
+
+impl Display for CreateStatisticsStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE ")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "STATISTICS ")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} ON ", self.name)?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropStatisticsStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for DropStatisticsStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP STATISTICS ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} ON ", self.name)?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct ExistsTableStmt {
     pub catalog: Option<Identifier>,
@@ -911,6 +1056,7 @@ pub enum OptimizeTableAction {
     All,
     Purge { before: Option<TimeTravelPoint> },
     Compact { target: CompactTarget },
+    Expire,
 }
 
 impl Display for OptimizeTableAction {
@@ -935,6 +1081,7 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
+            OptimizeTableAction::Expire => write!(f, "EXPIRE"),
         }
     }
 }
@@ -1120,6 +1267,10 @@ pub enum ModifyColumnAction {
     ConvertStoredComputedColumn(Identifier),
     // (column name id, new comment)
     Comment(Vec<ColumnComment>),
+    // (column name id, tags to set)
+    SetTags(Identifier, Vec<TagSetItem>),
+    // (column name id, tags to unset)
+    UnsetTags(Identifier, Vec<Identifier>),
 }
 
 impl Display for ModifyColumnAction {
@@ -1144,6 +1295,14 @@ impl Display for ModifyColumnAction {
                 write!(f, "{} DROP STORED", column)?
             }
             ModifyColumnAction::Comment(columns) => write_comma_separated_list(f, columns)?,
+            ModifyColumnAction::SetTags(column, tags) => {
+                write!(f, "{} SET TAG ", column)?;
+                write_comma_separated_list(f, tags)?
+            }
+            ModifyColumnAction::UnsetTags(column, tags) => {
+                write!(f, "{} UNSET TAG ", column)?;
+                write_comma_separated_list(f, tags)?
+            }
         }
 
         Ok(())
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::quote::QuotedString;
+use crate::ast::write_comma_separated_list;
+use crate::ast::CreateOption;
+use crate::ast::Identifier;
+
+// CREATE [ OR REPLACE ] TAG [ IF NOT EXISTS ] <name>
+// [ ALLOWED_VALUES '<val_1>' [ , '<val_2>' , ... ] ]
+// [ COMMENT = '<string_literal>' ]
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct CreateTagStmt {
+    pub create_option: CreateOption,
+    pub name: Identifier,
+    pub allowed_values: Option<Vec<String>>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, " OR REPLACE")?;
+        }
+        write!(f, " TAG")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}", self.name)?;
+        if let Some(allowed_values) = &self.allowed_values {
+            write!(f, " ALLOWED_VALUES ")?;
+            write_comma_separated_list(f, allowed_values.iter().map(|v| QuotedString(v, '\'')))?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = {}", QuotedString(comment, '\''))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct DropTagStmt {
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP TAG ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct AlterTagStmt {
+    pub name: Identifier,
+    pub action: AlterTagAction,
+}
+
+impl Display for AlterTagStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER TAG {} {}", self.name, self.action)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub enum AlterTagAction {
+    SetMaskingPolicy { policy: Identifier },
+    UnsetMaskingPolicy,
+}
+
+impl Display for AlterTagAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterTagAction::SetMaskingPolicy { policy } => {
+                write!(f, "SET MASKING POLICY {policy}")
+            }
+            AlterTagAction::UnsetMaskingPolicy => write!(f, "UNSET MASKING POLICY"),
+        }
+    }
+}
+
+/// `<tag_name> = '<tag_value>'` in `SET TAG`.
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct TagSetItem {
+    pub tag_name: Identifier,
+    pub tag_value: String,
+}
+
+impl Display for TagSetItem {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} = {}",
+            self.tag_name,
+            QuotedString(&self.tag_value, '\'')
+        )
+    }
+}
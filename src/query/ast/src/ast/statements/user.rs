@@ -21,6 +21,7 @@ use derive_visitor::DriveMut;
 use crate::ast::write_comma_separated_list;
 use crate::ast::AuthType;
 use crate::ast::CreateOption;
+use crate::ast::Identifier;
 use crate::ast::PrincipalIdentity;
 use crate::ast::ProcedureIdentity;
 use crate::ast::ShowOptions;
@@ -217,6 +218,12 @@ pub enum AccountMgrSource {
     ALL {
         level: AccountMgrLevel,
     },
+    /// Privileges on some columns of a table, e.g. `SELECT (c1, c2) ON db.t`.
+    ColumnPrivs {
+        privileges: Vec<UserPrivilegeType>,
+        columns: Vec<Identifier>,
+        level: AccountMgrLevel,
+    },
 }
 
 impl Display for AccountMgrSource {
@@ -234,6 +241,18 @@ impl Display for AccountMgrSource {
                 write!(f, " ON")?;
                 write!(f, " {}", level)?;
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                write!(f, " ")?;
+                write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
+                write!(f, " (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON")?;
+                write!(f, " {}", level)?;
+            }
         }
         Ok(())
     }
@@ -299,6 +318,11 @@ pub enum UserOptionItem {
     MustChangePassword(bool),
     SetWorkloadGroup(String),
     UnsetWorkloadGroup,
+    SetRsaPublicKey(String),
+    UnsetRsaPublicKey,
+    /// The second key slot, used to rotate the key without downtime.
+    SetRsaPublicKey2(String),
+    UnsetRsaPublicKey2,
 }
 
 impl Display for UserOptionItem {
@@ -315,6 +339,10 @@ impl Display for UserOptionItem {
             UserOptionItem::UnsetPasswordPolicy => write!(f, "UNSET PASSWORD POLICY"),
             UserOptionItem::Disabled(v) => write!(f, "DISABLED = {}", v),
             UserOptionItem::MustChangePassword(v) => write!(f, "MUST_CHANGE_PASSWORD = {}", v),
+            UserOptionItem::SetRsaPublicKey(v) => write!(f, "SET RSA_PUBLIC_KEY = '{}'", v),
+            UserOptionItem::UnsetRsaPublicKey => write!(f, "UNSET RSA_PUBLIC_KEY"),
+            UserOptionItem::SetRsaPublicKey2(v) => write!(f, "SET RSA_PUBLIC_KEY_2 = '{}'", v),
+            UserOptionItem::UnsetRsaPublicKey2 => write!(f, "UNSET RSA_PUBLIC_KEY_2"),
         }
     }
 }
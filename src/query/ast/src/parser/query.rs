@@ -557,8 +557,17 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         },
     );
 
+    let at_branch = map(
+        rule! { "(" ~ BRANCH ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, name, _)| TimeTravelPoint::Branch(name),
+    );
+    let at_tag = map(
+        rule! { "(" ~ TAG ~ "=>" ~ #ident ~ ")" },
+        |(_, _, _, name, _)| TimeTravelPoint::Tag(name),
+    );
+
     rule!(
-        #at_stream | #at_snapshot_or_ts
+        #at_stream | #at_branch | #at_tag | #at_snapshot_or_ts
     )(i)
 }
 
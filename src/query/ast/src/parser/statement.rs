@@ -796,11 +796,21 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( DATABASE | SCHEMA )
             ~ ( IF ~ ^NOT ~ ^EXISTS )?
             ~ #database_ref
+            ~ ( CLONE ~ ^#database_ref )?
             ~ #create_database_option?
         },
-        |(_, opt_or_replace, _, opt_if_not_exists, database, create_database_option)| {
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            database,
+            opt_clone_from,
+            create_database_option,
+        )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let clone_from = opt_clone_from.map(|(_, clone_from)| clone_from);
 
             let statement = match create_database_option {
                 Some(CreateDatabaseOption::DatabaseEngine(engine)) => {
@@ -809,6 +819,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                         database,
                         engine: Some(engine),
                         options: vec![],
+                        clone_from,
                     })
                 }
                 None => Statement::CreateDatabase(CreateDatabaseStmt {
@@ -816,6 +827,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     database,
                     engine: None,
                     options: vec![],
+                    clone_from,
                 }),
             };
 
@@ -1018,6 +1030,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( #engine )?
             ~ ( #uri_location )?
             ~ ( CLUSTER ~ ^BY ~ ( #cluster_type )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( TTL ~ ^#expr )?
             ~ ( #table_option )?
             ~ ( PARTITION ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
             ~ ( PROPERTIES ~  #connection_options )?
@@ -1034,6 +1047,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             engine,
             uri_location,
             opt_cluster_by,
+            opt_ttl,
             opt_table_options,
             opt_iceberg_table_partition_by,
             opt_table_properties,
@@ -1059,6 +1073,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     cluster_type: typ.unwrap_or(ClusterType::Linear),
                     cluster_exprs: exprs,
                 }),
+                ttl: opt_ttl.map(|(_, ttl)| ttl),
                 table_options: opt_table_options.unwrap_or_default(),
                 iceberg_table_partition: opt_iceberg_table_partition_by
                     .map(|(_, _, _, cols, _)| cols),
@@ -1548,6 +1563,52 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    let create_statistics = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ STATISTICS ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            (catalog, database, table),
+            _,
+            columns,
+            _,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateStatistics(CreateStatisticsStmt {
+                create_option,
+                name,
+                catalog,
+                database,
+                table,
+                columns,
+            }))
+        },
+    );
+
+    let drop_statistics = map(
+        rule! {
+            DROP ~ STATISTICS ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, name, _, (catalog, database, table))| {
+            Statement::DropStatistics(DropStatisticsStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let refresh_virtual_column = map(
         rule! {
             REFRESH ~ VIRTUAL ~ COLUMN ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1631,7 +1692,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule! {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
-            ~ ( WITH ~ ^#comma_separated_list1(user_option) )?
+            ~ ( #map(rule! { WITH ~ ^#comma_separated_list1(user_option) }, |(_, user_options)| user_options) | #comma_separated_list1(user_option) )?
         },
         |(_, _, user, opt_auth_option, opt_user_option)| {
             Statement::AlterUser(AlterUserStmt {
@@ -1640,9 +1701,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
-                user_options: opt_user_option
-                    .map(|(_, user_options)| user_options)
-                    .unwrap_or_default(),
+                user_options: opt_user_option.unwrap_or_default(),
             })
         },
     );
@@ -2099,6 +2158,69 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    // tag
+    let create_tag = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ TAG ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( ALLOWED_VALUES ~ ^#comma_separated_list1(literal_string) )?
+            ~ ( COMMENT ~ ^Eq ~ ^#literal_string )?
+        },
+        |(_, opt_or_replace, _, opt_if_not_exists, name, opt_allowed_values, opt_comment)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateTag(CreateTagStmt {
+                create_option,
+                name,
+                allowed_values: opt_allowed_values.map(|(_, values)| values),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            }))
+        },
+    );
+    let drop_tag = map(
+        rule! {
+            DROP ~ TAG ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropTag(DropTagStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let alter_tag = map(
+        rule! {
+            ALTER ~ TAG ~ #ident ~ #alter_tag_action
+        },
+        |(_, _, name, action)| Statement::AlterTag(AlterTagStmt { name, action }),
+    );
+
+    // plan baseline
+    let create_plan_baseline = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ PLAN ~ ^BASELINE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ ^FOR ~ ^#query
+        },
+        |(_, opt_or_replace, _, _, opt_if_not_exists, _, query)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreatePlanBaseline(CreatePlanBaselineStmt {
+                create_option,
+                query: Box::new(query),
+            }))
+        },
+    );
+    let drop_plan_baseline = map(
+        rule! {
+            DROP ~ PLAN ~ ^BASELINE ~ ( IF ~ ^EXISTS )? ~ ^#literal_string
+        },
+        |(_, _, _, opt_if_exists, sql_hash)| {
+            Statement::DropPlanBaseline(DropPlanBaselineStmt {
+                if_exists: opt_if_exists.is_some(),
+                sql_hash,
+            })
+        },
+    );
+
     let create_network_policy = map_res(
         rule! {
             CREATE ~  ( OR ~ ^REPLACE )? ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -2743,6 +2865,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #refresh_virtual_column: "`REFRESH VIRTUAL COLUMN FOR [<database>.]<table>`"
             | #show_virtual_columns : "`SHOW VIRTUAL COLUMNS FROM <table> [FROM|IN <catalog>.<database>] [<show_limit>]`"
             | #show_statistics: "`SHOW STATISTICS [FROM DATABASE [<catalog>.]<database> | FROM TABLE [<catalog>.]<database>.<table>]`"
+            | #create_statistics: "`CREATE [OR REPLACE] STATISTICS [IF NOT EXISTS] <name> ON [<database>.]<table> (<column>, ...)`"
+            | #drop_statistics: "`DROP STATISTICS [IF EXISTS] <name> ON [<database>.]<table>`"
             | #sequence
         ),
         rule!(
@@ -2770,6 +2894,11 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #create_data_mask_policy: "`CREATE MASKING POLICY [IF NOT EXISTS] mask_name as (val1 val_type1 [, val type]) return type -> case`"
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
+            | #create_tag: "`CREATE [OR REPLACE] TAG [IF NOT EXISTS] <name> [ALLOWED_VALUES '<val>' [, ...]] [COMMENT = '<string_literal>']`"
+            | #drop_tag: "`DROP TAG [IF EXISTS] <name>`"
+            | #alter_tag: "`ALTER TAG <name> { SET MASKING POLICY <policy_name> | UNSET MASKING POLICY }`"
+            | #create_plan_baseline: "`CREATE [OR REPLACE] PLAN BASELINE [IF NOT EXISTS] FOR <query>`"
+            | #drop_plan_baseline: "`DROP PLAN BASELINE [IF EXISTS] '<sql_hash>'`"
         ),
         rule!(
             #set_stmt : "`SET [variable] {<name> = <value> | (<name>, ...) = (<value>, ...)}`"
@@ -2884,7 +3013,7 @@ pub fn insert_stmt(
         map_res(
             rule! {
                 #with? ~ INSERT ~ #hint? ~ OVERWRITE? ~ INTO?  ~ TABLE?
-                ~ #dot_separated_idents_1_to_3
+                ~ #dot_separated_idents_1_to_3 ~ ( Divide ~ BRANCH ~ ^#ident )?
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
             },
@@ -2896,6 +3025,7 @@ pub fn insert_stmt(
                 into,
                 _,
                 (catalog, database, table),
+                opt_branch,
                 opt_columns,
                 source,
             )| {
@@ -2910,6 +3040,7 @@ pub fn insert_stmt(
                     catalog,
                     database,
                     table,
+                    branch: opt_branch.map(|(_, _, branch)| branch),
                     columns: opt_columns
                         .map(|(_, columns, _)| columns)
                         .unwrap_or_default(),
@@ -3191,18 +3322,72 @@ pub fn set_var_hints(i: Input) -> IResult<HintItem> {
     )(i)
 }
 
+pub fn optimizer_hint(i: Input) -> IResult<OptimizerHint> {
+    let tables = |i| {
+        map(
+            rule! {
+                "(" ~ #ident+ ~ ")"
+            },
+            |(_, tables, _)| tables,
+        )(i)
+    };
+    alt((
+        map(rule! { LEADING ~ #tables }, |(_, tables)| {
+            OptimizerHint::Leading(tables)
+        }),
+        map(rule! { BROADCAST ~ #tables }, |(_, tables)| {
+            OptimizerHint::Broadcast(tables)
+        }),
+        map(rule! { SHUFFLE ~ #tables }, |(_, tables)| {
+            OptimizerHint::Shuffle(tables)
+        }),
+        map(rule! { HASH_JOIN ~ #tables }, |(_, tables)| {
+            OptimizerHint::HashJoin(tables)
+        }),
+        map(rule! { NO_RUNTIME_FILTER ~ #tables }, |(_, tables)| {
+            OptimizerHint::NoRuntimeFilter(tables)
+        }),
+        value(OptimizerHint::NoDecorrelate, rule! { NO_DECORRELATE }),
+    ))(i)
+}
+
 pub fn hint(i: Input) -> IResult<Hint> {
+    enum HintEntry {
+        SetVar(HintItem),
+        Optimizer(OptimizerHint),
+    }
+
+    let hint_entry = alt((
+        map(set_var_hints, HintEntry::SetVar),
+        map(optimizer_hint, HintEntry::Optimizer),
+    ));
     let hint = map(
         rule! {
-            "/*+" ~ #set_var_hints+ ~ "*/"
+            "/*+" ~ #hint_entry+ ~ "*/"
+        },
+        |(_, entries, _)| {
+            let mut hints_list = vec![];
+            let mut optimizer_hints = vec![];
+            for entry in entries {
+                match entry {
+                    HintEntry::SetVar(item) => hints_list.push(item),
+                    HintEntry::Optimizer(hint) => optimizer_hints.push(hint),
+                }
+            }
+            Hint {
+                hints_list,
+                optimizer_hints,
+            }
         },
-        |(_, hints_list, _)| Hint { hints_list },
     );
     let invalid_hint = map(
         rule! {
             "/*+" ~ (!"*/" ~ #any_token)* ~ "*/"
         },
-        |_| Hint { hints_list: vec![] },
+        |_| Hint {
+            hints_list: vec![],
+            optimizer_hints: vec![],
+        },
     );
     rule!(#hint|#invalid_hint)(i)
 }
@@ -3453,16 +3638,61 @@ pub fn table_index_def(i: Input) -> IResult<TableIndexDefinition> {
 }
 
 pub fn constraint_def(i: Input) -> IResult<ConstraintDefinition> {
-    map_res(
+    let check = map(
+        rule! {
+            CHECK ~ ^"(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| ConstraintType::Check(expr),
+    );
+    // Key constraints are informational only, so `NOT ENFORCED` is implied.
+    let key_options = map(
+        rule! {
+            (NOT ~ ^ENFORCED)? ~ (RELY | NORELY)?
+        },
+        |(_, opt_rely)| {
+            opt_rely
+                .map(|token| matches!(token.kind, RELY))
+                .unwrap_or(true)
+        },
+    );
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ ^KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ #key_options
+        },
+        |(_, _, _, columns, _, rely)| ConstraintType::PrimaryKey { columns, rely },
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ #key_options
+        },
+        |(_, _, columns, _, rely)| ConstraintType::Unique { columns, rely },
+    );
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ ^KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ #key_options
+        },
+        |(_, _, _, columns, _, _, (ref_database, ref_table), _, ref_columns, _, rely)| {
+            ConstraintType::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+                rely,
+            }
+        },
+    );
+
+    map(
         rule! {
             (CONSTRAINT ~ #ident)?
-            ~ CHECK ~ ^"(" ~ ^#expr ~ ^")"
+            ~ ( #check | #primary_key | #unique | #foreign_key )
         },
-        |(opt_constraint_name, _, _, expr, _)| {
-            Ok(ConstraintDefinition {
-                name: opt_constraint_name.map(|(_, name)| name),
-                constraint_type: ConstraintType::Check(expr),
-            })
+        |(opt_constraint_name, constraint_type)| ConstraintDefinition {
+            name: opt_constraint_name.map(|(_, name)| name),
+            constraint_type,
         },
     )(i)
 }
@@ -3538,6 +3768,17 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         |(_, _, _, level)| AccountMgrSource::ALL { level },
     );
 
+    let column_privs = map(
+        rule! {
+            #comma_separated_list1(column_priv_type) ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(privileges, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privileges,
+            columns,
+            level,
+        },
+    );
+
     let udf_privs = map(
         rule! {
             USAGE ~ ON ~ UDF ~ #ident
@@ -3678,6 +3919,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         | #connection_privs: "ACCESS CONNECTION ON CONNECTION <connection_name>"
         | #seq_privs: "ACCESS SEQUENCE ON CONNECTION <seq_name>"
         | #privs : "<privileges> ON <privileges_level>"
+        | #column_privs : "<column_privileges> (<column>, ...) ON <database>.<table>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
         | #procedure_privs: "ACCESS PROCEDURE ON PROCEDURE <procedure_identity>"
@@ -3686,6 +3928,13 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     )(i)
 }
 
+pub fn column_priv_type(i: Input) -> IResult<UserPrivilegeType> {
+    alt((
+        value(UserPrivilegeType::Select, rule! { SELECT }),
+        value(UserPrivilegeType::Update, rule! { UPDATE }),
+    ))(i)
+}
+
 pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
     let usage = value(UserPrivilegeType::Usage, rule! { USAGE });
     let select = value(UserPrivilegeType::Select, rule! { SELECT });
@@ -4112,9 +4361,22 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
         },
     );
 
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#at_snapshot_or_ts )?
+        },
+        |(_, (catalog, database, table), travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: travel_point.map(|(_, point)| point),
+        },
+    );
+
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
@@ -4133,9 +4395,58 @@ pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
         |(_, _)| AlterDatabaseAction::RefreshDatabaseCache,
     );
 
+    let set_tags = map(
+        rule! {
+            SET ~ TAG ~ ^#comma_separated_list1(tag_set_item)
+        },
+        |(_, _, tags)| AlterDatabaseAction::SetTags { tags },
+    );
+
+    let unset_tags = map(
+        rule! {
+            UNSET ~ TAG ~ ^#comma_separated_list1(ident)
+        },
+        |(_, _, tags)| AlterDatabaseAction::UnsetTags { tags },
+    );
+
     rule!(
         #rename_database
         | #refresh_cache
+        | #set_tags
+        | #unset_tags
+    )(i)
+}
+
+pub fn tag_set_item(i: Input) -> IResult<TagSetItem> {
+    map(
+        rule! {
+            #ident ~ ^Eq ~ ^#literal_string
+        },
+        |(tag_name, _, tag_value)| TagSetItem {
+            tag_name,
+            tag_value,
+        },
+    )(i)
+}
+
+pub fn alter_tag_action(i: Input) -> IResult<AlterTagAction> {
+    let set_masking_policy = map(
+        rule! {
+            SET ~ MASKING ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, policy)| AlterTagAction::SetMaskingPolicy { policy },
+    );
+
+    let unset_masking_policy = map(
+        rule! {
+            UNSET ~ MASKING ~ ^POLICY
+        },
+        |(_, _, _)| AlterTagAction::UnsetMaskingPolicy,
+    );
+
+    rule!(
+        #set_masking_policy
+        | #unset_masking_policy
     )(i)
 }
 
@@ -4246,6 +4557,20 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
         |(column, _, _, _)| ModifyColumnAction::UnsetMaskingPolicy(column),
     );
 
+    let set_tags = map(
+        rule! {
+            #ident ~ SET ~ TAG ~ ^#comma_separated_list1(tag_set_item)
+        },
+        |(column, _, _, tags)| ModifyColumnAction::SetTags(column, tags),
+    );
+
+    let unset_tags = map(
+        rule! {
+            #ident ~ UNSET ~ TAG ~ ^#comma_separated_list1(ident)
+        },
+        |(column, _, _, tags)| ModifyColumnAction::UnsetTags(column, tags),
+    );
+
     let convert_stored_computed_column = map(
         rule! {
             #ident ~ DROP ~ STORED
@@ -4282,6 +4607,8 @@ pub fn modify_column_action(i: Input) -> IResult<ModifyColumnAction> {
     rule!(
         #set_mask_policy
         | #unset_mask_policy
+        | #set_tags
+        | #unset_tags
         | #convert_stored_computed_column
         | #modify_column_type
         | #modify_column_comment
@@ -4371,6 +4698,20 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, _, _)| AlterTableAction::DropAllRowAccessPolicies,
     );
 
+    let set_tags = map(
+        rule! {
+            SET ~ TAG ~ ^#comma_separated_list1(tag_set_item)
+        },
+        |(_, _, tags)| AlterTableAction::SetTags { tags },
+    );
+
+    let unset_tags = map(
+        rule! {
+            UNSET ~ TAG ~ ^#comma_separated_list1(ident)
+        },
+        |(_, _, tags)| AlterTableAction::UnsetTags { tags },
+    );
+
     let drop_column = map(
         rule! {
             DROP ~ COLUMN? ~ #ident
@@ -4396,6 +4737,20 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _)| AlterTableAction::DropTableClusterKey,
     );
 
+    let modify_table_ttl = map(
+        rule! {
+            TTL ~ ^#expr
+        },
+        |(_, ttl)| AlterTableAction::ModifyTableTTL { ttl },
+    );
+
+    let drop_table_ttl = map(
+        rule! {
+            DROP ~ TTL
+        },
+        |(_, _)| AlterTableAction::DropTableTTL,
+    );
+
     let recluster_table = map(
         rule! {
             RECLUSTER ~ FINAL? ~ ( WHERE ~ ^#expr )? ~ ( LIMIT ~ #literal_u64 )?
@@ -4407,6 +4762,29 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         },
     );
 
+    let table_ref_kind = |i| {
+        alt((
+            value(TableRefKind::Branch, rule! { BRANCH }),
+            value(TableRefKind::Tag, rule! { TAG }),
+        ))(i)
+    };
+    let create_table_ref = map(
+        rule! {
+            CREATE ~ #table_ref_kind ~ #ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, kind, name, opt_travel_point)| AlterTableAction::CreateTableRef {
+            kind,
+            name,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
+    let drop_table_ref = map(
+        rule! {
+            DROP ~ #table_ref_kind ~ #ident
+        },
+        |(_, kind, name)| AlterTableAction::DropTableRef { kind, name },
+    );
+
     let revert_table = map(
         rule! {
             FLASHBACK ~ TO ~ #travel_point
@@ -4447,12 +4825,16 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     rule!(
         #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #modify_table_ttl
+        | #drop_table_ttl
         | #drop_constraint
         | #rename_table
         | #swap_with
         | #rename_column
         | #modify_table_comment
         | #add_column
+        | #create_table_ref
+        | #drop_table_ref
         | #drop_column
         | #modify_column
         | #recluster_table
@@ -4465,6 +4847,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #drop_row_access_policy
         | #add_row_access_policy
         | #add_constraint
+        | #set_tags
+        | #unset_tags
     )(i)
 }
 
@@ -4587,6 +4971,7 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 target: opt_segment.map_or(CompactTarget::Block, |_| CompactTarget::Segment),
             }
         }),
+        value(OptimizeTableAction::Expire, rule! { EXPIRE }),
     ))(i)
 }
 
@@ -5146,6 +5531,30 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         },
         |(_, _, _)| UserOptionItem::UnsetWorkloadGroup,
     );
+    let set_rsa_public_key = map(
+        rule! {
+            SET ~ RSA_PUBLIC_KEY ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, key)| UserOptionItem::SetRsaPublicKey(key),
+    );
+    let unset_rsa_public_key = map(
+        rule! {
+            UNSET ~ RSA_PUBLIC_KEY
+        },
+        |(_, _)| UserOptionItem::UnsetRsaPublicKey,
+    );
+    let set_rsa_public_key_2 = map(
+        rule! {
+            SET ~ RSA_PUBLIC_KEY_2 ~ ^"=" ~ ^#literal_string
+        },
+        |(_, _, _, key)| UserOptionItem::SetRsaPublicKey2(key),
+    );
+    let unset_rsa_public_key_2 = map(
+        rule! {
+            UNSET ~ RSA_PUBLIC_KEY_2
+        },
+        |(_, _)| UserOptionItem::UnsetRsaPublicKey2,
+    );
 
     rule!(
         #tenant_setting
@@ -5159,6 +5568,10 @@ pub fn user_option(i: Input) -> IResult<UserOptionItem> {
         | #must_change_password
         | #set_workload_group
         | #unset_workload_group
+        | #set_rsa_public_key
+        | #unset_rsa_public_key
+        | #set_rsa_public_key_2
+        | #unset_rsa_public_key_2
     )(i)
 }
 
@@ -5180,6 +5593,8 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::KeyPair, rule! { KEY_PAIR }),
+        value(AuthType::Ldap, rule! { LDAP }),
     ))(i)
 }
 
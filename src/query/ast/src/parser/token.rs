@@ -341,6 +341,8 @@ pub enum TokenKind {
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
     ALLOWED_IP_LIST,
+    #[token("ALLOWED_VALUES", ignore(ascii_case))]
+    ALLOWED_VALUES,
     #[token("ADD", ignore(ascii_case))]
     ADD,
     #[token("AFTER", ignore(ascii_case))]
@@ -387,6 +389,8 @@ pub enum TokenKind {
     ATTACH,
     #[token("AVRO", ignore(ascii_case))]
     AVRO,
+    #[token("BASELINE", ignore(ascii_case))]
+    BASELINE,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
     #[token("BETWEEN", ignore(ascii_case))]
@@ -419,8 +423,12 @@ pub enum TokenKind {
     BOTH,
     #[token("BY", ignore(ascii_case))]
     BY,
+    #[token("BRANCH", ignore(ascii_case))]
+    BRANCH,
     #[token("BROTLI", ignore(ascii_case))]
     BROTLI,
+    #[token("BROADCAST", ignore(ascii_case))]
+    BROADCAST,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
     #[token("BLOCK", ignore(ascii_case))]
@@ -441,6 +449,8 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -613,6 +623,8 @@ pub enum TokenKind {
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
@@ -673,6 +685,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -717,6 +731,8 @@ pub enum TokenKind {
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HASH_JOIN", ignore(ascii_case))]
+    HASH_JOIN,
     #[token("HIGH", ignore(ascii_case))]
     HIGH,
     #[token("HILBERT", ignore(ascii_case))]
@@ -807,12 +823,16 @@ pub enum TokenKind {
     JWT,
     #[token("KEY", ignore(ascii_case))]
     KEY,
+    #[token("KEY_PAIR", ignore(ascii_case))]
+    KEY_PAIR,
     #[token("KILL", ignore(ascii_case))]
     KILL,
     #[token("LAST_DAY", ignore(ascii_case))]
     LAST_DAY,
     #[token("LATERAL", ignore(ascii_case))]
     LATERAL,
+    #[token("LDAP", ignore(ascii_case))]
+    LDAP,
     #[token("LINEAR", ignore(ascii_case))]
     LINEAR,
     #[token("LOCATION_PREFIX", ignore(ascii_case))]
@@ -896,10 +916,16 @@ pub enum TokenKind {
     NGRAM,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
+    #[token("NO_DECORRELATE", ignore(ascii_case))]
+    NO_DECORRELATE,
+    #[token("NO_RUNTIME_FILTER", ignore(ascii_case))]
+    NO_RUNTIME_FILTER,
     #[token("NONE", ignore(ascii_case))]
     NONE,
     #[token("NOORDER", ignore(ascii_case))]
     NOORDER,
+    #[token("NORELY", ignore(ascii_case))]
+    NORELY,
     #[token("NOSCAN", ignore(ascii_case))]
     NOSCAN,
     #[token("NOT", ignore(ascii_case))]
@@ -984,6 +1010,8 @@ pub enum TokenKind {
     PERF,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
+    #[token("PLAN", ignore(ascii_case))]
+    PLAN,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
     #[token("POLICIES", ignore(ascii_case))]
@@ -1022,10 +1050,14 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
+    #[token("RELY", ignore(ascii_case))]
+    RELY,
     #[token("RENAME", ignore(ascii_case))]
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
@@ -1052,6 +1084,10 @@ pub enum TokenKind {
     ROWS,
     #[token("ROW_TAG", ignore(ascii_case))]
     ROW_TAG,
+    #[token("RSA_PUBLIC_KEY", ignore(ascii_case))]
+    RSA_PUBLIC_KEY,
+    #[token("RSA_PUBLIC_KEY_2", ignore(ascii_case))]
+    RSA_PUBLIC_KEY_2,
     #[token("GRANT", ignore(ascii_case))]
     GRANT,
     #[token("REAL", ignore(ascii_case))]
@@ -1138,6 +1174,8 @@ pub enum TokenKind {
     SHA256_PASSWORD,
     #[token("SHOW", ignore(ascii_case))]
     SHOW,
+    #[token("SHUFFLE", ignore(ascii_case))]
+    SHUFFLE,
     #[token("SINCE", ignore(ascii_case))]
     SINCE,
     #[token("SIGNED", ignore(ascii_case))]
@@ -1220,6 +1258,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1272,6 +1312,8 @@ pub enum TokenKind {
     TRY_CAST,
     #[token("TSV", ignore(ascii_case))]
     TSV,
+    #[token("TTL", ignore(ascii_case))]
+    TTL,
     #[token("TUESDAY", ignore(ascii_case))]
     TUESDAY,
     #[token("TUPLE", ignore(ascii_case))]
@@ -1282,6 +1324,8 @@ pub enum TokenKind {
     UNBOUNDED,
     #[token("UNION", ignore(ascii_case))]
     UNION,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UINT16", ignore(ascii_case))]
     UINT16,
     #[token("UINT32", ignore(ascii_case))]
@@ -128,6 +128,8 @@ fn test_statement() {
         r#"CREATE OR REPLACE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
         r#"CREATE OR REPLACE INVERTED INDEX idx2 ON t1 (a, b);"#,
         r#"CREATE OR REPLACE NGRAM INDEX idx2 ON t1 (a, b);"#,
+        r#"CREATE STATISTICS IF NOT EXISTS s1 ON db.t1 (a, b);"#,
+        r#"DROP STATISTICS IF EXISTS s1 ON t1;"#,
         r#"create table a (c decimal(38, 0))"#,
         r#"create table a (c decimal(38))"#,
         r#"create table a (c1 decimal(38), c2 int) partition by (c1, c2) PROPERTIES ("read.split.target-size"='134217728', "read.split.metadata-target-size"='33554432');"#,
@@ -205,6 +207,10 @@ fn test_statement() {
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET WORKLOAD GROUP='W1'"#,
         r#"ALTER USER u1 WITH SET WORKLOAD GROUP = 'W1';"#,
         r#"ALTER USER u1 WITH UNSET WORKLOAD GROUP;"#,
+        r#"ALTER USER u1 SET RSA_PUBLIC_KEY = 'MIIBIjANBgkq';"#,
+        r#"ALTER USER u1 WITH SET RSA_PUBLIC_KEY_2 = 'abc', UNSET RSA_PUBLIC_KEY;"#,
+        r#"CREATE USER svc IDENTIFIED WITH key_pair WITH SET RSA_PUBLIC_KEY = 'abc'"#,
+        r#"CREATE USER alice IDENTIFIED WITH ldap"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH disabled=true"#,
@@ -315,6 +321,7 @@ SELECT * from s;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask;"#,
         r#"ALTER TABLE t MODIFY COLUMN b SET MASKING POLICY mask USING (b, c1);"#,
         r#"ALTER TABLE t MODIFY COLUMN b UNSET MASKING POLICY;"#,
+        r#"ALTER TABLE t MODIFY COLUMN b SET TAG pii = 'email';"#,
         r#"ALTER TABLE t ADD ROW ACCESS POLICY p1 ON (col1);"#,
         r#"ALTER TABLE t ADD ROW ACCESS POLICY p1 ON (col1, col2, col3);"#,
         r#"ALTER TABLE t drop row access policy p1;"#,
@@ -331,10 +338,14 @@ SELECT * from s;"#,
         r#"ALTER TABLE t ADD CONSTRAINT a_not_1 CHECK (a != 1);"#,
         r#"ALTER TABLE t ADD CHECK (a != 1);"#,
         r#"ALTER TABLE t DROP CONSTRAINT a_not_1;"#,
+        r#"ALTER TABLE t ADD CONSTRAINT t_pk PRIMARY KEY (a, b);"#,
+        r#"ALTER TABLE t ADD UNIQUE (c) NOT ENFORCED NORELY;"#,
+        r#"ALTER TABLE t ADD CONSTRAINT t_fk FOREIGN KEY (d) REFERENCES db.dim (id) RELY;"#,
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c refresh cache;"#,
+        r#"ALTER DATABASE c UNSET TAG pii;"#,
         r#"VACUUM TABLE t;"#,
         r#"VACUUM TABLE t DRY RUN;"#,
         r#"VACUUM TABLE t DRY RUN SUMMARY;"#,
@@ -376,6 +387,7 @@ SELECT * from s;"#,
         r#"GRANT SELECT ON db01.tb1 TO USER 'test-grant';"#,
         r#"GRANT SELECT ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT SELECT ON tb1 TO ROLE role1;"#,
+        r#"GRANT SELECT, UPDATE (a, b) ON db01.tb1 TO ROLE role1;"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant';"#,
@@ -678,6 +690,11 @@ SELECT * from s;"#,
         r#"CREATE OR REPLACE MASKING POLICY email_mask AS (val STRING) RETURNS STRING -> CASE WHEN current_role() IN ('ANALYST') THEN VAL ELSE '*********'END comment = 'this is a masking policy'"#,
         r#"DESC MASKING POLICY email_mask"#,
         r#"DROP MASKING POLICY IF EXISTS email_mask"#,
+        r#"CREATE TAG IF NOT EXISTS pii ALLOWED_VALUES 'email', 'phone' COMMENT = 'personal data'"#,
+        r#"ALTER TAG pii SET MASKING POLICY email_mask"#,
+        r#"DROP TAG IF EXISTS pii"#,
+        r#"CREATE OR REPLACE PLAN BASELINE FOR select * from t4"#,
+        r#"DROP PLAN BASELINE IF EXISTS '3f2a'"#,
         r#"REFRESH VIRTUAL COLUMN FOR t"#,
         r#"CREATE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
         r#"CREATE OR REPLACE NETWORK POLICY mypolicy ALLOWED_IP_LIST=('192.168.10.0/24') BLOCKED_IP_LIST=('192.168.10.99') COMMENT='test'"#,
@@ -1190,6 +1207,7 @@ fn test_query() {
         r#"select count(t.c) from t12_0004 at (snapshot => 'xxxx') as t"#,
         r#"select * from customer inner join orders"#,
         r#"select * from customer cross join orders"#,
+        r#"select /*+ LEADING(customer orders) BROADCAST(orders) NO_DECORRELATE */ * from customer cross join orders"#,
         r#"select * from customer inner join orders on (a = b)"#,
         r#"select * from customer inner join orders on a = b limit 1"#,
         r#"select * from customer inner join orders on a = b limit 2 offset 3"#,
@@ -38,6 +38,8 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
+use databend_common_storage::MultiColumnStatistics;
 use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -560,6 +562,10 @@ pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
     StreamInfo(TableInfo),
+    /// The head of a branch of the table.
+    Branch(String),
+    /// The snapshot of a tag of the table.
+    Tag(String),
 }
 
 #[derive(Debug, Copy, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -609,6 +615,16 @@ pub trait ColumnStatisticsProvider: Send {
     fn histogram(&self, _column_id: ColumnId) -> Option<Histogram> {
         None
     }
+
+    // return most common values if any
+    fn most_common_values(&self, _column_id: ColumnId) -> Option<MostCommonValues> {
+        None
+    }
+
+    // return the statistics of the column groups created by `CREATE STATISTICS`
+    fn multi_column_statistics(&self) -> Vec<MultiColumnStatistics> {
+        vec![]
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
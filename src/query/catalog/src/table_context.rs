@@ -31,6 +31,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::ColumnId;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
@@ -302,6 +303,15 @@ pub trait TableContext: Send + Sync {
         max_batch_size: Option<u64>,
     ) -> Result<Arc<dyn Table>>;
 
+    /// Get the ids of the columns which are hidden from the current user, who is only
+    /// granted SELECT on the other columns of the table. The result is cached in the query.
+    async fn get_hidden_column_ids(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<HashSet<ColumnId>>;
+
     async fn filter_out_copied_files(
         &self,
         catalog_name: &str,
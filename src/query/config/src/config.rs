@@ -1963,6 +1963,10 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub resources_management: Option<ResourcesManagementConfig>,
 
+    /// Authenticate the users of auth type `ldap` against a LDAP server.
+    #[clap(skip)]
+    pub ldap: Option<LdapConfig>,
+
     #[clap(long, value_name = "VALUE", default_value = "false")]
     pub enable_queries_executor: bool,
 }
@@ -2068,6 +2072,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
                 .map(|(k, v)| (k, v.into()))
                 .collect(),
             resources_management: self.resources_management,
+            ldap: self.ldap,
             enable_queries_executor: self.enable_queries_executor,
             check_connection_before_schedule: true,
         })
@@ -2173,6 +2178,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             network_policy_whitelist: inner.network_policy_whitelist,
             settings: HashMap::new(),
             resources_management: None,
+            ldap: inner.ldap,
             enable_queries_executor: inner.enable_queries_executor,
             embedded_mode: inner.embedded_mode,
         }
@@ -3647,6 +3653,79 @@ pub struct ResourcesManagementConfig {
     pub node_group: Option<String>,
 }
 
+/// Config of the LDAP server to authenticate the users of auth type `ldap`.
+///
+/// The user is authenticated in one of two modes:
+/// - simple bind: bind with the DN built from `bind_dn_template`.
+/// - search+bind: bind with `search_bind_dn` to look up the DN of the user under
+///   `search_base_dn` with `search_filter`, then bind with the found DN.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LdapConfig {
+    /// Url of the LDAP server, e.g. `ldap://127.0.0.1:389` or `ldaps://ldap.example.org`.
+    pub url: String,
+
+    /// DN template of the user for simple bind, `{username}` is replaced by the
+    /// login name, e.g. `uid={username},ou=people,dc=example,dc=org`.
+    /// Search+bind is used if it is empty.
+    pub bind_dn_template: String,
+
+    pub search_bind_dn: String,
+    pub search_bind_password: String,
+    pub search_base_dn: String,
+    /// `{username}` is replaced by the escaped login name.
+    pub search_filter: String,
+
+    /// Attribute of the user entry which lists the groups of the user.
+    pub group_attribute: String,
+
+    /// Maps a LDAP group, given by either its DN or CN, to a role.
+    /// The mapped roles are granted or revoked to match the groups on each login.
+    pub group_role_mapping: BTreeMap<String, String>,
+
+    /// Create the user on the first successful login if it does not exist.
+    pub auto_create_user: bool,
+
+    pub connect_timeout_secs: u64,
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        LdapConfig {
+            url: "".to_string(),
+            bind_dn_template: "".to_string(),
+            search_bind_dn: "".to_string(),
+            search_bind_password: "".to_string(),
+            search_base_dn: "".to_string(),
+            search_filter: "(uid={username})".to_string(),
+            group_attribute: "memberOf".to_string(),
+            group_role_mapping: BTreeMap::new(),
+            auto_create_user: false,
+            connect_timeout_secs: 10,
+        }
+    }
+}
+
+impl Debug for LdapConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LdapConfig")
+            .field("url", &self.url)
+            .field("bind_dn_template", &self.bind_dn_template)
+            .field("search_bind_dn", &self.search_bind_dn)
+            .field(
+                "search_bind_password",
+                &mask_string(&self.search_bind_password, 3),
+            )
+            .field("search_base_dn", &self.search_base_dn)
+            .field("search_filter", &self.search_filter)
+            .field("group_attribute", &self.group_attribute)
+            .field("group_role_mapping", &self.group_role_mapping)
+            .field("auto_create_user", &self.auto_create_user)
+            .field("connect_timeout_secs", &self.connect_timeout_secs)
+            .finish()
+    }
+}
+
 mod cache_config_converters {
     use log::warn;
 
@@ -37,6 +37,7 @@ use databend_common_storage::StorageConfig;
 use databend_common_tracing::Config as LogConfig;
 
 use super::config::Config;
+use super::config::LdapConfig;
 use super::config::ResourcesManagementConfig;
 use super::config::TelemetryConfig;
 use crate::BuiltInConfig;
@@ -250,6 +251,7 @@ pub struct QueryConfig {
 
     pub settings: HashMap<String, UserSettingValue>,
     pub resources_management: Option<ResourcesManagementConfig>,
+    pub ldap: Option<LdapConfig>,
 
     pub enable_queries_executor: bool,
     pub check_connection_before_schedule: bool,
@@ -336,6 +338,7 @@ impl Default for QueryConfig {
             network_policy_whitelist: Vec::new(),
             settings: HashMap::new(),
             resources_management: None,
+            ldap: None,
             enable_queries_executor: false,
             check_connection_before_schedule: true,
         }
@@ -38,6 +38,7 @@ pub use builtin::*;
 pub use config::CacheStorageTypeConfig;
 pub use config::Commands;
 pub use config::Config;
+pub use config::LdapConfig;
 pub use config::StorageConfig;
 pub use config::StorageNetworkConfig;
 pub use global::GlobalConfig;
@@ -67,6 +67,10 @@ impl QueryConfig {
             .as_ref()
             .map(|license| mask_sensitive_field(license));
 
+        if let Some(ldap) = &mut masked_config.ldap {
+            ldap.search_bind_password = mask_sensitive_field(&ldap.search_bind_password);
+        }
+
         masked_config
     }
 }
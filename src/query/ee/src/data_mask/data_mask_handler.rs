@@ -12,16 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_exception::Result;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
 use databend_common_meta_api::DatamaskApi;
+use databend_common_meta_api::TagApi;
 use databend_common_meta_app::app_error::AppError;
 use databend_common_meta_app::data_mask::CreateDatamaskReq;
 use databend_common_meta_app::data_mask::DataMaskNameIdent;
 use databend_common_meta_app::data_mask::DatamaskMeta;
 use databend_common_meta_app::data_mask::DropDatamaskReq;
+use databend_common_meta_app::tag::TagIdIdent;
+use databend_common_meta_app::tag::TagMaskPolicy;
+use databend_common_meta_app::tag::TaggableObject;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_types::SeqV;
@@ -86,6 +93,47 @@ impl DatamaskHandler for RealDatamaskHandler {
             })?;
         Ok(res)
     }
+
+    async fn get_tag_data_masks(
+        &self,
+        meta_api: Arc<MetaStore>,
+        tenant: &Tenant,
+        db_id: u64,
+        table_id: u64,
+    ) -> Result<Vec<TagMaskPolicy>> {
+        let mut references = meta_api
+            .get_object_tags(tenant, TaggableObject::Database { db_id })
+            .await?;
+        references.extend(
+            meta_api
+                .get_object_tags(tenant, TaggableObject::Table { table_id })
+                .await?,
+        );
+        if references.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let tag_ids = references.iter().map(|r| r.tag_id).collect::<BTreeSet<_>>();
+        let metas = meta_api
+            .get_pb_values_vec(tag_ids.iter().map(|id| TagIdIdent::new(tenant, *id)))
+            .await?;
+        let policies = tag_ids
+            .into_iter()
+            .zip(metas)
+            .filter_map(|(tag_id, meta)| Some((tag_id, meta?.data.masking_policy_id?)))
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(references
+            .into_iter()
+            .filter_map(|r| {
+                policies.get(&r.tag_id).map(|policy_id| TagMaskPolicy {
+                    object: r.object,
+                    tag_id: r.tag_id,
+                    policy_id: *policy_id,
+                })
+            })
+            .collect())
+    }
 }
 
 impl RealDatamaskHandler {
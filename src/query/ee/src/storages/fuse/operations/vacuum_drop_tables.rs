@@ -18,8 +18,11 @@ use std::time::Instant;
 
 use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_storages_fuse::operations::has_clone_references;
+use databend_common_storages_fuse::operations::remove_clone_references;
 use databend_common_storages_fuse::FuseTable;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropFileInfo;
 use databend_enterprise_vacuum_handler::vacuum_handler::VacuumDropTablesResult;
@@ -68,10 +71,25 @@ async fn vacuum_drop_single_table(
         table_info.meta.storage_params.is_some()
     );
 
+    // The files of a table are shared with its clones, they can only be removed after all
+    // the clones are vacuumed. Until then the table is reported as failed and retried later.
+    if has_clone_references(&operator, &dir).await? {
+        info!(
+            "vacuum drop table {:?} dir {:?} skipped, files are still used by cloned tables",
+            table_info.name, dir,
+        );
+        return Err(ErrorCode::StorageOther(format!(
+            "files of dropped table {} are still used by cloned tables",
+            table_info.name
+        )));
+    }
+
     let start = Instant::now();
 
     match dry_run_limit {
         None => {
+            // A clone releases the files it shares with its source tables.
+            remove_clone_references(&operator, &dir).await?;
             let result = operator.remove_all(&dir).await;
             if let Err(ref err) = result {
                 error!("failed to remove all in directory {}: {}", dir, err);
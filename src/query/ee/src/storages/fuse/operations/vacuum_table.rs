@@ -41,6 +41,7 @@ pub struct SnapshotReferencedFiles {
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
     pub segments_stats: HashSet<String>,
+    pub deletion_vectors: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -58,6 +59,9 @@ impl SnapshotReferencedFiles {
         for file in &self.segments_stats {
             files.push(file.clone());
         }
+        for file in &self.deletion_vectors {
+            files.push(file.clone());
+        }
         files
     }
 }
@@ -132,12 +136,31 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
-    Ok(Some(SnapshotReferencedFiles {
+    let mut referenced_files = SnapshotReferencedFiles {
         segments,
         blocks: locations_referenced.block_location,
         blocks_index: locations_referenced.bloom_location,
         segments_stats: locations_referenced.hll_location,
-    }))
+        deletion_vectors: locations_referenced.deletion_vector_location,
+    };
+
+    // 3. Files used by the clones, branches and tags of the table are referenced as well.
+    let mut clone_referenced = fuse_table.get_clone_referenced_locations(ctx).await?;
+    clone_referenced.extend(fuse_table.get_table_ref_referenced_locations(ctx).await?);
+    referenced_files.segments.extend(clone_referenced.segments);
+    referenced_files
+        .blocks
+        .extend(clone_referenced.locations.block_location);
+    referenced_files
+        .blocks_index
+        .extend(clone_referenced.locations.bloom_location);
+    referenced_files
+        .segments_stats
+        .extend(clone_referenced.locations.hll_location);
+    referenced_files
+        .deletion_vectors
+        .extend(clone_referenced.locations.deletion_vector_location);
+    Ok(Some(referenced_files))
 }
 
 // return orphan files to be purged
@@ -304,6 +327,37 @@ pub async fn do_gc_orphan_files(
         start.elapsed()
     );
     ctx.set_status_info(&status);
+
+    // 6. Purge orphan deletion vector files.
+    // 6.1 Get orphan deletion vector files to be purged
+    let deletion_vector_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        location_gen.deletion_vector_location_prefix(),
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "gc orphan: read deletion_vector_locations_to_be_purged:{}, cost:{:?}",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
+    // 6.2 Delete all the orphan deletion vector files to be purged
+    let purged_file_num = deletion_vector_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files(
+            ctx.clone(),
+            HashSet::from_iter(deletion_vector_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged deletion vector files:{}, cost:{:?}",
+        purged_file_num,
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
     Ok(())
 }
 
@@ -405,6 +459,23 @@ pub async fn do_dry_run_orphan_files(
 
     purge_files.extend(stats_locations_to_be_purged);
 
+    // 6. Get purge orphan deletion vector files.
+    let deletion_vector_locations_to_be_purged = get_orphan_files_to_be_purged(
+        fuse_table,
+        location_gen.deletion_vector_location_prefix(),
+        referenced_files.deletion_vectors,
+        retention_time,
+    )
+    .await?;
+    let status = format!(
+        "dry_run orphan: read deletion_vector_locations_to_be_purged:{}, cost:{:?}",
+        deletion_vector_locations_to_be_purged.len(),
+        start.elapsed()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(deletion_vector_locations_to_be_purged);
+
     Ok(())
 }
 
@@ -224,6 +224,14 @@ pub async fn do_vacuum2(
         slice_summary(&snapshots_to_gc)
     ));
 
+    // Files used by the clones, branches and tags of the table must be kept.
+    let mut clone_referenced = fuse_table.get_clone_referenced_locations(&ctx).await?;
+    clone_referenced.extend(fuse_table.get_table_ref_referenced_locations(&ctx).await?);
+    let mut snapshots_to_gc: Vec<String> = snapshots_to_gc
+        .into_iter()
+        .filter(|s| !clone_referenced.contains(s))
+        .collect();
+
     let start = std::time::Instant::now();
     let gc_root_timestamp = gc_root.timestamp.unwrap();
     let gc_root_segments = gc_root
@@ -257,7 +265,7 @@ pub async fn do_vacuum2(
     let start = std::time::Instant::now();
     let segments_to_gc: Vec<String> = segments_before_gc_root
         .into_iter()
-        .filter(|s| !gc_root_segments.contains(s))
+        .filter(|s| !gc_root_segments.contains(s) && !clone_referenced.contains(s))
         .collect();
     let stats_to_gc = segments_to_gc
         .iter()
@@ -278,8 +286,15 @@ pub async fn do_vacuum2(
         .read_segments::<Arc<CompactSegmentInfo>>(&gc_root.segments, false)
         .await?;
     let mut gc_root_blocks = HashSet::new();
+    let mut gc_root_deletion_vectors = HashSet::new();
     for segment in segments {
-        gc_root_blocks.extend(segment?.block_metas()?.iter().map(|b| b.location.0.clone()));
+        let block_metas = segment?.block_metas()?;
+        gc_root_blocks.extend(block_metas.iter().map(|b| b.location.0.clone()));
+        gc_root_deletion_vectors.extend(
+            block_metas
+                .iter()
+                .filter_map(|b| b.deletion_vector.as_ref().map(|dv| dv.location.0.clone())),
+        );
     }
     ctx.set_status_info(&format!(
         "[FUSE-VACUUM2] Read segments for table {}, elapsed: {:?}",
@@ -312,7 +327,7 @@ pub async fn do_vacuum2(
     let start = std::time::Instant::now();
     let blocks_to_gc: Vec<String> = blocks_before_gc_root
         .into_iter()
-        .filter(|b| !gc_root_blocks.contains(b))
+        .filter(|b| !gc_root_blocks.contains(b) && !clone_referenced.contains(b))
         .collect();
     ctx.set_status_info(&format!(
         "[FUSE-VACUUM2] Filtered blocks_to_gc for table {}, elapsed: {:?}, blocks_to_gc: {:?}",
@@ -321,6 +336,31 @@ pub async fn do_vacuum2(
         slice_summary(&blocks_to_gc)
     ));
 
+    let start = std::time::Instant::now();
+    let deletion_vectors_before_gc_root = list_until_timestamp(
+        fuse_table,
+        fuse_table
+            .meta_location_generator()
+            .deletion_vector_location_prefix(),
+        gc_root_timestamp,
+        false,
+        Some(gc_root_meta_ts),
+    )
+    .await?
+    .into_iter()
+    .map(|v| v.path().to_owned())
+    .collect::<Vec<_>>();
+    let deletion_vectors_to_gc: Vec<String> = deletion_vectors_before_gc_root
+        .into_iter()
+        .filter(|v| !gc_root_deletion_vectors.contains(v) && !clone_referenced.contains(v))
+        .collect();
+    ctx.set_status_info(&format!(
+        "[FUSE-VACUUM2] Filtered deletion_vectors_to_gc for table {}, elapsed: {:?}, deletion_vectors_to_gc: {:?}",
+        fuse_table.get_table_info().desc,
+        start.elapsed(),
+        slice_summary(&deletion_vectors_to_gc)
+    ));
+
     let start = std::time::Instant::now();
     let catalog = ctx.get_default_catalog()?;
     let table_agg_index_ids = catalog
@@ -362,11 +402,21 @@ pub async fn do_vacuum2(
     ));
 
     let start = std::time::Instant::now();
-    let subject_files_to_gc: Vec<_> = segments_to_gc
+    let mut subject_files_to_gc: Vec<_> = segments_to_gc
         .into_iter()
         .chain(blocks_to_gc.into_iter())
         .chain(stats_to_gc.into_iter())
+        .chain(deletion_vectors_to_gc.into_iter())
         .collect();
+    // A clone may have been created from one of the snapshots being removed.
+    if fuse_table
+        .refresh_clone_referenced_locations(&ctx, &mut clone_referenced)
+        .await?
+    {
+        indexes_to_gc.retain(|loc| !clone_referenced.contains(loc));
+        subject_files_to_gc.retain(|loc| !clone_referenced.contains(loc));
+        snapshots_to_gc.retain(|loc| !clone_referenced.contains(loc));
+    }
     let op = Files::create(ctx.clone(), fuse_table.get_operator());
 
     // order is important
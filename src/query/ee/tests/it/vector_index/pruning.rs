@@ -142,6 +142,7 @@ async fn test_block_pruner() -> Result<()> {
         table_indexes: Some(table_indexes),
         table_constraints: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     };
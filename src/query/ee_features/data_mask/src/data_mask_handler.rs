@@ -33,6 +33,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::data_mask::CreateDatamaskReq;
 use databend_common_meta_app::data_mask::DatamaskMeta;
 use databend_common_meta_app::data_mask::DropDatamaskReq;
+use databend_common_meta_app::tag::TagMaskPolicy;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_store::MetaStore;
 use databend_common_meta_types::SeqV;
@@ -60,6 +61,16 @@ pub trait DatamaskHandler: Sync + Send {
         tenant: &Tenant,
         policy_id: u64,
     ) -> Result<SeqV<DatamaskMeta>>;
+
+    /// Returns the masking policies bound to the tags set on the database,
+    /// the table or the columns of the table.
+    async fn get_tag_data_masks(
+        &self,
+        meta_api: Arc<MetaStore>,
+        tenant: &Tenant,
+        db_id: u64,
+        table_id: u64,
+    ) -> Result<Vec<TagMaskPolicy>>;
 }
 
 pub struct DatamaskHandlerWrapper {
@@ -106,6 +117,18 @@ impl DatamaskHandlerWrapper {
             .get_data_mask_by_id(meta_api, tenant, policy_id)
             .await
     }
+
+    pub async fn get_tag_data_masks(
+        &self,
+        meta_api: Arc<MetaStore>,
+        tenant: &Tenant,
+        db_id: u64,
+        table_id: u64,
+    ) -> Result<Vec<TagMaskPolicy>> {
+        self.handler
+            .get_tag_data_masks(meta_api, tenant, db_id, table_id)
+            .await
+    }
 }
 
 pub fn get_datamask_handler() -> Arc<DatamaskHandlerWrapper> {
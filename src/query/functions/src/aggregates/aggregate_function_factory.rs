@@ -28,7 +28,7 @@ use super::AggregateFunctionSortAdaptor;
 use super::Aggregators;
 
 // The NULL value in the those function needs to be handled separately.
-const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 8] = [
+const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 9] = [
     "array_agg",
     "list",
     "json_agg",
@@ -37,6 +37,7 @@ const NEED_NULL_AGGREGATE_FUNCTIONS: [&str; 8] = [
     "group_array_moving_avg",
     "group_array_moving_sum",
     "st_collect",
+    "st_union_agg",
 ];
 
 const STATE_SUFFIX: &str = "_state";
@@ -37,6 +37,7 @@ use databend_common_expression::ScalarRef;
 use databend_common_expression::StateSerdeItem;
 use databend_common_io::ewkb_to_geo;
 use databend_common_io::geo_to_ewkb;
+use databend_functions_scalar_geo::overlay::geometry_union_all;
 use geo::Geometry;
 use geo::GeometryCollection;
 use geo::LineString;
@@ -222,6 +223,107 @@ where T: ArgType
     }
 }
 
+#[derive(Debug)]
+pub struct StUnionAggState<T: ValueType> {
+    inner: StCollectState<T>,
+}
+
+impl<T: ValueType> Default for StUnionAggState<T> {
+    fn default() -> Self {
+        Self {
+            inner: StCollectState::default(),
+        }
+    }
+}
+
+impl<T> ScalarStateFunc<T> for StUnionAggState<T>
+where T: ArgType
+{
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn add(&mut self, other: Option<T::ScalarRef<'_>>) {
+        self.inner.add(other)
+    }
+
+    fn add_batch(&mut self, column: &T::Column, validity: Option<&Bitmap>) -> Result<()> {
+        self.inner.add_batch(column, validity)
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.inner.merge(&rhs.inner)
+    }
+
+    fn merge_result(&mut self, builder: &mut ColumnBuilder) -> Result<()> {
+        if self.inner.values.is_empty() {
+            builder.push(ScalarRef::Null);
+            return Ok(());
+        }
+
+        let mut srid = None;
+        let mut geos = Vec::new();
+        let values = mem::take(&mut self.inner.values);
+        for (i, value) in values.into_iter().enumerate() {
+            let val = T::upcast_scalar(value);
+            let v = val.as_geometry().unwrap();
+            let (geo, geo_srid) = ewkb_to_geo(&mut Ewkb(v))?;
+            if i == 0 {
+                srid = geo_srid;
+            } else if !srid.eq(&geo_srid) {
+                return Err(ErrorCode::GeometryError(format!(
+                    "Incompatible SRID: {} and {}",
+                    srid.unwrap_or_default(),
+                    geo_srid.unwrap_or_default()
+                )));
+            }
+            geos.push(geo);
+        }
+
+        let data = geo_to_ewkb(geometry_union_all(geos)?, srid)?;
+        let geometry_value = Scalar::Geometry(data);
+        builder.push(geometry_value.as_ref());
+        Ok(())
+    }
+}
+
+impl<T> StateSerde for StUnionAggState<T>
+where T: ArgType
+{
+    fn serialize_type(function_data: Option<&dyn super::FunctionData>) -> Vec<StateSerdeItem> {
+        StCollectState::<T>::serialize_type(function_data)
+    }
+
+    fn batch_serialize(
+        places: &[StateAddr],
+        loc: &[AggrStateLoc],
+        builders: &mut [ColumnBuilder],
+    ) -> Result<()> {
+        batch_serialize1::<ArrayType<T>, Self, _>(places, loc, builders, |state, builder| {
+            for v in &state.inner.values {
+                builder.put_item(T::to_scalar_ref(v));
+            }
+            builder.commit_row();
+            Ok(())
+        })
+    }
+
+    fn batch_merge(
+        places: &[StateAddr],
+        loc: &[AggrStateLoc],
+        state: &BlockEntry,
+        filter: Option<&Bitmap>,
+    ) -> Result<()> {
+        batch_merge1::<ArrayType<T>, Self, _>(places, loc, state, filter, |state, values| {
+            state
+                .inner
+                .values
+                .extend(T::iter_column(&values).map(T::to_owned_scalar));
+            Ok(())
+        })
+    }
+}
+
 #[derive(Clone)]
 struct AggregateStCollectFunction<T, State> {
     display_name: String,
@@ -427,3 +529,28 @@ pub fn try_create_aggregate_st_collect_function(
 pub fn aggregate_st_collect_function_desc() -> AggregateFunctionDescription {
     AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_collect_function))
 }
+
+pub fn try_create_aggregate_st_union_agg_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+    _sort_descs: Vec<AggregateFunctionSortDesc>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_params(display_name, params.len(), 0)?;
+    assert_unary_arguments(display_name, argument_types.len())?;
+    if argument_types[0].remove_nullable() != DataType::Geometry
+        && argument_types[0] != DataType::Null
+    {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "The argument of aggregate function {display_name} must be Geometry",
+        )));
+    }
+    let return_type = DataType::Nullable(Box::new(DataType::Geometry));
+
+    type State = StUnionAggState<GeometryType>;
+    AggregateStCollectFunction::<GeometryType, State>::try_create(display_name, return_type)
+}
+
+pub fn aggregate_st_union_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_union_agg_function))
+}
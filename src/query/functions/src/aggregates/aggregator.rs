@@ -51,6 +51,7 @@ use super::aggregate_range_bound::aggregate_range_bound_function_desc;
 use super::aggregate_retention::aggregate_retention_function_desc;
 use super::aggregate_skewness::aggregate_skewness_function_desc;
 use super::aggregate_st_collect::aggregate_st_collect_function_desc;
+use super::aggregate_st_collect::aggregate_st_union_agg_function_desc;
 use super::aggregate_stddev::aggregate_stddev_pop_function_desc;
 use super::aggregate_stddev::aggregate_stddev_samp_function_desc;
 use super::aggregate_string_agg::aggregate_string_agg_function_desc;
@@ -169,6 +170,7 @@ impl Aggregators {
         factory.register("mode", aggregate_mode_function_desc());
 
         factory.register("st_collect", aggregate_st_collect_function_desc());
+        factory.register("st_union_agg", aggregate_st_union_agg_function_desc());
 
         factory.register("markov_train", aggregate_markov_train_function_desc());
     }
@@ -0,0 +1,442 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::consts::U16;
+use aes_gcm::aead::AeadInPlace;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::AesGcm;
+use aes_gcm::Nonce;
+use aes_gcm::Tag;
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::BlockCipher;
+use cbc::cipher::BlockDecryptMut;
+use cbc::cipher::BlockEncrypt;
+use cbc::cipher::BlockEncryptMut;
+use cbc::cipher::BlockSizeUser;
+use cbc::cipher::KeyIvInit;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::Column;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionFactory;
+use databend_common_expression::FunctionProperty;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use rand::RngCore;
+
+const AES_BLOCK_SIZE: usize = 16;
+const GCM_NONCE_SIZE: usize = 12;
+const GCM_TAG_SIZE: usize = 16;
+
+/// The encrypted value is laid out as `iv || ciphertext [|| tag]`,
+/// so `decrypt` only needs the key (and the aad used by `encrypt`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EncryptionMode {
+    AesGcm,
+    AesCbc,
+}
+
+impl EncryptionMode {
+    fn parse(mode: &str) -> Result<Self, String> {
+        if mode.eq_ignore_ascii_case("aes-gcm") {
+            Ok(EncryptionMode::AesGcm)
+        } else if mode.eq_ignore_ascii_case("aes-cbc") {
+            Ok(EncryptionMode::AesCbc)
+        } else {
+            Err(format!(
+                "Unsupported encryption mode '{mode}', expected 'aes-gcm' or 'aes-cbc'"
+            ))
+        }
+    }
+
+    fn iv_size(&self) -> usize {
+        match self {
+            EncryptionMode::AesGcm => GCM_NONCE_SIZE,
+            EncryptionMode::AesCbc => AES_BLOCK_SIZE,
+        }
+    }
+}
+
+impl std::fmt::Display for EncryptionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionMode::AesGcm => write!(f, "aes-gcm"),
+            EncryptionMode::AesCbc => write!(f, "aes-cbc"),
+        }
+    }
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // encrypt(value, key [, iv [, aad [, mode]]])
+    let encrypt = FunctionFactory::Closure(Box::new(|_, args_type: &[DataType]| {
+        if !(2..=5).contains(&args_type.len()) {
+            return None;
+        }
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let f = Function {
+            signature: FunctionSignature {
+                name: "encrypt".to_string(),
+                args_type: encryption_args_type(args_type.len(), 4),
+                return_type: DataType::Binary,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(encrypt_fn),
+            },
+        };
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    }));
+    registry.register_function_factory("encrypt", encrypt);
+    // A random iv is generated for each row if it is not given.
+    registry.properties.insert(
+        "encrypt".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+
+    // decrypt(value, key [, aad [, mode]])
+    let decrypt = FunctionFactory::Closure(Box::new(|_, args_type: &[DataType]| {
+        if !(2..=4).contains(&args_type.len()) {
+            return None;
+        }
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let f = Function {
+            signature: FunctionSignature {
+                name: "decrypt".to_string(),
+                args_type: encryption_args_type(args_type.len(), 3),
+                return_type: DataType::Binary,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(decrypt_fn),
+            },
+        };
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    }));
+    registry.register_function_factory("decrypt", decrypt);
+
+    let try_decrypt = FunctionFactory::Closure(Box::new(|_, args_type: &[DataType]| {
+        if !(2..=4).contains(&args_type.len()) {
+            return None;
+        }
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let f = Function {
+            signature: FunctionSignature {
+                name: "try_decrypt".to_string(),
+                args_type: encryption_args_type(args_type.len(), 3),
+                return_type: DataType::Binary,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(decrypt_fn),
+            },
+        };
+        if has_null {
+            Some(Arc::new(f.error_to_null().passthrough_nullable()))
+        } else {
+            Some(Arc::new(f.error_to_null()))
+        }
+    }));
+    registry.register_function_factory("try_decrypt", try_decrypt);
+}
+
+// All arguments are binary, except the last optional `mode` argument.
+fn encryption_args_type(num_args: usize, mode_position: usize) -> Vec<DataType> {
+    (0..num_args)
+        .map(|i| {
+            if i == mode_position {
+                DataType::String
+            } else {
+                DataType::Binary
+            }
+        })
+        .collect()
+}
+
+fn encrypt_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        Value::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let value_arg = args[0].try_downcast::<BinaryType>().unwrap();
+    let key_arg = args[1].try_downcast::<BinaryType>().unwrap();
+    let iv_arg = args
+        .get(2)
+        .map(|arg| arg.try_downcast::<BinaryType>().unwrap());
+    let aad_arg = args
+        .get(3)
+        .map(|arg| arg.try_downcast::<BinaryType>().unwrap());
+    let mode_arg = args
+        .get(4)
+        .map(|arg| arg.try_downcast::<StringType>().unwrap());
+
+    let size = len.unwrap_or(1);
+    let mut builder = BinaryColumnBuilder::with_capacity(size, 0);
+    let mut random_iv = [0u8; AES_BLOCK_SIZE];
+    for idx in 0..size {
+        let value = unsafe { value_arg.index_unchecked(idx) };
+        let key = unsafe { key_arg.index_unchecked(idx) };
+        let iv = iv_arg
+            .as_ref()
+            .map(|arg| unsafe { arg.index_unchecked(idx) })
+            .unwrap_or_default();
+        let aad = aad_arg
+            .as_ref()
+            .map(|arg| unsafe { arg.index_unchecked(idx) })
+            .unwrap_or_default();
+        let mode = mode_arg
+            .as_ref()
+            .map(|arg| unsafe { arg.index_unchecked(idx) })
+            .unwrap_or("aes-gcm");
+
+        let result = EncryptionMode::parse(mode).and_then(|mode| {
+            if iv.is_empty() {
+                let random_iv = &mut random_iv[..mode.iv_size()];
+                rand::thread_rng().fill_bytes(random_iv);
+                encrypt_value(mode, value, key, random_iv, aad, &mut builder.data)
+            } else {
+                encrypt_value(mode, value, key, iv, aad, &mut builder.data)
+            }
+        });
+        if let Err(err) = result {
+            ctx.set_error(builder.len(), err);
+        }
+        builder.commit_row();
+    }
+
+    build_binary_value(len, builder)
+}
+
+fn decrypt_fn(args: &[Value<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        Value::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let value_arg = args[0].try_downcast::<BinaryType>().unwrap();
+    let key_arg = args[1].try_downcast::<BinaryType>().unwrap();
+    let aad_arg = args
+        .get(2)
+        .map(|arg| arg.try_downcast::<BinaryType>().unwrap());
+    let mode_arg = args
+        .get(3)
+        .map(|arg| arg.try_downcast::<StringType>().unwrap());
+
+    let size = len.unwrap_or(1);
+    let mut builder = BinaryColumnBuilder::with_capacity(size, 0);
+    for idx in 0..size {
+        let value = unsafe { value_arg.index_unchecked(idx) };
+        let key = unsafe { key_arg.index_unchecked(idx) };
+        let aad = aad_arg
+            .as_ref()
+            .map(|arg| unsafe { arg.index_unchecked(idx) })
+            .unwrap_or_default();
+        let mode = mode_arg
+            .as_ref()
+            .map(|arg| unsafe { arg.index_unchecked(idx) })
+            .unwrap_or("aes-gcm");
+
+        let result = EncryptionMode::parse(mode)
+            .and_then(|mode| decrypt_value(mode, value, key, aad, &mut builder.data));
+        if let Err(err) = result {
+            ctx.set_error(builder.len(), err);
+        }
+        builder.commit_row();
+    }
+
+    build_binary_value(len, builder)
+}
+
+fn build_binary_value(len: Option<usize>, builder: BinaryColumnBuilder) -> Value<AnyType> {
+    match len {
+        Some(_) => Value::Column(Column::Binary(builder.build())),
+        None => Value::Scalar(Scalar::Binary(builder.build_scalar())),
+    }
+}
+
+/// Appends `iv || ciphertext [|| tag]` to `output`, the output is left unchanged on error.
+fn encrypt_value(
+    mode: EncryptionMode,
+    value: &[u8],
+    key: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    output: &mut Vec<u8>,
+) -> Result<(), String> {
+    if iv.len() != mode.iv_size() {
+        return Err(format!(
+            "The iv of {} must be {} bytes, but got {} bytes",
+            mode,
+            mode.iv_size(),
+            iv.len()
+        ));
+    }
+    if mode == EncryptionMode::AesCbc && !aad.is_empty() {
+        return Err("Additional authenticated data is not supported by aes-cbc".to_string());
+    }
+
+    let start = output.len();
+    output.extend_from_slice(iv);
+    let result = match (mode, key.len()) {
+        (EncryptionMode::AesGcm, 16) => gcm_encrypt::<aes::Aes128>(key, iv, aad, value, output),
+        (EncryptionMode::AesGcm, 24) => gcm_encrypt::<aes::Aes192>(key, iv, aad, value, output),
+        (EncryptionMode::AesGcm, 32) => gcm_encrypt::<aes::Aes256>(key, iv, aad, value, output),
+        (EncryptionMode::AesCbc, 16) => cbc_encrypt::<aes::Aes128>(key, iv, value, output),
+        (EncryptionMode::AesCbc, 24) => cbc_encrypt::<aes::Aes192>(key, iv, value, output),
+        (EncryptionMode::AesCbc, 32) => cbc_encrypt::<aes::Aes256>(key, iv, value, output),
+        (_, len) => Err(invalid_key_length(len)),
+    };
+    if result.is_err() {
+        output.truncate(start);
+    }
+    result
+}
+
+/// Appends the plaintext of `iv || ciphertext [|| tag]` to `output`,
+/// the output is left unchanged on error.
+fn decrypt_value(
+    mode: EncryptionMode,
+    value: &[u8],
+    key: &[u8],
+    aad: &[u8],
+    output: &mut Vec<u8>,
+) -> Result<(), String> {
+    let min_len = match mode {
+        EncryptionMode::AesGcm => GCM_NONCE_SIZE + GCM_TAG_SIZE,
+        EncryptionMode::AesCbc => AES_BLOCK_SIZE * 2,
+    };
+    if value.len() < min_len {
+        return Err(format!(
+            "The encrypted value of {} must be at least {} bytes, but got {} bytes",
+            mode,
+            min_len,
+            value.len()
+        ));
+    }
+    if mode == EncryptionMode::AesCbc && !aad.is_empty() {
+        return Err("Additional authenticated data is not supported by aes-cbc".to_string());
+    }
+
+    let start = output.len();
+    let result = match (mode, key.len()) {
+        (EncryptionMode::AesGcm, 16) => gcm_decrypt::<aes::Aes128>(key, aad, value, output),
+        (EncryptionMode::AesGcm, 24) => gcm_decrypt::<aes::Aes192>(key, aad, value, output),
+        (EncryptionMode::AesGcm, 32) => gcm_decrypt::<aes::Aes256>(key, aad, value, output),
+        (EncryptionMode::AesCbc, 16) => cbc_decrypt::<aes::Aes128>(key, value, output),
+        (EncryptionMode::AesCbc, 24) => cbc_decrypt::<aes::Aes192>(key, value, output),
+        (EncryptionMode::AesCbc, 32) => cbc_decrypt::<aes::Aes256>(key, value, output),
+        (_, len) => Err(invalid_key_length(len)),
+    };
+    if result.is_err() {
+        output.truncate(start);
+    }
+    result
+}
+
+// The plaintext is encrypted in place in `output`, the tag is appended after the ciphertext.
+fn gcm_encrypt<C>(
+    key: &[u8],
+    iv: &[u8],
+    aad: &[u8],
+    value: &[u8],
+    output: &mut Vec<u8>,
+) -> Result<(), String>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    let cipher = AesGcm::<C, U12>::new_from_slice(key).map_err(|e| e.to_string())?;
+    let start = output.len();
+    output.extend_from_slice(value);
+    let tag = cipher
+        .encrypt_in_place_detached(Nonce::from_slice(iv), aad, &mut output[start..])
+        .map_err(|_| "Failed to encrypt value with aes-gcm".to_string())?;
+    output.extend_from_slice(&tag);
+    Ok(())
+}
+
+fn gcm_decrypt<C>(
+    key: &[u8],
+    aad: &[u8],
+    value: &[u8],
+    output: &mut Vec<u8>,
+) -> Result<(), String>
+where
+    C: BlockCipher + BlockSizeUser<BlockSize = U16> + BlockEncrypt + KeyInit,
+{
+    let (iv, rest) = value.split_at(GCM_NONCE_SIZE);
+    let (ciphertext, tag) = rest.split_at(rest.len() - GCM_TAG_SIZE);
+    let cipher = AesGcm::<C, U12>::new_from_slice(key).map_err(|e| e.to_string())?;
+    let start = output.len();
+    output.extend_from_slice(ciphertext);
+    cipher
+        .decrypt_in_place_detached(
+            Nonce::from_slice(iv),
+            aad,
+            &mut output[start..],
+            Tag::from_slice(tag),
+        )
+        .map_err(|_| {
+            "Failed to decrypt value with aes-gcm, the key or aad may be wrong".to_string()
+        })
+}
+
+// Reserve one more block in `output` for the PKCS#7 padding, and encrypt in place.
+fn cbc_encrypt<C>(key: &[u8], iv: &[u8], value: &[u8], output: &mut Vec<u8>) -> Result<(), String>
+where C: BlockCipher + BlockEncryptMut + KeyInit {
+    let encryptor = cbc::Encryptor::<C>::new_from_slices(key, iv).map_err(|e| e.to_string())?;
+    let start = output.len();
+    output.extend_from_slice(value);
+    output.resize(start + value.len() + AES_BLOCK_SIZE, 0);
+    let len = encryptor
+        .encrypt_padded_mut::<Pkcs7>(&mut output[start..], value.len())
+        .map_err(|e| e.to_string())?
+        .len();
+    output.truncate(start + len);
+    Ok(())
+}
+
+fn cbc_decrypt<C>(key: &[u8], value: &[u8], output: &mut Vec<u8>) -> Result<(), String>
+where C: BlockCipher + BlockDecryptMut + KeyInit {
+    let (iv, ciphertext) = value.split_at(AES_BLOCK_SIZE);
+    let decryptor = cbc::Decryptor::<C>::new_from_slices(key, iv).map_err(|e| e.to_string())?;
+    let start = output.len();
+    output.extend_from_slice(ciphertext);
+    let len = decryptor
+        .decrypt_padded_mut::<Pkcs7>(&mut output[start..])
+        .map_err(|_| "Failed to decrypt value with aes-cbc, the key may be wrong".to_string())?
+        .len();
+    output.truncate(start + len);
+    Ok(())
+}
+
+fn invalid_key_length(len: usize) -> String {
+    format!("The key of aes must be 16, 24 or 32 bytes, but got {len} bytes")
+}
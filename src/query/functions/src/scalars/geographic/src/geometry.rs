@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_base::runtime::catch_unwind;
 use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
 use databend_common_expression::types::geometry::GeometryType;
 use databend_common_expression::types::BinaryType;
 use databend_common_expression::types::BooleanType;
@@ -45,11 +47,15 @@ use databend_common_io::Axis;
 use databend_common_io::Extremum;
 use geo::coord;
 use geo::dimensions::Dimensions;
+use geo::line_intersection::line_intersection;
 use geo::Area;
+use geo::BooleanOps;
 use geo::BoundingRect;
+use geo::Centroid;
 use geo::Contains;
 use geo::ConvexHull;
 use geo::Coord;
+use geo::CoordsIter;
 use geo::EuclideanDistance;
 use geo::EuclideanLength;
 use geo::Geometry;
@@ -57,12 +63,15 @@ use geo::HasDimensions;
 use geo::HaversineDistance;
 use geo::Intersects;
 use geo::Line;
+use geo::LineIntersection;
 use geo::LineString;
 use geo::MultiLineString;
 use geo::MultiPolygon;
+use geo::OpType;
 use geo::Point;
 use geo::Polygon;
 use geo::Rect;
+use geo::Simplify;
 use geo::ToDegrees;
 use geo::ToRadians;
 use geo::Triangle;
@@ -81,6 +90,10 @@ use num_traits::AsPrimitive;
 use proj4rs::transform::transform;
 use proj4rs::Proj;
 
+use crate::overlay::geometry_buffer;
+use crate::overlay::geometry_make_valid;
+use crate::overlay::geometry_overlay;
+
 pub fn register(registry: &mut FunctionRegistry) {
     // aliases
     registry.register_aliases("st_aswkb", &["st_asbinary"]);
@@ -1665,6 +1678,263 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    // The set operations accept any geometry type, see `geometry_overlay`.
+    for (name, op) in [
+        ("st_union", OpType::Union),
+        ("st_intersection", OpType::Intersection),
+        ("st_difference", OpType::Difference),
+        ("st_symdifference", OpType::Xor),
+    ] {
+        registry
+            .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+                name,
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+                    move |l_ewkb, r_ewkb, builder, ctx| {
+                        if let Some(validity) = &ctx.validity {
+                            if !validity.get_bit(builder.len()) {
+                                builder.commit_row();
+                                return;
+                            }
+                        }
+
+                        match (
+                            ewkb_to_geo(&mut Ewkb(l_ewkb)),
+                            ewkb_to_geo(&mut Ewkb(r_ewkb)),
+                        ) {
+                            (Ok((l_geo, l_srid)), Ok((r_geo, r_srid))) => {
+                                if !check_incompatible_srid(l_srid, r_srid, builder.len(), ctx) {
+                                    builder.commit_row();
+                                    return;
+                                }
+                                match geometry_overlay(l_geo, r_geo, op)
+                                    .and_then(|geo| geo_to_ewkb(geo, l_srid))
+                                {
+                                    Ok(ewkb) => {
+                                        builder.put_slice(ewkb.as_slice());
+                                    }
+                                    Err(e) => {
+                                        ctx.set_error(builder.len(), e.to_string());
+                                    }
+                                }
+                            }
+                            (Err(e), _) | (_, Err(e)) => {
+                                ctx.set_error(builder.len(), e.to_string());
+                            }
+                        }
+                        builder.commit_row();
+                    },
+                ),
+            );
+    }
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_buffer",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |ewkb, distance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match ewkb_to_geo(&mut Ewkb(ewkb)).and_then(|(geo, srid)| {
+                        let buffered = geometry_buffer(&geo, distance.0)?;
+                        geo_to_ewkb(buffered, srid)
+                    }) {
+                        Ok(ewkb) => {
+                            builder.put_slice(ewkb.as_slice());
+                        }
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e.to_string());
+                        }
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_simplify",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |ewkb, tolerance, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match ewkb_to_geo(&mut Ewkb(ewkb)).and_then(|(geo, srid)| {
+                        geo_to_ewkb(geometry_simplify(geo, tolerance.0), srid)
+                    }) {
+                        Ok(ewkb) => {
+                            builder.put_slice(ewkb.as_slice());
+                        }
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e.to_string());
+                        }
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+
+    registry.register_combine_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_centroid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NullableType<GeometryType>>(
+            |ewkb, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push_null();
+                        return;
+                    }
+                }
+
+                match ewkb_to_geo(&mut Ewkb(ewkb)).and_then(|(geo, srid)| match geo.centroid() {
+                    Some(point) => geo_to_ewkb(Geometry::from(point), srid).map(Some),
+                    None => Ok(None),
+                }) {
+                    Ok(Some(binary)) => {
+                        builder.push(binary.as_slice());
+                    }
+                    Ok(None) => {
+                        builder.push_null();
+                    }
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push_null();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_combine_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_envelope",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NullableType<GeometryType>>(
+            |ewkb, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push_null();
+                        return;
+                    }
+                }
+
+                match ewkb_to_geo(&mut Ewkb(ewkb)).and_then(|(geo, srid)| {
+                    match geo.bounding_rect() {
+                        Some(rect) => geo_to_ewkb(rect_to_envelope(rect), srid).map(Some),
+                        None => Ok(None),
+                    }
+                }) {
+                    Ok(Some(binary)) => {
+                        builder.push(binary.as_slice());
+                    }
+                    Ok(None) => {
+                        builder.push_null();
+                    }
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push_null();
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType>(
+            |l_ewkb, r_ewkb, distance, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+
+                match (
+                    ewkb_to_geo(&mut Ewkb(l_ewkb)),
+                    ewkb_to_geo(&mut Ewkb(r_ewkb)),
+                ) {
+                    (Ok((l_geo, l_srid)), Ok((r_geo, r_srid))) => {
+                        if !check_incompatible_srid(l_srid, r_srid, builder.len(), ctx) {
+                            builder.push(false);
+                            return;
+                        }
+                        let is_within = l_geo.euclidean_distance(&r_geo) <= distance.0;
+                        builder.push(is_within);
+                    }
+                    (Err(e), _) | (_, Err(e)) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BooleanType, _, _>(
+        "st_isvalid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, BooleanType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(false);
+                    return;
+                }
+            }
+
+            match ewkb_to_geo(&mut Ewkb(ewkb)) {
+                Ok((geo, _)) => {
+                    builder.push(is_valid_geometry(&geo));
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(false);
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_makevalid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, GeometryType>(|ewkb, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.commit_row();
+                    return;
+                }
+            }
+
+            match ewkb_to_geo(&mut Ewkb(ewkb)).and_then(|(geo, srid)| {
+                let geo = if is_valid_geometry(&geo) {
+                    geo
+                } else {
+                    geometry_make_valid(geo)?
+                };
+                geo_to_ewkb(geo, srid)
+            }) {
+                Ok(ewkb) => {
+                    builder.put_slice(ewkb.as_slice());
+                }
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                }
+            }
+            builder.commit_row();
+        }),
+    );
 }
 
 fn st_transform_impl(
@@ -2060,3 +2330,124 @@ fn check_incompatible_srid(
         true
     }
 }
+
+fn geometry_simplify(geo: Geometry, tolerance: f64) -> Geometry {
+    match geo {
+        Geometry::LineString(line_string) => Geometry::LineString(line_string.simplify(&tolerance)),
+        Geometry::MultiLineString(multi_line_string) => {
+            Geometry::MultiLineString(multi_line_string.simplify(&tolerance))
+        }
+        Geometry::Polygon(polygon) => Geometry::Polygon(polygon.simplify(&tolerance)),
+        Geometry::MultiPolygon(multi_polygon) => {
+            Geometry::MultiPolygon(multi_polygon.simplify(&tolerance))
+        }
+        Geometry::GeometryCollection(collection) => Geometry::GeometryCollection(
+            collection
+                .into_iter()
+                .map(|geo| geometry_simplify(geo, tolerance))
+                .collect(),
+        ),
+        _ => geo,
+    }
+}
+
+/// The envelope of a point is the point itself, and the envelope of a
+/// horizontal or vertical geometry is a line, otherwise it is a polygon.
+fn rect_to_envelope(rect: Rect) -> Geometry {
+    let (min, max) = (rect.min(), rect.max());
+    if min == max {
+        Geometry::Point(Point::from(min))
+    } else if min.x == max.x || min.y == max.y {
+        Geometry::LineString(LineString::new(vec![min, max]))
+    } else {
+        Geometry::Polygon(rect.to_polygon())
+    }
+}
+
+fn is_valid_geometry(geo: &Geometry) -> bool {
+    if geo
+        .coords_iter()
+        .any(|c| !c.x.is_finite() || !c.y.is_finite())
+    {
+        return false;
+    }
+    match geo {
+        Geometry::LineString(line_string) => line_string.0.len() != 1,
+        Geometry::MultiLineString(multi_line_string) => multi_line_string
+            .iter()
+            .all(|line_string| line_string.0.len() != 1),
+        Geometry::Polygon(polygon) => is_valid_polygon(polygon),
+        Geometry::MultiPolygon(multi_polygon) => {
+            if !multi_polygon.iter().all(is_valid_polygon) {
+                return false;
+            }
+            // The interiors of the member polygons must not overlap.
+            for (i, l_polygon) in multi_polygon.iter().enumerate() {
+                for r_polygon in multi_polygon.iter().skip(i + 1) {
+                    // geo may panic on overlapping members, which are invalid anyway.
+                    let overlaps =
+                        catch_unwind(|| l_polygon.intersection(r_polygon).unsigned_area() > 0.0)
+                            .unwrap_or(true);
+                    if overlaps {
+                        return false;
+                    }
+                }
+            }
+            true
+        }
+        Geometry::GeometryCollection(collection) => collection.iter().all(is_valid_geometry),
+        _ => true,
+    }
+}
+
+fn is_valid_polygon(polygon: &Polygon) -> bool {
+    let exterior = polygon.exterior();
+    if exterior.0.is_empty() {
+        return polygon.interiors().is_empty();
+    }
+    let rings = std::iter::once(exterior).chain(polygon.interiors().iter());
+    for ring in rings.clone() {
+        if ring.0.len() < 4 || !ring.is_closed() || !is_simple_ring(ring) {
+            return false;
+        }
+    }
+    // Rings may touch at a point, but must not cross each other.
+    let rings = rings.collect::<Vec<_>>();
+    for (i, l_ring) in rings.iter().enumerate() {
+        for r_ring in rings.iter().skip(i + 1) {
+            for l_line in l_ring.lines() {
+                for r_line in r_ring.lines() {
+                    match line_intersection(l_line, r_line) {
+                        Some(LineIntersection::SinglePoint { is_proper, .. }) if !is_proper => {}
+                        None => {}
+                        _ => return false,
+                    }
+                }
+            }
+        }
+    }
+    let shell = Polygon::new(exterior.clone(), vec![]);
+    polygon.interiors().iter().all(|interior| {
+        interior
+            .points()
+            .all(|point| shell.contains(&point) || shell.exterior().intersects(&point))
+    })
+}
+
+/// A ring is simple if only adjacent segments touch, and only at their shared endpoint.
+fn is_simple_ring(ring: &LineString) -> bool {
+    let lines = ring.lines().collect::<Vec<_>>();
+    let num_lines = lines.len();
+    for i in 0..num_lines {
+        for j in (i + 1)..num_lines {
+            let is_adjacent = j == i + 1 || (i == 0 && j == num_lines - 1);
+            match line_intersection(lines[i], lines[j]) {
+                None => {}
+                Some(LineIntersection::SinglePoint { is_proper, .. })
+                    if is_adjacent && !is_proper => {}
+                _ => return false,
+            }
+        }
+    }
+    true
+}
@@ -28,3 +28,4 @@ pub mod geo;
 pub mod geo_h3;
 pub mod geography;
 pub mod geometry;
+pub mod overlay;
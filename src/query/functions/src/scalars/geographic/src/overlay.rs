@@ -0,0 +1,960 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Overlay operations of planar geometries: the set operations, the buffer and the repair
+//! of invalid polygons.
+
+use std::collections::HashMap;
+
+use databend_common_base::runtime::catch_unwind;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use geo::coord;
+use geo::line_intersection::line_intersection;
+use geo::Area;
+use geo::BooleanOps;
+use geo::Contains;
+use geo::Coord;
+use geo::CoordsIter;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::GeometryCollection;
+use geo::Intersects;
+use geo::Line;
+use geo::LineIntersection;
+use geo::LineString;
+use geo::MultiLineString;
+use geo::MultiPoint;
+use geo::MultiPolygon;
+use geo::OpType;
+use geo::Point;
+use geo::Polygon;
+
+/// Number of segments used to approximate a quarter circle in `st_buffer`.
+const BUFFER_QUADRANT_SEGMENTS: usize = 8;
+
+/// The parts of a geometry, grouped by dimension.
+struct GeometryParts {
+    points: Vec<Point>,
+    lines: Vec<LineString>,
+    polygons: MultiPolygon,
+}
+
+impl GeometryParts {
+    fn new(geo: Geometry) -> Self {
+        let mut parts = GeometryParts {
+            points: vec![],
+            lines: vec![],
+            polygons: MultiPolygon::new(vec![]),
+        };
+        parts.add(geo);
+        parts
+    }
+
+    fn add(&mut self, geo: Geometry) {
+        match geo {
+            Geometry::Point(point) => self.points.push(point),
+            Geometry::MultiPoint(multi_point) => self.points.extend(multi_point),
+            Geometry::Line(line) => self.lines.push(LineString::from(line)),
+            Geometry::LineString(line_string) => self.lines.push(line_string),
+            Geometry::MultiLineString(multi_line_string) => self.lines.extend(multi_line_string),
+            Geometry::Polygon(polygon) => self.polygons.0.push(polygon),
+            Geometry::MultiPolygon(multi_polygon) => self.polygons.0.extend(multi_polygon),
+            Geometry::Rect(rect) => self.polygons.0.push(rect.to_polygon()),
+            Geometry::Triangle(triangle) => self.polygons.0.push(triangle.to_polygon()),
+            Geometry::GeometryCollection(collection) => {
+                for geo in collection {
+                    self.add(geo);
+                }
+            }
+        }
+    }
+
+    fn intersects(&self, point: &Point) -> bool {
+        self.points.contains(point)
+            || self.lines.iter().any(|line| line.intersects(point))
+            || self.polygons.intersects(point)
+    }
+
+    fn union(self, other: GeometryParts) -> Result<GeometryParts> {
+        let polygons = union_polygons(vec![self.polygons, other.polygons])?;
+        let lines = merge_lines(self.lines, other.lines);
+        let lines = clip_lines(lines, &polygons, true)?;
+        let points = self.points.into_iter().chain(other.points);
+        Ok(GeometryParts::assemble(points, lines, polygons))
+    }
+
+    fn intersection(&self, other: &GeometryParts) -> Result<GeometryParts> {
+        let polygons = if self.polygons.0.is_empty() || other.polygons.0.is_empty() {
+            MultiPolygon::new(vec![])
+        } else {
+            polygon_boolean_op(&self.polygons, &other.polygons, OpType::Intersection)?
+        };
+
+        let mut lines = overlap_lines(&self.lines, &other.lines);
+        if !other.polygons.0.is_empty() {
+            let clipped = clip_lines(self.lines.clone(), &other.polygons, false)?;
+            lines = merge_lines(lines, clipped);
+        }
+        if !self.polygons.0.is_empty() {
+            let clipped = clip_lines(other.lines.clone(), &self.polygons, false)?;
+            lines = merge_lines(lines, clipped);
+        }
+        let lines = clip_lines(lines, &polygons, true)?;
+
+        let points = self
+            .points
+            .iter()
+            .filter(|point| other.intersects(point))
+            .chain(other.points.iter().filter(|point| self.intersects(point)))
+            .cloned()
+            .chain(crossing_points(&self.lines, &other.lines));
+        Ok(GeometryParts::assemble(points, lines, polygons))
+    }
+
+    fn difference(&self, other: &GeometryParts) -> Result<GeometryParts> {
+        let polygons = if other.polygons.0.is_empty() {
+            self.polygons.clone()
+        } else {
+            polygon_boolean_op(&self.polygons, &other.polygons, OpType::Difference)?
+        };
+        let lines = subtract_lines(&self.lines, &other.lines);
+        let lines = clip_lines(lines, &other.polygons, true)?;
+        let points = self
+            .points
+            .iter()
+            .filter(|point| !other.intersects(point))
+            .cloned();
+        Ok(GeometryParts::assemble(points, lines, polygons))
+    }
+
+    /// Builds the parts of a result, dropping the points covered by its lines and polygons.
+    fn assemble(
+        points: impl Iterator<Item = Point>,
+        lines: Vec<LineString>,
+        polygons: MultiPolygon,
+    ) -> GeometryParts {
+        let mut parts = GeometryParts {
+            points: vec![],
+            lines,
+            polygons,
+        };
+        for point in points {
+            if !parts.intersects(&point) {
+                parts.points.push(point);
+            }
+        }
+        parts
+    }
+
+    fn into_geometry(mut self) -> Geometry {
+        let mut geos = Vec::new();
+        if !self.polygons.0.is_empty() {
+            geos.push(multi_polygon_to_geometry(self.polygons));
+        }
+        match self.lines.len() {
+            0 => {}
+            1 => geos.push(Geometry::LineString(self.lines.pop().unwrap())),
+            _ => geos.push(Geometry::MultiLineString(MultiLineString::new(self.lines))),
+        }
+        match self.points.len() {
+            0 => {}
+            1 => geos.push(Geometry::Point(self.points.pop().unwrap())),
+            _ => geos.push(Geometry::MultiPoint(MultiPoint::new(self.points))),
+        }
+        match geos.len() {
+            0 => Geometry::GeometryCollection(GeometryCollection::default()),
+            1 => geos.pop().unwrap(),
+            _ => Geometry::GeometryCollection(GeometryCollection::new_from(geos)),
+        }
+    }
+}
+
+/// Runs a set operation on two geometries of any type.
+///
+/// Polygonal parts are combined with boolean operations, linear parts are split where
+/// they overlap other parts, and the parts covered by a part of higher dimension are
+/// dropped from the result.
+pub fn geometry_overlay(l_geo: Geometry, r_geo: Geometry, op: OpType) -> Result<Geometry> {
+    let l_parts = GeometryParts::new(l_geo);
+    let r_parts = GeometryParts::new(r_geo);
+    let parts = match op {
+        OpType::Union => l_parts.union(r_parts)?,
+        OpType::Intersection => l_parts.intersection(&r_parts)?,
+        OpType::Difference => l_parts.difference(&r_parts)?,
+        OpType::Xor => {
+            let l_only = l_parts.difference(&r_parts)?;
+            let r_only = r_parts.difference(&l_parts)?;
+            l_only.union(r_only)?
+        }
+    };
+    Ok(parts.into_geometry())
+}
+
+/// Computes the union of all the geometries.
+pub fn geometry_union_all(geos: Vec<Geometry>) -> Result<Geometry> {
+    let mut points = Vec::new();
+    let mut lines = Vec::new();
+    let mut polygons = Vec::with_capacity(geos.len());
+    for geo in geos {
+        let parts = GeometryParts::new(geo);
+        points.extend(parts.points);
+        lines = merge_lines(lines, parts.lines);
+        polygons.push(parts.polygons);
+    }
+    let polygons = union_polygons(polygons)?;
+    let lines = clip_lines(lines, &polygons, true)?;
+    Ok(GeometryParts::assemble(points.into_iter(), lines, polygons).into_geometry())
+}
+
+/// Unwraps a `MultiPolygon` produced by a boolean operation into the simplest geometry type.
+fn multi_polygon_to_geometry(mut multi_polygon: MultiPolygon) -> Geometry {
+    if multi_polygon.0.len() == 1 {
+        Geometry::Polygon(multi_polygon.0.pop().unwrap())
+    } else {
+        Geometry::MultiPolygon(multi_polygon)
+    }
+}
+
+/// Runs a boolean operation on two polygonal geometries.
+///
+/// geo panics on some invalid inputs, such as non-finite coordinates or badly
+/// self-intersecting rings, so the inputs are checked and a panic is returned as an error.
+fn polygon_boolean_op(
+    l_polygons: &MultiPolygon,
+    r_polygons: &MultiPolygon,
+    op: OpType,
+) -> Result<MultiPolygon> {
+    check_finite(l_polygons.coords_iter().chain(r_polygons.coords_iter()))?;
+    catch_unwind(|| l_polygons.boolean_op(r_polygons, op)).map_err(|_| {
+        ErrorCode::GeometryError(
+            "Failed to compute the boolean operation on invalid geometry, the input can be repaired with ST_MAKEVALID",
+        )
+    })
+}
+
+/// Computes the union of the polygons by merging them pairwise, level by level, so every
+/// polygon takes part in `O(log n)` boolean operations instead of `O(n)`.
+fn union_polygons(mut polygons: Vec<MultiPolygon>) -> Result<MultiPolygon> {
+    polygons.retain(|polygons| !polygons.0.is_empty());
+    while polygons.len() > 1 {
+        let mut merged = Vec::with_capacity(polygons.len() / 2 + 1);
+        let mut iter = polygons.into_iter();
+        while let Some(l_polygons) = iter.next() {
+            match iter.next() {
+                Some(r_polygons) => {
+                    merged.push(polygon_boolean_op(&l_polygons, &r_polygons, OpType::Union)?);
+                }
+                None => merged.push(l_polygons),
+            }
+        }
+        polygons = merged;
+    }
+    Ok(polygons.pop().unwrap_or_else(|| MultiPolygon::new(vec![])))
+}
+
+fn check_finite(mut coords: impl Iterator<Item = Coord>) -> Result<()> {
+    if coords.any(|coord| !coord.x.is_finite() || !coord.y.is_finite()) {
+        return Err(ErrorCode::GeometryError(
+            "Geometry with non-finite coordinates is not supported",
+        ));
+    }
+    Ok(())
+}
+
+/// Keeps the parts of the lines inside the polygons, or outside of them if `invert` is set.
+fn clip_lines(
+    lines: Vec<LineString>,
+    polygons: &MultiPolygon,
+    invert: bool,
+) -> Result<Vec<LineString>> {
+    if lines.is_empty() || polygons.0.is_empty() {
+        return Ok(if invert { lines } else { vec![] });
+    }
+    let lines = MultiLineString::new(lines);
+    check_finite(lines.coords_iter().chain(polygons.coords_iter()))?;
+    let clipped = catch_unwind(|| polygons.clip(&lines, invert)).map_err(|_| {
+        ErrorCode::GeometryError(
+            "Failed to clip lines by invalid geometry, the input can be repaired with ST_MAKEVALID",
+        )
+    })?;
+    Ok(clipped.0)
+}
+
+/// Adds the parts of `others` that don't overlap `lines`.
+fn merge_lines(mut lines: Vec<LineString>, others: Vec<LineString>) -> Vec<LineString> {
+    if lines.is_empty() {
+        return others;
+    }
+    let others = subtract_lines(&others, &lines);
+    lines.extend(others);
+    lines
+}
+
+/// Returns the parts of the lines not overlapped by `others`.
+fn subtract_lines(lines: &[LineString], others: &[LineString]) -> Vec<LineString> {
+    split_lines(lines, others, false)
+}
+
+/// Returns the parts of the lines overlapped by `others`.
+fn overlap_lines(lines: &[LineString], others: &[LineString]) -> Vec<LineString> {
+    split_lines(lines, others, true)
+}
+
+/// Splits every segment of the lines at the ranges overlapped by the segments of `others`,
+/// and keeps either the overlapped ranges or the rest.
+fn split_lines(lines: &[LineString], others: &[LineString], overlapped: bool) -> Vec<LineString> {
+    let other_segments = others
+        .iter()
+        .flat_map(|line| line.lines())
+        .collect::<Vec<_>>();
+    let mut result = Vec::new();
+    for line in lines {
+        let mut coords: Vec<Coord> = Vec::new();
+        for segment in line.lines() {
+            if segment.start == segment.end {
+                continue;
+            }
+            let mut ranges = other_segments
+                .iter()
+                .filter_map(|other| match line_intersection(segment, *other) {
+                    Some(LineIntersection::Collinear { intersection }) => {
+                        let start = segment_fraction(segment, intersection.start);
+                        let end = segment_fraction(segment, intersection.end);
+                        Some((start.min(end), start.max(end)))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            ranges.sort_by(|l, r| l.0.total_cmp(&r.0));
+
+            // The ranges of the segment kept in the result.
+            let mut kept = Vec::new();
+            let mut covered_until = 0.0;
+            for (start, end) in ranges {
+                if overlapped {
+                    kept.push((start.max(covered_until), end));
+                } else if start > covered_until {
+                    kept.push((covered_until, start));
+                }
+                covered_until = f64::max(covered_until, end);
+            }
+            if !overlapped && covered_until < 1.0 {
+                kept.push((covered_until, 1.0));
+            }
+
+            for (start, end) in kept {
+                if start >= end {
+                    continue;
+                }
+                let start = segment_point(segment, start);
+                let end = segment_point(segment, end);
+                if coords.last() != Some(&start) {
+                    if coords.len() > 1 {
+                        result.push(LineString::new(std::mem::take(&mut coords)));
+                    }
+                    coords = vec![start];
+                }
+                coords.push(end);
+            }
+        }
+        if coords.len() > 1 {
+            result.push(LineString::new(coords));
+        }
+    }
+    result
+}
+
+/// The points where the segments of the lines cross the segments of `others`.
+fn crossing_points(lines: &[LineString], others: &[LineString]) -> Vec<Point> {
+    let mut points = Vec::new();
+    for segment in lines.iter().flat_map(|line| line.lines()) {
+        for other in others.iter().flat_map(|line| line.lines()) {
+            if let Some(LineIntersection::SinglePoint { intersection, .. }) =
+                line_intersection(segment, other)
+            {
+                let point = Point::from(intersection);
+                if !points.contains(&point) {
+                    points.push(point);
+                }
+            }
+        }
+    }
+    points
+}
+
+/// The position of a point on the segment, 0 at its start and 1 at its end.
+fn segment_fraction(segment: Line, coord: Coord) -> f64 {
+    if coord == segment.start {
+        return 0.0;
+    }
+    if coord == segment.end {
+        return 1.0;
+    }
+    let delta = segment.delta();
+    let offset = coord - segment.start;
+    let fraction =
+        (offset.x * delta.x + offset.y * delta.y) / (delta.x * delta.x + delta.y * delta.y);
+    fraction.clamp(0.0, 1.0)
+}
+
+fn segment_point(segment: Line, fraction: f64) -> Coord {
+    if fraction <= 0.0 {
+        segment.start
+    } else if fraction >= 1.0 {
+        segment.end
+    } else {
+        segment.start + segment.delta() * fraction
+    }
+}
+
+/// Computes the area within `distance` of the geometry.
+///
+/// The boundary of the area is made of the offset curves of the geometry, that is the
+/// segments moved by `distance` to either of their sides and joined by arcs around the
+/// vertices. The curves are noded into a planar graph, and the faces of the graph within
+/// `distance` of the geometry are merged into the result. A negative distance is only
+/// meaningful for polygons, which are shrunk to the faces farther than `distance` from
+/// their boundary.
+pub fn geometry_buffer(geo: &Geometry, distance: f64) -> Result<Geometry> {
+    if !distance.is_finite() {
+        return Err(ErrorCode::GeometryError(format!(
+            "Invalid buffer distance {distance}"
+        )));
+    }
+    if distance == 0.0 {
+        return Ok(geo.clone());
+    }
+    check_finite(geo.coords_iter())?;
+
+    let radius = distance.abs();
+    let mut curves = Vec::new();
+    if distance > 0.0 {
+        collect_offset_curves(geo, radius, &mut curves);
+        let buffered = polygonize(&curves, |coord| {
+            geo.euclidean_distance(&Geometry::Point(Point::from(coord))) < radius
+        })?;
+        return Ok(multi_polygon_to_geometry(buffered));
+    }
+
+    let polygons = GeometryParts::new(geo.clone()).polygons;
+    let boundary = polygons
+        .iter()
+        .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .cloned()
+        .collect::<MultiLineString>();
+    for ring in &boundary {
+        add_offset_curves(&ring.0, true, radius, &mut curves);
+    }
+    let shrunk = polygonize(&curves, |coord| {
+        let point = Point::from(coord);
+        polygons.contains(&point) && boundary.euclidean_distance(&point) > radius
+    })?;
+    Ok(multi_polygon_to_geometry(shrunk))
+}
+
+fn collect_offset_curves(geo: &Geometry, radius: f64, curves: &mut Vec<Line>) {
+    match geo {
+        Geometry::Point(point) => add_offset_curves(&[point.0], false, radius, curves),
+        Geometry::MultiPoint(multi_point) => {
+            for point in multi_point {
+                add_offset_curves(&[point.0], false, radius, curves);
+            }
+        }
+        Geometry::Line(line) => add_offset_curves(&[line.start, line.end], false, radius, curves),
+        Geometry::LineString(line_string) => {
+            add_offset_curves(&line_string.0, false, radius, curves)
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            for line_string in multi_line_string {
+                add_offset_curves(&line_string.0, false, radius, curves);
+            }
+        }
+        Geometry::Polygon(polygon) => {
+            for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                add_offset_curves(&ring.0, true, radius, curves);
+            }
+        }
+        Geometry::MultiPolygon(multi_polygon) => {
+            for polygon in multi_polygon {
+                collect_offset_curves(&Geometry::Polygon(polygon.clone()), radius, curves);
+            }
+        }
+        Geometry::Rect(rect) => {
+            collect_offset_curves(&Geometry::Polygon(rect.to_polygon()), radius, curves)
+        }
+        Geometry::Triangle(triangle) => {
+            collect_offset_curves(&Geometry::Polygon(triangle.to_polygon()), radius, curves)
+        }
+        Geometry::GeometryCollection(collection) => {
+            for geo in collection {
+                collect_offset_curves(geo, radius, curves);
+            }
+        }
+    }
+}
+
+/// Adds the offset curves on both sides of a path, a single point becomes a circle.
+fn add_offset_curves(coords: &[Coord], closed: bool, radius: f64, curves: &mut Vec<Line>) {
+    let mut coords = coords.to_vec();
+    coords.dedup();
+    if closed && coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    match coords.len() {
+        0 => {}
+        1 => add_closed_curve(&circle_coords(coords[0], radius), curves),
+        _ if closed => {
+            add_closed_curve(&right_offset_curve(&coords, radius), curves);
+            coords.reverse();
+            add_closed_curve(&right_offset_curve(&coords, radius), curves);
+        }
+        _ => {
+            // Going along an open path and back, the ends are reversals, which are joined
+            // by half circles.
+            let mut path = coords.clone();
+            path.extend(coords[1..coords.len() - 1].iter().rev());
+            add_closed_curve(&right_offset_curve(&path, radius), curves);
+        }
+    }
+}
+
+/// The offset curve on the right side of a closed path.
+///
+/// Where the path turns to the left, the offset segments are joined by an arc around the
+/// vertex. Where it turns to the right, they cross each other, and are joined through the
+/// vertex itself, the loop this makes is outside the result.
+fn right_offset_curve(path: &[Coord], radius: f64) -> Vec<Coord> {
+    let right_normal = |start: Coord, end: Coord| {
+        let delta = end - start;
+        let length = delta.x.hypot(delta.y);
+        coord! { x: delta.y / length * radius, y: -delta.x / length * radius }
+    };
+    let mut curve = Vec::new();
+    for i in 0..path.len() {
+        let (start, vertex, end) = (
+            path[i],
+            path[(i + 1) % path.len()],
+            path[(i + 2) % path.len()],
+        );
+        let (from, to) = (right_normal(start, vertex), right_normal(vertex, end));
+        curve.push(start + from);
+        curve.push(vertex + from);
+
+        let (incoming, outgoing) = (vertex - start, end - vertex);
+        let cross = incoming.x * outgoing.y - incoming.y * outgoing.x;
+        let dot = incoming.x * outgoing.x + incoming.y * outgoing.y;
+        if cross > 0.0 || (cross == 0.0 && dot < 0.0) {
+            let start_angle = from.y.atan2(from.x);
+            let mut sweep = to.y.atan2(to.x) - start_angle;
+            if sweep <= 0.0 {
+                sweep += std::f64::consts::TAU;
+            }
+            let step = std::f64::consts::FRAC_PI_2 / BUFFER_QUADRANT_SEGMENTS as f64;
+            let num_steps = (sweep / step).ceil() as usize;
+            for step in 1..num_steps {
+                let angle = start_angle + sweep * step as f64 / num_steps as f64;
+                curve.push(coord! {
+                    x: vertex.x + radius * angle.cos(),
+                    y: vertex.y + radius * angle.sin(),
+                });
+            }
+        } else if cross < 0.0 {
+            curve.push(vertex);
+        }
+    }
+    curve
+}
+
+fn circle_coords(center: Coord, radius: f64) -> Vec<Coord> {
+    let num_segments = BUFFER_QUADRANT_SEGMENTS * 4;
+    (0..num_segments)
+        .map(|i| {
+            let angle = std::f64::consts::TAU * i as f64 / num_segments as f64;
+            coord! {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+fn add_closed_curve(coords: &[Coord], curves: &mut Vec<Line>) {
+    for i in 0..coords.len() {
+        let segment = Line::new(coords[i], coords[(i + 1) % coords.len()]);
+        if segment.start != segment.end {
+            curves.push(segment);
+        }
+    }
+}
+
+/// Repairs an invalid geometry.
+///
+/// Every polygon is rebuilt from its noded rings, see `repair_polygon`, and the rebuilt
+/// polygons are merged by a union, so overlapping members of a `MultiPolygon` are
+/// dissolved. Degenerate lines are dropped, and other geometries are returned as is.
+pub fn geometry_make_valid(geo: Geometry) -> Result<Geometry> {
+    match geo {
+        Geometry::Polygon(_)
+        | Geometry::MultiPolygon(_)
+        | Geometry::Rect(_)
+        | Geometry::Triangle(_) => {
+            let polygons = GeometryParts::new(geo).polygons;
+            let repaired = polygons
+                .iter()
+                .map(repair_polygon)
+                .collect::<Result<Vec<_>>>()?;
+            // The repaired polygons are valid, so their union only fails on a bug.
+            let valid = union_polygons(repaired)
+                .map_err(|_| ErrorCode::GeometryError("Failed to merge the repaired polygons"))?;
+            Ok(multi_polygon_to_geometry(valid))
+        }
+        Geometry::LineString(line_string) if line_string.0.len() == 1 => {
+            Ok(Geometry::Point(Point::from(line_string.0[0])))
+        }
+        Geometry::MultiLineString(multi_line_string) => Ok(Geometry::MultiLineString(
+            multi_line_string
+                .into_iter()
+                .filter(|line_string| line_string.0.len() > 1)
+                .collect(),
+        )),
+        Geometry::GeometryCollection(collection) => Ok(Geometry::GeometryCollection(
+            collection
+                .into_iter()
+                .map(geometry_make_valid)
+                .collect::<Result<_>>()?,
+        )),
+        _ => Ok(geo),
+    }
+}
+
+/// Rebuilds a polygon whose rings may cross themselves or each other, the result is the
+/// area inside an odd number of rings.
+fn repair_polygon(polygon: &Polygon) -> Result<MultiPolygon> {
+    check_finite(polygon.coords_iter())?;
+    let mut ring_segments = Vec::new();
+    for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+        ring_segments.extend(ring.lines().filter(|segment| segment.start != segment.end));
+    }
+    polygonize(&ring_segments, |coord| {
+        crossing_count(&ring_segments, coord) % 2 == 1
+    })
+}
+
+/// Builds the polygons covering the faces accepted by `inside` of the planar graph made by
+/// noding the segments, that is splitting them at every point where they cross or overlap.
+///
+/// The result is traced along the edges separating the accepted faces from the others. A
+/// traced ring passing a vertex twice is split there, counterclockwise rings are shells and
+/// clockwise rings are holes.
+fn polygonize(segments: &[Line], inside: impl Fn(Coord) -> bool) -> Result<MultiPolygon> {
+    let graph = PlanarGraph::new(segments);
+    let num_half_edges = graph.edges.len() * 2;
+
+    // Find the faces of the graph, each half edge bounds the face on its left.
+    let mut face_of = vec![usize::MAX; num_half_edges];
+    let mut face_inside = Vec::new();
+    for start in 0..num_half_edges {
+        if face_of[start] != usize::MAX {
+            continue;
+        }
+        let face = face_inside.len();
+        let mut half_edge = start;
+        loop {
+            face_of[half_edge] = face;
+            half_edge = graph.next(half_edge, |_| true);
+            if half_edge == start {
+                break;
+            }
+        }
+        face_inside.push(inside(graph.probe_left(start)));
+    }
+
+    // Trace the boundary of the accepted faces.
+    let is_boundary =
+        |half_edge: usize| face_inside[face_of[half_edge]] && !face_inside[face_of[half_edge ^ 1]];
+    let mut visited = vec![false; num_half_edges];
+    let mut shells = Vec::new();
+    let mut holes = Vec::new();
+    for start in 0..num_half_edges {
+        if visited[start] || !is_boundary(start) {
+            continue;
+        }
+        let mut vertices = Vec::new();
+        let mut half_edge = start;
+        loop {
+            if visited[half_edge] {
+                return Err(ErrorCode::GeometryError(
+                    "Failed to build the polygons from the noded rings",
+                ));
+            }
+            visited[half_edge] = true;
+            vertices.push(graph.origin(half_edge));
+            half_edge = graph.next(half_edge, is_boundary);
+            if half_edge == start {
+                break;
+            }
+        }
+        for ring in split_at_repeated_vertices(vertices) {
+            let mut coords = ring
+                .into_iter()
+                .map(|vertex| graph.coords[vertex])
+                .collect::<Vec<_>>();
+            coords.push(coords[0]);
+            let ring = LineString::new(coords);
+            let area = Polygon::new(ring.clone(), vec![]).signed_area();
+            if area > 0.0 {
+                shells.push((Polygon::new(ring, vec![]), area));
+            } else if area < 0.0 {
+                holes.push(ring);
+            }
+        }
+    }
+
+    // A hole belongs to the smallest shell containing it.
+    for hole in holes {
+        let segment = hole.lines().next().unwrap();
+        let point = Point::from(segment_point(segment, 0.5));
+        let shell = shells
+            .iter_mut()
+            .filter(|(shell, _)| shell.contains(&point))
+            .min_by(|l, r| l.1.total_cmp(&r.1));
+        if let Some((shell, _)) = shell {
+            shell.interiors_push(hole);
+        }
+    }
+    Ok(MultiPolygon::new(
+        shells.into_iter().map(|(shell, _)| shell).collect(),
+    ))
+}
+
+/// Splits a closed walk into the simple closed walks it's made of.
+fn split_at_repeated_vertices(vertices: Vec<usize>) -> Vec<Vec<usize>> {
+    let mut rings = Vec::new();
+    let mut walk = Vec::with_capacity(vertices.len());
+    let mut positions = HashMap::new();
+    for vertex in vertices {
+        if let Some(&position) = positions.get(&vertex) {
+            let mut ring = vec![vertex];
+            ring.extend(walk.split_off(position + 1));
+            for vertex in &ring[1..] {
+                positions.remove(vertex);
+            }
+            rings.push(ring);
+        } else {
+            positions.insert(vertex, walk.len());
+            walk.push(vertex);
+        }
+    }
+    rings.push(walk);
+    rings.retain(|ring| ring.len() > 2);
+    rings
+}
+
+/// The number of segments crossed by the horizontal ray from the point to the right.
+fn crossing_count(segments: &[Line], point: Coord) -> usize {
+    segments
+        .iter()
+        .filter(|segment| {
+            let (start, end) = (segment.start, segment.end);
+            (start.y > point.y) != (end.y > point.y)
+                && point.x < start.x + (point.y - start.y) * (end.x - start.x) / (end.y - start.y)
+        })
+        .count()
+}
+
+/// A planar graph built from segments, which are split at their intersections.
+///
+/// The edge `i` is made of the half edges `2 * i`, from its first vertex to the second,
+/// and `2 * i + 1` in the opposite direction.
+struct PlanarGraph {
+    coords: Vec<Coord>,
+    edges: Vec<(usize, usize)>,
+    /// The half edges leaving every vertex, in counterclockwise order.
+    outgoing: Vec<Vec<usize>>,
+    /// The position of every half edge in the outgoing half edges of its origin.
+    positions: Vec<usize>,
+}
+
+impl PlanarGraph {
+    fn new(segments: &[Line]) -> Self {
+        let mut split_points = segments
+            .iter()
+            .map(|segment| vec![segment.start, segment.end])
+            .collect::<Vec<_>>();
+        // Sweep the segments by their x ranges, only overlapping ranges can intersect.
+        let x_range = |segment: &Line| {
+            (
+                segment.start.x.min(segment.end.x),
+                segment.start.x.max(segment.end.x),
+            )
+        };
+        let mut order = (0..segments.len()).collect::<Vec<_>>();
+        order.sort_by(|l, r| {
+            x_range(&segments[*l])
+                .0
+                .total_cmp(&x_range(&segments[*r]).0)
+        });
+        for (k, &i) in order.iter().enumerate() {
+            let max_x = x_range(&segments[i]).1;
+            for &j in &order[k + 1..] {
+                if x_range(&segments[j]).0 > max_x {
+                    break;
+                }
+                match line_intersection(segments[i], segments[j]) {
+                    Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                        split_points[i].push(intersection);
+                        split_points[j].push(intersection);
+                    }
+                    Some(LineIntersection::Collinear { intersection }) => {
+                        for coord in [intersection.start, intersection.end] {
+                            split_points[i].push(coord);
+                            split_points[j].push(coord);
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        let mut graph = PlanarGraph {
+            coords: vec![],
+            edges: vec![],
+            outgoing: vec![],
+            positions: vec![],
+        };
+        let mut vertices = HashMap::new();
+        let mut edges = HashMap::new();
+        for (segment, mut points) in segments.iter().zip(split_points) {
+            points.sort_by(|l, r| {
+                segment_fraction(*segment, *l).total_cmp(&segment_fraction(*segment, *r))
+            });
+            points.dedup();
+            let points = points
+                .into_iter()
+                .map(|coord| {
+                    // -0.0 and 0.0 are the same vertex.
+                    let key = ((coord.x + 0.0).to_bits(), (coord.y + 0.0).to_bits());
+                    *vertices.entry(key).or_insert_with(|| {
+                        graph.coords.push(coord);
+                        graph.coords.len() - 1
+                    })
+                })
+                .collect::<Vec<_>>();
+            for pair in points.windows(2) {
+                let (start, end) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+                if start != end {
+                    edges.entry((start, end)).or_insert_with(|| {
+                        graph.edges.push((start, end));
+                    });
+                }
+            }
+        }
+
+        graph.outgoing = vec![vec![]; graph.coords.len()];
+        for half_edge in 0..graph.edges.len() * 2 {
+            let origin = graph.origin(half_edge);
+            graph.outgoing[origin].push(half_edge);
+        }
+        graph.positions = vec![0; graph.edges.len() * 2];
+        for outgoing in graph.outgoing.iter_mut() {
+            let angles = outgoing
+                .iter()
+                .map(|half_edge| {
+                    let (origin, dest) = (
+                        graph.coords[Self::origin_of(&graph.edges, *half_edge)],
+                        graph.coords[Self::origin_of(&graph.edges, *half_edge ^ 1)],
+                    );
+                    ((dest.y - origin.y).atan2(dest.x - origin.x), *half_edge)
+                })
+                .collect::<Vec<_>>();
+            let mut angles = angles;
+            angles.sort_by(|l, r| l.0.total_cmp(&r.0));
+            *outgoing = angles.into_iter().map(|(_, half_edge)| half_edge).collect();
+            for (position, half_edge) in outgoing.iter().enumerate() {
+                graph.positions[*half_edge] = position;
+            }
+        }
+        graph
+    }
+
+    fn origin_of(edges: &[(usize, usize)], half_edge: usize) -> usize {
+        let (start, end) = edges[half_edge / 2];
+        if half_edge % 2 == 0 {
+            start
+        } else {
+            end
+        }
+    }
+
+    fn origin(&self, half_edge: usize) -> usize {
+        Self::origin_of(&self.edges, half_edge)
+    }
+
+    fn origin_coord(&self, half_edge: usize) -> Coord {
+        self.coords[self.origin(half_edge)]
+    }
+
+    /// The half edge following `half_edge` around the face on its left, skipping the half
+    /// edges not accepted by `filter`.
+    ///
+    /// It's the first accepted half edge clockwise from the twin of `half_edge` around
+    /// their shared vertex, the twin itself is never accepted.
+    fn next(&self, half_edge: usize, filter: impl Fn(usize) -> bool) -> usize {
+        let twin = half_edge ^ 1;
+        let outgoing = &self.outgoing[self.origin(twin)];
+        let position = self.positions[twin];
+        for i in 1..outgoing.len() {
+            let candidate = outgoing[(position + outgoing.len() - i) % outgoing.len()];
+            if filter(candidate) {
+                return candidate;
+            }
+        }
+        twin
+    }
+
+    /// A point in the face on the left of the half edge.
+    ///
+    /// It's taken halfway between the middle of the half edge and the nearest edge in the
+    /// direction of its left normal.
+    fn probe_left(&self, half_edge: usize) -> Coord {
+        let start = self.origin_coord(half_edge);
+        let end = self.origin_coord(half_edge ^ 1);
+        let middle = segment_point(Line::new(start, end), 0.5);
+        let (dx, dy) = (end.x - start.x, end.y - start.y);
+        let length = dx.hypot(dy);
+        let normal = coord! { x: -dy / length, y: dx / length };
+
+        let mut nearest = length;
+        for (edge, (l, r)) in self.edges.iter().enumerate() {
+            if edge == half_edge / 2 {
+                continue;
+            }
+            let (a, b) = (self.coords[*l], self.coords[*r]);
+            // Solve `middle + t * normal = a + s * (b - a)`.
+            let ab = b - a;
+            let denominator = normal.x * ab.y - normal.y * ab.x;
+            if denominator == 0.0 {
+                continue;
+            }
+            let am = a - middle;
+            let t = (am.x * ab.y - am.y * ab.x) / denominator;
+            let s = (am.x * normal.y - am.y * normal.x) / denominator;
+            if t > 0.0 && (0.0..=1.0).contains(&s) && t < nearest {
+                nearest = t;
+            }
+        }
+        middle + normal * (nearest / 2.0)
+    }
+}
@@ -23,6 +23,7 @@ mod bitmap;
 mod boolean;
 mod comparison;
 mod control;
+mod encryption;
 
 pub mod geo_func {
     pub use databend_functions_scalar_geo::*;
@@ -42,6 +43,7 @@ mod other;
 mod string;
 mod string_multi_args;
 mod tuple;
+mod url;
 mod variant;
 mod vector;
 
@@ -78,6 +80,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     geo_func::geo::register(registry);
     geo_func::geo_h3::register(registry);
     hash::register(registry);
+    encryption::register(registry);
     other::register(registry);
     databend_functions_scalar_decimal::register_to_decimal(registry);
     vector::register(registry);
@@ -87,4 +90,5 @@ pub fn register(registry: &mut FunctionRegistry) {
     hilbert::register(registry);
     dt_func::interval::register(registry);
     obfuscator::register(registry);
+    url::register(registry);
 }
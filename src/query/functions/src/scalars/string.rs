@@ -79,6 +79,15 @@ pub const ALL_STRING_FUNC_NAMES: &[&str] = &[
     "regexp_like",
     "regexp_replace",
     "regexp_substr",
+    "url_protocol",
+    "url_domain",
+    "url_top_level_domain",
+    "url_path",
+    "url_fragment",
+    "url_query_parameter",
+    "url_encode",
+    "url_decode",
+    "cut_url_parameter",
 ];
 
 /// Functions that works with all strings, allow other types to be casted to string.
@@ -1169,6 +1169,50 @@ fn register_to_number(registry: &mut FunctionRegistry) {
     );
 }
 
+// Adding a constant number of days, hours, ... is monotonic, as long as the result is in
+// the valid range, so the domain of the result can be computed from the bounds of the input.
+fn calc_shift_date_domain(
+    date: &SimpleDomain<i32>,
+    delta: &SimpleDomain<i64>,
+    days_per_unit: i64,
+    sign: impl Fn(i64) -> i64,
+) -> FunctionDomain<DateType> {
+    let shift = |date: i32| {
+        let days = (date as i64).checked_add(sign(delta.min).checked_mul(days_per_unit)?)?;
+        (DATE_MIN as i64..=DATE_MAX as i64)
+            .contains(&days)
+            .then_some(days as i32)
+    };
+    if delta.min != delta.max || delta.min == i64::MIN {
+        return FunctionDomain::MayThrow;
+    }
+    match (shift(date.min), shift(date.max)) {
+        (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+        _ => FunctionDomain::MayThrow,
+    }
+}
+
+fn calc_shift_timestamp_domain(
+    ts: &SimpleDomain<i64>,
+    delta: &SimpleDomain<i64>,
+    micros_per_unit: i64,
+    sign: impl Fn(i64) -> i64,
+) -> FunctionDomain<TimestampType> {
+    let shift = |ts: i64| {
+        let micros = ts.checked_add(sign(delta.min).checked_mul(micros_per_unit)?)?;
+        (TIMESTAMP_MIN..=TIMESTAMP_MAX)
+            .contains(&micros)
+            .then_some(micros)
+    };
+    if delta.min != delta.max || delta.min == i64::MIN {
+        return FunctionDomain::MayThrow;
+    }
+    match (shift(ts.min), shift(ts.max)) {
+        (Some(min), Some(max)) => FunctionDomain::Domain(SimpleDomain { min, max }),
+        _ => FunctionDomain::MayThrow,
+    }
+}
+
 macro_rules! signed_ident {
     ($name: ident) => {
         -$name
@@ -1313,7 +1357,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<DateType, Int64Type, DateType, _, _>(
                 concat!($op, "_days"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, date, delta| calc_shift_date_domain(date, delta, 1, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<DateType, Int64Type, DateType>(|date, delta, builder, _| {
                     builder.push(EvalDaysImpl::eval_date(date, $signed_wrapper!{delta}))
                 }),
@@ -1321,7 +1365,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<TimestampType, Int64Type, TimestampType, _, _>(
                 concat!($op, "_days"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, ts, delta| calc_shift_timestamp_domain(ts, delta, MICROSECS_PER_DAY, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<TimestampType, Int64Type, TimestampType>(
                     |ts, delta, builder, _| {
                         builder.push(EvalDaysImpl::eval_timestamp(ts, $signed_wrapper!{delta}))
@@ -1332,7 +1376,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<DateType, Int64Type, DateType, _, _>(
                 concat!($op, "_weeks"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, date, delta| calc_shift_date_domain(date, delta, 7, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<DateType, Int64Type, DateType>(|date, delta, builder, _| {
                     let delta = 7 * delta;
                     builder.push(EvalDaysImpl::eval_date(date, $signed_wrapper!{delta}))
@@ -1341,7 +1385,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<TimestampType, Int64Type, TimestampType, _, _>(
                 concat!($op, "_weeks"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, ts, delta| calc_shift_timestamp_domain(ts, delta, 7 * MICROSECS_PER_DAY, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<TimestampType, Int64Type, TimestampType>(
                     |ts, delta, builder, _| {
                         let delta = 7 * delta;
@@ -1368,7 +1412,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<TimestampType, Int64Type, TimestampType, _, _>(
                 concat!($op, "_hours"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, ts, delta| calc_shift_timestamp_domain(ts, delta, FACTOR_HOUR * MICROS_PER_SEC, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<TimestampType, Int64Type, TimestampType>(
                     |ts, delta, builder, _| {
                         builder.push(EvalTimesImpl::eval_timestamp(
@@ -1398,7 +1442,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<TimestampType, Int64Type, TimestampType, _, _>(
                 concat!($op, "_minutes"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, ts, delta| calc_shift_timestamp_domain(ts, delta, FACTOR_MINUTE * MICROS_PER_SEC, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<TimestampType, Int64Type, TimestampType>(
                     |ts, delta, builder, _| {
                         builder.push(EvalTimesImpl::eval_timestamp(
@@ -1428,7 +1472,7 @@ macro_rules! impl_register_arith_functions {
             registry.register_passthrough_nullable_2_arg::<TimestampType, Int64Type, TimestampType, _, _>(
                 concat!($op, "_seconds"),
 
-                |_, _, _| FunctionDomain::MayThrow,
+                |_, ts, delta| calc_shift_timestamp_domain(ts, delta, FACTOR_SECOND * MICROS_PER_SEC, |d| $signed_wrapper!{d}),
                 vectorize_with_builder_2_arg::<TimestampType, Int64Type, TimestampType>(
                     |ts, delta, builder, _| {
                         builder.push(EvalTimesImpl::eval_timestamp(
@@ -20,6 +20,7 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod plan_baseline;
 mod quota;
 mod role;
 mod serde;
@@ -40,6 +41,7 @@ pub use connection::ConnectionMgr;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyMgr;
+pub use plan_baseline::PlanBaselineMgr;
 pub use procedure::ProcedureMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
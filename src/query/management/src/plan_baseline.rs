@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::principal::PlanBaselineIdent;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_kvapi::kvapi::KvApiExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::UpsertKV;
+use futures::TryStreamExt;
+
+pub struct PlanBaselineMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: Tenant,
+}
+
+impl PlanBaselineMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &Tenant) -> Self {
+        PlanBaselineMgr {
+            kv_api,
+            tenant: tenant.clone(),
+        }
+    }
+
+    fn baseline_key(&self, sql_hash: &str) -> String {
+        PlanBaselineIdent::new(self.tenant.clone(), sql_hash).to_string_key()
+    }
+
+    fn baseline_prefix(&self) -> String {
+        PlanBaselineIdent::new(self.tenant.clone(), "").to_string_key()
+    }
+}
+
+// Baselines are stored as json, same as user settings.
+impl PlanBaselineMgr {
+    /// Store a baseline. Unless `overwrite` is set, fails if a baseline for the same query exists.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn add_baseline(&self, baseline: PlanBaseline, overwrite: bool) -> Result<()> {
+        let val = serde_json::to_vec(&baseline)?;
+        let key = self.baseline_key(&baseline.sql_hash);
+        let upsert = if overwrite {
+            UpsertKV::update(&key, &val)
+        } else {
+            UpsertKV::insert(&key, &val)
+        };
+
+        let res = self.kv_api.upsert_kv(upsert).await?;
+        if !overwrite && !res.is_changed() {
+            return Err(ErrorCode::PlanBaselineAlreadyExists(format!(
+                "Plan baseline for query '{}' already exists",
+                baseline.sql_hash
+            )));
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_baseline(&self, sql_hash: &str) -> Result<Option<PlanBaseline>> {
+        let key = self.baseline_key(sql_hash);
+        let Some(seqv) = self.kv_api.get_kv(&key).await? else {
+            return Ok(None);
+        };
+        Ok(Some(serde_json::from_slice(&seqv.data)?))
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_baselines(&self) -> Result<Vec<PlanBaseline>> {
+        let prefix = self.baseline_prefix();
+        let mut strm = self.kv_api.list_kv(&prefix).await?;
+
+        let mut baselines = Vec::new();
+        while let Some(item) = strm.try_next().await? {
+            let baseline: PlanBaseline = serde_json::from_slice(&item.value.unwrap().data)?;
+            baselines.push(baseline);
+        }
+        Ok(baselines)
+    }
+
+    /// Remove a baseline, returns false if it does not exist.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn drop_baseline(&self, sql_hash: &str) -> Result<bool> {
+        let key = self.baseline_key(sql_hash);
+        let res = self.kv_api.upsert_kv(UpsertKV::delete(&key)).await?;
+        Ok(res.is_changed())
+    }
+}
@@ -15,6 +15,7 @@
 #![feature(duration_constructors)]
 #![allow(clippy::uninlined_format_args)]
 
+mod plan_baseline;
 mod quota;
 mod role;
 mod setting;
@@ -0,0 +1,105 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi::KvApiExt;
+use databend_common_meta_store::MetaStore;
+use databend_common_version::BUILD_INFO;
+use fastrace::func_name;
+
+fn new_baseline(sql_hash: &str, plan: &str) -> PlanBaseline {
+    PlanBaseline {
+        sql_hash: sql_hash.to_string(),
+        query_text: "SELECT * FROM t1, t2 WHERE t1.a = t2.a".to_string(),
+        plan: plan.to_string(),
+        plan_display: plan.to_string(),
+        created_on: Utc::now(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_plan_baseline() -> Result<()> {
+    let (kv_api, mgr) = new_plan_baseline_api().await?;
+
+    // Add baseline.
+    {
+        let baseline = new_baseline("3f2a", "(t2 INNER HASH JOIN t1)");
+        mgr.add_baseline(baseline.clone(), false).await?;
+        let value = kv_api
+            .get_kv("__fd_plan_baselines/databend_query/3f2a")
+            .await?;
+        assert_eq!(value.unwrap().data, serde_json::to_vec(&baseline)?);
+    }
+
+    // Add again without overwrite.
+    {
+        let baseline = new_baseline("3f2a", "(t1 INNER HASH JOIN t2)");
+        let res = mgr.add_baseline(baseline, false).await;
+        assert_eq!(
+            res.unwrap_err().code(),
+            ErrorCode::PLAN_BASELINE_ALREADY_EXISTS
+        );
+        let actual = mgr.get_baseline("3f2a").await?;
+        assert_eq!(actual.unwrap().plan, "(t2 INNER HASH JOIN t1)");
+    }
+
+    // Add again with overwrite.
+    {
+        let baseline = new_baseline("3f2a", "(t1 INNER HASH JOIN t2)");
+        mgr.add_baseline(baseline.clone(), true).await?;
+        let actual = mgr.get_baseline("3f2a").await?;
+        assert_eq!(actual, Some(baseline));
+    }
+
+    // List baselines.
+    {
+        mgr.add_baseline(new_baseline("4b1c", "NO JOIN"), false)
+            .await?;
+        let actual = mgr.list_baselines().await?;
+        let hashes = actual
+            .iter()
+            .map(|b| b.sql_hash.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(hashes, vec!["3f2a", "4b1c"]);
+    }
+
+    // Drop baseline.
+    {
+        assert!(mgr.drop_baseline("3f2a").await?);
+        assert!(!mgr.drop_baseline("3f2a").await?);
+        assert!(mgr.get_baseline("3f2a").await?.is_none());
+        assert_eq!(mgr.list_baselines().await?.len(), 1);
+    }
+
+    Ok(())
+}
+
+async fn new_plan_baseline_api() -> Result<(Arc<MetaStore>, PlanBaselineMgr)> {
+    let test_api = MetaStore::new_local_testing(&BUILD_INFO).await;
+    let test_api = Arc::new(test_api);
+
+    let mgr = PlanBaselineMgr::create(
+        test_api.clone(),
+        &Tenant::new_or_err("databend_query", func_name!()).unwrap(),
+    );
+    Ok((test_api, mgr))
+}
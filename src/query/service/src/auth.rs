@@ -25,6 +25,8 @@ use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_users::JwtAuthenticator;
+use databend_common_users::KeyPairIssuer;
+use databend_common_users::LdapAuthenticator;
 use databend_common_users::UserApiProvider;
 use fastrace::func_name;
 use log::info;
@@ -35,6 +37,7 @@ use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    ldap_auth: Option<LdapAuthenticator>,
 }
 
 #[derive(Debug, Serialize)]
@@ -95,6 +98,7 @@ impl AuthMgr {
     fn create(cfg: &InnerConfig, version: BuildInfoRef) -> Arc<AuthMgr> {
         Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(&cfg.query, version),
+            ldap_auth: LdapAuthenticator::create(&cfg.query),
         })
     }
 
@@ -127,6 +131,11 @@ impl AuthMgr {
                 token: t,
                 client_ip,
             } => {
+                if let Some(issuer) = KeyPairIssuer::from_token(t) {
+                    return self
+                        .auth_key_pair(session, t, issuer, client_ip.as_deref())
+                        .await;
+                }
                 let jwt_auth = self
                     .jwt_auth
                     .as_ref()
@@ -276,9 +285,13 @@ impl AuthMgr {
             } => {
                 let tenant = session.get_current_tenant();
                 let identity = UserIdentity::new(name, "%");
-                let mut user = user_api
+                let (mut user, is_new_user) = match user_api
                     .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
-                    .await?;
+                    .await
+                {
+                    Ok(user) => (user, false),
+                    Err(e) => (self.new_ldap_user(e, name)?, true),
+                };
 
                 // check global network policy if user is not account admin
                 if !user.is_account_admin() && !global_network_policy.is_empty() {
@@ -315,11 +328,17 @@ impl AuthMgr {
                             }
                         }
                     },
+                    AuthInfo::Ldap => {
+                        self.auth_ldap(&tenant, &mut user, p.as_deref(), is_new_user)
+                            .await
+                    }
                     _ => Err(ErrorCode::AuthenticateFailure("[AUTH] Authentication failed: user exists but is not configured for password authentication")),
                 };
-                UserApiProvider::instance()
-                    .update_user_login_result(tenant, identity, authed.is_ok(), &user)
-                    .await?;
+                if !is_new_user {
+                    UserApiProvider::instance()
+                        .update_user_login_result(tenant, identity, authed.is_ok(), &user)
+                        .await?;
+                }
 
                 authed?;
 
@@ -328,4 +347,102 @@ impl AuthMgr {
             }
         }
     }
+
+    /// Returns the user to be created by LDAP authentication if the user does not
+    /// exist and `auto_create_user` is enabled, otherwise returns the error.
+    pub fn new_ldap_user(&self, err: ErrorCode, name: &str) -> Result<UserInfo> {
+        match &self.ldap_auth {
+            Some(ldap_auth)
+                if ldap_auth.auto_create_user() && err.code() == ErrorCode::UNKNOWN_USER =>
+            {
+                Ok(UserInfo::new(name, "%", AuthInfo::Ldap))
+            }
+            _ => Err(err),
+        }
+    }
+
+    /// Authenticate the password of the user against the LDAP server, then grant
+    /// or revoke the roles mapped from the LDAP groups of the user.
+    /// A new user is created only after it is authenticated.
+    #[async_backtrace::framed]
+    pub async fn auth_ldap(
+        &self,
+        tenant: &Tenant,
+        user: &mut UserInfo,
+        password: Option<&[u8]>,
+        is_new_user: bool,
+    ) -> Result<()> {
+        let ldap_auth = self.ldap_auth.as_ref().ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(
+                "[AUTH] LDAP authentication failed: LDAP is not configured on this server",
+            )
+        })?;
+        let ldap_user = ldap_auth
+            .authenticate(&user.name, password.unwrap_or_default())
+            .await?;
+
+        if is_new_user {
+            for role in &ldap_user.roles {
+                user.grants.grant_role(role.clone());
+            }
+            info!("[AUTH] LDAP create user: {}", user.name);
+            UserApiProvider::instance()
+                .add_user(tenant, user.clone(), &CreateOption::CreateIfNotExists)
+                .await?;
+            return Ok(());
+        }
+        ldap_auth.sync_roles(tenant, user, &ldap_user).await
+    }
+
+    /// Authenticate with a JWT signed by the private key of the user, the
+    /// token is verified with the public keys set on the user.
+    #[async_backtrace::framed]
+    async fn auth_key_pair(
+        &self,
+        session: &mut Session,
+        token: &str,
+        issuer: KeyPairIssuer,
+        client_ip: Option<&str>,
+    ) -> Result<(String, Option<String>)> {
+        let user_api = UserApiProvider::instance();
+        let global_network_policy = session
+            .get_settings()
+            .get_network_policy()
+            .unwrap_or_default();
+        let tenant = session.get_current_tenant();
+        let identity = UserIdentity::new(&issuer.user, "%");
+        let user = user_api
+            .get_user_with_client_ip(&tenant, identity.clone(), client_ip)
+            .await?;
+        // The public keys are not an additional credential of users with other auth types.
+        if user.auth_info != AuthInfo::KeyPair {
+            return Err(ErrorCode::AuthenticateFailure("[AUTH] Authentication failed: user exists but is not configured for key-pair authentication"));
+        }
+        if user.option.rsa_public_keys().next().is_none() {
+            return Err(ErrorCode::AuthenticateFailure(
+                "[AUTH] Key-pair authentication failed: user has no public key set",
+            ));
+        }
+
+        // check global network policy if user is not account admin
+        if !user.is_account_admin() && !global_network_policy.is_empty() {
+            user_api
+                .enforce_network_policy(&tenant, &global_network_policy, client_ip)
+                .await?;
+        }
+
+        // Locked users can't login, and failed logins are counted like failed password logins.
+        // There is no password to change, so the expired password is ignored.
+        user_api
+            .check_login_password(&tenant, identity.clone(), &user)
+            .await?;
+        let claims = issuer.verify_token(token, &user.option);
+        user_api
+            .update_user_login_result(tenant, identity, claims.is_ok(), &user)
+            .await?;
+        let claims = claims?;
+
+        session.set_authed_user(user, claims.custom.role).await?;
+        Ok((issuer.user, None))
+    }
 }
@@ -51,6 +51,12 @@ impl BuiltinUsers {
             AuthType::JWT => {
                 Self::check_no_auth_string(auth_config.auth_string.clone(), AuthInfo::JWT)
             }
+            AuthType::KeyPair => Err(ErrorCode::InvalidConfig(
+                "auth_type key_pair is not supported for built-in users",
+            )),
+            AuthType::Ldap => Err(ErrorCode::InvalidConfig(
+                "auth_type ldap is not supported for built-in users",
+            )),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match &auth_config.auth_string {
@@ -50,6 +50,7 @@ use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
 use databend_common_storages_system::OneTable;
 use databend_common_storages_system::PasswordPoliciesTable;
+use databend_common_storages_system::PlanBaselinesTable;
 use databend_common_storages_system::PrivateTaskHistoryTable;
 use databend_common_storages_system::PrivateTasksTable;
 use databend_common_storages_system::ProceduresTable;
@@ -63,6 +64,7 @@ use databend_common_storages_system::StatisticsTable;
 use databend_common_storages_system::TableFunctionsTable;
 use databend_common_storages_system::TablesTableWithHistory;
 use databend_common_storages_system::TablesTableWithoutHistory;
+use databend_common_storages_system::TagReferencesTable;
 use databend_common_storages_system::TaskHistoryTable;
 use databend_common_storages_system::TasksTable;
 use databend_common_storages_system::TempFilesTable;
@@ -142,6 +144,7 @@ impl SystemDatabase {
             ViewsTableWithoutHistory::create(sys_db_meta.next_table_id(), ctl_name),
             ProceduresTable::create(sys_db_meta.next_table_id()),
             StatisticsTable::create(sys_db_meta.next_table_id(), ctl_name),
+            PlanBaselinesTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_system_table_load;
@@ -176,6 +179,7 @@ impl SystemDatabase {
                     config.query.max_query_log_size,
                 ),
                 ConstraintsTable::create(sys_db_meta.next_table_id()),
+                TagReferencesTable::create(sys_db_meta.next_table_id()),
             ]);
             if config.task.on {
                 table_list.push(PrivateTasksTable::create(sys_db_meta.next_table_id()));
@@ -23,6 +23,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
 use databend_common_management::RoleApi;
 use databend_common_management::WarehouseInfo;
 use databend_common_meta_app::principal::GetProcedureReq;
@@ -45,6 +46,11 @@ use databend_common_sql::plans::Mutation;
 use databend_common_sql::plans::OptimizeCompactBlock;
 use databend_common_sql::plans::PresignAction;
 use databend_common_sql::plans::RewriteKind;
+use databend_common_sql::plans::TagObject;
+use databend_common_sql::ColumnEntry;
+use databend_common_sql::ColumnSet;
+use databend_common_sql::IndexType;
+use databend_common_sql::Metadata;
 use databend_common_sql::Planner;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -148,7 +154,8 @@ impl PrivilegeAccess {
                 catalog_name: catalog_name.clone(),
                 db_id: *db_id,
             },
-            GrantObject::TableById(catalog_name, db_id, table_id) => OwnershipObject::Table {
+            GrantObject::TableById(catalog_name, db_id, table_id)
+            | GrantObject::ColumnById(catalog_name, db_id, table_id, _) => OwnershipObject::Table {
                 catalog_name: catalog_name.clone(),
                 db_id: *db_id,
                 table_id: *table_id,
@@ -332,6 +339,36 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    async fn validate_tag_object_access(&self, object: &TagObject) -> Result<()> {
+        match object {
+            TagObject::Database { catalog, database } => {
+                self.validate_db_access(catalog, database, UserPrivilegeType::Alter, false)
+                    .await
+            }
+            TagObject::Table {
+                catalog,
+                database,
+                table,
+            }
+            | TagObject::Column {
+                catalog,
+                database,
+                table,
+                ..
+            } => {
+                self.validate_table_access(
+                    catalog,
+                    database,
+                    table,
+                    UserPrivilegeType::Alter,
+                    false,
+                    false,
+                )
+                .await
+            }
+        }
+    }
+
     async fn validate_table_access(
         &self,
         catalog_name: &str,
@@ -460,6 +497,60 @@ impl PrivilegeAccess {
         Ok(())
     }
 
+    // Fallback of `validate_table_access` when the privilege is not granted on the whole table:
+    // the access is allowed only if the privilege is granted on every column it touches.
+    async fn validate_columns_access(
+        &self,
+        catalog_name: &str,
+        db_name: &str,
+        table_name: &str,
+        columns: Option<Vec<(ColumnId, String)>>,
+        privilege: UserPrivilegeType,
+        table_err: ErrorCode,
+    ) -> Result<()> {
+        let Some(columns) = columns.filter(|columns| !columns.is_empty()) else {
+            return Err(table_err);
+        };
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let ObjectId::Table(db_id, table_id) = self
+            .convert_to_id(&tenant, &catalog, db_name, Some(table_name), false)
+            .await?
+        else {
+            return Err(table_err);
+        };
+
+        let session = self.ctx.get_current_session();
+        for (column_id, column_name) in columns {
+            let object =
+                GrantObject::ColumnById(catalog_name.to_string(), db_id, table_id, column_id);
+            if let Err(err) = session.validate_privilege(&object, privilege, false).await {
+                if err.code() != ErrorCode::PERMISSION_DENIED {
+                    return Err(err);
+                }
+                let current_user = self.ctx.get_current_user()?;
+                let roles_name = session
+                    .get_all_effective_roles()
+                    .await?
+                    .iter()
+                    .map(|r| r.name.clone())
+                    .collect::<Vec<_>>()
+                    .join(",");
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied: privilege [{:?}] is required on '{}'.'{}'.'{}' or on its column '{}' for user {} with roles [{}]",
+                    privilege,
+                    catalog_name,
+                    db_name,
+                    table_name,
+                    column_name,
+                    &current_user.identity().display(),
+                    roles_name,
+                )));
+            }
+        }
+        Ok(())
+    }
+
     async fn validate_warehouse_ownership(
         &self,
         warehouse: String,
@@ -640,7 +731,8 @@ impl PrivilegeAccess {
             | GrantObject::Connection(_)
             | GrantObject::Sequence(_)
             | GrantObject::Procedure(_)
-            | GrantObject::TableById(_, _, _) => true,
+            | GrantObject::TableById(_, _, _)
+            | GrantObject::ColumnById(_, _, _, _) => true,
             GrantObject::Global => false,
         };
 
@@ -687,6 +779,7 @@ impl PrivilegeAccess {
 
                 match grant_object {
                     GrantObject::TableById(_, _, _) => Err(ErrorCode::PermissionDenied("")),
+                    GrantObject::ColumnById(_, _, _, _) => Err(ErrorCode::PermissionDenied("")),
                     GrantObject::DatabaseById(_, _) => Err(ErrorCode::PermissionDenied("")),
                     GrantObject::Procedure(_) => Err(ErrorCode::PermissionDenied(format!(
                         "Permission denied: privilege [{:?}] is required on PROCEDURE for user {} with roles [{}]. \
@@ -1030,7 +1123,13 @@ impl AccessChecker for PrivilegeAccess {
                     }
                 }
 
+                for connection in s_expr.get_secret_connections() {
+                    self.validate_connection_access(connection, UserPrivilegeType::AccessConnection)
+                        .await?;
+                }
+
                 let metadata = metadata.read().clone();
+                let scan_columns = s_expr.get_scan_columns();
 
                 for table in metadata.tables() {
                     if enable_experimental_rbac_check && table.is_source_of_stage() {
@@ -1055,7 +1154,13 @@ impl AccessChecker for PrivilegeAccess {
                     // like this sql: copy into t from (select * from @s3); will bind a mock table with name `system.read_parquet(s3)`
                     // this is no means to check table `system.read_parquet(s3)` privilege
                     if !table.is_source_of_stage() {
-                        self.validate_table_access(catalog_name, table.database(), table.name(), UserPrivilegeType::Select, false, false).await?
+                        if let Err(err) = self.validate_table_access(catalog_name, table.database(), table.name(), UserPrivilegeType::Select, false, false).await {
+                            if err.code() != ErrorCode::PERMISSION_DENIED {
+                                return Err(err);
+                            }
+                            let columns = read_column_ids(&metadata, table.index(), scan_columns.get(&table.index()));
+                            self.validate_columns_access(catalog_name, table.database(), table.name(), columns, UserPrivilegeType::Select, err).await?;
+                        }
                     }
                 }
             }
@@ -1070,9 +1175,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ShowCreateDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Select, false).await?
             }
-            Plan::CreateDatabase(_) => {
+            Plan::CreateDatabase(plan) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDatabase, true, false)
                     .await?;
+                if let Some(source) = &plan.clone_source {
+                    self.validate_db_access(&source.catalog, &source.database, UserPrivilegeType::Select, false).await?;
+                }
             }
             Plan::DropDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Drop, plan.if_exists).await?;
@@ -1169,6 +1277,9 @@ impl AccessChecker for PrivilegeAccess {
                 if let Some(query) = &plan.as_select {
                     self.check(ctx, query).await?;
                 }
+                if let Some(source) = &plan.clone_source {
+                    self.validate_table_access(&source.catalog, &source.database, &source.table, UserPrivilegeType::Select, false, false).await?;
+                }
             }
             Plan::DropTable(plan) => {
                 // For attach table
@@ -1246,6 +1357,15 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropTableClusterKey(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Drop, false, false).await?
             }
+            Plan::ModifyTableTTL(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::CreateTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             Plan::RefreshTableCache(_) | Plan::RefreshDatabaseCache(_) => {
                 // Only Iceberg support this plan
                 return Ok(())
@@ -1259,6 +1379,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::OptimizePurge(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             }
+            Plan::OptimizeExpire(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Delete, false, false).await?
+            }
             Plan::OptimizeCompactSegment(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             }
@@ -1278,6 +1401,12 @@ impl AccessChecker for PrivilegeAccess {
             Plan::AnalyzeTable(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Super, false, false).await?
             }
+            Plan::CreateStatistics(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropStatistics(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
             // Dictionary
             Plan::ShowCreateDictionary(_)
             | Plan::CreateDictionary(_)
@@ -1364,7 +1493,46 @@ impl AccessChecker for PrivilegeAccess {
                     MutationType::Delete => vec![UserPrivilegeType::Delete],
                 };
                 for privilege in privileges {
-                    self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, privilege, false, false).await?;
+                    if let Err(err) = self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, privilege, false, false).await {
+                        if err.code() != ErrorCode::PERMISSION_DENIED || plan.mutation_type != MutationType::Update {
+                            return Err(err);
+                        }
+                        // UPDATE is also allowed by the UPDATE privilege on all the assigned columns.
+                        let schema = plan.metadata.read().table(plan.target_table_index).table().schema();
+                        let columns = plan
+                            .matched_evaluators
+                            .iter()
+                            .filter_map(|evaluator| evaluator.update.as_ref())
+                            .flat_map(|update| update.keys())
+                            .map(|idx| {
+                                let field = schema.field(*idx);
+                                (field.column_id(), field.name().clone())
+                            })
+                            .collect::<Vec<_>>();
+                        self.validate_columns_access(&plan.catalog_name, &plan.database_name, &plan.table_name, Some(columns), privilege, err).await?;
+
+                        // The columns read by the SET expressions and the WHERE clause also require SELECT.
+                        let mut read_columns = s_expr.child(0)?.get_predicate_columns();
+                        read_columns.extend(plan.direct_filter.iter().flat_map(|filter| filter.used_columns()));
+                        for evaluator in plan.matched_evaluators.iter() {
+                            if let Some(condition) = &evaluator.condition {
+                                read_columns.extend(condition.used_columns());
+                            }
+                            for scalar in evaluator.update.iter().flat_map(|update| update.values()) {
+                                read_columns.extend(scalar.used_columns());
+                            }
+                        }
+                        let columns = read_column_ids(&plan.metadata.read(), plan.target_table_index, Some(&read_columns));
+                        if columns.as_ref().is_some_and(|columns| columns.is_empty()) {
+                            continue;
+                        }
+                        if let Err(err) = self.validate_table_access(&plan.catalog_name, &plan.database_name, &plan.table_name, UserPrivilegeType::Select, false, false).await {
+                            if err.code() != ErrorCode::PERMISSION_DENIED {
+                                return Err(err);
+                            }
+                            self.validate_columns_access(&plan.catalog_name, &plan.database_name, &plan.table_name, columns, UserPrivilegeType::Select, err).await?;
+                        }
+                    }
                 }
             }
             Plan::CreateView(plan) => {
@@ -1582,6 +1750,20 @@ impl AccessChecker for PrivilegeAccess {
                 )
                     .await?;
             }
+            Plan::CreateTag(_)
+            | Plan::DropTag(_)
+            | Plan::AlterTag(_)
+            | Plan::CreatePlanBaseline(_)
+            | Plan::DropPlanBaseline(_) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
+                    .await?;
+            }
+            Plan::SetObjectTags(plan) => {
+                self.validate_tag_object_access(&plan.object).await?;
+            }
+            Plan::UnsetObjectTags(plan) => {
+                self.validate_tag_object_access(&plan.object).await?;
+            }
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
@@ -1699,6 +1881,44 @@ impl AccessChecker for PrivilegeAccess {
     }
 }
 
+// Resolve the table columns read by a query to the ids of the top level fields in the table
+// schema, which are the objects column privileges are granted on.
+// Returns None if some column can not be resolved.
+fn read_column_ids(
+    metadata: &Metadata,
+    table_index: IndexType,
+    scan_columns: Option<&ColumnSet>,
+) -> Option<Vec<(ColumnId, String)>> {
+    let schema = metadata.table(table_index).table().schema();
+    let mut columns = Vec::new();
+    let indices = scan_columns
+        .into_iter()
+        .flatten()
+        .chain(metadata.lazy_columns().iter());
+    for index in indices {
+        let column = match metadata.column(*index) {
+            ColumnEntry::BaseTableColumn(column) if column.table_index == table_index => {
+                match (&column.path_indices, column.column_id) {
+                    (Some(path_indices), _) => {
+                        let field = schema.fields().get(*path_indices.first()?)?;
+                        (field.column_id(), field.name().clone())
+                    }
+                    (None, Some(column_id)) => (column_id, column.column_name.clone()),
+                    (None, None) => return None,
+                }
+            }
+            ColumnEntry::VirtualColumn(column) if column.table_index == table_index => {
+                (column.source_column_id, column.source_column_name.clone())
+            }
+            _ => continue,
+        };
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+    }
+    Some(columns)
+}
+
 fn check_db_tb_ownership_access(
     identity: &String,
     catalog: &String,
@@ -1824,7 +2044,7 @@ async fn has_priv(
                         *ldb == db_name
                     }
                 }
-                GrantObject::TableById(_, ldb, ltab) => {
+                GrantObject::TableById(_, ldb, ltab) | GrantObject::ColumnById(_, ldb, ltab, _) => {
                     if let Some(table) = table_id {
                         *ldb == db_id && *ltab == table
                     } else {
@@ -81,6 +81,32 @@ pub async fn validate_grant_object_exists(
                 ));
             }
         }
+        GrantObject::ColumnById(catalog_name, db_id, table_id, column_id) => {
+            let catalog = ctx.get_catalog(catalog_name).await?;
+
+            let Some(table_meta) = catalog.get_table_meta_by_id(*table_id).await? else {
+                return Err(databend_common_exception::ErrorCode::UnknownTableId(
+                    format!(
+                        "table id `{}`.`{}` not exists in catalog '{}'",
+                        db_id, table_id, catalog_name,
+                    ),
+                ));
+            };
+            if !table_meta
+                .data
+                .schema
+                .fields()
+                .iter()
+                .any(|f| f.column_id() == *column_id)
+            {
+                return Err(databend_common_exception::ErrorCode::UnknownColumn(
+                    format!(
+                        "column id {} not exists in table id `{}`.`{}`",
+                        column_id, db_id, table_id,
+                    ),
+                ));
+            }
+        }
         GrantObject::UDF(udf) => {
             if !UserApiProvider::instance().exists_udf(&tenant, udf).await? {
                 return Err(databend_common_exception::ErrorCode::UnknownFunction(
@@ -18,7 +18,9 @@ mod notification;
 mod query_log;
 mod stream;
 mod table;
+mod tag;
 mod task;
+mod ttl;
 mod util;
 
 pub mod table_option_validation;
@@ -29,9 +31,16 @@ pub use query_log::InterpreterQueryLog;
 pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
 pub use table::check_referenced_computed_columns;
+pub use table::check_referenced_ttl_column;
+pub use tag::database_has_tags;
+pub use tag::get_tag_by_name;
+pub use tag::mark_tagged_table;
+pub use tag::mark_tagged_tables;
+pub use tag::resolve_taggable_object;
 pub use task::get_task_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
+pub use ttl::delete_expired_rows;
 pub use util::check_deduplicate_label;
 
 pub use self::metrics::*;
@@ -19,7 +19,9 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_sql::parse_computed_expr;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 
 pub fn check_referenced_computed_columns(
     ctx: Arc<dyn TableContext>,
@@ -55,3 +57,21 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Checks that the TTL expression of the table is still valid on the altered schema.
+pub fn check_referenced_ttl_column(
+    ctx: Arc<dyn TableContext>,
+    table_info: &TableInfo,
+    schema: DataSchemaRef,
+    column: &str,
+) -> Result<()> {
+    if let Some(ttl) = table_info.options().get(OPT_KEY_TTL) {
+        if parse_computed_expr(ctx, schema, ttl).is_err() {
+            return Err(ErrorCode::AlterTableError(format!(
+                "column `{}` is referenced by the TTL expression `{}`, drop the TTL first",
+                column, ttl
+            )));
+        }
+    }
+    Ok(())
+}
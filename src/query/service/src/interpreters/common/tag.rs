@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::TagApi;
+use databend_common_meta_app::app_error::AppError;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_app::tag::TagMeta;
+use databend_common_meta_app::tag::TagNameIdent;
+use databend_common_meta_app::tag::TaggableObject;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_store::MetaStore;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::TagObject;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_TAGGED;
+
+/// Resolve the database, table or column a tag is set on to the ids it is stored by.
+pub async fn resolve_taggable_object(
+    ctx: Arc<dyn TableContext>,
+    object: &TagObject,
+) -> Result<TaggableObject> {
+    let tenant = ctx.get_tenant();
+    let (catalog_name, database) = match object {
+        TagObject::Database { catalog, database }
+        | TagObject::Table {
+            catalog, database, ..
+        }
+        | TagObject::Column {
+            catalog, database, ..
+        } => (catalog, database),
+    };
+
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    if catalog.is_external() {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Tags are not supported on objects of external catalog {}",
+            catalog_name
+        )));
+    }
+
+    let (table_name, column_name) = match object {
+        TagObject::Database { .. } => {
+            let db = catalog.get_database(&tenant, database).await?;
+            return Ok(TaggableObject::Database {
+                db_id: db.get_db_info().database_id.db_id,
+            });
+        }
+        TagObject::Table { table, .. } => (table, None),
+        TagObject::Column { table, column, .. } => (table, Some(column)),
+    };
+
+    let table = catalog.get_table(&tenant, database, table_name).await?;
+    let table_info = table.get_table_info();
+    if table.is_temp() || table_info.db_type != DatabaseType::NormalDB {
+        return Err(ErrorCode::TableEngineNotSupported(format!(
+            "{}.{} doesn't support tags",
+            database, table_name
+        )));
+    }
+
+    let table_id = table_info.ident.table_id;
+    match column_name {
+        None => Ok(TaggableObject::Table { table_id }),
+        Some(column_name) => {
+            let schema = table.schema();
+            let (_, field) = schema.column_with_name(column_name).ok_or_else(|| {
+                ErrorCode::UnknownColumn(format!("Cannot find column {}", column_name))
+            })?;
+            Ok(TaggableObject::Column {
+                table_id,
+                column_id: field.column_id,
+            })
+        }
+    }
+}
+
+/// Get the id and meta of a tag by its name, returns `UnknownTag` if it does not exist.
+pub async fn get_tag_by_name(
+    meta_api: &MetaStore,
+    tenant: &Tenant,
+    name: &str,
+) -> Result<(u64, TagMeta)> {
+    let name_ident = TagNameIdent::new(tenant, name);
+    match meta_api.get_tag(&name_ident).await? {
+        Some((seq_id, seq_meta)) => Ok((*seq_id.data, seq_meta.data)),
+        None => Err(AppError::from(name_ident.unknown_error("get tag")).into()),
+    }
+}
+
+/// Mark the tables the tags set on `object` apply to, the tag masking policies
+/// are only resolved when binding marked tables.
+///
+/// The tables are marked before the tags are set, and stay marked after the tags
+/// are unset, so a table with tags is never read without its tag masking policies.
+pub async fn mark_tagged_tables(ctx: Arc<dyn TableContext>, object: &TagObject) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let (catalog_name, database, table) = match object {
+        TagObject::Database { catalog, database } => (catalog, database, None),
+        TagObject::Table {
+            catalog,
+            database,
+            table,
+        }
+        | TagObject::Column {
+            catalog,
+            database,
+            table,
+            ..
+        } => (catalog, database, Some(table)),
+    };
+
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let tables = match table {
+        Some(table) => vec![catalog.get_table(&tenant, database, table).await?],
+        None => catalog.list_tables(&tenant, database).await?,
+    };
+    for table in tables {
+        mark_tagged_table(catalog.as_ref(), &tenant, database, table.as_ref()).await?;
+    }
+    Ok(())
+}
+
+/// Mark a table moved to or created in a database with tags.
+pub async fn mark_tagged_table(
+    catalog: &dyn Catalog,
+    tenant: &Tenant,
+    database: &str,
+    table: &dyn Table,
+) -> Result<()> {
+    let table_info = table.get_table_info();
+    if table.is_temp()
+        || table_info.db_type != DatabaseType::NormalDB
+        || table_info.options().contains_key(OPT_KEY_TAGGED)
+    {
+        return Ok(());
+    }
+
+    let req = UpsertTableOptionReq {
+        table_id: table_info.ident.table_id,
+        seq: MatchSeq::GE(1),
+        options: HashMap::from([(OPT_KEY_TAGGED.to_string(), Some("1".to_string()))]),
+    };
+    catalog.upsert_table_option(tenant, database, req).await?;
+    Ok(())
+}
+
+/// Returns true if tags are set on the database, its tables are masked by them.
+pub async fn database_has_tags(
+    ctx: Arc<dyn TableContext>,
+    catalog_name: &str,
+    database: &str,
+) -> Result<bool> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    if catalog.is_external() {
+        return Ok(false);
+    }
+    let db = catalog.get_database(&tenant, database).await?;
+    let db_id = db.get_db_info().database_id.db_id;
+    let meta_api = UserApiProvider::instance().get_meta_store_client();
+    let tags = meta_api
+        .get_object_tags(&tenant, TaggableObject::Database { db_id })
+        .await?;
+    Ok(!tags.is_empty())
+}
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::quote::display_ident;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_sql::Planner;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+
+/// Physically deletes the expired rows of a table with TTL, see `OPTIMIZE TABLE ... EXPIRE`.
+///
+/// The deletion is a plain `DELETE`, so the blocks whose rows are all expired are dropped
+/// without being read, and the partially expired blocks are rewritten. It's never run by
+/// compaction or recluster, so writes to the table don't pay for it.
+#[async_backtrace::framed]
+pub async fn delete_expired_rows(
+    ctx: &Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let tbl = ctx.get_table(catalog, database, table).await?;
+    let Some(ttl) = tbl.options().get(OPT_KEY_TTL) else {
+        return Ok(());
+    };
+    if tbl.is_read_only() {
+        return Ok(());
+    }
+
+    let sql_dialect = ctx.get_settings().get_sql_dialect()?;
+    let quote = |name: &str| display_ident(name, true, true, sql_dialect);
+    let sql = format!(
+        "DELETE FROM {}.{}.{} WHERE ({}) <= now()",
+        quote(catalog),
+        quote(database),
+        quote(table),
+        ttl
+    );
+    info!("[TTL] Deleting expired rows of table {database}.{table}: {sql}");
+
+    let delete_ctx = ctx
+        .get_current_session()
+        .create_query_context(&databend_common_version::BUILD_INFO)
+        .await?;
+    let mut planner = Planner::new(delete_ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let interpreter = InterpreterFactory::get(delete_ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(delete_ctx).await?;
+    let _: Vec<DataBlock> = stream.try_collect().await?;
+
+    // The table cached in the context is stale after the deletion.
+    ctx.evict_table_from_cache(catalog, database, table)?;
+    Ok(())
+}
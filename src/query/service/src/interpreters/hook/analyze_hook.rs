@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_base::runtime::GlobalIORuntime;
@@ -20,6 +19,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_storages_fuse::operations::AnalyzeStatsReceivers;
 use databend_common_storages_fuse::FuseTable;
 use log::info;
 
@@ -78,7 +78,7 @@ async fn do_analyze(ctx: Arc<QueryContext>, desc: AnalyzeDesc) -> Result<()> {
         ctx.clone(),
         table_snapshot,
         &mut pipeline,
-        HashMap::new(),
+        AnalyzeStatsReceivers::default(),
         true,
     )?;
     pipeline.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
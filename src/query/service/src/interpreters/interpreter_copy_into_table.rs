@@ -168,6 +168,7 @@ impl CopyIntoTableInterpreter {
                 kind: FragmentKind::Merge,
                 keys: Vec::new(),
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -12,19 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::CreateOption as AstCreateOption;
+use databend_common_ast::ast::CreateTableSource;
+use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableType;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CloneDatabaseSource;
 use databend_common_sql::plans::CreateDatabasePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use log::debug;
 
+use crate::interpreters::CreateTableInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -70,6 +82,13 @@ impl Interpreter for CreateDatabaseInterpreter {
             )));
         };
 
+        // Resolve the tables to clone before creating the database, so that an unknown
+        // source database fails the statement without leaving an empty database behind.
+        let clone_tables = match &self.plan.clone_source {
+            Some(source) => Some(self.list_tables_to_clone(source).await?),
+            None => None,
+        };
+
         let create_db_req: CreateDatabaseReq = self.plan.clone().into();
         let reply = catalog.create_database(create_db_req).await?;
 
@@ -93,6 +112,73 @@ impl Interpreter for CreateDatabaseInterpreter {
             }
         }
 
+        if let (Some(source), Some(tables)) = (&self.plan.clone_source, clone_tables) {
+            for table in tables {
+                self.clone_table(source, &table).await?;
+            }
+        }
+
         Ok(PipelineBuildResult::create())
     }
 }
+
+impl CreateDatabaseInterpreter {
+    async fn list_tables_to_clone(&self, source: &CloneDatabaseSource) -> Result<Vec<String>> {
+        let catalog = self.ctx.get_catalog(&source.catalog).await?;
+        let tables = catalog
+            .list_tables(&self.plan.tenant, &source.database)
+            .await?;
+        // Only the fuse tables stored in the default storage can be cloned, views,
+        // streams and tables of other engines are skipped.
+        Ok(tables
+            .into_iter()
+            .filter(|table| {
+                table.engine() == "FUSE"
+                    && !table.is_temp()
+                    && !table.is_read_only()
+                    && table.get_table_info().meta.storage_params.is_none()
+            })
+            .map(|table| table.name().to_string())
+            .collect())
+    }
+
+    async fn clone_table(&self, source: &CloneDatabaseSource, table: &str) -> Result<()> {
+        let quoted = |name: &str| Identifier::from_name_with_quoted(None, name, Some('"'));
+        let stmt = CreateTableStmt {
+            create_option: AstCreateOption::CreateIfNotExists,
+            catalog: Some(quoted(&self.plan.catalog)),
+            database: Some(quoted(&self.plan.database)),
+            table: quoted(table),
+            source: Some(CreateTableSource::Clone {
+                catalog: Some(quoted(&source.catalog)),
+                database: Some(quoted(&source.database)),
+                table: quoted(table),
+                travel_point: None,
+            }),
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            cluster_by: None,
+            ttl: None,
+            table_options: BTreeMap::new(),
+            iceberg_table_partition: None,
+            table_properties: None,
+            as_query: None,
+            table_type: TableType::Normal,
+        };
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let plan = planner
+            .plan_stmt(&Statement::CreateTable(stmt), false)
+            .await?;
+        let Plan::CreateTable(plan) = plan else {
+            return Err(ErrorCode::Internal(format!(
+                "Logical error, expect a CREATE TABLE plan to clone table {}.{}, got {}",
+                source.database, table, plan
+            )));
+        };
+        CreateTableInterpreter::try_create(self.ctx.clone(), *plan)?
+            .execute2()
+            .await?;
+        Ok(())
+    }
+}
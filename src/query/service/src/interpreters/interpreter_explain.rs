@@ -62,6 +62,7 @@ use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
 use crate::pipelines::executor::QueryPipelineExecutor;
 use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::adaptive_replan;
 use crate::schedulers::build_query_pipeline;
 use crate::schedulers::Fragmenter;
 use crate::schedulers::QueryFragmentsActions;
@@ -448,6 +449,7 @@ impl ExplainInterpreter {
 
         let mut builder = PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), true);
         let plan = builder.build(s_expr, required).await?;
+        let plan = adaptive_replan(&self.ctx, plan).await?;
         let build_res = build_query_pipeline(&self.ctx, &[], &plan, ignore_result).await?;
 
         // Drain the data
@@ -472,7 +474,8 @@ impl ExplainInterpreter {
         if let Some(build_info) = mutation_build_info {
             builder.set_mutation_build_info(build_info);
         }
-        let mut plan = builder.build(s_expr, required).await?;
+        let plan = builder.build(s_expr, required).await?;
+        let mut plan = adaptive_replan(&self.ctx, plan).await?;
         let build_res = build_query_pipeline(&self.ctx, &[], &plan, ignore_result).await?;
 
         // Drain the data
@@ -89,6 +89,8 @@ use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_drop_constraint::DropTableConstraintInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
 use crate::interpreters::interpreter_table_row_access_add::AddTableRowAccessPolicyInterpreter;
+use crate::interpreters::interpreter_table_statistics_create::CreateStatisticsInterpreter;
+use crate::interpreters::interpreter_table_statistics_drop::DropStatisticsInterpreter;
 use crate::interpreters::interpreter_table_unset_options::UnsetOptionsInterpreter;
 use crate::interpreters::interpreter_task_alter::AlterTaskInterpreter;
 use crate::interpreters::interpreter_task_create::CreateTaskInterpreter;
@@ -385,6 +387,15 @@ impl InterpreterFactory {
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::ModifyTableTTL(modify_table_ttl) => Ok(Arc::new(
+                ModifyTableTTLInterpreter::try_create(ctx, *modify_table_ttl.clone())?,
+            )),
+            Plan::CreateTableRef(create_table_ref) => Ok(Arc::new(
+                CreateTableRefInterpreter::try_create(ctx, *create_table_ref.clone())?,
+            )),
+            Plan::DropTableRef(drop_table_ref) => Ok(Arc::new(
+                DropTableRefInterpreter::try_create(ctx, *drop_table_ref.clone())?,
+            )),
             Plan::RefreshTableCache(refresh_table_cache) => Ok(Arc::new(
                 RefreshTableCacheInterpreter::try_create(ctx, *refresh_table_cache.clone())?,
             )),
@@ -400,6 +411,10 @@ impl InterpreterFactory {
                 ctx,
                 *purge.clone(),
             )?)),
+            Plan::OptimizeExpire(expire) => Ok(Arc::new(OptimizeExpireInterpreter::try_create(
+                ctx,
+                *expire.clone(),
+            )?)),
             Plan::OptimizeCompactSegment(compact_segment) => Ok(Arc::new(
                 OptimizeCompactSegmentInterpreter::try_create(ctx, *compact_segment.clone())?,
             )),
@@ -425,6 +440,12 @@ impl InterpreterFactory {
                 ctx,
                 *analyze_table.clone(),
             )?)),
+            Plan::CreateStatistics(create_statistics) => Ok(Arc::new(
+                CreateStatisticsInterpreter::try_create(ctx, *create_statistics.clone())?,
+            )),
+            Plan::DropStatistics(drop_statistics) => Ok(Arc::new(
+                DropStatisticsInterpreter::try_create(ctx, *drop_statistics.clone())?,
+            )),
             Plan::ExistsTable(exists_table) => Ok(Arc::new(ExistsTableInterpreter::try_create(
                 ctx,
                 *exists_table.clone(),
@@ -650,6 +671,25 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateTag(p) => Ok(Arc::new(CreateTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropTag(p) => Ok(Arc::new(DropTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::AlterTag(p) => Ok(Arc::new(AlterTagInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::SetObjectTags(p) => Ok(Arc::new(SetObjectTagsInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::UnsetObjectTags(p) => Ok(Arc::new(UnsetObjectTagsInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CreatePlanBaseline(p) => Ok(Arc::new(CreatePlanBaselineInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropPlanBaseline(p) => Ok(Arc::new(DropPlanBaselineInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
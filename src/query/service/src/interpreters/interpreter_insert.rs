@@ -135,6 +135,10 @@ impl Interpreter for InsertInterpreter {
                         let constraint_expr = expr_binder.get_expr(constraint_name, expr)?;
                         table_constraints.push((constraint_name.clone(), constraint_expr))
                     }
+                    // Key constraints are informational and never enforced.
+                    Constraint::PrimaryKey(_)
+                    | Constraint::Unique(_)
+                    | Constraint::ForeignKey(_) => {}
                 }
             }
 
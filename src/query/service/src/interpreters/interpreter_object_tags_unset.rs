@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_api::TagApi;
+use databend_common_sql::plans::UnsetObjectTagsPlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::common::get_tag_by_name;
+use crate::interpreters::common::resolve_taggable_object;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct UnsetObjectTagsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: UnsetObjectTagsPlan,
+}
+
+impl UnsetObjectTagsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: UnsetObjectTagsPlan) -> Result<Self> {
+        Ok(UnsetObjectTagsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for UnsetObjectTagsInterpreter {
+    fn name(&self) -> &str {
+        "UnsetObjectTagsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let object = resolve_taggable_object(self.ctx.clone(), &self.plan.object).await?;
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let mut tag_ids = Vec::with_capacity(self.plan.tags.len());
+        for tag_name in &self.plan.tags {
+            let (tag_id, _) = get_tag_by_name(&meta_api, &self.plan.tenant, tag_name).await?;
+            tag_ids.push(tag_id);
+        }
+
+        meta_api
+            .unset_object_tags(&self.plan.tenant, object, tag_ids)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_sql::plans::OptimizeExpirePlan;
+
+use crate::interpreters::common::delete_expired_rows;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// `OPTIMIZE TABLE ... EXPIRE`, physically deletes the expired rows of a table with TTL.
+pub struct OptimizeExpireInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: OptimizeExpirePlan,
+}
+
+impl OptimizeExpireInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: OptimizeExpirePlan) -> Result<Self> {
+        Ok(OptimizeExpireInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for OptimizeExpireInterpreter {
+    fn name(&self) -> &str {
+        "OptimizeExpireInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        delete_expired_rows(
+            &self.ctx,
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.table,
+        )
+        .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
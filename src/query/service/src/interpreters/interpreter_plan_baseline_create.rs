@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PlanBaseline;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_sql::plans::CreatePlanBaselinePlan;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::reset_plan_baseline_usage;
+use databend_common_sql::PlanShape;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreatePlanBaselineInterpreter {
+    #[allow(dead_code)]
+    ctx: Arc<QueryContext>,
+    plan: CreatePlanBaselinePlan,
+}
+
+impl CreatePlanBaselineInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreatePlanBaselinePlan) -> Result<Self> {
+        Ok(CreatePlanBaselineInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreatePlanBaselineInterpreter {
+    fn name(&self) -> &str {
+        "CreatePlanBaselineInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = self.plan.query.as_ref()
+        else {
+            return Err(ErrorCode::Internal(
+                "Plan baseline can only be created for a query",
+            ));
+        };
+        let shape = PlanShape::extract(s_expr, &metadata.read());
+
+        let baseline = PlanBaseline {
+            sql_hash: self.plan.sql_hash.clone(),
+            query_text: self.plan.query_text.clone(),
+            plan: serde_json::to_string(&shape)?,
+            plan_display: shape.to_string(),
+            created_on: Utc::now(),
+        };
+        let overwrite = matches!(self.plan.create_option, CreateOption::CreateOrReplace);
+        let api = UserApiProvider::instance().plan_baseline_api(&self.plan.tenant);
+        match api.add_baseline(baseline, overwrite).await {
+            Ok(_) => {}
+            Err(e)
+                if e.code() == ErrorCode::PLAN_BASELINE_ALREADY_EXISTS
+                    && matches!(self.plan.create_option, CreateOption::CreateIfNotExists) =>
+            {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        }
+
+        reset_plan_baseline_usage(self.plan.tenant.tenant_name(), &self.plan.sql_hash);
+        Ok(PipelineBuildResult::create())
+    }
+}
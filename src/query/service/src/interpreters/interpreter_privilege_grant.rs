@@ -135,7 +135,7 @@ impl GrantPrivilegeInterpreter {
             GrantObject::Procedure(p) => Ok(OwnershipObject::Procedure {
                 procedure_id: *p,
             }),
-            GrantObject::Global => Err(ErrorCode::IllegalGrant(
+            GrantObject::Global | GrantObject::ColumnById(..) => Err(ErrorCode::IllegalGrant(
                 "Illegal GRANT/REVOKE command; please consult the manual to see which privileges can be used",
             )),
         }
@@ -213,8 +213,10 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         let plan = self.plan.clone();
 
-        validate_grant_privileges(&plan.on, plan.priv_types)?;
-        validate_grant_object_exists(&self.ctx, &plan.on).await?;
+        for object in &plan.on {
+            validate_grant_privileges(object, plan.priv_types)?;
+            validate_grant_object_exists(&self.ctx, object).await?;
+        }
 
         // TODO: check user existence
         // TODO: check privilege on granting on the grant object
@@ -224,14 +226,21 @@ impl Interpreter for GrantPrivilegeInterpreter {
 
         match plan.principal {
             PrincipalIdentity::User(user) => {
-                user_mgr
-                    .grant_privileges_to_user(&tenant, user, plan.on, plan.priv_types)
-                    .await?;
+                for object in plan.on {
+                    user_mgr
+                        .grant_privileges_to_user(&tenant, user.clone(), object, plan.priv_types)
+                        .await?;
+                }
             }
             PrincipalIdentity::Role(role) => {
                 if plan.priv_types.has_privilege(Ownership) && plan.priv_types.len() == 1 {
+                    let [object] = plan.on.as_slice() else {
+                        return Err(ErrorCode::IllegalGrant(
+                            "Illegal GRANT/REVOKE command; ownership can only be granted on a single object",
+                        ));
+                    };
                     let owner_object = self
-                        .convert_to_ownerobject(&tenant, &plan.on, plan.on.catalog())
+                        .convert_to_ownerobject(&tenant, object, object.catalog())
                         .await?;
                     if self.ctx.get_current_role().is_some() {
                         if let OwnershipObject::Warehouse { .. } = owner_object {
@@ -253,9 +262,11 @@ impl Interpreter for GrantPrivilegeInterpreter {
                         ));
                     }
                 } else {
-                    user_mgr
-                        .grant_privileges_to_role(&tenant, &role, plan.on, plan.priv_types)
-                        .await?;
+                    for object in plan.on {
+                        user_mgr
+                            .grant_privileges_to_role(&tenant, &role, object, plan.priv_types)
+                            .await?;
+                    }
                 }
                 // grant_ownership and grant_privileges_to_role will modify the kv in meta.
                 // So we need invalidate the role cache.
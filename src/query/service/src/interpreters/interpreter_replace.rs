@@ -281,6 +281,7 @@ impl ReplaceInterpreter {
                 kind: FragmentKind::Expansive,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -290,6 +291,7 @@ impl ReplaceInterpreter {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -352,6 +354,7 @@ impl ReplaceInterpreter {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -50,6 +50,7 @@ use crate::physical_plans::PhysicalPlan;
 use crate::physical_plans::PhysicalPlanBuilder;
 use crate::physical_plans::PhysicalPlanCast;
 use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::adaptive_replan;
 use crate::schedulers::build_query_pipeline;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
@@ -123,10 +124,8 @@ impl SelectInterpreter {
 
     #[fastrace::trace(name = "SelectInterpreter::build_pipeline")]
     #[async_backtrace::framed]
-    pub async fn build_pipeline(
-        &self,
-        mut physical_plan: PhysicalPlan,
-    ) -> Result<PipelineBuildResult> {
+    pub async fn build_pipeline(&self, physical_plan: PhysicalPlan) -> Result<PipelineBuildResult> {
+        let mut physical_plan = adaptive_replan(&self.ctx, physical_plan).await?;
         if let Some(exchange) = Exchange::from_mut_physical_plan(&mut physical_plan) {
             if exchange.kind == FragmentKind::Merge && self.ignore_result {
                 exchange.ignore_exchange = self.ignore_result;
@@ -12,11 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_channel::Receiver;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::plans::AnalyzeTablePlan;
 use databend_common_sql::plans::Plan;
@@ -24,6 +25,7 @@ use databend_common_sql::BindContext;
 use databend_common_sql::Planner;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 use databend_common_storages_factory::Table;
+use databend_common_storages_fuse::operations::AnalyzeStatsReceivers;
 use databend_common_storages_fuse::operations::HistogramInfoSink;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_index::Index;
@@ -77,6 +79,38 @@ impl AnalyzeTableInterpreter {
         };
         Ok((select_plan, bind_context))
     }
+
+    /// Builds the pipeline of the statistics sql as a source pipeline of `build_res`,
+    /// and returns the receiver of its result blocks.
+    async fn build_stats_pipeline(
+        &self,
+        sql: String,
+        build_res: &mut PipelineBuildResult,
+    ) -> Result<Receiver<DataBlock>> {
+        let (stats_plan, bind_context) = self.plan_sql(sql, true).await?;
+        let mut stats_build_res = build_query_pipeline(
+            &QueryContext::create_from(self.ctx.as_ref()),
+            &bind_context.columns,
+            &stats_plan,
+            false,
+        )
+        .await?;
+        let (tx, rx) = async_channel::unbounded();
+        stats_build_res.main_pipeline.add_sink(|input_port| {
+            Ok(ProcessorPtr::create(HistogramInfoSink::create(
+                Some(tx.clone()),
+                input_port.clone(),
+            )))
+        })?;
+
+        build_res
+            .sources_pipelines
+            .push(stats_build_res.main_pipeline.finalize(None));
+        build_res
+            .sources_pipelines
+            .extend(stats_build_res.sources_pipelines);
+        Ok(rx)
+    }
 }
 
 #[async_trait::async_trait]
@@ -114,7 +148,7 @@ impl Interpreter for AnalyzeTableInterpreter {
         // After profiling, computing histogram is heavy and the bottleneck is window function(90%).
         // It's possible to OOM if the table is too large and spilling isn't enabled.
         // We add a setting `enable_analyze_histogram` to control whether to compute histogram(default is closed).
-        let mut histogram_info_receivers = HashMap::new();
+        let mut stats_receivers = AnalyzeStatsReceivers::default();
         let quote = self
             .ctx
             .get_settings()
@@ -150,36 +184,104 @@ impl Interpreter for AnalyzeTableInterpreter {
                     .collect::<Vec<_>>();
             for (sql, col_id) in histogram_sqls.into_iter() {
                 info!("Analyze histogram via sql: {sql}");
-                let (histogram_plan, bind_context) = self.plan_sql(sql, true).await?;
-                let mut histogram_build_res = build_query_pipeline(
-                    &QueryContext::create_from(self.ctx.as_ref()),
-                    &bind_context.columns,
-                    &histogram_plan,
-                    false,
-                )
-                .await?;
-                let (tx, rx) = async_channel::unbounded();
-                histogram_build_res.main_pipeline.add_sink(|input_port| {
-                    Ok(ProcessorPtr::create(HistogramInfoSink::create(
-                        Some(tx.clone()),
-                        input_port.clone(),
-                    )))
-                })?;
-
-                build_res
-                    .sources_pipelines
-                    .push(histogram_build_res.main_pipeline.finalize(None));
-                build_res
-                    .sources_pipelines
-                    .extend(histogram_build_res.sources_pipelines);
-                histogram_info_receivers.insert(col_id, rx);
+                let rx = self.build_stats_pipeline(sql, &mut build_res).await?;
+                stats_receivers.histograms.insert(col_id, rx);
             }
         }
+
+        let max_most_common_values = self
+            .ctx
+            .get_settings()
+            .get_max_analyze_most_common_values()?;
+        if max_most_common_values > 0
+            && self.ctx.get_settings().get_enable_table_snapshot_stats()?
+        {
+            // Only values that appear more than once are worth recording,
+            // the others are estimated well enough by the ndv.
+            let mcv_sqls = table
+                .schema()
+                .fields()
+                .iter()
+                .filter(|f| RangeIndex::supported_type(&f.data_type().into()))
+                .map(|f| {
+                    let col_name = format!("{quote}{}{quote}", f.name);
+                    (
+                        format!(
+                            "SELECT {col_name}, COUNT() AS count \
+                            FROM {}.{} WHERE {col_name} IS NOT NULL \
+                            GROUP BY {col_name} HAVING COUNT() > 1 \
+                            ORDER BY count DESC LIMIT {max_most_common_values}",
+                            plan.database, plan.table,
+                        ),
+                        f.column_id(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            for (sql, col_id) in mcv_sqls.into_iter() {
+                info!("Analyze most common values via sql: {sql}");
+                let rx = self.build_stats_pipeline(sql, &mut build_res).await?;
+                stats_receivers.most_common_values.insert(col_id, rx);
+            }
+        }
+
+        if self.ctx.get_settings().get_enable_table_snapshot_stats()? {
+            let schema = table.schema();
+            for (name, def) in table.get_table_info().meta.multi_column_statistics.iter() {
+                let Some(col_names) = def
+                    .column_ids
+                    .iter()
+                    .map(|id| {
+                        schema
+                            .fields()
+                            .iter()
+                            .find(|f| f.column_id() == *id)
+                            .map(|f| format!("{quote}{}{quote}", f.name))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                else {
+                    continue;
+                };
+                let group_by = col_names.join(", ");
+                // The dependency degree of a column is the fraction of rows whose value of
+                // the column is determined by the values of the other columns in the group.
+                let dependencies = (0..col_names.len())
+                    .map(|i| {
+                        let others = col_names
+                            .iter()
+                            .enumerate()
+                            .filter(|(j, _)| *j != i)
+                            .map(|(_, c)| c.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!(
+                            "(SELECT SUM(IF(n = 1, c, 0)) / SUM(c) FROM ( \
+                                SELECT COUNT(DISTINCT {}) AS n, COUNT() AS c \
+                                FROM {}.{} GROUP BY {others} \
+                            )) AS d{i}",
+                            col_names[i], plan.database, plan.table,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let sql = format!(
+                    "SELECT (SELECT COUNT() FROM ( \
+                        SELECT {group_by} FROM {}.{} GROUP BY {group_by} \
+                    )) AS ndv, {dependencies}",
+                    plan.database, plan.table,
+                );
+                info!("Analyze multi-column statistics {name} via sql: {sql}");
+                let rx = self.build_stats_pipeline(sql, &mut build_res).await?;
+                stats_receivers
+                    .multi_column_statistics
+                    .insert(name.clone(), (def.column_ids.clone(), rx));
+            }
+        }
+
         table.do_analyze(
             self.ctx.clone(),
             snapshot,
             &mut build_res.main_pipeline,
-            histogram_info_receivers,
+            stats_receivers,
             self.plan.no_scan,
         )?;
         Ok(build_res)
@@ -51,8 +51,10 @@ use databend_common_sql::DefaultExprBinder;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FuseSegmentFormat;
 use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_ANALYZE;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_VACUUM;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_attach_table::get_attach_table_handler;
@@ -65,10 +67,12 @@ use databend_storages_common_table_meta::table::OPT_KEY_SEGMENT_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TAGGED;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::database_has_tags;
 use crate::interpreters::common::table_option_validation::is_valid_approx_distinct_columns;
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
@@ -209,6 +213,7 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
 
         let mut req = self.build_request(None)?;
+        self.mark_tagged_database(&mut req).await?;
 
         // create a dropped table first.
         req.as_dropped = true;
@@ -379,11 +384,34 @@ impl CreateTableInterpreter {
                 });
             }
         }
-        let req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
+        let mut req = if let Some(storage_prefix) = self.plan.options.get(OPT_KEY_STORAGE_PREFIX) {
             self.build_attach_request(storage_prefix).await
         } else {
             self.build_request(stat)
         }?;
+        self.mark_tagged_database(&mut req).await?;
+
+        // A clone is created as a dropped table, and made visible after the files it
+        // shares with other tables are referenced, see `create_clone_table`.
+        let clone_snapshot_location = self
+            .plan
+            .clone_source
+            .as_ref()
+            .and_then(|source| source.snapshot_location.clone());
+        if let (Some(source), Some(snapshot_location)) =
+            (&self.plan.clone_source, &clone_snapshot_location)
+        {
+            let source_table = self
+                .ctx
+                .get_table(&source.catalog, &source.database, &source.table)
+                .await?;
+            let ctx: Arc<dyn TableContext> = self.ctx.clone();
+            FuseTable::try_from_table(source_table.as_ref())?
+                .check_clone_snapshot_retained(&ctx, snapshot_location)
+                .await?;
+            req.as_dropped = true;
+            req.table_meta.drop_on = Some(Utc::now());
+        }
 
         if !catalog.support_partition()
             && (req.table_properties.is_some() || req.table_partition.is_some())
@@ -398,6 +426,13 @@ impl CreateTableInterpreter {
         if let Some(prefix) = req.table_meta.options.get(OPT_KEY_TEMP_PREFIX).cloned() {
             self.register_temp_table(prefix).await?;
         }
+        if let Some(snapshot_location) = clone_snapshot_location {
+            if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+                return Ok(PipelineBuildResult::create());
+            }
+            self.create_clone_table(&req, &reply, &snapshot_location)
+                .await?;
+        }
 
         // iceberg table do not need to generate ownership.
         if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) && !catalog.is_external() {
@@ -408,6 +443,18 @@ impl CreateTableInterpreter {
         Ok(PipelineBuildResult::create())
     }
 
+    /// A table created in a database with tags is masked by the tags of the database.
+    async fn mark_tagged_database(&self, req: &mut CreateTableReq) -> Result<()> {
+        if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX)
+            && database_has_tags(self.ctx.clone(), &self.plan.catalog, &self.plan.database).await?
+        {
+            req.table_meta
+                .options
+                .insert(OPT_KEY_TAGGED.to_string(), "1".to_string());
+        }
+        Ok(())
+    }
+
     /// Build CreateTableReq from CreateTablePlanV2.
     ///
     /// - Rebuild `DataSchema` with default exprs.
@@ -426,7 +473,12 @@ impl CreateTableInterpreter {
         } else {
             self.plan.field_comments.clone()
         };
-        let schema = TableSchemaRefExt::create(fields);
+        // A clone shares the blocks of its source table, the column ids must be kept.
+        let schema = if self.plan.clone_source.is_some() {
+            self.plan.schema.clone()
+        } else {
+            TableSchemaRefExt::create(fields)
+        };
         let mut options = self.plan.options.clone();
 
         if self.plan.engine == Engine::Fuse {
@@ -487,9 +539,14 @@ impl CreateTableInterpreter {
         is_valid_option_of_type::<u32>(&table_meta.options, FUSE_OPT_KEY_ENABLE_AUTO_VACUUM)?;
         // check enable auto analyze.
         is_valid_option_of_type::<u32>(&table_meta.options, FUSE_OPT_KEY_ENABLE_AUTO_ANALYZE)?;
+        // check enable deletion vector.
+        is_valid_option_of_type::<u32>(&table_meta.options, FUSE_OPT_KEY_ENABLE_DELETION_VECTOR)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
+            if self.plan.clone_source.is_some() && key == OPT_KEY_SNAPSHOT_LOCATION {
+                continue;
+            }
             if !is_valid_create_opt(&key, &self.plan.engine) {
                 let msg = format!(
                     "table option {key} is invalid for create table statement with engine {}",
@@ -540,6 +597,50 @@ impl CreateTableInterpreter {
             .await
     }
 
+    /// Register the new table, created as a dropped table, as a user of the files of the
+    /// table it's cloned from, then make it visible.
+    ///
+    /// The references must exist before the table is committed, otherwise the GC of the
+    /// source table may purge the shared files in between. If registering fails, the
+    /// table stays invisible and is available for vacuum.
+    async fn create_clone_table(
+        &self,
+        req: &CreateTableReq,
+        reply: &CreateTableReply,
+        snapshot_location: &str,
+    ) -> Result<()> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table_id_seq = reply
+            .table_id_seq
+            .expect("internal error: table_id_seq must have been set. CLONE of table");
+        let table_info = TableInfo::new(
+            &self.plan.database,
+            &self.plan.table,
+            TableIdent::new(reply.table_id, table_id_seq),
+            req.table_meta.clone(),
+        );
+        let table = catalog.get_table_by_info(&table_info)?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        fuse_table
+            .register_clone_references(&ctx, snapshot_location)
+            .await?;
+
+        let req = CommitTableMetaReq {
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            db_id: reply.db_id,
+            table_id: reply.table_id,
+            prev_table_id: reply.prev_table_id,
+            orphan_table_name: reply.orphan_table_name.clone(),
+        };
+        catalog.commit_table_meta(req).await?;
+        Ok(())
+    }
+
     async fn register_temp_table(&self, prefix: String) -> Result<()> {
         let session = self.ctx.get_current_session();
         if let Some(id) = session.get_client_session_id() {
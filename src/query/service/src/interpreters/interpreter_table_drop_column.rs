@@ -26,6 +26,7 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl_column;
 use crate::interpreters::interpreter_table_add_column::commit_table_meta;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -102,6 +103,15 @@ impl Interpreter for DropTableColumnInterpreter {
                 self.plan.column.as_str(),
             )?;
         }
+        let mut new_schema: DataSchema = table_info.schema().into();
+        new_schema.drop_column(self.plan.column.as_str())?;
+        // Check if this column is referenced by the TTL expression.
+        check_referenced_ttl_column(
+            self.ctx.clone(),
+            table_info,
+            Arc::new(new_schema),
+            self.plan.column.as_str(),
+        )?;
         // If the column is table index column, the column can't be dropped.
         if !table_info.meta.indexes.is_empty() {
             for (index_name, index) in &table_info.meta.indexes {
@@ -113,6 +123,17 @@ impl Interpreter for DropTableColumnInterpreter {
                 }
             }
         }
+        // If the column is a key constraint column, the column can't be dropped.
+        for (constraint_name, constraint) in &table_info.meta.constraints {
+            if constraint.key_column_ids().contains(&field.column_id) {
+                return Err(ErrorCode::AlterTableError(format!(
+                    "column `{}` is referenced by {} constraint `{}`, drop the constraint first",
+                    field.name,
+                    constraint.type_name(),
+                    constraint_name,
+                )));
+            }
+        }
 
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
@@ -130,6 +151,10 @@ impl Interpreter for DropTableColumnInterpreter {
                 }
             }
         }
+        // multi-column statistics are only estimation hints, drop the ones on the column.
+        new_table_meta
+            .multi_column_statistics
+            .retain(|_, def| !def.column_ids.contains(&field.column_id));
 
         commit_table_meta(
             &self.ctx,
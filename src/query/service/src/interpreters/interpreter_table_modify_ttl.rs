@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::ModifyTableTTLPlan;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct ModifyTableTTLInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: ModifyTableTTLPlan,
+}
+
+impl ModifyTableTTLInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: ModifyTableTTLPlan) -> Result<Self> {
+        Ok(ModifyTableTTLInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for ModifyTableTTLInterpreter {
+    fn name(&self) -> &str {
+        "ModifyTableTTLInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+
+        let table = catalog
+            .get_table(&tenant, &plan.database, &plan.table)
+            .await?;
+        if plan.ttl.is_none() && !table.options().contains_key(OPT_KEY_TTL) {
+            return Ok(PipelineBuildResult::create());
+        }
+        // check mutability
+        table.check_mutable()?;
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let table_info = fuse_table.get_table_info();
+        let mut new_table_meta = table_info.meta.clone();
+        match &plan.ttl {
+            Some(ttl) => {
+                new_table_meta
+                    .options
+                    .insert(OPT_KEY_TTL.to_string(), ttl.clone());
+            }
+            None => {
+                new_table_meta.options.remove(OPT_KEY_TTL);
+            }
+        }
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+            base_snapshot_location: fuse_table.snapshot_loc(),
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -454,6 +454,7 @@ impl ReclusterTableInterpreter {
                 kind: FragmentKind::Normal,
                 keys: vec![expr],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -716,6 +717,7 @@ impl ReclusterTableInterpreter {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::DropTableRefPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableRefInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableRefPlan,
+}
+
+impl DropTableRefInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableRefPlan) -> Result<Self> {
+        Ok(DropTableRefInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableRefInterpreter {
+    fn name(&self) -> &str {
+        "DropTableRefInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let plan = &self.plan;
+        let catalog = self.ctx.get_catalog(&plan.catalog).await?;
+        let table = catalog
+            .get_table(&plan.tenant, &plan.database, &plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+        if table.is_temp() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Branches and tags are not supported for temporary table '{}'",
+                plan.table
+            )));
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let ctx = self.ctx.clone() as Arc<dyn TableContext>;
+        fuse_table
+            .drop_table_ref(&ctx, plan.kind, &plan.name)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -14,11 +14,14 @@
 
 use std::sync::Arc;
 
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_sql::plans::RenameTablePlan;
 
+use crate::interpreters::common::database_has_tags;
+use crate::interpreters::common::mark_tagged_table;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -51,6 +54,36 @@ impl Interpreter for RenameTableInterpreter {
         // You must have ALTER and DROP privileges for the original table,
         // and CREATE and INSERT privileges for the new table.
         let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+
+        // A table moved to a database with tags is masked by the tags of the database,
+        // it's marked before it can be read from there.
+        if self.plan.new_database != self.plan.database
+            && database_has_tags(
+                self.ctx.clone(),
+                &self.plan.catalog,
+                &self.plan.new_database,
+            )
+            .await?
+        {
+            let tenant = &self.plan.tenant;
+            match catalog
+                .get_table(tenant, &self.plan.database, &self.plan.table)
+                .await
+            {
+                Ok(table) => {
+                    mark_tagged_table(
+                        catalog.as_ref(),
+                        tenant,
+                        &self.plan.database,
+                        table.as_ref(),
+                    )
+                    .await?
+                }
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE && self.plan.if_exists => {}
+                Err(e) => return Err(e),
+            }
+        }
+
         let _resp = catalog
             .rename_table(RenameTableReq {
                 if_exists: self.plan.if_exists,
@@ -27,6 +27,7 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::check_referenced_ttl_column;
 use crate::interpreters::interpreter_table_add_column::commit_table_meta;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
@@ -100,10 +101,17 @@ impl Interpreter for RenameTableColumnInterpreter {
             if field.computed_expr().is_none() {
                 let index = schema.index_of(self.plan.old_column.as_str())?;
                 schema.rename_field(index, self.plan.new_column.as_str());
+                let schema = Arc::new(schema);
                 // Check if old column is referenced by computed columns.
                 check_referenced_computed_columns(
                     self.ctx.clone(),
-                    Arc::new(schema),
+                    schema.clone(),
+                    self.plan.old_column.as_str(),
+                )?;
+                check_referenced_ttl_column(
+                    self.ctx.clone(),
+                    table_info,
+                    schema,
                     self.plan.old_column.as_str(),
                 )?;
             }
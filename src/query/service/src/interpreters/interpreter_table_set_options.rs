@@ -29,12 +29,14 @@ use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::io::read::RowOrientedSegmentReader;
 use databend_common_storages_fuse::io::SegmentsIO;
+use databend_common_storages_fuse::operations::AnalyzeStatsReceivers;
 use databend_common_storages_fuse::segment_format_from_location;
 use databend_common_storages_fuse::FuseSegmentFormat;
 use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_ANALYZE;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_AUTO_VACUUM;
+use databend_common_storages_fuse::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use databend_storages_common_table_meta::meta::column_oriented_segment::AbstractSegment;
 use databend_storages_common_table_meta::meta::column_oriented_segment::ColumnOrientedSegmentBuilder;
 use databend_storages_common_table_meta::meta::column_oriented_segment::SegmentBuilder;
@@ -130,6 +132,10 @@ impl Interpreter for SetOptionsInterpreter {
 
         // Same as settings of FUSE_OPT_KEY_ENABLE_AUTO_VACUUM, expect value type is unsigned integer
         is_valid_option_of_type::<u32>(&self.plan.set_options, FUSE_OPT_KEY_ENABLE_AUTO_VACUUM)?;
+        is_valid_option_of_type::<u32>(
+            &self.plan.set_options,
+            FUSE_OPT_KEY_ENABLE_DELETION_VECTOR,
+        )?;
 
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
         let database = self.plan.database.as_str();
@@ -322,7 +328,7 @@ async fn analyze_table(
         ctx.clone(),
         table_snapshot,
         &mut pipeline,
-        HashMap::new(),
+        AnalyzeStatsReceivers::default(),
         false,
     )?;
     pipeline.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
@@ -38,6 +38,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use itertools::Itertools;
 
 use crate::interpreters::Interpreter;
@@ -283,6 +284,10 @@ impl ShowCreateTableInterpreter {
                 .push_str(format!(" CLUSTER BY {}{}", cluster_type, cluster_keys_str).as_str());
         }
 
+        if let Some(ttl) = table_info.options().get(OPT_KEY_TTL) {
+            table_create_sql.push_str(format!(" TTL {}", ttl).as_str());
+        }
+
         if !hide_options_in_show_create_table || engine == "ICEBERG" || engine == "DELTA" {
             table_create_sql.push_str({
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
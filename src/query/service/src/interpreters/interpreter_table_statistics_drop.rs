@@ -0,0 +1,84 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::plans::DropStatisticsPlan;
+
+use crate::interpreters::interpreter_table_add_column::commit_table_meta;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Clone, Debug)]
+pub struct DropStatisticsInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropStatisticsPlan,
+}
+
+impl DropStatisticsInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropStatisticsPlan) -> Result<Self> {
+        Ok(DropStatisticsInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropStatisticsInterpreter {
+    fn name(&self) -> &str {
+        "DropStatisticsInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let tbl_name = self.plan.table.as_str();
+
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        // check mutability
+        tbl.check_mutable()?;
+
+        let mut table_info = tbl.get_table_info().clone();
+        if self.plan.if_exists
+            && !table_info
+                .meta
+                .multi_column_statistics
+                .contains_key(&self.plan.name)
+        {
+            return Ok(PipelineBuildResult::create());
+        }
+        table_info
+            .meta
+            .drop_multi_column_statistics(&self.plan.name)?;
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let new_table_meta = table_info.meta.clone();
+        commit_table_meta(
+            &self.ctx,
+            tbl.as_ref(),
+            &table_info,
+            new_table_meta,
+            catalog,
+        )
+        .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
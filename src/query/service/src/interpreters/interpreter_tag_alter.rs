@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
+use databend_common_meta_api::TagApi;
+use databend_common_meta_app::app_error::AppError;
+use databend_common_meta_app::data_mask::DataMaskNameIdent;
+use databend_common_meta_app::tag::TagNameIdent;
+use databend_common_sql::plans::AlterTagPlan;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_data_mask_feature::get_datamask_handler;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AlterTagInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterTagPlan,
+}
+
+impl AlterTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterTagPlan) -> Result<Self> {
+        Ok(AlterTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterTagInterpreter {
+    fn name(&self) -> &str {
+        "AlterTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::DataMask)?;
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let tenant = &self.plan.tenant;
+
+        let policy_id = match &self.plan.masking_policy {
+            Some(mask_name) => {
+                let name_ident = DataMaskNameIdent::new(tenant, mask_name);
+                let Some(seq_id) = meta_api.get_pb(&name_ident).await? else {
+                    return Err(ErrorCode::UnknownDatamask(format!(
+                        "Data mask policy {} not found",
+                        mask_name
+                    )));
+                };
+
+                // A tag masks each tagged column on its own,
+                // so the policy can not reference other columns.
+                let policy = get_datamask_handler()
+                    .get_data_mask(meta_api.clone(), tenant, mask_name.clone())
+                    .await?;
+                if policy.args.len() != 1 {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Mask policy {} with {} arguments can not be set on a tag, only single argument policies are allowed",
+                        mask_name,
+                        policy.args.len()
+                    )));
+                }
+
+                Some(*seq_id.data)
+            }
+            None => None,
+        };
+
+        let name_ident = TagNameIdent::new(tenant, &self.plan.name);
+        if let Err(e) = meta_api
+            .set_tag_masking_policy(&name_ident, policy_id)
+            .await?
+        {
+            return Err(AppError::from(e).into());
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_api::TagApi;
+use databend_common_meta_app::app_error::AppError;
+use databend_common_sql::plans::CreateTagPlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct CreateTagInterpreter {
+    #[allow(dead_code)]
+    ctx: Arc<QueryContext>,
+    plan: CreateTagPlan,
+}
+
+impl CreateTagInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTagPlan) -> Result<Self> {
+        Ok(CreateTagInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTagInterpreter {
+    fn name(&self) -> &str {
+        "CreateTagInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        if let Err(e) = meta_api.create_tag(self.plan.clone().into()).await? {
+            return Err(AppError::from(e).into());
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
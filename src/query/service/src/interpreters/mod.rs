@@ -74,13 +74,18 @@ mod interpreter_notification_alter;
 mod interpreter_notification_create;
 mod interpreter_notification_desc;
 mod interpreter_notification_drop;
+mod interpreter_object_tags_set;
+mod interpreter_object_tags_unset;
 mod interpreter_optimize_compact_block;
 mod interpreter_optimize_compact_segment;
+mod interpreter_optimize_expire;
 mod interpreter_optimize_purge;
 mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_plan_baseline_create;
+mod interpreter_plan_baseline_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -136,7 +141,10 @@ mod interpreter_table_index_refresh;
 mod interpreter_table_modify_column;
 mod interpreter_table_modify_comment;
 mod interpreter_table_modify_connection;
+mod interpreter_table_modify_ttl;
 mod interpreter_table_recluster;
+mod interpreter_table_ref_create;
+mod interpreter_table_ref_drop;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
@@ -145,11 +153,16 @@ mod interpreter_table_row_access_drop;
 mod interpreter_table_row_access_drop_all;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
+mod interpreter_table_statistics_create;
+mod interpreter_table_statistics_drop;
 mod interpreter_table_swap;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
 mod interpreter_table_unset_options;
 mod interpreter_table_vacuum;
+mod interpreter_tag_alter;
+mod interpreter_tag_create;
+mod interpreter_tag_drop;
 mod interpreter_task_alter;
 mod interpreter_task_create;
 mod interpreter_task_describe;
@@ -218,13 +231,18 @@ pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
 pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
 pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
 pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_object_tags_set::SetObjectTagsInterpreter;
+pub use interpreter_object_tags_unset::UnsetObjectTagsInterpreter;
 pub use interpreter_optimize_compact_block::OptimizeCompactBlockInterpreter;
 pub use interpreter_optimize_compact_segment::OptimizeCompactSegmentInterpreter;
+pub use interpreter_optimize_expire::OptimizeExpireInterpreter;
 pub use interpreter_optimize_purge::OptimizePurgeInterpreter;
 pub use interpreter_password_policy_alter::AlterPasswordPolicyInterpreter;
 pub use interpreter_password_policy_create::CreatePasswordPolicyInterpreter;
 pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
+pub use interpreter_plan_baseline_create::CreatePlanBaselineInterpreter;
+pub use interpreter_plan_baseline_drop::DropPlanBaselineInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_procedure_desc::DescProcedureInterpreter;
@@ -256,7 +274,10 @@ pub use interpreter_table_index_drop::DropTableIndexInterpreter;
 pub use interpreter_table_index_refresh::RefreshTableIndexInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_modify_comment::ModifyTableCommentInterpreter;
+pub use interpreter_table_modify_ttl::ModifyTableTTLInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
+pub use interpreter_table_ref_create::CreateTableRefInterpreter;
+pub use interpreter_table_ref_drop::DropTableRefInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
 pub use interpreter_table_row_access_add::AddTableRowAccessPolicyInterpreter;
@@ -268,6 +289,9 @@ pub use interpreter_table_swap::SwapTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_tag_alter::AlterTagInterpreter;
+pub use interpreter_tag_create::CreateTagInterpreter;
+pub use interpreter_tag_drop::DropTagInterpreter;
 pub use interpreter_unset::UnSetInterpreter;
 pub use interpreter_unset_workload_group_quotas::UnsetWorkloadGroupQuotasInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
@@ -62,6 +62,18 @@ impl<'a> PhysicalFormat for ExchangeFormatter<'a> {
             })),
         ];
 
+        if let Some(skew) = &self.inner.skew {
+            node_children.push(FormatTreeNode::new(format!(
+                "skewed keys: {}, hot rows: {}",
+                skew.hot_predicate.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                if skew.broadcast {
+                    "Broadcast"
+                } else {
+                    "RoundRobin"
+                }
+            )));
+        }
+
         let input_formatter = self.inner.input.formatter()?;
         node_children.push(input_formatter.dispatch(ctx)?);
 
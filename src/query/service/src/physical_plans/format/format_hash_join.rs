@@ -114,6 +114,19 @@ impl<'a> PhysicalFormat for HashJoinFormatter<'a> {
             FormatTreeNode::new(format!("filters: [{filters}]")),
         ];
 
+        if !self.inner.hint.is_empty() {
+            node_children.push(FormatTreeNode::new(format!(
+                "join hints: [{}]",
+                self.inner.hint
+            )));
+        }
+
+        if let Some(adaptive) = &self.inner.adaptive {
+            node_children.push(FormatTreeNode::new(format!(
+                "adaptive execution: {adaptive}"
+            )));
+        }
+
         if !build_runtime_filters.is_empty() {
             if self.inner.broadcast_id.is_some() {
                 node_children.push(FormatTreeNode::with_children(
@@ -52,6 +52,8 @@ mod format_row_fetch;
 mod format_secure_filter;
 mod format_shuffle;
 mod format_sort;
+mod format_sort_merge_join;
+mod format_spatial_join;
 mod format_table_scan;
 mod format_udf;
 mod format_union_all;
@@ -100,6 +102,8 @@ pub use format_row_fetch::*;
 pub use format_secure_filter::*;
 pub use format_shuffle::*;
 pub use format_sort::*;
+pub use format_sort_merge_join::*;
+pub use format_spatial_join::*;
 pub use format_table_scan::*;
 pub use format_udf::*;
 pub use format_union_all::*;
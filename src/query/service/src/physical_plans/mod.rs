@@ -54,6 +54,8 @@ mod physical_replace_into;
 mod physical_row_fetch;
 mod physical_secure_filter;
 mod physical_sort;
+mod physical_sort_merge_join;
+mod physical_spatial_join;
 mod physical_table_scan;
 mod physical_udf;
 mod physical_union_all;
@@ -78,9 +80,13 @@ pub use physical_cte_consumer::MaterializeCTERef;
 pub use physical_distributed_insert_select::DistributedInsertSelect;
 pub use physical_eval_scalar::EvalScalar;
 pub use physical_exchange::Exchange;
+pub use physical_exchange::ShuffleSkew;
 pub use physical_exchange_sink::ExchangeSink;
 pub use physical_exchange_source::ExchangeSource;
 pub use physical_filter::Filter;
+pub use physical_hash_join::hot_key_predicate;
+pub use physical_hash_join::AdaptiveJoinDecision;
+pub use physical_hash_join::AdaptiveJoinInfo;
 pub use physical_hash_join::HashJoin;
 pub use physical_join_filter::JoinRuntimeFilter;
 pub use physical_join_filter::PhysicalRuntimeFilter;
@@ -105,6 +111,8 @@ pub use physical_row_fetch::RowFetch;
 pub use physical_secure_filter::SecureFilter;
 pub use physical_sequence::*;
 pub use physical_sort::Sort;
+pub use physical_sort_merge_join::SortMergeJoin;
+pub use physical_spatial_join::SpatialJoin;
 pub use physical_table_scan::TableScan;
 pub use physical_udf::UdfFunctionDesc;
 pub use physical_union_all::UnionAll;
@@ -461,6 +461,7 @@ impl PhysicalPlanBuilder {
                         kind,
                         ignore_exchange: false,
                         allow_adjust_parallelism: true,
+                        skew: None,
                         meta: PhysicalPlanMeta::new("Exchange"),
                         input: PhysicalPlan::new(aggregate_partial),
                     })
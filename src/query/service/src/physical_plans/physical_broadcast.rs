@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeSet;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -20,6 +21,9 @@ use databend_common_expression::DataSchemaRef;
 use databend_common_sql::executor::physical_plans::FragmentKind;
 
 use super::Exchange;
+use super::HashJoin;
+use super::PhysicalPlanCast;
+use super::Sort;
 use crate::physical_plans::format::BroadcastSinkFormatter;
 use crate::physical_plans::format::PhysicalFormat;
 use crate::physical_plans::physical_plan::IPhysicalPlan;
@@ -138,6 +142,7 @@ pub fn build_broadcast_plan(broadcast_id: u32) -> Result<PhysicalPlan> {
         kind: FragmentKind::Expansive,
         keys: vec![],
         allow_adjust_parallelism: true,
+        skew: None,
         ignore_exchange: false,
         meta: PhysicalPlanMeta::new("Exchange"),
     });
@@ -149,12 +154,34 @@ pub fn build_broadcast_plan(broadcast_id: u32) -> Result<PhysicalPlan> {
     }))
 }
 
-pub fn build_broadcast_plans(ctx: &dyn TableContext) -> Result<Vec<PhysicalPlan>> {
-    let mut plans = vec![];
-    let next_broadcast_id = ctx.get_next_broadcast_id();
+/// Build the broadcast plans of the broadcast ids used by the plan. The ids of the stages
+/// already executed by adaptive query execution are not in the plan anymore, a broadcast plan
+/// without any sender would never finish.
+pub fn build_broadcast_plans(
+    ctx: &dyn TableContext,
+    plan: &PhysicalPlan,
+) -> Result<Vec<PhysicalPlan>> {
+    let mut broadcast_ids = BTreeSet::new();
+    collect_broadcast_ids(plan, &mut broadcast_ids);
     ctx.reset_broadcast_id();
-    for broadcast_id in 0..next_broadcast_id {
+
+    let mut plans = vec![];
+    for broadcast_id in broadcast_ids {
         plans.push(build_broadcast_plan(broadcast_id)?);
     }
     Ok(plans)
 }
+
+/// Collect the broadcast ids of the hash joins and sorts in the plan.
+#[recursive::recursive]
+pub fn collect_broadcast_ids(plan: &PhysicalPlan, broadcast_ids: &mut BTreeSet<u32>) {
+    if let Some(join) = HashJoin::from_physical_plan(plan) {
+        broadcast_ids.extend(join.broadcast_id);
+    } else if let Some(sort) = Sort::from_physical_plan(plan) {
+        broadcast_ids.extend(sort.broadcast_id);
+    }
+
+    for child in plan.children() {
+        collect_broadcast_ids(child, broadcast_ids);
+    }
+}
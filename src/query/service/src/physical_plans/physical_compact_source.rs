@@ -271,6 +271,7 @@ impl PhysicalPlanBuilder {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("ConstantTableScan"),
             });
@@ -39,6 +39,16 @@ pub struct Exchange {
     pub keys: Vec<RemoteExpr>,
     pub ignore_exchange: bool,
     pub allow_adjust_parallelism: bool,
+    pub skew: Option<ShuffleSkew>,
+}
+
+/// How the rows of the hot keys of a skewed hash join are shuffled.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ShuffleSkew {
+    // A non-null boolean expression which is true for the rows of hot keys.
+    pub hot_predicate: RemoteExpr,
+    // Broadcast the hot rows to all nodes, otherwise spread them in round robin.
+    pub broadcast: bool,
 }
 
 #[typetag::serde]
@@ -88,6 +98,7 @@ impl IPhysicalPlan for Exchange {
             keys: self.keys.clone(),
             ignore_exchange: self.ignore_exchange,
             allow_adjust_parallelism: self.allow_adjust_parallelism,
+            skew: self.skew.clone(),
         })
     }
 }
@@ -135,6 +146,7 @@ impl PhysicalPlanBuilder {
             keys,
             allow_adjust_parallelism,
             ignore_exchange: false,
+            skew: None,
             meta: PhysicalPlanMeta::new("Exchange"),
         }))
     }
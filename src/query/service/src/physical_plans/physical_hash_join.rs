@@ -15,11 +15,15 @@
 use std::any::Any;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::cast_scalar;
 use databend_common_expression::type_check::check_cast;
+use databend_common_expression::type_check::check_function;
 use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ConstantFolder;
@@ -27,15 +31,21 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipe;
 use databend_common_pipeline_core::PipeItem;
+use databend_common_sql::executor::physical_plans::FragmentKind;
 use databend_common_sql::optimizer::ir::SExpr;
 use databend_common_sql::plans::Join;
+use databend_common_sql::plans::JoinHint;
+use databend_common_sql::plans::JoinSkew;
 use databend_common_sql::plans::JoinType;
 use databend_common_sql::ColumnEntry;
 use databend_common_sql::ColumnSet;
@@ -55,6 +65,7 @@ use crate::physical_plans::physical_plan::PhysicalPlan;
 use crate::physical_plans::physical_plan::PhysicalPlanMeta;
 use crate::physical_plans::Exchange;
 use crate::physical_plans::PhysicalPlanBuilder;
+use crate::physical_plans::ShuffleSkew;
 use crate::pipelines::processors::transforms::HashJoinFactory;
 use crate::pipelines::processors::transforms::HashJoinProbeState;
 use crate::pipelines::processors::transforms::RuntimeFiltersDesc;
@@ -130,6 +141,84 @@ pub struct HashJoin {
 
     pub runtime_filter: PhysicalRuntimeFilters,
     pub broadcast_id: Option<u32>,
+
+    // Only used for explain
+    pub hint: JoinHint,
+    // The runtime statistics and decision of adaptive query execution, only used for explain.
+    pub adaptive: Option<AdaptiveJoinInfo>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub enum AdaptiveJoinDecision {
+    KeepShuffle,
+    KeepBroadcast,
+    ShuffleToBroadcast,
+    BroadcastToShuffle,
+    // The hot keys of the skewed side are split out of the hash shuffles of both sides.
+    SplitSkewedPartition,
+    CoalescePartitions,
+    ExceedMaterializeLimit,
+}
+
+impl Display for AdaptiveJoinDecision {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdaptiveJoinDecision::KeepShuffle => write!(f, "keep shuffle"),
+            AdaptiveJoinDecision::KeepBroadcast => write!(f, "keep broadcast"),
+            AdaptiveJoinDecision::ShuffleToBroadcast => write!(f, "shuffle -> broadcast"),
+            AdaptiveJoinDecision::BroadcastToShuffle => write!(f, "broadcast -> shuffle"),
+            AdaptiveJoinDecision::SplitSkewedPartition => write!(f, "split skewed partition"),
+            AdaptiveJoinDecision::CoalescePartitions => write!(f, "coalesce partitions"),
+            AdaptiveJoinDecision::ExceedMaterializeLimit => {
+                write!(f, "keep plan, build side exceeds materialize limit")
+            }
+        }
+    }
+}
+
+/// The actual statistics of the materialized build side stage and the re-planning decision.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AdaptiveJoinInfo {
+    pub decision: AdaptiveJoinDecision,
+    pub build_rows: usize,
+    pub build_bytes: usize,
+    // The number of build rows sent to each node by the hash shuffle.
+    pub partition_rows: Vec<usize>,
+    // The number of probe rows sent to each node, only if the probe side is measured.
+    pub probe_partition_rows: Vec<usize>,
+}
+
+impl Display for AdaptiveJoinInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let AdaptiveJoinDecision::ExceedMaterializeLimit = self.decision {
+            // The stage isn't materialized, the bytes are read by its scans.
+            return write!(
+                f,
+                "{}, build read bytes: {}",
+                self.decision, self.build_bytes
+            );
+        }
+
+        write!(
+            f,
+            "{}, build rows: {}, build bytes: {}",
+            self.decision, self.build_rows, self.build_bytes
+        )?;
+        for (name, partition_rows) in [
+            ("partition rows", &self.partition_rows),
+            ("probe partition rows", &self.probe_partition_rows),
+        ] {
+            if !partition_rows.is_empty() {
+                let partition_rows = partition_rows
+                    .iter()
+                    .map(|rows| rows.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, ", {name}: [{partition_rows}]")?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[typetag::serde]
@@ -251,6 +340,8 @@ impl IPhysicalPlan for HashJoin {
             build_side_cache_info: self.build_side_cache_info.clone(),
             runtime_filter: self.runtime_filter.clone(),
             broadcast_id: self.broadcast_id,
+            hint: self.hint.clone(),
+            adaptive: self.adaptive.clone(),
         })
     }
 
@@ -604,7 +695,7 @@ impl PhysicalPlanBuilder {
     /// # Arguments
     /// * `probe_side` - The probe side physical plan
     /// * `build_side` - The build side physical plan
-    fn unify_keys(
+    pub(crate) fn unify_keys(
         &self,
         probe_side: &mut PhysicalPlan,
         build_side: &mut PhysicalPlan,
@@ -658,6 +749,69 @@ impl PhysicalPlanBuilder {
         Ok(())
     }
 
+    /// Splits the hot keys of a skewed join out of the hash shuffles of both sides
+    ///
+    /// The hot rows of the skewed side are spread across the nodes, and the hot rows
+    /// of the other side are broadcast, so every pair of matching rows meets on exactly one node.
+    ///
+    /// # Arguments
+    /// * `join` - Join operation
+    /// * `probe_side` - The probe side physical plan
+    /// * `build_side` - The build side physical plan
+    fn apply_join_skew(
+        &self,
+        join: &Join,
+        probe_side: &mut PhysicalPlan,
+        build_side: &mut PhysicalPlan,
+    ) -> Result<()> {
+        let Some(skew) = &join.skew else {
+            return Ok(());
+        };
+        if join.need_hold_hash_table || join.equi_conditions.len() != 1 {
+            return Ok(());
+        }
+
+        let probe_is_skewed = join.equi_conditions[0]
+            .left
+            .used_columns()
+            .contains(&skew.column);
+        if !JoinSkew::can_spread(&join.join_type, if probe_is_skewed { 0 } else { 1 }) {
+            return Ok(());
+        }
+
+        let (Some(probe_exchange), Some(build_exchange)) = (
+            Exchange::from_mut_physical_plan(probe_side),
+            Exchange::from_mut_physical_plan(build_side),
+        ) else {
+            return Ok(());
+        };
+        if probe_exchange.kind != FragmentKind::Normal
+            || build_exchange.kind != FragmentKind::Normal
+            || probe_exchange.keys.len() != 1
+            || build_exchange.keys.len() != 1
+        {
+            return Ok(());
+        }
+
+        // The exchange keys are unified, so both sides select the same hot keys.
+        let (Some(probe_predicate), Some(build_predicate)) = (
+            hot_key_predicate(&self.func_ctx, &probe_exchange.keys[0], &skew.hot_keys)?,
+            hot_key_predicate(&self.func_ctx, &build_exchange.keys[0], &skew.hot_keys)?,
+        ) else {
+            return Ok(());
+        };
+
+        probe_exchange.skew = Some(ShuffleSkew {
+            hot_predicate: probe_predicate,
+            broadcast: !probe_is_skewed,
+        });
+        build_exchange.skew = Some(ShuffleSkew {
+            hot_predicate: build_predicate,
+            broadcast: probe_is_skewed,
+        });
+        Ok(())
+    }
+
     /// Prepares runtime filter expression for join conditions
     ///
     /// # Arguments
@@ -1226,6 +1380,8 @@ impl PhysicalPlanBuilder {
             build_side_cache_info,
             runtime_filter,
             broadcast_id,
+            hint: join.hint.clone(),
+            adaptive: None,
         }))
     }
 
@@ -1250,6 +1406,7 @@ impl PhysicalPlanBuilder {
 
         // Step 3: unify exchange keys
         self.unify_keys(&mut probe_side, &mut build_side)?;
+        self.apply_join_skew(join, &mut probe_side, &mut build_side)?;
 
         // Step 4: Prepare schemas for both sides
         let build_schema = self.prepare_build_schema(&join.join_type, &build_side)?;
@@ -1349,3 +1506,41 @@ impl PhysicalPlanBuilder {
         .await
     }
 }
+
+/// Builds a non-null boolean expression matching the rows whose exchange key is a hot key
+pub fn hot_key_predicate(
+    func_ctx: &FunctionContext,
+    key: &RemoteExpr,
+    hot_keys: &[Scalar],
+) -> Result<Option<RemoteExpr>> {
+    let key = key.as_expr(&BUILTIN_FUNCTIONS);
+    let key_type = key.data_type().remove_nullable();
+
+    let mut predicate: Option<Expr> = None;
+    for hot_key in hot_keys {
+        let Ok(hot_key) = cast_scalar(None, hot_key.clone(), key_type.clone(), &BUILTIN_FUNCTIONS)
+        else {
+            continue;
+        };
+        let eq = check_function(
+            None,
+            "eq",
+            &[],
+            &[key.clone(), Expr::constant(hot_key, Some(key_type.clone()))],
+            &BUILTIN_FUNCTIONS,
+        )?;
+        predicate = Some(match predicate {
+            None => eq,
+            Some(predicate) => {
+                check_function(None, "or", &[], &[predicate, eq], &BUILTIN_FUNCTIONS)?
+            }
+        });
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(None);
+    };
+    let predicate = check_function(None, "is_true", &[], &[predicate], &BUILTIN_FUNCTIONS)?;
+    let (predicate, _) = ConstantFolder::fold(&predicate, func_ctx, &BUILTIN_FUNCTIONS);
+    Ok(Some(predicate.as_remote_expr()))
+}
@@ -12,30 +12,60 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::Expr as AstExpr;
+use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::ROW_ID_COLUMN_ID;
+use databend_common_expression::ROW_ID_COL_NAME;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::binder::JoinPredicate;
+use databend_common_sql::binder::INTERNAL_COLUMN_FACTORY;
 use databend_common_sql::optimizer::ir::RelExpr;
 use databend_common_sql::optimizer::ir::RelationalProperty;
 use databend_common_sql::optimizer::ir::SExpr;
+use databend_common_sql::optimizer::optimizers::DefaultCostModel;
+use databend_common_sql::optimizer::optimizers::KeyOrder;
 use databend_common_sql::plans::Join;
 use databend_common_sql::plans::JoinType;
+use databend_common_sql::plans::RelOperator;
+use databend_common_sql::BaseTableColumn;
+use databend_common_sql::ColumnEntry;
 use databend_common_sql::ColumnSet;
+use databend_common_sql::IndexType;
 use databend_common_sql::ScalarExpr;
+use databend_storages_common_table_meta::table::ClusterType;
 
 use crate::physical_plans::explain::PlanStatsInfo;
 use crate::physical_plans::physical_plan::PhysicalPlan;
+use crate::physical_plans::physical_plan::PhysicalPlanMeta;
 use crate::physical_plans::PhysicalPlanBuilder;
+use crate::physical_plans::PhysicalPlanCast;
+use crate::physical_plans::RowFetch;
+use crate::physical_plans::TableScan;
 
 pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
     AsofJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    // The first arg is the spatial condition, the second arg is other conditions
+    SpatialJoin(ScalarExpr, Vec<ScalarExpr>),
+    // Whether the left and right inputs are already sorted on the join keys
+    SortMergeJoin(bool, bool),
 }
 
-// Choose physical join type by join conditions
-pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
+// Choose physical join type by join conditions, `sort_merge_join` is set if sort-merge join
+// is cheaper than hash join, with whether its inputs are already sorted on the join keys.
+pub fn physical_join(
+    join: &Join,
+    s_expr: &SExpr,
+    enable_spatial_join: bool,
+    sort_merge_join: Option<(bool, bool)>,
+) -> Result<PhysicalJoinType> {
     let check_asof = matches!(
         join.join_type,
         JoinType::Asof | JoinType::LeftAsof | JoinType::RightAsof
@@ -47,6 +77,9 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         ));
     }
     if !join.equi_conditions.is_empty() && !check_asof {
+        if let Some((left_sorted, right_sorted)) = sort_merge_join {
+            return Ok(PhysicalJoinType::SortMergeJoin(left_sorted, right_sorted));
+        }
         // Contain equi condition, use hash join
         return Ok(PhysicalJoinType::Hash);
     }
@@ -56,6 +89,12 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         return Ok(PhysicalJoinType::Hash);
     }
 
+    if join.hint.hash_join && !check_asof {
+        // Hash join is required by the `HASH_JOIN` hint, the non-equi conditions are
+        // evaluated as other conditions of the hash join.
+        return Ok(PhysicalJoinType::Hash);
+    }
+
     let left_rel_expr = RelExpr::with_s_expr(s_expr.child(0)?);
     let right_rel_expr = RelExpr::with_s_expr(s_expr.child(1)?);
     let right_stat_info = right_rel_expr.derive_cardinality()?;
@@ -69,6 +108,20 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
 
     let left_prop = left_rel_expr.derive_relational_prop()?;
     let right_prop = right_rel_expr.derive_relational_prop()?;
+    if let Some(position) = join.spatial_join_condition(
+        enable_spatial_join,
+        &left_prop,
+        &right_prop,
+        &right_stat_info,
+    ) {
+        let mut other_conditions = join.non_equi_conditions.clone();
+        let spatial_condition = other_conditions.remove(position);
+        return Ok(PhysicalJoinType::SpatialJoin(
+            spatial_condition,
+            other_conditions,
+        ));
+    }
+
     let mut range_conditions = vec![];
     let mut other_conditions = vec![];
     for condition in join.non_equi_conditions.iter() {
@@ -96,6 +149,157 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
     Ok(PhysicalJoinType::Hash)
 }
 
+impl PhysicalPlanBuilder {
+    // Check whether the equi join can be executed as sort-merge join, and whether it is cheaper
+    // than hash join. Returns whether the left and right inputs are already sorted on the keys.
+    fn choose_sort_merge_join(&self, join: &Join, s_expr: &SExpr) -> Result<Option<(bool, bool)>> {
+        if join.equi_conditions.is_empty()
+            || join.hint.hash_join
+            || join.hint.distribution.is_some()
+            || join.skew.is_some()
+            || join.marker_index.is_some()
+            || join.single_to_inner.is_some()
+            || join.from_correlated_subquery
+            || join.need_hold_hash_table
+            || join.build_side_cache_info.is_some()
+            || !matches!(
+                join.join_type,
+                JoinType::Inner
+                    | JoinType::Left
+                    | JoinType::Right
+                    | JoinType::Full
+                    | JoinType::LeftSemi
+                    | JoinType::LeftAnti
+                    | JoinType::RightSemi
+                    | JoinType::RightAnti
+            )
+        {
+            return Ok(None);
+        }
+
+        let cast_rules = &BUILTIN_FUNCTIONS.get_auto_cast_rules("eq");
+        for condition in join.equi_conditions.iter() {
+            if condition.is_null_equal {
+                return Ok(None);
+            }
+            let common_ty = common_super_type(
+                condition.left.data_type()?,
+                condition.right.data_type()?,
+                cast_rules,
+            );
+            if !common_ty.is_some_and(|ty| is_sort_merge_join_key_type(&ty)) {
+                return Ok(None);
+            }
+        }
+
+        let left_child = s_expr.child(0)?;
+        let right_child = s_expr.child(1)?;
+        let left_keys = join
+            .equi_conditions
+            .iter()
+            .map(|c| &c.left)
+            .collect::<Vec<_>>();
+        let right_keys = join
+            .equi_conditions
+            .iter()
+            .map(|c| &c.right)
+            .collect::<Vec<_>>();
+        let left_order = self.key_order(left_child, &left_keys)?;
+        let right_order = self.key_order(right_child, &right_keys)?;
+
+        let left_card = RelExpr::with_s_expr(left_child)
+            .derive_cardinality()?
+            .cardinality;
+        let right_card = RelExpr::with_s_expr(right_child)
+            .derive_cardinality()?
+            .cardinality;
+        let cost_model = DefaultCostModel::new(self.ctx.clone())?;
+        Ok(cost_model
+            .prefer_merge_join(left_card, right_card, left_order, right_order)
+            .then_some((
+                left_order == KeyOrder::Sorted,
+                right_order == KeyOrder::Sorted,
+            )))
+    }
+
+    // How the input is ordered on the keys in ascending order, looking through the operators
+    // that keep the order of their input.
+    fn key_order(&self, s_expr: &SExpr, keys: &[&ScalarExpr]) -> Result<KeyOrder> {
+        match s_expr.plan() {
+            RelOperator::Filter(_) | RelOperator::EvalScalar(_) => {
+                self.key_order(s_expr.child(0)?, keys)
+            }
+            RelOperator::Sort(sort) if sort.window_partition.is_none() => {
+                let mut items = sort.items.iter();
+                let sorted = keys.iter().all(|key| match (key, items.next()) {
+                    (ScalarExpr::BoundColumnRef(column), Some(item)) => {
+                        item.asc && item.index == column.column.index
+                    }
+                    _ => false,
+                });
+                Ok(match sorted {
+                    true => KeyOrder::Sorted,
+                    false => KeyOrder::Unsorted,
+                })
+            }
+            RelOperator::Scan(scan) if self.is_clustered_on_keys(scan.table_index, keys) => {
+                Ok(KeyOrder::Clustered)
+            }
+            _ => Ok(KeyOrder::Unsorted),
+        }
+    }
+
+    // Check whether the keys are a prefix of the linear cluster key of the table.
+    fn is_clustered_on_keys(&self, table_index: IndexType, keys: &[&ScalarExpr]) -> bool {
+        let metadata = self.metadata.read();
+        let table = metadata.table(table_index).table();
+        if table.cluster_type() != Some(ClusterType::Linear) {
+            return false;
+        }
+        let Some(cluster_keys) = table.resolve_cluster_keys(self.ctx.clone()) else {
+            return false;
+        };
+
+        let mut cluster_keys = cluster_keys.iter();
+        keys.iter().all(|key| {
+            let ScalarExpr::BoundColumnRef(column) = key else {
+                return false;
+            };
+            let ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index: column_table_index,
+                column_name,
+                path_indices: None,
+                ..
+            }) = metadata.column(column.column.index)
+            else {
+                return false;
+            };
+            match cluster_keys.next() {
+                Some(AstExpr::ColumnRef { column, .. }) => {
+                    *column_table_index == table_index
+                        && column.column.name().eq_ignore_ascii_case(column_name)
+                }
+                _ => false,
+            }
+        })
+    }
+}
+
+// Float keys are excluded, the equality of NaN and -0.0 differs between the
+// comparison functions and the encoded sort keys.
+fn is_sort_merge_join_key_type(data_type: &DataType) -> bool {
+    match data_type.remove_nullable() {
+        DataType::Number(num) => !num.is_float(),
+        DataType::Boolean
+        | DataType::Decimal(_)
+        | DataType::Date
+        | DataType::Timestamp
+        | DataType::String
+        | DataType::Binary => true,
+        _ => false,
+    }
+}
+
 fn check_condition(
     expr: &ScalarExpr,
     left_prop: &RelationalProperty,
@@ -135,10 +339,20 @@ impl PhysicalPlanBuilder {
         &mut self,
         s_expr: &SExpr,
         join: &databend_common_sql::plans::Join,
-        required: ColumnSet,
+        mut required: ColumnSet,
         stat_info: PlanStatsInfo,
     ) -> Result<PhysicalPlan> {
         // 1. Prune unused Columns.
+        // Columns of a large scan that are not used by the join are fetched after the join by row id.
+        let lazy_read = self.join_lazy_read(s_expr, join, &required)?;
+        if let Some(lazy_read) = &lazy_read {
+            required = required
+                .difference(&lazy_read.columns)
+                .cloned()
+                .collect::<ColumnSet>();
+            required.insert(lazy_read.row_id_index);
+        }
+
         let mut others_required = join
             .non_equi_conditions
             .iter()
@@ -175,8 +389,14 @@ impl PhysicalPlanBuilder {
 
         // 2. Build physical plan.
         // Choose physical join type by join conditions
-        let physical_join = physical_join(join, s_expr)?;
-        match physical_join {
+        let settings = self.ctx.get_settings();
+        let enable_spatial_join = settings.get_enable_spatial_join()?;
+        let sort_merge_join = match settings.get_enable_sort_merge_join()? {
+            true => self.choose_sort_merge_join(join, s_expr)?,
+            false => None,
+        };
+        let physical_join = physical_join(join, s_expr, enable_spatial_join, sort_merge_join)?;
+        let plan = match physical_join {
             PhysicalJoinType::Hash => {
                 self.build_hash_join(
                     join,
@@ -185,7 +405,7 @@ impl PhysicalPlanBuilder {
                     others_required,
                     left_required,
                     right_required,
-                    stat_info,
+                    stat_info.clone(),
                 )
                 .await
             }
@@ -197,6 +417,230 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(join, s_expr, left_required, right_required, range, other)
                     .await
             }
+            PhysicalJoinType::SpatialJoin(spatial, other) => {
+                self.build_spatial_join(join, s_expr, left_required, right_required, spatial, other)
+                    .await
+            }
+            PhysicalJoinType::SortMergeJoin(left_sorted, right_sorted) => {
+                self.build_sort_merge_join(
+                    join,
+                    s_expr,
+                    left_required,
+                    right_required,
+                    left_sorted,
+                    right_sorted,
+                )
+                .await
+            }
+        }?;
+
+        match lazy_read {
+            Some(lazy_read) => self.build_join_row_fetch(plan, lazy_read, stat_info),
+            None => Ok(plan),
+        }
+    }
+
+    // Choose the columns of the larger join input's table scan that can be read after the join,
+    // only if the join is estimated to keep a small part of the scan rows.
+    fn join_lazy_read(
+        &self,
+        s_expr: &SExpr,
+        join: &Join,
+        required: &ColumnSet,
+    ) -> Result<Option<JoinLazyRead>> {
+        let threshold = self.ctx.get_settings().get_join_lazy_read_threshold()?;
+        if threshold == 0
+            || join.need_hold_hash_table
+            || join.is_lateral
+            || join.single_to_inner.is_some()
+            || join.build_side_cache_info.is_some()
+        {
+            return Ok(None);
+        }
+
+        // The row id of a NULL-extended row can't be fetched, so only the preserved side is allowed.
+        let (left, right) = match join.join_type {
+            JoinType::Inner => (true, true),
+            JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti => (true, false),
+            JoinType::Right | JoinType::RightSemi | JoinType::RightAnti => (false, true),
+            _ => return Ok(None),
+        };
+        let left_card = RelExpr::with_s_expr(s_expr.child(0)?)
+            .derive_cardinality()?
+            .cardinality;
+        let right_card = RelExpr::with_s_expr(s_expr.child(1)?)
+            .derive_cardinality()?
+            .cardinality;
+        let (child_index, child_card) = match (left, right) {
+            (true, true) if right_card > left_card => (1, right_card),
+            (true, _) => (0, left_card),
+            _ => (1, right_card),
+        };
+        let join_card = RelExpr::with_s_expr(s_expr)
+            .derive_cardinality()?
+            .cardinality;
+        if child_card < 1.0 || join_card > child_card * threshold as f64 / 100.0 {
+            return Ok(None);
+        }
+
+        let Some(table_index) = lazy_read_table(s_expr.child(child_index)?)? else {
+            return Ok(None);
+        };
+        let mut metadata = self.metadata.write();
+        let table = metadata.table(table_index).table();
+        // The row id of the table may be used by mutation or read by another join.
+        if !table.supported_lazy_materialize()
+            || !table.supported_internal_column(ROW_ID_COLUMN_ID)
+            || metadata.row_id_index_by_table_index(table_index).is_some()
+        {
+            return Ok(None);
+        }
+
+        let mut join_columns = ColumnSet::new();
+        for condition in &join.equi_conditions {
+            join_columns.extend(condition.left.used_columns());
+            join_columns.extend(condition.right.used_columns());
+        }
+        for condition in &join.non_equi_conditions {
+            join_columns.extend(condition.used_columns());
+        }
+        let columns = required
+            .iter()
+            .filter(|index| !join_columns.contains(index))
+            .filter(|index| {
+                matches!(
+                    metadata.column(**index),
+                    ColumnEntry::BaseTableColumn(BaseTableColumn {
+                        table_index: column_table_index,
+                        virtual_expr: None,
+                        ..
+                    }) if *column_table_index == table_index
+                )
+            })
+            .cloned()
+            .collect::<ColumnSet>();
+        if columns.is_empty() {
+            return Ok(None);
+        }
+
+        let internal_column = INTERNAL_COLUMN_FACTORY
+            .get_internal_column(ROW_ID_COL_NAME)
+            .unwrap();
+        let row_id_index = metadata.add_internal_column(table_index, internal_column);
+        metadata.set_table_row_id_index(table_index, row_id_index);
+        Ok(Some(JoinLazyRead {
+            table_index,
+            row_id_index,
+            columns,
+        }))
+    }
+
+    fn build_join_row_fetch(
+        &self,
+        plan: PhysicalPlan,
+        lazy_read: JoinLazyRead,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let input_schema = plan.output_schema()?;
+        let row_id_col_offset = input_schema.index_of(&lazy_read.row_id_index.to_string())?;
+        let source = find_table_source(&plan, lazy_read.table_index)
+            .ok_or_else(|| ErrorCode::Internal("Table scan of join lazy read is not found"))?
+            .clone();
+
+        // Columns still required by the operators below the join are already in the input.
+        let lazy_columns = lazy_read
+            .columns
+            .iter()
+            .filter(|index| !input_schema.has_field(&index.to_string()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if lazy_columns.is_empty() {
+            return Ok(plan);
+        }
+
+        let metadata = self.metadata.read();
+        let mut has_inner_column = false;
+        let fetched_fields = lazy_columns
+            .iter()
+            .map(|index| {
+                let col = metadata.column(*index);
+                if let ColumnEntry::BaseTableColumn(c) = col {
+                    if c.path_indices.is_some() {
+                        has_inner_column = true;
+                    }
+                }
+                DataField::new(&index.to_string(), col.data_type())
+            })
+            .collect();
+        let table_schema = source.source_info.schema();
+        let cols_to_fetch = Self::build_projection(
+            &metadata,
+            &table_schema,
+            lazy_columns.iter(),
+            has_inner_column,
+            true,
+            true,
+        );
+
+        Ok(PhysicalPlan::new(RowFetch {
+            meta: PhysicalPlanMeta::new("RowFetch"),
+            input: plan,
+            source: Box::new(source),
+            row_id_col_offset,
+            cols_to_fetch,
+            fetched_fields,
+            need_wrap_nullable: false,
+            stat_info: Some(stat_info),
+        }))
+    }
+}
+
+struct JoinLazyRead {
+    table_index: IndexType,
+    row_id_index: IndexType,
+    // Columns of the table that are fetched after the join.
+    columns: ColumnSet,
+}
+
+// Find the table whose rows are passed through to the output of the join input,
+// the row ids of the table are kept unchanged by filters, projections and inner joins.
+#[recursive::recursive]
+fn lazy_read_table(s_expr: &SExpr) -> Result<Option<IndexType>> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) => {
+            if scan.is_lazy_table
+                || scan.update_stream_columns
+                || scan.change_type.is_some()
+                || scan.agg_index.is_some()
+            {
+                return Ok(None);
+            }
+            Ok(Some(scan.table_index))
+        }
+        RelOperator::Filter(_) | RelOperator::EvalScalar(_) => lazy_read_table(s_expr.child(0)?),
+        RelOperator::Join(join) if join.join_type == JoinType::Inner => {
+            let left_card = RelExpr::with_s_expr(s_expr.child(0)?)
+                .derive_cardinality()?
+                .cardinality;
+            let right_card = RelExpr::with_s_expr(s_expr.child(1)?)
+                .derive_cardinality()?
+                .cardinality;
+            match right_card > left_card {
+                true => lazy_read_table(s_expr.child(1)?),
+                false => lazy_read_table(s_expr.child(0)?),
+            }
+        }
+        _ => Ok(None),
+    }
+}
+
+#[recursive::recursive]
+fn find_table_source(plan: &PhysicalPlan, table_index: IndexType) -> Option<&DataSourcePlan> {
+    if let Some(scan) = TableScan::from_physical_plan(plan) {
+        if scan.table_index == Some(table_index) {
+            return Some(&scan.source);
         }
     }
+    plan.children()
+        .find_map(|child| find_table_source(child, table_index))
 }
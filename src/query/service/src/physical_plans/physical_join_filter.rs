@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
@@ -154,6 +155,17 @@ impl JoinRuntimeFilter {
 
         let mut filters = Vec::new();
 
+        // Tables hinted by `NO_RUNTIME_FILTER` don't receive runtime filters.
+        let no_runtime_filter_tables = {
+            let metadata = metadata.read();
+            metadata
+                .optimizer_hints()
+                .no_runtime_filter
+                .iter()
+                .flat_map(|table| metadata.hinted_table_indexes(table))
+                .collect::<HashSet<_>>()
+        };
+
         let build_side_data_distribution = s_expr.build_side_child().get_data_distribution()?;
 
         // Process each probe key that has runtime filter information
@@ -167,6 +179,10 @@ impl JoinRuntimeFilter {
                 continue;
             }
 
+            if no_runtime_filter_tables.contains(&table_index) {
+                continue;
+            }
+
             let data_type = build_key
                 .as_expr(&BUILTIN_FUNCTIONS)
                 .data_type()
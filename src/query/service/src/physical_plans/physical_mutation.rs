@@ -401,6 +401,7 @@ impl PhysicalPlanBuilder {
                     kind: FragmentKind::Merge,
                     keys: vec![],
                     allow_adjust_parallelism: true,
+                    skew: None,
                     ignore_exchange: false,
                     meta: PhysicalPlanMeta::new("Exchange"),
                 });
@@ -631,6 +632,7 @@ impl PhysicalPlanBuilder {
                 kind: FragmentKind::Merge,
                 keys: vec![],
                 allow_adjust_parallelism: true,
+                skew: None,
                 ignore_exchange: false,
                 meta: PhysicalPlanMeta::new("Exchange"),
             });
@@ -741,6 +743,7 @@ pub fn build_block_id_shuffle_exchange(
         meta: PhysicalPlanMeta::new("Exchange"),
         keys: vec![block_id_shuffle_key.as_remote_expr()],
         allow_adjust_parallelism: true,
+        skew: None,
         ignore_exchange: false,
     }))
 }
@@ -520,6 +520,7 @@ impl PhysicalPlanBuilder {
             keys: vec![],
             ignore_exchange: false,
             allow_adjust_parallelism: false,
+            skew: None,
             meta: PhysicalPlanMeta::new("Exchange"),
         });
 
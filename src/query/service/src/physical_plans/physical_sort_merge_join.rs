@@ -0,0 +1,385 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Expr;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::SortColumnDescription;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
+use databend_common_pipeline_transforms::blocks::CompoundBlockOperator;
+use databend_common_sql::evaluator::BlockOperator;
+use databend_common_sql::optimizer::ir::SExpr;
+use databend_common_sql::plans::Join;
+use databend_common_sql::plans::JoinType;
+use databend_common_sql::ColumnSet;
+use databend_common_sql::ScalarExpr;
+use databend_common_sql::TypeCheck;
+
+use crate::physical_plans::explain::PlanStatsInfo;
+use crate::physical_plans::format::PhysicalFormat;
+use crate::physical_plans::format::SortMergeJoinFormatter;
+use crate::physical_plans::physical_plan::IPhysicalPlan;
+use crate::physical_plans::physical_plan::PhysicalPlan;
+use crate::physical_plans::physical_plan::PhysicalPlanMeta;
+use crate::physical_plans::physical_sort::SortStep;
+use crate::physical_plans::EvalScalar;
+use crate::physical_plans::Filter;
+use crate::physical_plans::PhysicalPlanBuilder;
+use crate::physical_plans::PhysicalPlanCast;
+use crate::physical_plans::Sort;
+use crate::pipelines::builders::SortPipelineBuilder;
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoiner;
+use crate::pipelines::processors::transforms::sort_merge_join::TransformSortMergeJoin;
+use crate::pipelines::processors::transforms::sort_merge_join::TransformSortMergeJoinPartition;
+use crate::pipelines::PipelineBuilder;
+
+/// Sort-merge join sorts both inputs on the join keys with the sort pipeline, which
+/// spills to storage under memory pressure, and then merges the two sorted streams.
+/// An input that is already sorted on the join keys is merged without sorting again.
+/// The sorted inputs are cut into key ranges, which are merged in parallel.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SortMergeJoin {
+    pub meta: PhysicalPlanMeta,
+    pub left: PhysicalPlan,
+    pub right: PhysicalPlan,
+    // The equi condition keys, both sides are cast to the common type
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    // Whether the input is already sorted on the join keys
+    pub left_sorted: bool,
+    pub right_sorted: bool,
+    // The non-equi conditions, evaluated on the merged schema of both sides
+    pub other_conditions: Vec<RemoteExpr>,
+    pub join_type: JoinType,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+#[typetag::serde]
+impl IPhysicalPlan for SortMergeJoin {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn get_meta(&self) -> &PhysicalPlanMeta {
+        &self.meta
+    }
+
+    fn get_meta_mut(&mut self) -> &mut PhysicalPlanMeta {
+        &mut self.meta
+    }
+
+    #[recursive::recursive]
+    fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = &'a PhysicalPlan> + 'a> {
+        Box::new(std::iter::once(&self.left).chain(std::iter::once(&self.right)))
+    }
+
+    fn children_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut PhysicalPlan> + 'a> {
+        Box::new(std::iter::once(&mut self.left).chain(std::iter::once(&mut self.right)))
+    }
+
+    fn formatter(&self) -> Result<Box<dyn PhysicalFormat + '_>> {
+        Ok(SortMergeJoinFormatter::create(self))
+    }
+
+    fn get_desc(&self) -> Result<String> {
+        let mut conditions = self
+            .left_keys
+            .iter()
+            .zip(self.right_keys.iter())
+            .map(|(left, right)| {
+                format!(
+                    "{} = {}",
+                    left.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                    right.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        conditions.extend(
+            self.other_conditions
+                .iter()
+                .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display()),
+        );
+
+        Ok(conditions.join(" AND "))
+    }
+
+    fn derive(&self, mut children: Vec<PhysicalPlan>) -> PhysicalPlan {
+        assert_eq!(children.len(), 2);
+        let right_child = children.pop().unwrap();
+        let left_child = children.pop().unwrap();
+
+        PhysicalPlan::new(SortMergeJoin {
+            meta: self.meta.clone(),
+            left: left_child,
+            right: right_child,
+            left_keys: self.left_keys.clone(),
+            right_keys: self.right_keys.clone(),
+            left_sorted: self.left_sorted,
+            right_sorted: self.right_sorted,
+            other_conditions: self.other_conditions.clone(),
+            join_type: self.join_type.clone(),
+            output_schema: self.output_schema.clone(),
+            stat_info: self.stat_info.clone(),
+        })
+    }
+
+    fn build_pipeline2(&self, builder: &mut PipelineBuilder) -> Result<()> {
+        self.build_sorted_side(builder, &self.left, &self.left_keys, self.left_sorted)?;
+        let left_sinks = builder.main_pipeline.take_sinks();
+
+        self.build_sorted_side(builder, &self.right, &self.right_keys, self.right_sorted)?;
+        let right_sinks = builder.main_pipeline.take_sinks();
+
+        builder.main_pipeline.extend_sinks(left_sinks);
+        builder.main_pipeline.extend_sinks(right_sinks);
+
+        let max_threads = builder.settings.get_max_threads()? as usize;
+        let left_port = InputPort::create();
+        let right_port = InputPort::create();
+        let outputs = (0..max_threads)
+            .map(|_| OutputPort::create())
+            .collect::<Vec<_>>();
+        let partitioner = TransformSortMergeJoinPartition::create(
+            left_port.clone(),
+            right_port.clone(),
+            outputs.clone(),
+            self,
+        )?;
+        builder
+            .main_pipeline
+            .add_pipe(Pipe::create(2, max_threads, vec![PipeItem::create(
+                partitioner,
+                vec![left_port, right_port],
+                outputs,
+            )]));
+
+        builder.main_pipeline.add_transform(|input, output| {
+            let joiner = SortMergeJoiner::create(builder.ctx.clone(), self)?;
+            Ok(TransformSortMergeJoin::create(input, output, joiner))
+        })
+    }
+}
+
+impl SortMergeJoin {
+    // Build the pipeline of one side, which outputs a single stream sorted on the join keys.
+    // The key columns are appended after the columns of the side.
+    fn build_sorted_side(
+        &self,
+        builder: &mut PipelineBuilder,
+        side: &PhysicalPlan,
+        keys: &[RemoteExpr],
+        sorted: bool,
+    ) -> Result<()> {
+        side.build_pipeline(builder)?;
+
+        let input_schema = side.output_schema()?;
+        let num_input_columns = input_schema.num_fields();
+        let exprs = keys
+            .iter()
+            .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+            .collect::<Vec<_>>();
+        let mut fields = input_schema.fields().clone();
+        fields.extend(
+            exprs.iter().enumerate().map(|(i, expr)| {
+                DataField::new(&format!("_merge_key_{i}"), expr.data_type().clone())
+            }),
+        );
+        let sort_schema = DataSchemaRefExt::create(fields);
+
+        builder.main_pipeline.add_transformer(|| {
+            CompoundBlockOperator::new(
+                vec![BlockOperator::Map {
+                    exprs: exprs.clone(),
+                    projections: None,
+                }],
+                builder.func_ctx.clone(),
+                num_input_columns,
+            )
+        });
+
+        // The sorted input is only trusted if it's still a single ordered stream.
+        if !sorted || builder.main_pipeline.output_len() != 1 {
+            let sort_desc = (0..keys.len())
+                .map(|i| SortColumnDescription {
+                    offset: num_input_columns + i,
+                    asc: true,
+                    nulls_first: false,
+                })
+                .collect::<Vec<_>>();
+
+            let max_threads = builder.settings.get_max_threads()? as usize;
+            if builder.main_pipeline.output_len() == 1 || max_threads == 1 {
+                builder.main_pipeline.try_resize(max_threads)?;
+            }
+            SortPipelineBuilder::create(
+                builder.ctx.clone(),
+                sort_schema,
+                sort_desc.into(),
+                None,
+                builder.settings.get_enable_fixed_rows_sort()?,
+            )?
+            .remove_order_col_at_last()
+            .build_full_sort_pipeline(&mut builder.main_pipeline)?;
+        }
+
+        if builder.main_pipeline.output_len() != 1 {
+            return Err(ErrorCode::Internal(
+                "The sorted input of sort-merge join must be a single stream",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub async fn build_sort_merge_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        left_sorted: bool,
+        right_sorted: bool,
+    ) -> Result<PhysicalPlan> {
+        let (mut left_side, mut right_side) = self
+            .build_join_sides(s_expr, left_required, right_required)
+            .await?;
+        self.unify_keys(&mut left_side, &mut right_side)?;
+
+        let left_input_schema = left_side.output_schema()?;
+        let right_input_schema = right_side.output_schema()?;
+        let cast_rules = &BUILTIN_FUNCTIONS.get_auto_cast_rules("eq");
+        let mut left_keys = Vec::with_capacity(join.equi_conditions.len());
+        let mut right_keys = Vec::with_capacity(join.equi_conditions.len());
+        for condition in join.equi_conditions.iter() {
+            let left_expr = resolve_key(&condition.left, &left_input_schema)?;
+            let right_expr = resolve_key(&condition.right, &right_input_schema)?;
+
+            let left_type = left_expr.data_type();
+            let right_type = right_expr.data_type();
+            let common_ty = common_super_type(left_type.clone(), right_type.clone(), cast_rules)
+                .ok_or_else(|| {
+                    ErrorCode::IllegalDataType(format!(
+                        "Cannot find common type for {:?} and {:?}",
+                        left_type, right_type
+                    ))
+                })?;
+
+            let left_expr = check_cast(
+                left_expr.span(),
+                false,
+                left_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let right_expr = check_cast(
+                right_expr.span(),
+                false,
+                right_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+
+            let (left_expr, _) =
+                ConstantFolder::fold(&left_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let (right_expr, _) =
+                ConstantFolder::fold(&right_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            left_keys.push(left_expr);
+            right_keys.push(right_expr);
+        }
+
+        // A presorted input is only reused if the keys are its plain sort columns.
+        let left_sorted = left_sorted && is_sorted_input(&left_side, &left_keys);
+        let right_sorted = right_sorted && is_sorted_input(&right_side, &right_keys);
+
+        let left_schema = self.prepare_probe_schema(&join.join_type, &left_side)?;
+        let right_schema = self.prepare_build_schema(&join.join_type, &right_side)?;
+        let merged_schema = DataSchemaRefExt::create(
+            left_schema
+                .fields()
+                .iter()
+                .chain(right_schema.fields())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+        let output_schema = match join.join_type {
+            JoinType::LeftSemi | JoinType::LeftAnti => left_schema,
+            JoinType::RightSemi | JoinType::RightAnti => right_schema,
+            _ => merged_schema.clone(),
+        };
+
+        Ok(PhysicalPlan::new(SortMergeJoin {
+            meta: PhysicalPlanMeta::new("SortMergeJoin"),
+            left: left_side,
+            right: right_side,
+            left_keys: left_keys.iter().map(Expr::as_remote_expr).collect(),
+            right_keys: right_keys.iter().map(Expr::as_remote_expr).collect(),
+            left_sorted,
+            right_sorted,
+            other_conditions: join
+                .non_equi_conditions
+                .iter()
+                .map(|scalar| resolve_scalar(scalar, &merged_schema))
+                .collect::<Result<_>>()?,
+            join_type: join.join_type.clone(),
+            output_schema,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
+// The filters and scalar evaluations above the sort keep the order of its single output stream.
+fn is_sorted_input(side: &PhysicalPlan, keys: &[Expr]) -> bool {
+    if !keys.iter().all(|key| matches!(key, Expr::ColumnRef(_))) {
+        return false;
+    }
+    if let Some(filter) = Filter::from_physical_plan(side) {
+        return is_sorted_input(&filter.input, keys);
+    }
+    if let Some(eval_scalar) = EvalScalar::from_physical_plan(side) {
+        return is_sorted_input(&eval_scalar.input, keys);
+    }
+    Sort::from_physical_plan(side)
+        .is_some_and(|sort| matches!(sort.step, SortStep::Single | SortStep::Final))
+}
+
+fn resolve_key(scalar: &ScalarExpr, schema: &DataSchemaRef) -> Result<Expr> {
+    scalar
+        .type_check(schema.as_ref())?
+        .project_column_ref(|index| schema.index_of(&index.to_string()))
+}
+
+fn resolve_scalar(scalar: &ScalarExpr, schema: &DataSchemaRef) -> Result<RemoteExpr> {
+    Ok(resolve_key(scalar, schema)?.as_remote_expr())
+}
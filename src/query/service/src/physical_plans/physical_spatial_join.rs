@@ -0,0 +1,298 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::Sinker;
+use databend_common_sql::binder::JoinPredicate;
+use databend_common_sql::optimizer::ir::RelExpr;
+use databend_common_sql::optimizer::ir::SExpr;
+use databend_common_sql::plans::spatial_join_distance;
+use databend_common_sql::plans::Join;
+use databend_common_sql::plans::JoinType;
+use databend_common_sql::ColumnSet;
+use databend_common_sql::ScalarExpr;
+use databend_common_sql::TypeCheck;
+
+use crate::physical_plans::explain::PlanStatsInfo;
+use crate::physical_plans::format::PhysicalFormat;
+use crate::physical_plans::format::SpatialJoinFormatter;
+use crate::physical_plans::physical_plan::IPhysicalPlan;
+use crate::physical_plans::physical_plan::PhysicalPlan;
+use crate::physical_plans::physical_plan::PhysicalPlanMeta;
+use crate::physical_plans::PhysicalPlanBuilder;
+use crate::pipelines::processors::transforms::spatial_join::SpatialJoinState;
+use crate::pipelines::processors::transforms::spatial_join::TransformSpatialJoinBuild;
+use crate::pipelines::processors::transforms::spatial_join::TransformSpatialJoinProbe;
+use crate::pipelines::PipelineBuilder;
+
+/// Spatial join builds an R-tree with the bounding boxes of the build side geometries,
+/// and probes it with the bounding boxes of the probe side geometries.
+/// The candidate pairs are then filtered by the spatial condition and other conditions.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SpatialJoin {
+    pub meta: PhysicalPlanMeta,
+    pub probe: PhysicalPlan,
+    pub build: PhysicalPlan,
+    // The geometry arguments of the spatial condition
+    pub probe_key: RemoteExpr,
+    pub build_key: RemoteExpr,
+    // The distance of `st_dwithin`, used to expand the bounding boxes of probe side
+    pub distance: f64,
+    pub spatial_condition: RemoteExpr,
+    pub other_conditions: Vec<RemoteExpr>,
+    // Now only support inner join
+    pub join_type: JoinType,
+    pub output_schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+#[typetag::serde]
+impl IPhysicalPlan for SpatialJoin {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn get_meta(&self) -> &PhysicalPlanMeta {
+        &self.meta
+    }
+
+    fn get_meta_mut(&mut self) -> &mut PhysicalPlanMeta {
+        &mut self.meta
+    }
+
+    #[recursive::recursive]
+    fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.output_schema.clone())
+    }
+
+    fn children<'a>(&'a self) -> Box<dyn Iterator<Item = &'a PhysicalPlan> + 'a> {
+        Box::new(std::iter::once(&self.probe).chain(std::iter::once(&self.build)))
+    }
+
+    fn children_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut PhysicalPlan> + 'a> {
+        Box::new(std::iter::once(&mut self.probe).chain(std::iter::once(&mut self.build)))
+    }
+
+    fn formatter(&self) -> Result<Box<dyn PhysicalFormat + '_>> {
+        Ok(SpatialJoinFormatter::create(self))
+    }
+
+    fn get_desc(&self) -> Result<String> {
+        let mut conditions = vec![self
+            .spatial_condition
+            .as_expr(&BUILTIN_FUNCTIONS)
+            .sql_display()];
+        conditions.extend(
+            self.other_conditions
+                .iter()
+                .map(|x| x.as_expr(&BUILTIN_FUNCTIONS).sql_display()),
+        );
+
+        Ok(conditions.join(" AND "))
+    }
+
+    fn derive(&self, mut children: Vec<PhysicalPlan>) -> PhysicalPlan {
+        assert_eq!(children.len(), 2);
+        let build_child = children.pop().unwrap();
+        let probe_child = children.pop().unwrap();
+
+        PhysicalPlan::new(SpatialJoin {
+            meta: self.meta.clone(),
+            probe: probe_child,
+            build: build_child,
+            probe_key: self.probe_key.clone(),
+            build_key: self.build_key.clone(),
+            distance: self.distance,
+            spatial_condition: self.spatial_condition.clone(),
+            other_conditions: self.other_conditions.clone(),
+            join_type: self.join_type.clone(),
+            output_schema: self.output_schema.clone(),
+            stat_info: self.stat_info.clone(),
+        })
+    }
+
+    fn build_pipeline2(&self, builder: &mut PipelineBuilder) -> Result<()> {
+        let state = Arc::new(SpatialJoinState::create(builder.ctx.clone(), self)?);
+        self.build_build_side(state.clone(), builder)?;
+        self.build_probe_side(state, builder)
+    }
+}
+
+impl SpatialJoin {
+    // Build the probe-side pipeline, probe blocks are streamed through the join.
+    fn build_probe_side(
+        &self,
+        state: Arc<SpatialJoinState>,
+        builder: &mut PipelineBuilder,
+    ) -> Result<()> {
+        self.probe.build_pipeline(builder)?;
+
+        builder.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformSpatialJoinProbe::create(
+                input,
+                output,
+                state.clone(),
+            )))
+        })
+    }
+
+    // Build the build-side pipeline, which collects all blocks to build the R-tree.
+    fn build_build_side(
+        &self,
+        state: Arc<SpatialJoinState>,
+        builder: &mut PipelineBuilder,
+    ) -> Result<()> {
+        let build_side_builder = builder.create_sub_pipeline_builder();
+
+        let mut build_res = build_side_builder.finalize(&self.build)?;
+        build_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(
+                Sinker::<TransformSpatialJoinBuild>::create(
+                    input,
+                    TransformSpatialJoinBuild::create(state.clone()),
+                ),
+            ))
+        })?;
+
+        builder
+            .pipelines
+            .push(build_res.main_pipeline.finalize(None));
+        builder.pipelines.extend(build_res.sources_pipelines);
+        Ok(())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub async fn build_spatial_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        spatial_condition: ScalarExpr,
+        other_conditions: Vec<ScalarExpr>,
+    ) -> Result<PhysicalPlan> {
+        // Build the R-tree with the smaller side, the optimizer broadcasts the same side.
+        let left_cardinality = RelExpr::with_s_expr(s_expr.child(0)?)
+            .derive_cardinality()?
+            .cardinality;
+        let right_cardinality = RelExpr::with_s_expr(s_expr.child(1)?)
+            .derive_cardinality()?
+            .cardinality;
+        let (probe_child, build_child, probe_required, build_required) =
+            match Join::spatial_join_build_child(left_cardinality, right_cardinality) {
+                0 => (
+                    s_expr.child(1)?,
+                    s_expr.child(0)?,
+                    right_required,
+                    left_required,
+                ),
+                _ => (
+                    s_expr.child(0)?,
+                    s_expr.child(1)?,
+                    left_required,
+                    right_required,
+                ),
+            };
+
+        let probe_prop = RelExpr::with_s_expr(probe_child).derive_relational_prop()?;
+        let build_prop = RelExpr::with_s_expr(build_child).derive_relational_prop()?;
+
+        let probe_side = self.build(probe_child, probe_required).await?;
+        let build_side = self.build(build_child, build_required).await?;
+
+        let probe_schema = self.prepare_probe_schema(&join.join_type, &probe_side)?;
+        let build_schema = self.prepare_build_schema(&join.join_type, &build_side)?;
+        let merged_schema = DataSchemaRefExt::create(
+            probe_schema
+                .fields()
+                .iter()
+                .chain(build_schema.fields())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+
+        let ScalarExpr::FunctionCall(func) = &spatial_condition else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid spatial join condition: {:?}",
+                spatial_condition
+            )));
+        };
+        let distance = match func.arguments.get(2) {
+            Some(arg) => spatial_join_distance(arg).ok_or_else(|| {
+                ErrorCode::Internal("The distance of spatial join must be a constant")
+            })?,
+            None => 0.0,
+        };
+
+        let mut probe_key = None;
+        let mut build_key = None;
+        for arg in func.arguments.iter().take(2) {
+            match JoinPredicate::new(arg, &probe_prop, &build_prop) {
+                JoinPredicate::Left(_) => {
+                    probe_key = Some(resolve_scalar(arg, &probe_schema)?);
+                }
+                JoinPredicate::Right(_) => {
+                    build_key = Some(resolve_scalar(arg, &build_schema)?);
+                }
+                JoinPredicate::ALL(_) | JoinPredicate::Both { .. } | JoinPredicate::Other(_) => {
+                    return Err(ErrorCode::Internal(format!(
+                        "Invalid spatial join condition: {:?}",
+                        spatial_condition
+                    )));
+                }
+            }
+        }
+        let (Some(probe_key), Some(build_key)) = (probe_key, build_key) else {
+            return Err(ErrorCode::Internal(format!(
+                "Invalid spatial join condition: {:?}",
+                spatial_condition
+            )));
+        };
+
+        Ok(PhysicalPlan::new(SpatialJoin {
+            meta: PhysicalPlanMeta::new("SpatialJoin"),
+            probe: probe_side,
+            build: build_side,
+            probe_key,
+            build_key,
+            distance,
+            spatial_condition: resolve_scalar(&spatial_condition, &merged_schema)?,
+            other_conditions: other_conditions
+                .iter()
+                .map(|scalar| resolve_scalar(scalar, &merged_schema))
+                .collect::<Result<_>>()?,
+            join_type: join.join_type.clone(),
+            output_schema: merged_schema,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
+}
+
+fn resolve_scalar(scalar: &ScalarExpr, schema: &DataSchemaRef) -> Result<RemoteExpr> {
+    let expr = scalar
+        .type_check(schema.as_ref())?
+        .project_column_ref(|index| schema.index_of(&index.to_string()))?;
+    Ok(expr.as_remote_expr())
+}
@@ -20,6 +20,8 @@ mod new_hash_join;
 pub(crate) mod range_join;
 mod runtime_pool;
 pub mod sort;
+pub(crate) mod sort_merge_join;
+pub(crate) mod spatial_join;
 mod transform_async_function;
 mod transform_branched_async_function;
 mod transform_cache_scan;
@@ -0,0 +1,601 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::ops::Range;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_column::bitmap::Bitmap;
+use databend_common_exception::Result;
+use databend_common_expression::local_block_meta_serde;
+use databend_common_expression::types::BinaryColumn;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_transforms::sorts::core::RowConverter;
+use databend_common_pipeline_transforms::sorts::core::VariableRowConverter;
+use databend_common_sql::executor::cast_expr_to_non_null_boolean;
+use databend_common_sql::plans::JoinType;
+
+use crate::physical_plans::SortMergeJoin;
+use crate::pipelines::processors::transforms::wrap_true_validity;
+use crate::sessions::QueryContext;
+
+/// Merges a key range of the two inputs of sort-merge join, both sorted by the join keys
+/// in ascending order.
+///
+/// Rows with the same key form a group on each side. The ranges are cut between keys, so
+/// a group is always complete in its range. Rows with NULL keys never match and are
+/// handled as unmatched rows.
+pub struct SortMergeJoiner {
+    func_ctx: FunctionContext,
+    max_block_size: usize,
+    join_type: JoinType,
+    other_conditions: Vec<Expr>,
+    // The column types of each side in the merged schema
+    left_types: Vec<DataType>,
+    right_types: Vec<DataType>,
+
+    left: MergeSide,
+    right: MergeSide,
+    group: Option<GroupJoin>,
+    output: VecDeque<DataBlock>,
+}
+
+impl SortMergeJoiner {
+    pub fn create(ctx: Arc<QueryContext>, join: &SortMergeJoin) -> Result<Self> {
+        let other_conditions = join
+            .other_conditions
+            .iter()
+            .map(|condition| cast_expr_to_non_null_boolean(condition.as_expr(&BUILTIN_FUNCTIONS)))
+            .collect::<Result<_>>()?;
+
+        let wrap_left = matches!(join.join_type, JoinType::Right | JoinType::Full);
+        let wrap_right = matches!(join.join_type, JoinType::Left | JoinType::Full);
+        let side_types = |schema: DataSchemaRef, wrap: bool| {
+            schema
+                .fields()
+                .iter()
+                .map(|field| match wrap {
+                    true => field.data_type().wrap_nullable(),
+                    false => field.data_type().clone(),
+                })
+                .collect::<Vec<_>>()
+        };
+        let left_types = side_types(join.left.output_schema()?, wrap_left);
+        let right_types = side_types(join.right.output_schema()?, wrap_right);
+
+        Ok(SortMergeJoiner {
+            func_ctx: ctx.get_function_context()?,
+            max_block_size: ctx.get_settings().get_max_block_size()? as usize,
+            join_type: join.join_type.clone(),
+            other_conditions,
+            left: MergeSide::default(),
+            right: MergeSide::default(),
+            left_types,
+            right_types,
+            group: None,
+            output: VecDeque::new(),
+        })
+    }
+
+    /// Start to merge the next key range, the previous range must be finished.
+    pub fn start_range(&mut self, range: SortMergeJoinRange) {
+        self.left.blocks = range.left.into();
+        self.right.blocks = range.right.into();
+    }
+
+    pub fn next_output(&mut self) -> Option<DataBlock> {
+        self.output.pop_front()
+    }
+
+    /// Merge the rows of the range until there is output, returns false if the range is finished.
+    pub fn merge(&mut self) -> Result<bool> {
+        loop {
+            if !self.output.is_empty() {
+                return Ok(true);
+            }
+
+            if let Some(group) = &mut self.group {
+                let joined = group.join_next(
+                    &self.join_type,
+                    &self.other_conditions,
+                    &self.func_ctx,
+                    self.max_block_size,
+                    &self.left_types,
+                    &self.right_types,
+                )?;
+                match joined {
+                    Some(block) if !block.is_empty() => self.output.push_back(block),
+                    Some(_) => {}
+                    None => {
+                        let group = self.group.take().unwrap();
+                        self.finish_group(group)?;
+                    }
+                }
+                continue;
+            }
+
+            match (self.left.has_row(), self.right.has_row()) {
+                (false, false) => return Ok(false),
+                (true, false) => match self.keep_left_unmatched() {
+                    true => {
+                        let rows = self.left.take_run(|_, _| true);
+                        self.output_left_unmatched(rows)?;
+                    }
+                    false => return Ok(false),
+                },
+                (false, true) => match self.keep_right_unmatched() {
+                    true => {
+                        let rows = self.right.take_run(|_, _| true);
+                        self.output_right_unmatched(rows)?;
+                    }
+                    false => return Ok(false),
+                },
+                (true, true) => {
+                    if !self.left.is_valid() {
+                        let rows = self.left.take_run(|_, valid| !valid);
+                        self.output_left_unmatched(rows)?;
+                        continue;
+                    }
+                    if !self.right.is_valid() {
+                        let rows = self.right.take_run(|_, valid| !valid);
+                        self.output_right_unmatched(rows)?;
+                        continue;
+                    }
+
+                    match self.left.key().cmp(self.right.key()) {
+                        Ordering::Less => {
+                            let right_key = self.right.key().to_vec();
+                            let rows = self
+                                .left
+                                .take_run(|key, valid| valid && key < right_key.as_slice());
+                            self.output_left_unmatched(rows)?;
+                        }
+                        Ordering::Greater => {
+                            let left_key = self.left.key().to_vec();
+                            let rows = self
+                                .right
+                                .take_run(|key, valid| valid && key < left_key.as_slice());
+                            self.output_right_unmatched(rows)?;
+                        }
+                        Ordering::Equal => {
+                            let key = self.left.key().to_vec();
+                            let left_rows = self.left.group_len(&key);
+                            let right_rows = self.right.group_len(&key);
+                            let left = self.left.take_rows(left_rows)?;
+                            let right = self.right.take_rows(right_rows)?;
+                            self.start_group(left, right)?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn keep_left_unmatched(&self) -> bool {
+        matches!(
+            self.join_type,
+            JoinType::Left | JoinType::Full | JoinType::LeftAnti
+        )
+    }
+
+    fn keep_right_unmatched(&self) -> bool {
+        matches!(
+            self.join_type,
+            JoinType::Right | JoinType::Full | JoinType::RightAnti
+        )
+    }
+
+    fn start_group(&mut self, left: DataBlock, right: DataBlock) -> Result<()> {
+        // Without other conditions, every pair of rows in the group matches.
+        if self.other_conditions.is_empty() {
+            match self.join_type {
+                JoinType::LeftSemi => return self.push_output(left),
+                JoinType::RightSemi => return self.push_output(right),
+                JoinType::LeftAnti | JoinType::RightAnti => return Ok(()),
+                _ => {}
+            }
+        }
+
+        self.group = Some(GroupJoin::new(left, right));
+        Ok(())
+    }
+
+    fn finish_group(&mut self, group: GroupJoin) -> Result<()> {
+        let GroupJoin {
+            left,
+            right,
+            left_matched,
+            right_matched,
+            ..
+        } = group;
+
+        match self.join_type {
+            JoinType::Left | JoinType::Full | JoinType::LeftAnti => {
+                self.output_left_unmatched(take_flagged(&left, &left_matched, false)?)?;
+            }
+            JoinType::LeftSemi => {
+                self.push_output(take_flagged(&left, &left_matched, true)?)?;
+            }
+            _ => {}
+        }
+
+        match self.join_type {
+            JoinType::Right | JoinType::Full | JoinType::RightAnti => {
+                self.output_right_unmatched(take_flagged(&right, &right_matched, false)?)?;
+            }
+            JoinType::RightSemi => {
+                self.push_output(take_flagged(&right, &right_matched, true)?)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn output_left_unmatched(&mut self, left: DataBlock) -> Result<()> {
+        let num_rows = left.num_rows();
+        match self.join_type {
+            JoinType::Left | JoinType::Full => {
+                let mut block = align_block(left, &self.left_types);
+                block.merge_block(null_block(&self.right_types, num_rows));
+                self.push_output(block)
+            }
+            JoinType::LeftAnti => self.push_output(left),
+            _ => Ok(()),
+        }
+    }
+
+    fn output_right_unmatched(&mut self, right: DataBlock) -> Result<()> {
+        let num_rows = right.num_rows();
+        match self.join_type {
+            JoinType::Right | JoinType::Full => {
+                let mut block = null_block(&self.left_types, num_rows);
+                block.merge_block(align_block(right, &self.right_types));
+                self.push_output(block)
+            }
+            JoinType::RightAnti => self.push_output(right),
+            _ => Ok(()),
+        }
+    }
+
+    fn push_output(&mut self, block: DataBlock) -> Result<()> {
+        if !block.is_empty() {
+            self.output.push_back(block);
+        }
+        Ok(())
+    }
+}
+
+/// A block of one input with the encoded join keys, which are compared as bytes.
+pub struct SortedBlock {
+    data: DataBlock,
+    keys: BinaryColumn,
+    // The rows with NULL keys are invalid, `None` means all rows are valid.
+    validity: Option<Bitmap>,
+    position: usize,
+}
+
+impl SortedBlock {
+    /// The key columns are appended after the `num_columns` columns of the side.
+    pub fn create(
+        block: DataBlock,
+        num_columns: usize,
+        row_converter: &VariableRowConverter,
+    ) -> Result<Self> {
+        let num_rows = block.num_rows();
+        let mut entries = block.take_columns();
+        let key_entries = entries.split_off(num_columns);
+        let keys = row_converter.convert(&key_entries, num_rows)?;
+        let validity = key_validity(&key_entries, num_rows);
+
+        Ok(SortedBlock {
+            data: DataBlock::new(entries, num_rows),
+            keys,
+            validity,
+            position: 0,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.data.num_rows()
+    }
+
+    pub fn last_key(&self) -> &[u8] {
+        self.keys.value(self.num_rows() - 1)
+    }
+
+    /// Split off the rows with keys less than the bound.
+    pub fn take_less_than(&mut self, bound: &[u8]) -> Option<SortedBlock> {
+        let (mut low, mut high) = (0, self.num_rows());
+        while low < high {
+            let mid = (low + high) / 2;
+            match self.keys.value(mid) < bound {
+                true => low = mid + 1,
+                false => high = mid,
+            }
+        }
+
+        if low == 0 {
+            return None;
+        }
+        let rows = self.slice(0..low);
+        *self = self.slice(low..self.num_rows());
+        Some(rows)
+    }
+
+    fn slice(&self, range: Range<usize>) -> SortedBlock {
+        SortedBlock {
+            data: self.data.slice(range.clone()),
+            keys: self.keys.slice(range.clone()),
+            validity: self
+                .validity
+                .clone()
+                .map(|validity| validity.sliced(range.start, range.len())),
+            position: 0,
+        }
+    }
+
+    fn is_valid(&self, row: usize) -> bool {
+        self.validity
+            .as_ref()
+            .is_none_or(|validity| validity.get_bit(row))
+    }
+}
+
+/// The rows of both inputs in a key range, sent from the partitioner to the joiners.
+pub struct SortMergeJoinRange {
+    pub left: Vec<SortedBlock>,
+    pub right: Vec<SortedBlock>,
+}
+
+local_block_meta_serde!(SortMergeJoinRange);
+
+impl Debug for SortMergeJoinRange {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("SortMergeJoinRange").finish()
+    }
+}
+
+#[typetag::serde(name = "sort_merge_join_range")]
+impl BlockMetaInfo for SortMergeJoinRange {}
+
+// The blocks of one input in the current range.
+#[derive(Default)]
+struct MergeSide {
+    blocks: VecDeque<SortedBlock>,
+}
+
+impl MergeSide {
+    fn has_row(&self) -> bool {
+        !self.blocks.is_empty()
+    }
+
+    fn key(&self) -> &[u8] {
+        let block = &self.blocks[0];
+        block.keys.value(block.position)
+    }
+
+    fn is_valid(&self) -> bool {
+        let block = &self.blocks[0];
+        block.is_valid(block.position)
+    }
+
+    // Take the rows from the current position of the first block while the predicate holds.
+    fn take_run<F>(&mut self, predicate: F) -> DataBlock
+    where F: Fn(&[u8], bool) -> bool {
+        let block = &mut self.blocks[0];
+        let start = block.position;
+        let mut end = start;
+        while end < block.data.num_rows() && predicate(block.keys.value(end), block.is_valid(end)) {
+            end += 1;
+        }
+
+        let rows = block.data.slice(start..end);
+        block.position = end;
+        if end == block.data.num_rows() {
+            self.blocks.pop_front();
+        }
+        rows
+    }
+
+    // The number of rows with the given key from the current position.
+    fn group_len(&self, key: &[u8]) -> usize {
+        let mut len = 0;
+        for block in self.blocks.iter() {
+            let num_rows = block.data.num_rows();
+            let mut row = block.position;
+            while row < num_rows && block.is_valid(row) && block.keys.value(row) == key {
+                row += 1;
+            }
+            len += row - block.position;
+            if row < num_rows {
+                break;
+            }
+        }
+        len
+    }
+
+    fn take_rows(&mut self, mut len: usize) -> Result<DataBlock> {
+        let mut blocks = vec![];
+        while len > 0 {
+            let block = &mut self.blocks[0];
+            let start = block.position;
+            let end = block.data.num_rows().min(start + len);
+            blocks.push(block.data.slice(start..end));
+            len -= end - start;
+            block.position = end;
+            if end == block.data.num_rows() {
+                self.blocks.pop_front();
+            }
+        }
+        DataBlock::concat(&blocks)
+    }
+}
+
+// The rows of the same key from both sides, joined in chunks of at most `max_block_size` pairs.
+struct GroupJoin {
+    left: DataBlock,
+    right: DataBlock,
+    next_left_row: usize,
+    left_matched: Vec<bool>,
+    right_matched: Vec<bool>,
+}
+
+impl GroupJoin {
+    fn new(left: DataBlock, right: DataBlock) -> Self {
+        GroupJoin {
+            left_matched: vec![false; left.num_rows()],
+            right_matched: vec![false; right.num_rows()],
+            left,
+            right,
+            next_left_row: 0,
+        }
+    }
+
+    fn join_next(
+        &mut self,
+        join_type: &JoinType,
+        other_conditions: &[Expr],
+        func_ctx: &FunctionContext,
+        max_block_size: usize,
+        left_types: &[DataType],
+        right_types: &[DataType],
+    ) -> Result<Option<DataBlock>> {
+        let left_rows = self.left.num_rows();
+        let right_rows = self.right.num_rows();
+        if self.next_left_row >= left_rows {
+            return Ok(None);
+        }
+
+        let start = self.next_left_row;
+        let end = left_rows.min(start + (max_block_size / right_rows).max(1));
+        self.next_left_row = end;
+
+        let num_pairs = (end - start) * right_rows;
+        let mut left_indices = Vec::with_capacity(num_pairs);
+        let mut right_indices = Vec::with_capacity(num_pairs);
+        for left_row in start..end {
+            for right_row in 0..right_rows {
+                left_indices.push(left_row as u32);
+                right_indices.push(right_row as u32);
+            }
+        }
+
+        let mut pairs = align_block(self.left.take(&left_indices)?, left_types);
+        pairs.merge_block(align_block(self.right.take(&right_indices)?, right_types));
+
+        let mut selection = Bitmap::new_constant(true, num_pairs);
+        for condition in other_conditions.iter() {
+            let evaluator = Evaluator::new(&pairs, func_ctx, &BUILTIN_FUNCTIONS);
+            let predicate = evaluator
+                .run(condition)?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            selection = match predicate {
+                Value::Scalar(true) => selection,
+                Value::Scalar(false) => Bitmap::new_constant(false, num_pairs),
+                Value::Column(column) => &selection & &column,
+            };
+        }
+
+        for (pair, selected) in selection.iter().enumerate() {
+            if selected {
+                self.left_matched[left_indices[pair] as usize] = true;
+                self.right_matched[right_indices[pair] as usize] = true;
+            }
+        }
+
+        match join_type {
+            JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full => {
+                Ok(Some(pairs.filter_with_bitmap(&selection)?))
+            }
+            // Semi and anti joins only output rows after the whole group is joined.
+            _ => Ok(Some(DataBlock::empty())),
+        }
+    }
+}
+
+fn take_flagged(block: &DataBlock, flags: &[bool], value: bool) -> Result<DataBlock> {
+    let indices = flags
+        .iter()
+        .enumerate()
+        .filter(|(_, flag)| **flag == value)
+        .map(|(row, _)| row as u32)
+        .collect::<Vec<_>>();
+    match indices.len() == block.num_rows() {
+        true => Ok(block.clone()),
+        false => block.take(&indices),
+    }
+}
+
+fn key_validity(keys: &[BlockEntry], num_rows: usize) -> Option<Bitmap> {
+    let mut validity: Option<Bitmap> = None;
+    for entry in keys {
+        let entry_validity = match entry {
+            BlockEntry::Const(Scalar::Null, _, _) | BlockEntry::Column(Column::Null { .. }) => {
+                Bitmap::new_constant(false, num_rows)
+            }
+            BlockEntry::Column(Column::Nullable(column)) => column.validity.clone(),
+            _ => continue,
+        };
+        validity = Some(match validity {
+            Some(validity) => &validity & &entry_validity,
+            None => entry_validity,
+        });
+    }
+    validity
+}
+
+// Wrap the columns as nullable if required by the merged schema.
+fn align_block(block: DataBlock, types: &[DataType]) -> DataBlock {
+    if types.iter().all(|ty| !ty.is_nullable()) {
+        return block;
+    }
+
+    let num_rows = block.num_rows();
+    let validity = Bitmap::new_constant(true, num_rows);
+    let entries = block
+        .columns()
+        .iter()
+        .zip(types.iter())
+        .map(|(entry, ty)| match ty.is_nullable() {
+            true => wrap_true_validity(entry, num_rows, &validity),
+            false => entry.clone(),
+        })
+        .collect();
+    DataBlock::new(entries, num_rows)
+}
+
+fn null_block(types: &[DataType], num_rows: usize) -> DataBlock {
+    let entries = types
+        .iter()
+        .map(|ty| BlockEntry::new_const_column(ty.wrap_nullable(), Scalar::Null, num_rows))
+        .collect();
+    DataBlock::new(entries, num_rows)
+}
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoinRange;
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoiner;
+
+/// Merges the key ranges cut by the partitioner, one range at a time.
+pub struct TransformSortMergeJoin {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    joiner: SortMergeJoiner,
+    range: Option<SortMergeJoinRange>,
+    merging: bool,
+}
+
+impl TransformSortMergeJoin {
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        joiner: SortMergeJoiner,
+    ) -> ProcessorPtr {
+        ProcessorPtr::create(Box::new(TransformSortMergeJoin {
+            input,
+            output,
+            joiner,
+            range: None,
+            merging: false,
+        }))
+    }
+}
+
+impl Processor for TransformSortMergeJoin {
+    fn name(&self) -> String {
+        String::from("TransformSortMergeJoin")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(data_block) = self.joiner.next_output() {
+            self.output.push_data(Ok(data_block));
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.merging || self.range.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.input.has_data() {
+            let mut data_block = self.input.pull_data().unwrap()?;
+            let range = data_block
+                .take_meta()
+                .and_then(SortMergeJoinRange::downcast_from)
+                .ok_or_else(|| ErrorCode::Internal("Expected a range of sort-merge join"))?;
+            self.range = Some(range);
+            return Ok(Event::Sync);
+        }
+
+        if self.input.is_finished() {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(range) = self.range.take() {
+            self.joiner.start_range(range);
+        }
+
+        self.merging = self.joiner.merge()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::SortColumnDescription;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::sorts::core::RowConverter;
+use databend_common_pipeline_transforms::sorts::core::VariableRowConverter;
+
+use crate::physical_plans::SortMergeJoin;
+use crate::pipelines::processors::transforms::sort_merge_join::SortMergeJoinRange;
+use crate::pipelines::processors::transforms::sort_merge_join::SortedBlock;
+
+/// Cuts the two sorted inputs of sort-merge join into key ranges, which are merged by
+/// the joiners in parallel.
+///
+/// A range ends before the smallest last key received from the inputs that are not
+/// finished, so no later row falls into it and a group of equal keys is never split.
+/// Only the inputs that limit the range are pulled, the others are throttled by back
+/// pressure.
+pub struct TransformSortMergeJoinPartition {
+    left_port: Arc<InputPort>,
+    right_port: Arc<InputPort>,
+    outputs: Vec<Arc<OutputPort>>,
+
+    row_converter: VariableRowConverter,
+    left: PartitionSide,
+    right: PartitionSide,
+    ranges: VecDeque<SortMergeJoinRange>,
+}
+
+impl TransformSortMergeJoinPartition {
+    pub fn create(
+        left_port: Arc<InputPort>,
+        right_port: Arc<InputPort>,
+        outputs: Vec<Arc<OutputPort>>,
+        join: &SortMergeJoin,
+    ) -> Result<ProcessorPtr> {
+        // Both sides of the keys are cast to the common type.
+        let key_fields = join
+            .left_keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| {
+                DataField::new(
+                    &i.to_string(),
+                    key.as_expr(&BUILTIN_FUNCTIONS).data_type().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let sort_desc = (0..key_fields.len())
+            .map(|offset| SortColumnDescription {
+                offset,
+                asc: true,
+                nulls_first: false,
+            })
+            .collect::<Vec<_>>();
+        let row_converter =
+            VariableRowConverter::create(&sort_desc, DataSchemaRefExt::create(key_fields))?;
+
+        Ok(ProcessorPtr::create(Box::new(
+            TransformSortMergeJoinPartition {
+                left_port,
+                right_port,
+                outputs,
+                row_converter,
+                left: PartitionSide::new(join.left.output_schema()?.num_fields()),
+                right: PartitionSide::new(join.right.output_schema()?.num_fields()),
+                ranges: VecDeque::new(),
+            },
+        )))
+    }
+
+    // The upper bound of the next range, `None` if all rows can be taken.
+    fn bound(&self) -> Option<&[u8]> {
+        [&self.left, &self.right]
+            .into_iter()
+            .filter(|side| !side.finished)
+            .filter_map(|side| side.last_key())
+            .min()
+    }
+
+    // Whether the side has to be pulled before the next range can be cut.
+    fn need_data(&self, side: &PartitionSide) -> bool {
+        if side.finished || side.data.is_some() {
+            return false;
+        }
+        match (side.last_key(), self.bound()) {
+            (Some(key), Some(bound)) => key == bound,
+            _ => true,
+        }
+    }
+
+    fn pull(side: &mut PartitionSide, input: &InputPort) -> Result<bool> {
+        if input.has_data() {
+            side.data = Some(input.pull_data().unwrap()?);
+            return Ok(true);
+        }
+        if input.is_finished() {
+            side.finished = true;
+            return Ok(true);
+        }
+        input.set_need_data();
+        Ok(false)
+    }
+}
+
+impl Processor for TransformSortMergeJoinPartition {
+    fn name(&self) -> String {
+        String::from("TransformSortMergeJoinPartition")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.outputs.iter().all(|output| output.is_finished()) {
+            self.left_port.finish();
+            self.right_port.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.ranges.is_empty() {
+            for output in self.outputs.iter() {
+                if !output.is_finished() && output.can_push() {
+                    if let Some(range) = self.ranges.pop_front() {
+                        output.push_data(Ok(DataBlock::empty_with_meta(Box::new(range))));
+                    }
+                }
+            }
+            if !self.ranges.is_empty() {
+                self.left_port.set_not_need_data();
+                self.right_port.set_not_need_data();
+                return Ok(Event::NeedConsume);
+            }
+        }
+
+        if self.left.data.is_some() || self.right.data.is_some() {
+            return Ok(Event::Sync);
+        }
+
+        if self.left.finished && self.right.finished {
+            if self.left.has_row() || self.right.has_row() {
+                return Ok(Event::Sync);
+            }
+            self.outputs.iter().for_each(|output| output.finish());
+            return Ok(Event::Finished);
+        }
+
+        let mut pulled = false;
+        if self.need_data(&self.left) {
+            pulled |= Self::pull(&mut self.left, &self.left_port)?;
+        }
+        if self.need_data(&self.right) {
+            pulled |= Self::pull(&mut self.right, &self.right_port)?;
+        }
+        match pulled {
+            true => Ok(Event::Sync),
+            false => Ok(Event::NeedData),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        for side in [&mut self.left, &mut self.right] {
+            if let Some(block) = side.data.take() {
+                if !block.is_empty() {
+                    let block = SortedBlock::create(block, side.num_columns, &self.row_converter)?;
+                    side.blocks.push_back(block);
+                }
+            }
+        }
+
+        // An unfinished side without rows may still receive any key.
+        if [&self.left, &self.right]
+            .iter()
+            .any(|side| !side.finished && !side.has_row())
+        {
+            return Ok(());
+        }
+
+        let range = match self.bound().map(|bound| bound.to_vec()) {
+            Some(bound) => SortMergeJoinRange {
+                left: self.left.take_less_than(&bound),
+                right: self.right.take_less_than(&bound),
+            },
+            None => SortMergeJoinRange {
+                left: self.left.blocks.drain(..).collect(),
+                right: self.right.blocks.drain(..).collect(),
+            },
+        };
+        if !range.left.is_empty() || !range.right.is_empty() {
+            self.ranges.push_back(range);
+        }
+        Ok(())
+    }
+}
+
+// The received blocks of one input that are not sent in a range yet.
+struct PartitionSide {
+    num_columns: usize,
+    blocks: VecDeque<SortedBlock>,
+    data: Option<DataBlock>,
+    finished: bool,
+}
+
+impl PartitionSide {
+    fn new(num_columns: usize) -> Self {
+        PartitionSide {
+            num_columns,
+            blocks: VecDeque::new(),
+            data: None,
+            finished: false,
+        }
+    }
+
+    fn has_row(&self) -> bool {
+        !self.blocks.is_empty()
+    }
+
+    fn last_key(&self) -> Option<&[u8]> {
+        self.blocks.back().map(|block| block.last_key())
+    }
+
+    fn take_less_than(&mut self, bound: &[u8]) -> Vec<SortedBlock> {
+        let mut rows = vec![];
+        while let Some(block) = self.blocks.front_mut() {
+            match block.take_less_than(bound) {
+                Some(taken) => rows.push(taken),
+                None => break,
+            }
+            if block.num_rows() == 0 {
+                self.blocks.pop_front();
+            } else {
+                break;
+            }
+        }
+        rows
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::read_bounding_box;
+use databend_common_sql::executor::cast_expr_to_non_null_boolean;
+use geozero::wkb::Ewkb;
+use parking_lot::Mutex;
+use rstar::primitives::GeomWithData;
+use rstar::primitives::Rectangle;
+use rstar::RTree;
+use rstar::AABB;
+
+use crate::physical_plans::SpatialJoin;
+use crate::pipelines::executor::WatchNotify;
+use crate::sessions::QueryContext;
+
+type SpatialIndexItem = GeomWithData<Rectangle<[f64; 2]>, u32>;
+// The lower and upper corners of a bounding box.
+type BoundingBox = ([f64; 2], [f64; 2]);
+
+// The R-tree of the build side, the data of each item is the row index in build block.
+struct SpatialIndex {
+    block: DataBlock,
+    tree: RTree<SpatialIndexItem>,
+}
+
+pub struct SpatialJoinState {
+    func_ctx: FunctionContext,
+    max_block_size: usize,
+    probe_key: Expr,
+    build_key: Expr,
+    distance: f64,
+    // The spatial condition and other conditions, used to filter candidate pairs.
+    conditions: Vec<Expr>,
+
+    build_blocks: Mutex<Vec<DataBlock>>,
+    build_sinker_count: Mutex<usize>,
+    spatial_index: OnceLock<SpatialIndex>,
+    // Pipeline event related
+    build_finished: Mutex<bool>,
+    finished_notify: Arc<WatchNotify>,
+}
+
+impl SpatialJoinState {
+    pub fn create(ctx: Arc<QueryContext>, spatial_join: &SpatialJoin) -> Result<Self> {
+        let conditions = std::iter::once(&spatial_join.spatial_condition)
+            .chain(spatial_join.other_conditions.iter())
+            .map(|condition| cast_expr_to_non_null_boolean(condition.as_expr(&BUILTIN_FUNCTIONS)))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            func_ctx: ctx.get_function_context()?,
+            max_block_size: ctx.get_settings().get_max_block_size()? as usize,
+            probe_key: spatial_join.probe_key.as_expr(&BUILTIN_FUNCTIONS),
+            build_key: spatial_join.build_key.as_expr(&BUILTIN_FUNCTIONS),
+            distance: spatial_join.distance,
+            conditions,
+            build_blocks: Mutex::new(vec![]),
+            build_sinker_count: Mutex::new(0),
+            spatial_index: OnceLock::new(),
+            build_finished: Mutex::new(false),
+            finished_notify: Arc::new(WatchNotify::new()),
+        })
+    }
+
+    pub(crate) fn build_attach(&self) {
+        let mut build_sinker_count = self.build_sinker_count.lock();
+        *build_sinker_count += 1;
+    }
+
+    pub(crate) fn build_detach(&self) -> Result<()> {
+        let mut build_sinker_count = self.build_sinker_count.lock();
+        *build_sinker_count -= 1;
+        if *build_sinker_count == 0 {
+            // All build blocks are collected, build the R-tree.
+            self.build_spatial_index()?;
+            let mut build_finished = self.build_finished.lock();
+            *build_finished = true;
+            self.finished_notify.notify_waiters();
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sink_build(&self, block: DataBlock) -> Result<()> {
+        if !block.is_empty() {
+            self.build_blocks.lock().push(block);
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn wait_build_finish(&self) -> Result<()> {
+        let notified = {
+            let build_finished = self.build_finished.lock();
+
+            match *build_finished {
+                true => None,
+                false => Some(self.finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    fn build_spatial_index(&self) -> Result<()> {
+        let build_blocks = std::mem::take(&mut *self.build_blocks.lock());
+        let block = if build_blocks.is_empty() {
+            DataBlock::empty()
+        } else {
+            DataBlock::concat(&build_blocks)?
+        };
+
+        let mut items = Vec::with_capacity(block.num_rows());
+        if !block.is_empty() {
+            let bboxes = self.bounding_boxes(&block, &self.build_key, 0.0)?;
+            for (row, bbox) in bboxes.into_iter().enumerate() {
+                if let Some((min, max)) = bbox {
+                    items.push(GeomWithData::new(
+                        Rectangle::from_corners(min, max),
+                        row as u32,
+                    ));
+                }
+            }
+        }
+
+        let _ = self.spatial_index.set(SpatialIndex {
+            block,
+            tree: RTree::bulk_load(items),
+        });
+        Ok(())
+    }
+
+    // Probe the R-tree with the probe block, returns the joined blocks.
+    pub(crate) fn probe(&self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let Some(spatial_index) = self.spatial_index.get() else {
+            return Ok(vec![]);
+        };
+        if block.is_empty() || spatial_index.tree.size() == 0 {
+            return Ok(vec![]);
+        }
+
+        let bboxes = self.bounding_boxes(&block, &self.probe_key, self.distance)?;
+        let mut result_blocks = vec![];
+        let mut probe_indices = Vec::with_capacity(self.max_block_size);
+        let mut build_indices = Vec::with_capacity(self.max_block_size);
+        for (row, bbox) in bboxes.into_iter().enumerate() {
+            let Some((min, max)) = bbox else {
+                continue;
+            };
+            let envelope = AABB::from_corners(min, max);
+            for item in spatial_index
+                .tree
+                .locate_in_envelope_intersecting(&envelope)
+            {
+                probe_indices.push(row as u32);
+                build_indices.push(item.data);
+            }
+
+            if probe_indices.len() >= self.max_block_size {
+                let result = self.merge_and_filter(
+                    &block,
+                    &spatial_index.block,
+                    &probe_indices,
+                    &build_indices,
+                )?;
+                if !result.is_empty() {
+                    result_blocks.push(result);
+                }
+                probe_indices.clear();
+                build_indices.clear();
+            }
+        }
+
+        if !probe_indices.is_empty() {
+            let result = self.merge_and_filter(
+                &block,
+                &spatial_index.block,
+                &probe_indices,
+                &build_indices,
+            )?;
+            if !result.is_empty() {
+                result_blocks.push(result);
+            }
+        }
+        Ok(result_blocks)
+    }
+
+    fn merge_and_filter(
+        &self,
+        probe_block: &DataBlock,
+        build_block: &DataBlock,
+        probe_indices: &[u32],
+        build_indices: &[u32],
+    ) -> Result<DataBlock> {
+        let mut result_block = probe_block.take(probe_indices)?;
+        result_block.merge_block(build_block.take(build_indices)?);
+
+        for condition in self.conditions.iter() {
+            let evaluator = Evaluator::new(&result_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            let predicate = evaluator
+                .run(condition)?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            result_block = result_block.filter_boolean_value(&predicate)?;
+            if result_block.is_empty() {
+                break;
+            }
+        }
+        Ok(result_block)
+    }
+
+    // Compute the bounding box of each geometry, NULL and empty geometries have no bounding box.
+    fn bounding_boxes(
+        &self,
+        block: &DataBlock,
+        key: &Expr,
+        distance: f64,
+    ) -> Result<Vec<Option<BoundingBox>>> {
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let column = evaluator
+            .run(key)?
+            .convert_to_full_column(key.data_type(), block.num_rows());
+
+        column
+            .iter()
+            .map(|value| {
+                let ScalarRef::Geometry(buf) = value else {
+                    return Ok(None);
+                };
+                let bbox =
+                    read_bounding_box(&mut Ewkb(buf))?.map(|(min_x, min_y, max_x, max_y)| {
+                        ([min_x - distance, min_y - distance], [
+                            max_x + distance,
+                            max_y + distance,
+                        ])
+                    });
+                Ok(bbox)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_sinks::Sink;
+
+use crate::pipelines::processors::transforms::spatial_join::SpatialJoinState;
+
+enum SpatialJoinStep {
+    // Wait for the R-tree of build side
+    WaitBuild,
+    Probe,
+}
+
+pub struct TransformSpatialJoinProbe {
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data_blocks: VecDeque<DataBlock>,
+    state: Arc<SpatialJoinState>,
+    step: SpatialJoinStep,
+}
+
+impl TransformSpatialJoinProbe {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        state: Arc<SpatialJoinState>,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformSpatialJoinProbe {
+            input_port,
+            output_port,
+            input_data: None,
+            output_data_blocks: Default::default(),
+            state,
+            step: SpatialJoinStep::WaitBuild,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSpatialJoinProbe {
+    fn name(&self) -> String {
+        "TransformSpatialJoinProbe".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        match self.step {
+            SpatialJoinStep::WaitBuild => Ok(Event::Async),
+            SpatialJoinStep::Probe => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    self.input_port.set_not_need_data();
+                    return Ok(Event::NeedConsume);
+                }
+
+                if let Some(data) = self.output_data_blocks.pop_front() {
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    self.output_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            let res = self.state.probe(data_block)?;
+            self.output_data_blocks.extend(res);
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let SpatialJoinStep::WaitBuild = self.step {
+            self.state.wait_build_finish().await?;
+            self.step = SpatialJoinStep::Probe;
+        }
+        Ok(())
+    }
+}
+
+pub struct TransformSpatialJoinBuild {
+    state: Arc<SpatialJoinState>,
+}
+
+impl TransformSpatialJoinBuild {
+    pub fn create(state: Arc<SpatialJoinState>) -> Self {
+        state.build_attach();
+        TransformSpatialJoinBuild { state }
+    }
+}
+
+impl Sink for TransformSpatialJoinBuild {
+    const NAME: &'static str = "TransformSpatialJoinBuild";
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.state.build_detach()
+    }
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        self.state.sink_build(data_block)
+    }
+}
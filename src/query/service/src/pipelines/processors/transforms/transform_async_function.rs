@@ -19,11 +19,14 @@ use std::sync::Arc;
 
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_catalog::catalog::Catalog;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::UInt64Type;
 use databend_common_expression::AutoIncrementExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
 use databend_common_meta_app::principal::AutoIncrementKey;
 use databend_common_meta_app::schema::GetAutoIncrementNextValueReq;
 use databend_common_meta_app::schema::GetSequenceNextValueReq;
@@ -216,6 +219,24 @@ impl TransformAsyncFunction {
     }
 }
 
+impl TransformAsyncFunction {
+    // transform add the secret of a connection as a constant column.
+    async fn transform_get_secret(
+        &self,
+        data_block: &mut DataBlock,
+        connection_name: &str,
+    ) -> Result<()> {
+        let connection = self.ctx.get_connection(connection_name).await?;
+        let Some(secret) = connection.secret() else {
+            return Err(ErrorCode::BadArguments(format!(
+                "Connection '{connection_name}' is not a secret connection"
+            )));
+        };
+        data_block.add_const_column(Scalar::String(secret.to_string()), DataType::String);
+        Ok(())
+    }
+}
+
 pub trait NextValFetcher {
     async fn fetch(
         self,
@@ -381,6 +402,10 @@ impl AsyncTransform for TransformAsyncFunction {
                     )
                     .await?;
                 }
+                AsyncFunctionArgument::SecretFunction(connection_name) => {
+                    self.transform_get_secret(&mut data_block, connection_name)
+                        .await?;
+                }
             }
         }
         Ok(data_block)
@@ -94,7 +94,8 @@ impl AsyncTransform for TransformBranchedAsyncFunction {
                     )
                     .await?;
                 }
-                AsyncFunctionArgument::DictGetFunction(_) => unreachable!(),
+                AsyncFunctionArgument::DictGetFunction(_)
+                | AsyncFunctionArgument::SecretFunction(_) => unreachable!(),
             }
         }
         Ok(block)
@@ -288,6 +288,7 @@ async fn create_memory_table_for_cte_scan(
                     table_indexes: None,
                     table_constraints: None,
                     attached_columns: None,
+                    clone_source: None,
                 });
             }
 
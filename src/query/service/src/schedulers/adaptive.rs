@@ -0,0 +1,644 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_settings::SkewJoinMode;
+use databend_common_sql::executor::physical_plans::FragmentKind;
+use databend_common_sql::plans::JoinSkew;
+use databend_common_sql::plans::JoinType;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::physical_plans::build_broadcast_plan;
+use crate::physical_plans::collect_broadcast_ids;
+use crate::physical_plans::hot_key_predicate;
+use crate::physical_plans::AdaptiveJoinDecision;
+use crate::physical_plans::AdaptiveJoinInfo;
+use crate::physical_plans::AggregatePartial;
+use crate::physical_plans::ConstantTableScan;
+use crate::physical_plans::EvalScalar;
+use crate::physical_plans::Exchange;
+use crate::physical_plans::Filter;
+use crate::physical_plans::HashJoin;
+use crate::physical_plans::IPhysicalPlan;
+use crate::physical_plans::PhysicalPlan;
+use crate::physical_plans::PhysicalPlanCast;
+use crate::physical_plans::PhysicalPlanMeta;
+use crate::physical_plans::ShuffleSkew;
+use crate::physical_plans::TableScan;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::schedulers::commit_distributed_plans;
+use crate::servers::flight::v1::scatter::HashFlightScatter;
+use crate::sessions::QueryContext;
+use crate::stream::PullingExecutorStream;
+
+/// Re-plan the distributed plan with the actual statistics of its build side stages
+/// if adaptive query execution is enabled, otherwise the plan is returned as is.
+#[async_backtrace::framed]
+pub async fn adaptive_replan(ctx: &Arc<QueryContext>, plan: PhysicalPlan) -> Result<PhysicalPlan> {
+    if !ctx.get_settings().get_enable_adaptive_query_execution()?
+        || !plan.is_distributed_plan()
+        || plan.is_warehouse_distributed_plan()
+        || ctx.get_cluster().is_empty()
+    {
+        return Ok(plan);
+    }
+
+    AdaptiveQueryExecutor::create(ctx.clone(), &plan)
+        .replan(plan)
+        .await
+}
+
+/// AdaptiveQueryExecutor materializes the build side stages of distributed hash joins
+/// stage by stage, bottom-up. A stage is the input of the build side exchange, the joins
+/// inside it are re-planned before the stage itself is materialized.
+///
+/// With the actual row count, bytes and the rows of each shuffle partition of the stage,
+/// the join is re-planned:
+/// - a small build side is broadcast instead of shuffled.
+/// - a large build side is shuffled instead of broadcast.
+/// - the hot keys of a skewed side are split out of the hash shuffles, see `ShuffleSkew`.
+///   The probe side stage is also materialized to measure it if it's small enough.
+/// - small shuffle partitions are coalesced into a single stream on each node.
+///
+/// Broadcast and shuffle change the distribution of the join output, so they are only applied
+/// if the output of the join goes through an exchange before any operator relies on it.
+///
+/// A stage is only materialized if the bytes read by its scans fit adaptive_max_materialize_bytes.
+/// The materialized stage is replaced by a constant table scan, so it isn't executed twice.
+pub struct AdaptiveQueryExecutor {
+    ctx: Arc<QueryContext>,
+    next_plan_id: u32,
+}
+
+struct MaterializedStage {
+    blocks: Vec<DataBlock>,
+    rows: usize,
+    bytes: usize,
+}
+
+impl AdaptiveQueryExecutor {
+    pub fn create(ctx: Arc<QueryContext>, plan: &PhysicalPlan) -> AdaptiveQueryExecutor {
+        AdaptiveQueryExecutor {
+            ctx,
+            next_plan_id: max_plan_id(plan) + 1,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn replan(mut self, mut plan: PhysicalPlan) -> Result<PhysicalPlan> {
+        let max_bytes = self
+            .ctx
+            .get_settings()
+            .get_adaptive_max_materialize_bytes()? as usize;
+
+        // The root of a distributed plan is the merge exchange, its output is not redistributed.
+        let mut joins = vec![];
+        collect_adaptive_joins(&plan, false, &mut joins);
+
+        for (join_id, redistributed) in joins {
+            let Some(join) = find_hash_join(&mut plan, join_id) else {
+                continue;
+            };
+            let Some(exchange) = Exchange::from_physical_plan(&join.build) else {
+                continue;
+            };
+
+            let stage = exchange.input.clone();
+            let hash_keys = match exchange.kind {
+                FragmentKind::Normal => exchange.keys.clone(),
+                _ if can_shuffle(&join.join_type) => join.build_keys.clone(),
+                _ => vec![],
+            };
+            let estimated_bytes = estimated_stage_bytes(&stage);
+            if estimated_bytes > max_bytes {
+                info!(
+                    "[ADAPTIVE] Join {} build side stage reads {} bytes, exceeds the materialize limit",
+                    join_id, estimated_bytes
+                );
+                join.adaptive = Some(AdaptiveJoinInfo {
+                    decision: AdaptiveJoinDecision::ExceedMaterializeLimit,
+                    build_rows: 0,
+                    build_bytes: estimated_bytes,
+                    partition_rows: vec![],
+                    probe_partition_rows: vec![],
+                });
+                continue;
+            }
+
+            let build = self.materialize(&stage).await?;
+            let partition_rows = self.partition_rows(&build.blocks, hash_keys)?;
+            let scan = self.constant_table_scan(&stage, build.blocks.as_slice())?;
+            if let Some(exchange) = Exchange::from_mut_physical_plan(&mut join.build) {
+                exchange.input = scan;
+            }
+
+            let decision = self.decide(join, &build, &partition_rows, redistributed)?;
+            let mut adaptive = AdaptiveJoinInfo {
+                decision,
+                build_rows: build.rows,
+                build_bytes: build.bytes,
+                partition_rows,
+                probe_partition_rows: vec![],
+            };
+
+            let keep_shuffle = matches!(
+                adaptive.decision,
+                AdaptiveJoinDecision::KeepShuffle | AdaptiveJoinDecision::CoalescePartitions
+            );
+            if redistributed
+                && keep_shuffle
+                && self
+                    .split_skewed_keys(join, &build, max_bytes, &mut adaptive)
+                    .await?
+            {
+                adaptive.decision = AdaptiveJoinDecision::SplitSkewedPartition;
+            }
+
+            info!(
+                "[ADAPTIVE] Join {} build side stage has {} rows, {} bytes, decision: {}",
+                join_id, build.rows, build.bytes, adaptive.decision
+            );
+
+            self.rewrite(join, &adaptive.decision);
+            join.adaptive = Some(adaptive);
+        }
+
+        Ok(plan)
+    }
+
+    // Execute the stage and collect its output on the coordinator.
+    #[async_backtrace::framed]
+    async fn materialize(&mut self, stage: &PhysicalPlan) -> Result<MaterializedStage> {
+        let stage_plan = PhysicalPlan::new(Exchange {
+            meta: PhysicalPlanMeta::with_plan_id("Exchange", self.next_plan_id()),
+            input: stage.clone(),
+            kind: FragmentKind::Merge,
+            keys: vec![],
+            ignore_exchange: false,
+            allow_adjust_parallelism: true,
+            skew: None,
+        });
+
+        // The runtime filters of the shuffle joins inside the stage are merged by their broadcast
+        // plans, the ids are not in the plan anymore once the stage is replaced.
+        let mut broadcast_ids = BTreeSet::new();
+        collect_broadcast_ids(stage, &mut broadcast_ids);
+        let broadcast_plans = broadcast_ids
+            .into_iter()
+            .map(build_broadcast_plan)
+            .collect::<Result<Vec<_>>>()?;
+
+        let stage_ctx = QueryContext::create_from(self.ctx.as_ref());
+        let build_res = commit_distributed_plans(
+            &stage_ctx,
+            broadcast_plans.iter().chain(std::iter::once(&stage_plan)),
+        )
+        .await?;
+        let settings = ExecutorSettings::try_create(stage_ctx.clone())?;
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+        stage_ctx.set_executor(pulling_executor.get_inner())?;
+
+        let mut stream = PullingExecutorStream::create(pulling_executor)?;
+        let mut materialized = MaterializedStage {
+            blocks: vec![],
+            rows: 0,
+            bytes: 0,
+        };
+
+        while let Some(block) = stream.try_next().await? {
+            if !block.is_empty() {
+                materialized.rows += block.num_rows();
+                materialized.bytes += block.memory_size();
+                materialized.blocks.push(block);
+            }
+        }
+
+        Ok(materialized)
+    }
+
+    // Count the rows of each partition as the hash shuffle of the exchange would scatter them.
+    fn partition_rows(
+        &self,
+        blocks: &[DataBlock],
+        hash_keys: Vec<RemoteExpr>,
+    ) -> Result<Vec<usize>> {
+        if hash_keys.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let partitions = self.ctx.get_cluster().nodes.len();
+        let func_ctx = self.ctx.get_function_context()?;
+        let scatter = HashFlightScatter::try_create(func_ctx, hash_keys, partitions, 0)?;
+
+        let mut partition_rows = vec![0; partitions];
+        for block in blocks {
+            for (index, block) in scatter.execute(block.clone())?.iter().enumerate() {
+                partition_rows[index] += block.num_rows();
+            }
+        }
+
+        Ok(partition_rows)
+    }
+
+    fn decide(
+        &self,
+        join: &HashJoin,
+        build: &MaterializedStage,
+        partition_rows: &[usize],
+        redistributed: bool,
+    ) -> Result<AdaptiveJoinDecision> {
+        let is_broadcast = Exchange::from_physical_plan(&join.build)
+            .is_some_and(|exchange| exchange.kind == FragmentKind::Expansive);
+
+        let settings = self.ctx.get_settings();
+        // The distribution required by hints or enforce settings is never changed, neither is
+        // the distribution of a join whose parent relies on it.
+        let fixed = !redistributed
+            || join.hint.distribution.is_some()
+            || settings.get_enforce_broadcast_join()?
+            || settings.get_enforce_shuffle_join()?;
+        let broadcast_threshold = settings.get_adaptive_broadcast_join_threshold()? as usize;
+
+        if is_broadcast {
+            if !fixed && can_shuffle(&join.join_type) && build.bytes > broadcast_threshold {
+                return Ok(AdaptiveJoinDecision::BroadcastToShuffle);
+            }
+            return Ok(AdaptiveJoinDecision::KeepBroadcast);
+        }
+
+        let probe_is_shuffled = Exchange::from_physical_plan(&join.probe)
+            .is_some_and(|exchange| exchange.kind == FragmentKind::Normal);
+        if !fixed
+            && probe_is_shuffled
+            && can_broadcast(&join.join_type)
+            && build.bytes <= broadcast_threshold
+        {
+            return Ok(AdaptiveJoinDecision::ShuffleToBroadcast);
+        }
+
+        let coalesce_rows = settings.get_adaptive_coalesce_partition_rows()? as usize;
+        let max_partition_rows = partition_rows.iter().copied().max().unwrap_or_default();
+        if max_partition_rows <= coalesce_rows {
+            return Ok(AdaptiveJoinDecision::CoalescePartitions);
+        }
+
+        Ok(AdaptiveJoinDecision::KeepShuffle)
+    }
+
+    // Split the hot keys of the skewed side out of the hash shuffles of both sides of a join.
+    // The build side is already measured, the probe side stage is materialized to measure it
+    // if the bytes read by its scans fit the materialize limit.
+    #[async_backtrace::framed]
+    async fn split_skewed_keys(
+        &mut self,
+        join: &mut HashJoin,
+        build: &MaterializedStage,
+        max_bytes: usize,
+        adaptive: &mut AdaptiveJoinInfo,
+    ) -> Result<bool> {
+        let settings = self.ctx.get_settings();
+        if settings.get_skew_join_mode()? == SkewJoinMode::Disable {
+            return Ok(false);
+        }
+        let min_frequency = settings.get_skew_join_hot_key_percentage()? as f64 / 100.0;
+
+        let (Some(probe_exchange), Some(build_exchange)) = (
+            Exchange::from_physical_plan(&join.probe),
+            Exchange::from_physical_plan(&join.build),
+        ) else {
+            return Ok(false);
+        };
+        if probe_exchange.kind != FragmentKind::Normal
+            || build_exchange.kind != FragmentKind::Normal
+            || probe_exchange.keys.len() != 1
+            || build_exchange.keys.len() != 1
+            || probe_exchange.skew.is_some()
+            || build_exchange.skew.is_some()
+        {
+            return Ok(false);
+        }
+
+        let probe_key = probe_exchange.keys[0].clone();
+        let build_key = build_exchange.keys[0].clone();
+        let probe_stage = probe_exchange.input.clone();
+
+        // The child index of the skewed side, the rows of its largest partition and the hot keys.
+        let mut skewed: Option<(usize, usize, Vec<Scalar>)> = None;
+        if JoinSkew::can_spread(&join.join_type, 1)
+            && self.is_skewed(&adaptive.partition_rows, build.rows)?
+        {
+            let hot_keys = self.hot_keys(&build.blocks, &build_key, build.rows, min_frequency)?;
+            let max_partition_rows = adaptive.partition_rows.iter().copied().max();
+            if !hot_keys.is_empty() {
+                skewed = Some((1, max_partition_rows.unwrap_or_default(), hot_keys));
+            }
+        }
+
+        if JoinSkew::can_spread(&join.join_type, 0)
+            && estimated_stage_bytes(&probe_stage) <= max_bytes
+        {
+            let probe = self.materialize(&probe_stage).await?;
+            adaptive.probe_partition_rows =
+                self.partition_rows(&probe.blocks, vec![probe_key.clone()])?;
+            let scan = self.constant_table_scan(&probe_stage, probe.blocks.as_slice())?;
+            if let Some(exchange) = Exchange::from_mut_physical_plan(&mut join.probe) {
+                exchange.input = scan;
+            }
+
+            let max_partition_rows = adaptive
+                .probe_partition_rows
+                .iter()
+                .copied()
+                .max()
+                .unwrap_or_default();
+            if self.is_skewed(&adaptive.probe_partition_rows, probe.rows)?
+                && skewed
+                    .as_ref()
+                    .is_none_or(|(_, rows, _)| *rows < max_partition_rows)
+            {
+                let hot_keys =
+                    self.hot_keys(&probe.blocks, &probe_key, probe.rows, min_frequency)?;
+                if !hot_keys.is_empty() {
+                    skewed = Some((0, max_partition_rows, hot_keys));
+                }
+            }
+        }
+
+        let Some((child_index, _, hot_keys)) = skewed else {
+            return Ok(false);
+        };
+
+        // The exchange keys are unified, so both sides select the same hot keys.
+        let func_ctx = self.ctx.get_function_context()?;
+        let (Some(probe_predicate), Some(build_predicate)) = (
+            hot_key_predicate(&func_ctx, &probe_key, &hot_keys)?,
+            hot_key_predicate(&func_ctx, &build_key, &hot_keys)?,
+        ) else {
+            return Ok(false);
+        };
+
+        if let Some(exchange) = Exchange::from_mut_physical_plan(&mut join.probe) {
+            exchange.skew = Some(ShuffleSkew {
+                hot_predicate: probe_predicate,
+                broadcast: child_index != 0,
+            });
+        }
+        if let Some(exchange) = Exchange::from_mut_physical_plan(&mut join.build) {
+            exchange.skew = Some(ShuffleSkew {
+                hot_predicate: build_predicate,
+                broadcast: child_index == 0,
+            });
+        }
+        Ok(true)
+    }
+
+    // The largest shuffle partition is skewed if it exceeds adaptive_coalesce_partition_rows and
+    // the average partition by adaptive_skewed_partition_factor.
+    fn is_skewed(&self, partition_rows: &[usize], rows: usize) -> Result<bool> {
+        let Some(max_partition_rows) = partition_rows.iter().copied().max() else {
+            return Ok(false);
+        };
+
+        let settings = self.ctx.get_settings();
+        let coalesce_rows = settings.get_adaptive_coalesce_partition_rows()? as usize;
+        let skewed_factor = settings.get_adaptive_skewed_partition_factor()? as usize;
+        let avg_partition_rows = rows / partition_rows.len();
+        Ok(max_partition_rows > coalesce_rows
+            && max_partition_rows > avg_partition_rows * skewed_factor)
+    }
+
+    // The values of the exchange key whose share of the rows reaches min_frequency,
+    // the most frequent first.
+    fn hot_keys(
+        &self,
+        blocks: &[DataBlock],
+        key: &RemoteExpr,
+        rows: usize,
+        min_frequency: f64,
+    ) -> Result<Vec<Scalar>> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let key = key.as_expr(&BUILTIN_FUNCTIONS);
+
+        let mut key_rows: HashMap<Scalar, usize> = HashMap::new();
+        for block in blocks {
+            let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let column = evaluator
+                .run(&key)?
+                .convert_to_full_column(key.data_type(), block.num_rows());
+            // The null keys never match.
+            for value in column
+                .iter()
+                .filter(|value| !matches!(value, ScalarRef::Null))
+            {
+                *key_rows.entry(value.to_owned()).or_default() += 1;
+            }
+        }
+
+        let min_rows = ((rows as f64 * min_frequency).ceil() as usize).max(1);
+        let mut hot_keys = key_rows
+            .into_iter()
+            .filter(|(_, rows)| *rows >= min_rows)
+            .collect::<Vec<_>>();
+        hot_keys.sort_by(|(l_key, l_rows), (r_key, r_rows)| {
+            r_rows
+                .cmp(l_rows)
+                .then_with(|| l_key.partial_cmp(r_key).unwrap_or(Ordering::Equal))
+        });
+        Ok(hot_keys.into_iter().map(|(key, _)| key).collect())
+    }
+
+    fn rewrite(&mut self, join: &mut HashJoin, decision: &AdaptiveJoinDecision) {
+        let Some(build_exchange) = Exchange::from_mut_physical_plan(&mut join.build) else {
+            return;
+        };
+
+        match decision {
+            AdaptiveJoinDecision::ShuffleToBroadcast => {
+                build_exchange.kind = FragmentKind::Expansive;
+                build_exchange.keys = vec![];
+                build_exchange.skew = None;
+
+                // The probe side stays on its nodes, the parent redistributes the join output.
+                // The broadcast id is kept, merging the runtime filters of the complete build sides
+                // of each node is redundant but correct.
+                if let Some(probe_exchange) = Exchange::from_physical_plan(&join.probe) {
+                    join.probe = probe_exchange.input.clone();
+                }
+            }
+            AdaptiveJoinDecision::BroadcastToShuffle => {
+                build_exchange.kind = FragmentKind::Normal;
+                build_exchange.keys = join.build_keys.clone();
+
+                join.probe = PhysicalPlan::new(Exchange {
+                    meta: PhysicalPlanMeta::with_plan_id("Exchange", self.next_plan_id()),
+                    input: join.probe.clone(),
+                    kind: FragmentKind::Normal,
+                    keys: join.probe_keys.clone(),
+                    ignore_exchange: false,
+                    allow_adjust_parallelism: true,
+                    skew: None,
+                });
+
+                // Each node only builds a part of the hash table, the runtime filters must be merged.
+                if join.broadcast_id.is_none() {
+                    join.broadcast_id = Some(self.ctx.get_next_broadcast_id());
+                }
+            }
+            AdaptiveJoinDecision::CoalescePartitions => {
+                build_exchange.allow_adjust_parallelism = false;
+            }
+            // The skew of the exchanges is set by split_skewed_keys.
+            AdaptiveJoinDecision::SplitSkewedPartition
+            | AdaptiveJoinDecision::KeepShuffle
+            | AdaptiveJoinDecision::KeepBroadcast
+            | AdaptiveJoinDecision::ExceedMaterializeLimit => {}
+        }
+    }
+
+    fn constant_table_scan(
+        &mut self,
+        stage: &PhysicalPlan,
+        blocks: &[DataBlock],
+    ) -> Result<PhysicalPlan> {
+        let output_schema = stage.output_schema()?;
+        let block = match blocks.is_empty() {
+            true => DataBlock::empty_with_schema(output_schema.clone()),
+            false => DataBlock::concat(blocks)?,
+        };
+
+        Ok(PhysicalPlan::new(ConstantTableScan {
+            meta: PhysicalPlanMeta::with_plan_id("ConstantTableScan", self.next_plan_id()),
+            values: block
+                .columns()
+                .iter()
+                .map(BlockEntry::to_column)
+                .collect::<Vec<Column>>(),
+            num_rows: block.num_rows(),
+            output_schema,
+        }))
+    }
+
+    fn next_plan_id(&mut self) -> u32 {
+        let plan_id = self.next_plan_id;
+        self.next_plan_id += 1;
+        plan_id
+    }
+}
+
+// The join types whose build side can be broadcast, the build side of them is not preserved.
+fn can_broadcast(join_type: &JoinType) -> bool {
+    !matches!(
+        join_type,
+        JoinType::Right
+            | JoinType::Full
+            | JoinType::RightAnti
+            | JoinType::RightSemi
+            | JoinType::RightSingle
+            | JoinType::LeftMark
+            | JoinType::InnerAny
+            | JoinType::LeftAny
+            | JoinType::RightAny
+            | JoinType::Asof
+            | JoinType::LeftAsof
+            | JoinType::RightAsof
+    )
+}
+
+// The join types whose join keys can be used as shuffle keys, the join schemas of them
+// are the same as the schemas of their children.
+fn can_shuffle(join_type: &JoinType) -> bool {
+    matches!(
+        join_type,
+        JoinType::Inner | JoinType::LeftSemi | JoinType::LeftAnti
+    )
+}
+
+#[recursive::recursive]
+fn max_plan_id(plan: &PhysicalPlan) -> u32 {
+    plan.children()
+        .map(max_plan_id)
+        .fold(plan.get_id(), std::cmp::max)
+}
+
+// The bytes read by the scans of the stage, an upper bound of its output unless it joins.
+#[recursive::recursive]
+fn estimated_stage_bytes(plan: &PhysicalPlan) -> usize {
+    if let Some(scan) = TableScan::from_physical_plan(plan) {
+        return scan.source.statistics.read_bytes;
+    }
+    if let Some(scan) = ConstantTableScan::from_physical_plan(plan) {
+        return scan.values.iter().map(Column::memory_size).sum();
+    }
+
+    plan.children().map(estimated_stage_bytes).sum()
+}
+
+// Collect the hash joins whose build side is behind a shuffle or broadcast exchange, children
+// first. `redistributed` is whether the output of the plan goes through an exchange before any
+// operator which may rely on its distribution, only filters, projections and partial aggregates
+// are passed through.
+#[recursive::recursive]
+fn collect_adaptive_joins(plan: &PhysicalPlan, redistributed: bool, joins: &mut Vec<(u32, bool)>) {
+    let children_redistributed = Exchange::check_physical_plan(plan)
+        || (redistributed
+            && (Filter::check_physical_plan(plan)
+                || EvalScalar::check_physical_plan(plan)
+                || AggregatePartial::check_physical_plan(plan)));
+    for child in plan.children() {
+        collect_adaptive_joins(child, children_redistributed, joins);
+    }
+
+    let Some(join) = HashJoin::from_physical_plan(plan) else {
+        return;
+    };
+
+    if join.need_hold_hash_table || join.build_side_cache_info.is_some() {
+        return;
+    }
+
+    if let Some(exchange) = Exchange::from_physical_plan(&join.build) {
+        if matches!(
+            exchange.kind,
+            FragmentKind::Normal | FragmentKind::Expansive
+        ) {
+            joins.push((join.get_id(), redistributed));
+        }
+    }
+}
+
+#[recursive::recursive]
+fn find_hash_join(plan: &mut PhysicalPlan, plan_id: u32) -> Option<&mut HashJoin> {
+    if plan.get_id() == plan_id {
+        return HashJoin::from_mut_physical_plan(plan);
+    }
+
+    plan.children_mut()
+        .find_map(|child| find_hash_join(child, plan_id))
+}
@@ -221,6 +221,7 @@ impl FragmentDeriveHandle {
                 destination_ids: get_executors(cluster),
                 shuffle_keys: exchange_sink.keys.clone(),
                 allow_adjust_parallelism: exchange_sink.allow_adjust_parallelism,
+                skew: exchange_sink.skew.clone(),
             })),
             FragmentKind::Merge => Some(MergeExchange::create(
                 cluster.local_id(),
@@ -262,6 +263,7 @@ impl DeriveHandle for FragmentDeriveHandle {
                 destination_fragment_id: usize::MAX,
                 ignore_exchange: exchange.ignore_exchange,
                 allow_adjust_parallelism: exchange.allow_adjust_parallelism,
+                skew: exchange.skew.clone(),
                 meta: PhysicalPlanMeta::with_plan_id("ExchangeSink", plan_id),
             });
 
@@ -95,12 +95,20 @@ pub async fn build_local_pipeline(
 pub async fn build_distributed_pipeline(
     ctx: &Arc<QueryContext>,
     plan: &PhysicalPlan,
+) -> Result<PipelineBuildResult> {
+    let broadcast_plans = build_broadcast_plans(ctx.as_ref(), plan)?;
+    commit_distributed_plans(ctx, broadcast_plans.iter().chain(std::iter::once(plan))).await
+}
+
+/// Build the fragments of the plans and commit their actions to the cluster.
+/// Unlike build_distributed_pipeline, the broadcast plans of the query are not included.
+#[async_backtrace::framed]
+pub async fn commit_distributed_plans<'a>(
+    ctx: &Arc<QueryContext>,
+    plans: impl Iterator<Item = &'a PhysicalPlan>,
 ) -> Result<PipelineBuildResult> {
     let mut fragments_actions = QueryFragmentsActions::create(ctx.clone());
-    for plan in build_broadcast_plans(ctx.as_ref())?
-        .iter()
-        .chain(std::iter::once(plan))
-    {
+    for plan in plans {
         let fragmenter = Fragmenter::try_create(ctx.clone())?;
         let fragments = fragmenter.build_fragment(plan)?;
 
@@ -14,6 +14,8 @@
 
 use databend_common_expression::RemoteExpr;
 
+use crate::physical_plans::ShuffleSkew;
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum DataExchange {
     Merge(MergeExchange),
@@ -36,6 +38,7 @@ pub struct ShuffleDataExchange {
     pub destination_ids: Vec<String>,
     pub shuffle_keys: Vec<RemoteExpr>,
     pub allow_adjust_parallelism: bool,
+    pub skew: Option<ShuffleSkew>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -29,6 +29,7 @@ use crate::servers::flight::v1::exchange::ShuffleExchangeParams;
 use crate::servers::flight::v1::scatter::BroadcastFlightScatter;
 use crate::servers::flight::v1::scatter::FlightScatter;
 use crate::servers::flight::v1::scatter::HashFlightScatter;
+use crate::servers::flight::v1::scatter::SkewFlightScatter;
 use crate::sessions::QueryContext;
 
 pub trait ExchangeInjector: Send + Sync + 'static {
@@ -93,12 +94,23 @@ impl ExchangeInjector for DefaultExchangeInjector {
                     .iter()
                     .position(|x| x == local_id)
                     .unwrap();
-                HashFlightScatter::try_create(
-                    ctx.get_function_context()?,
+                let func_ctx = ctx.get_function_context()?;
+                let scatter = HashFlightScatter::try_create(
+                    func_ctx.clone(),
                     exchange.shuffle_keys.clone(),
                     exchange.destination_ids.len(),
                     local_pos,
-                )?
+                )?;
+                match &exchange.skew {
+                    None => scatter,
+                    Some(skew) => SkewFlightScatter::try_create(
+                        func_ctx,
+                        &skew.hot_predicate,
+                        skew.broadcast,
+                        exchange.destination_ids.len(),
+                        scatter,
+                    )?,
+                }
             }
         }))
     }
@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::servers::flight::v1::scatter::flight_scatter::FlightScatter;
+
+/// Scatters the rows of hot keys apart from the hash shuffle.
+///
+/// The rows of the skewed join side matching the hot keys are spread over all
+/// nodes in round robin, while the matching rows of the other join side are
+/// broadcast, so that no single node has to join all rows of a hot key.
+pub struct SkewFlightScatter {
+    func_ctx: FunctionContext,
+    hot_predicate: Expr,
+    cold_predicate: Expr,
+    broadcast: bool,
+    scatter_size: usize,
+    next_index: AtomicUsize,
+    inner: Box<dyn FlightScatter>,
+}
+
+impl SkewFlightScatter {
+    pub fn try_create(
+        func_ctx: FunctionContext,
+        hot_predicate: &RemoteExpr,
+        broadcast: bool,
+        scatter_size: usize,
+        inner: Box<dyn FlightScatter>,
+    ) -> Result<Box<dyn FlightScatter>> {
+        let hot_predicate = hot_predicate.as_expr(&BUILTIN_FUNCTIONS);
+        let cold_predicate = check_function(
+            None,
+            "not",
+            &[],
+            &[hot_predicate.clone()],
+            &BUILTIN_FUNCTIONS,
+        )?;
+
+        Ok(Box::new(SkewFlightScatter {
+            func_ctx,
+            hot_predicate,
+            cold_predicate,
+            broadcast,
+            scatter_size,
+            next_index: AtomicUsize::new(0),
+            inner,
+        }))
+    }
+
+    fn filter(&self, data_block: &DataBlock, predicate: &Expr) -> Result<DataBlock> {
+        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let predicate = evaluator
+            .run(predicate)?
+            .try_downcast::<BooleanType>()
+            .unwrap();
+        data_block.clone().filter_boolean_value(&predicate)
+    }
+
+    fn scatter_hot_rows(&self, data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        if self.broadcast {
+            return Ok(vec![data_block; self.scatter_size]);
+        }
+
+        let num_rows = data_block.num_rows();
+        let offset = self.next_index.fetch_add(num_rows, Ordering::Relaxed);
+        let indices = (0..num_rows)
+            .map(|row| ((offset + row) % self.scatter_size) as u64)
+            .collect::<Vec<_>>();
+        DataBlock::scatter(&data_block, &indices, self.scatter_size)
+    }
+}
+
+impl FlightScatter for SkewFlightScatter {
+    fn name(&self) -> &'static str {
+        "Skew"
+    }
+
+    fn execute(&self, mut data_block: DataBlock) -> Result<Vec<DataBlock>> {
+        let block_meta = data_block.take_meta();
+        let hot_block = self.filter(&data_block, &self.hot_predicate)?;
+        if hot_block.is_empty() {
+            return self.inner.execute(data_block.add_meta(block_meta)?);
+        }
+
+        let cold_block = self.filter(&data_block, &self.cold_predicate)?;
+        let cold_blocks = self.inner.execute(cold_block)?;
+        let hot_blocks = self.scatter_hot_rows(hot_block)?;
+
+        let mut res = Vec::with_capacity(self.scatter_size);
+        for (cold_block, hot_block) in cold_blocks.into_iter().zip(hot_blocks) {
+            let data_block = DataBlock::concat(&[cold_block, hot_block])?;
+            res.push(data_block.add_meta(block_meta.clone())?);
+        }
+
+        Ok(res)
+    }
+}
@@ -143,11 +143,16 @@ impl FlightSqlService for FlightSqlServiceImpl {
         >,
         Status,
     > {
-        let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
-            .map_err(Status::invalid_argument)?;
         let client_ip = request.remote_addr().map(|a| a.ip().to_string());
-        let session =
-            FlightSqlServiceImpl::auth_user_password(user, password, client_ip.as_deref()).await?;
+        let session = match FlightSqlServiceImpl::get_bearer_token(request.metadata()) {
+            Some(token) => FlightSqlServiceImpl::auth_jwt(token, client_ip.as_deref()).await?,
+            None => {
+                let (user, password) = FlightSqlServiceImpl::get_user_password(request.metadata())
+                    .map_err(Status::invalid_argument)?;
+                FlightSqlServiceImpl::auth_user_password(user, password, client_ip.as_deref())
+                    .await?
+            }
+        };
         let token = Uuid::new_v4().to_string();
         let result = HandshakeResponse {
             protocol_version: 0,
@@ -25,6 +25,8 @@ use tonic::Request;
 use tonic::Status;
 
 use super::status;
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
 use crate::servers::flight_sql::flight_sql_service::FlightSqlServiceImpl;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
@@ -83,6 +85,39 @@ impl FlightSqlServiceImpl {
         Ok((user.to_string(), pass.to_string()))
     }
 
+    pub(super) fn get_bearer_token(metadata: &MetadataMap) -> Option<String> {
+        let authorization = Self::get_header_value(metadata, "authorization")?;
+        authorization
+            .strip_prefix("Bearer ")
+            .map(|token| token.trim().to_string())
+    }
+
+    /// Authenticate with a JWT, e.g. the token signed by the private key of
+    /// a user for key-pair authentication.
+    #[async_backtrace::framed]
+    pub(super) async fn auth_jwt(
+        token: String,
+        client_ip: Option<&str>,
+    ) -> Result<Arc<Session>, Status> {
+        let session_manager = SessionManager::instance();
+        let mut session = session_manager
+            .create_session(SessionType::FlightSQL)
+            .await
+            .map_err(|e| status!("Could not create session", e))?;
+
+        let credential = Credential::Jwt {
+            token,
+            client_ip: client_ip.map(|ip| ip.to_string()),
+        };
+        AuthMgr::instance()
+            .auth(&mut session, &credential, true)
+            .await
+            .map_err(|e| Status::unauthenticated(e.message()))?;
+
+        let session = session_manager.register_session(session)?;
+        Ok(session)
+    }
+
     #[async_backtrace::framed]
     pub(super) async fn auth_user_password(
         user: String,
@@ -100,11 +135,20 @@ impl FlightSqlServiceImpl {
 
         let tenant = session.get_current_tenant();
 
+        let auth_mgr = AuthMgr::instance();
         let identity = UserIdentity::new(&user, "%");
-        let mut user = user_api
+        let (mut user, is_new_user) = match user_api
             .get_user_with_client_ip(&tenant, identity.clone(), client_ip)
             .await
-            .map_err(|e| status!("get_user fail {}", e))?;
+        {
+            Ok(user) => (user, false),
+            Err(e) => (
+                auth_mgr
+                    .new_ldap_user(e, &user)
+                    .map_err(|e| status!("get_user fail {}", e))?,
+                true,
+            ),
+        };
 
         // check global network policy if user is not account admin
         if !user.is_account_admin() {
@@ -148,12 +192,18 @@ impl FlightSqlServiceImpl {
                     }
                 }
             },
+            AuthInfo::Ldap => auth_mgr
+                .auth_ldap(&tenant, &mut user, password.as_deref(), is_new_user)
+                .await
+                .map_err(|e| Status::unauthenticated(e.message())),
             _ => Err(Status::unauthenticated("wrong auth type")),
         };
 
-        user_api
-            .update_user_login_result(tenant, identity, authed.is_ok(), &user)
-            .await?;
+        if !is_new_user {
+            user_api
+                .update_user_login_result(tenant, identity, authed.is_ok(), &user)
+                .await?;
+        }
         authed?;
 
         session
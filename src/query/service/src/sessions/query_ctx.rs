@@ -70,6 +70,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -83,6 +84,7 @@ use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::GrantObject;
 use databend_common_meta_app::principal::OnErrorMode;
+use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
@@ -618,6 +620,79 @@ impl QueryContext {
         let receivers = receivers.get_mut(cte_name).unwrap();
         receivers.pop().unwrap()
     }
+
+    async fn resolve_hidden_column_ids(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<HashSet<ColumnId>> {
+        let table_id = table.get_id();
+        let Ok(user) = self.get_current_user() else {
+            return Ok(HashSet::new());
+        };
+        let roles = self.get_all_effective_roles().await?;
+        let mut entries = user.grants.entries();
+        for role in roles.iter() {
+            entries.extend(role.grants.entries());
+        }
+        let column_grants = entries
+            .iter()
+            .filter_map(|entry| match entry.object() {
+                GrantObject::ColumnById(grant_catalog, db_id, id, column_id)
+                    if grant_catalog == catalog && *id == table_id =>
+                {
+                    Some((*db_id, *column_id, *entry.privileges()))
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let Some((db_id, _, _)) = column_grants.first() else {
+            return Ok(HashSet::new());
+        };
+        let db_id = *db_id;
+
+        let table_object = GrantObject::TableById(catalog.to_string(), db_id, table_id);
+        let legacy_table_object = GrantObject::Table(
+            catalog.to_string(),
+            database.to_string(),
+            table.name().to_string(),
+        );
+        for object in [table_object, legacy_table_object] {
+            if self
+                .validate_privilege(&object, UserPrivilegeType::Select, false)
+                .await
+                .is_ok()
+            {
+                return Ok(HashSet::new());
+            }
+        }
+        let owner_object = OwnershipObject::Table {
+            catalog_name: catalog.to_string(),
+            db_id,
+            table_id,
+        };
+        if self
+            .get_current_session()
+            .has_ownership(&owner_object, false)
+            .await?
+        {
+            return Ok(HashSet::new());
+        }
+
+        let visible_column_ids = column_grants
+            .into_iter()
+            .filter(|(_, _, privileges)| privileges.contains(UserPrivilegeType::Select))
+            .map(|(_, column_id, _)| column_id)
+            .collect::<HashSet<_>>();
+        Ok(table
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| field.column_id())
+            .filter(|column_id| !visible_column_ids.contains(column_id))
+            .collect())
+    }
 }
 
 #[async_trait::async_trait]
@@ -1316,6 +1391,37 @@ impl TableContext for QueryContext {
         Ok(table)
     }
 
+    #[async_backtrace::framed]
+    async fn get_hidden_column_ids(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<HashSet<ColumnId>> {
+        if table.is_temp()
+            || table.is_stage_table()
+            || database.eq_ignore_ascii_case("system")
+            || database.eq_ignore_ascii_case("information_schema")
+        {
+            return Ok(HashSet::new());
+        }
+
+        let table_id = table.get_id();
+        let key = (catalog.to_string(), table_id);
+        if let Some(column_ids) = self.shared.hidden_column_ids.lock().get(&key) {
+            return Ok(column_ids.clone());
+        }
+
+        let column_ids = self
+            .resolve_hidden_column_ids(catalog, database, table)
+            .await?;
+        self.shared
+            .hidden_column_ids
+            .lock()
+            .insert(key, column_ids.clone());
+        Ok(column_ids)
+    }
+
     #[async_backtrace::framed]
     async fn filter_out_copied_files(
         &self,
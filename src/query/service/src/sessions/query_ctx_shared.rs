@@ -49,6 +49,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
@@ -124,6 +125,7 @@ pub struct QueryContextShared {
     pub(super) abort_notify: Arc<WatchNotify>,
     pub(super) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(super) streams_refs: Arc<RwLock<HashMap<DatabaseAndTable, bool>>>,
+    pub(super) hidden_column_ids: Arc<Mutex<HashMap<(String, u64), HashSet<ColumnId>>>>,
     affect: Arc<Mutex<Option<QueryAffect>>>,
     pub(super) catalog_manager: Arc<CatalogManager>,
     pub(super) data_operator: DataOperator,
@@ -228,6 +230,7 @@ impl QueryContextShared {
             abort_notify: Arc::new(WatchNotify::new()),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             streams_refs: Default::default(),
+            hidden_column_ids: Default::default(),
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
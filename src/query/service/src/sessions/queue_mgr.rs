@@ -659,6 +659,7 @@ impl QueryEntry {
 
             // DDL: Heavy actions.
             Plan::OptimizePurge(_)
+            | Plan::OptimizeExpire(_)
             | Plan::OptimizeCompactSegment(_)
             | Plan::OptimizeCompactBlock { .. }
             | Plan::VacuumTable(_)
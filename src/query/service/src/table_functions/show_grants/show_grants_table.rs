@@ -469,6 +469,7 @@ async fn show_account_grants(
     // It will re-write the exists key.
     let mut catalog_db_ids: HashMap<String, Vec<(u64, String)>> = HashMap::new();
     let mut catalog_table_ids: HashMap<String, Vec<(u64, u64, String)>> = HashMap::new();
+    let mut catalog_column_ids: HashMap<String, Vec<(u64, u64, u32, String)>> = HashMap::new();
 
     let procedure_api = UserApiProvider::instance().procedure_api(&tenant);
 
@@ -490,6 +491,15 @@ async fn show_account_grants(
                         )]);
                     }
                 }
+                GrantObject::ColumnById(catalog_name, db_id, table_id, column_id) => {
+                    // column privileges are always listed one by one, `ALL (col)` is not a valid grant
+                    let privileges_str =
+                        UserPrivilegeSet::from(*grant_entry.privileges()).to_string();
+                    catalog_column_ids
+                        .entry(catalog_name.clone())
+                        .or_default()
+                        .push((*db_id, *table_id, *column_id, privileges_str));
+                }
                 GrantObject::DatabaseById(catalog_name, db_id) => {
                     let privileges_str = get_priv_str(&grant_entry);
                     if let Some(dbs_id_priv) = catalog_db_ids.get_mut(catalog_name) {
@@ -770,6 +780,74 @@ async fn show_account_grants(
         }
     }
 
+    for (catalog_name, columns_priv_id) in catalog_column_ids.into_iter() {
+        let catalog = ctx.get_catalog(&catalog_name).await?;
+        let mut db_ids = columns_priv_id
+            .iter()
+            .map(|res| res.0)
+            .collect::<HashSet<u64>>()
+            .into_iter()
+            .collect::<Vec<u64>>();
+        db_ids.sort();
+        let db_names = catalog.mget_database_names_by_ids(&tenant, &db_ids).await?;
+        let db_map = db_ids
+            .into_iter()
+            .zip(db_names.into_iter())
+            .filter_map(|(db_id, db_name)| db_name.map(|db_name| (db_id, db_name)))
+            .collect::<HashMap<_, _>>();
+
+        let mut table_ids = columns_priv_id
+            .iter()
+            .map(|res| res.1)
+            .collect::<HashSet<u64>>()
+            .into_iter()
+            .collect::<Vec<u64>>();
+        table_ids.sort();
+        let table_names = catalog
+            .mget_table_names_by_ids(&tenant, &table_ids, false)
+            .await?;
+        let mut table_map = HashMap::with_capacity(table_ids.len());
+        for (table_id, table_name) in table_ids.into_iter().zip(table_names.into_iter()) {
+            let Some(table_name) = table_name else {
+                continue;
+            };
+            if let Some(table_meta) = catalog.get_table_meta_by_id(table_id).await? {
+                table_map.insert(table_id, (table_name, table_meta.data.schema.clone()));
+            }
+        }
+
+        for (db_id, table_id, column_id, privilege_str) in columns_priv_id.into_iter() {
+            let (Some(db_name), Some((table_name, schema))) =
+                (db_map.get(&db_id), table_map.get(&table_id))
+            else {
+                continue;
+            };
+            // the column may have been dropped
+            let Some(field) = schema.fields().iter().find(|f| f.column_id() == column_id) else {
+                continue;
+            };
+            let grant_str = format!(
+                "GRANT {} ({}) ON '{}'.'{}'.'{}' TO {}",
+                &privilege_str,
+                field.name(),
+                catalog_name,
+                db_name,
+                table_name,
+                identity
+            );
+            object_name.push(format!(
+                "{}.{}.{}.{}",
+                catalog_name,
+                db_name,
+                table_name,
+                field.name()
+            ));
+            object_id.push(Some(table_id.to_string()));
+            privileges.push(privilege_str);
+            grant_list.push(grant_str);
+        }
+    }
+
     let names: Vec<String> = vec![name; privileges.len()];
     let grant_tos: Vec<String> = vec![grant_to; privileges.len()];
     Ok(Some(DataBlock::new_from_columns(vec![
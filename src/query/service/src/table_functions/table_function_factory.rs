@@ -27,6 +27,7 @@ use databend_common_storages_fuse::table_functions::FuseColumnFunc;
 use databend_common_storages_fuse::table_functions::FuseDumpSnapshotsFunc;
 use databend_common_storages_fuse::table_functions::FuseEncodingFunc;
 use databend_common_storages_fuse::table_functions::FuseStatisticsFunc;
+use databend_common_storages_fuse::table_functions::FuseTableRefsFunc;
 use databend_common_storages_fuse::table_functions::FuseTimeTravelSizeFunc;
 use databend_common_storages_fuse::table_functions::FuseVacuumDropAggregatingIndex;
 use databend_common_storages_fuse::table_functions::FuseVacuumDropInvertedIndex;
@@ -146,6 +147,14 @@ impl TableFunctionFactory {
             ),
         );
 
+        creators.insert(
+            "fuse_table_refs".to_string(),
+            (
+                next_id(),
+                Arc::new(TableFunctionTemplate::<FuseTableRefsFunc>::create),
+            ),
+        );
+
         creators.insert(
             "fuse_amend".to_string(),
             (
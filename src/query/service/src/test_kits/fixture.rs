@@ -390,6 +390,7 @@ impl TestFixture {
             table_indexes: None,
             table_constraints: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
             table_partition: None,
         }
@@ -418,6 +419,7 @@ impl TestFixture {
             table_indexes: None,
             table_constraints: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
             table_partition: None,
         }
@@ -458,6 +460,7 @@ impl TestFixture {
             table_indexes: None,
             table_constraints: None,
             attached_columns: None,
+            clone_source: None,
             table_partition: None,
         }
     }
@@ -497,6 +500,7 @@ impl TestFixture {
             table_indexes: None,
             table_constraints: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
         }
     }
@@ -544,6 +548,7 @@ impl TestFixture {
             table_indexes: None,
             table_constraints: None,
             attached_columns: None,
+            clone_source: None,
             table_properties: Default::default(),
             table_partition: None,
         }
@@ -594,6 +599,7 @@ impl TestFixture {
                 engine: "".to_string(),
                 ..Default::default()
             },
+            clone_source: None,
         };
 
         self.default_ctx
@@ -14,13 +14,16 @@
 
 use base64::engine::general_purpose;
 use base64::prelude::*;
+use chrono::Utc;
 use databend_common_base::base::tokio;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::AuthType;
 use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_users::CustomClaims;
 use databend_common_users::EnsureUser;
+use databend_common_users::KeyPairPublicKey;
 use databend_common_users::UserApiProvider;
 use databend_query::auth::AuthMgr;
 use databend_query::auth::Credential;
@@ -1004,3 +1007,200 @@ async fn test_auth_mgr_ensure_default_role() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_key_pair() -> Result<()> {
+    // no jwks endpoint is configured, key-pair tokens are verified with the user's public keys.
+    let _fixture = TestFixture::setup().await?;
+    let mut session = TestFixture::create_dummy_session().await;
+    let auth_mgr = AuthMgr::instance();
+    let tenant = session.get_current_tenant();
+
+    let user_name = "svc";
+    let rsa_pair = RS256KeyPair::generate(2048)?;
+    let rsa_pem = rsa_pair.public_key().to_pem()?;
+    let rsa_fingerprint = KeyPairPublicKey::from_pem(&rsa_pem)?.fingerprint()?;
+    let ed_pair = Ed25519KeyPair::generate();
+    let ed_pem = ed_pair.public_key().to_pem();
+    let ed_fingerprint = KeyPairPublicKey::from_pem(&ed_pem)?.fingerprint()?;
+
+    let mut user_info = UserInfo::new(user_name, "%", AuthInfo::KeyPair);
+    user_info.option = user_info
+        .option
+        .with_rsa_public_key(Some(rsa_pem))
+        .with_rsa_public_key_2(Some(ed_pem));
+    UserApiProvider::instance()
+        .add_user(&tenant, user_info, &CreateOption::Create)
+        .await?;
+
+    let claims = |fingerprint: &str, valid_for: Duration| {
+        Claims::with_custom_claims(CustomClaims::new(), valid_for)
+            .with_issuer(format!("{user_name}.{fingerprint}"))
+            .with_subject(user_name.to_string())
+    };
+
+    // signed by the first key
+    {
+        let token = rsa_pair.sign(claims(&rsa_fingerprint, Duration::from_mins(10)))?;
+        auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Jwt {
+                    token,
+                    client_ip: None,
+                },
+                true,
+            )
+            .await?;
+        assert_eq!(session.get_current_user()?.name, user_name);
+    }
+
+    // signed by the second key
+    {
+        let token = ed_pair.sign(claims(&ed_fingerprint, Duration::from_mins(10)))?;
+        let res = auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Jwt {
+                    token,
+                    client_ip: None,
+                },
+                true,
+            )
+            .await;
+        assert!(res.is_ok());
+    }
+
+    // the token lives too long
+    {
+        let token = rsa_pair.sign(claims(&rsa_fingerprint, Duration::from_hours(2)))?;
+        let res = auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Jwt {
+                    token,
+                    client_ip: None,
+                },
+                true,
+            )
+            .await;
+        assert!(res.is_err());
+        assert!(res
+            .unwrap_err()
+            .message()
+            .contains("token lifetime must not exceed 1 hour"));
+    }
+
+    // signed by a key which is not set on the user
+    {
+        let other_pair = RS256KeyPair::generate(2048)?;
+        let token = other_pair.sign(claims(&rsa_fingerprint, Duration::from_mins(10)))?;
+        let res = auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Jwt {
+                    token,
+                    client_ip: None,
+                },
+                true,
+            )
+            .await;
+        assert!(res.is_err());
+    }
+
+    // key-pair users can not login with password
+    {
+        let res = auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Password {
+                    name: user_name.to_string(),
+                    password: Some(b"password".to_vec()),
+                    client_ip: None,
+                },
+                true,
+            )
+            .await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_auth_mgr_with_key_pair_not_allowed() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+    let mut session = TestFixture::create_dummy_session().await;
+    let auth_mgr = AuthMgr::instance();
+    let tenant = session.get_current_tenant();
+
+    let key_pair = RS256KeyPair::generate(2048)?;
+    let pem = key_pair.public_key().to_pem()?;
+    let fingerprint = KeyPairPublicKey::from_pem(&pem)?.fingerprint()?;
+    let token = |user_name: &str| {
+        let claims = Claims::with_custom_claims(CustomClaims::new(), Duration::from_mins(10))
+            .with_issuer(format!("{user_name}.{fingerprint}"))
+            .with_subject(user_name.to_string());
+        key_pair.sign(claims)
+    };
+
+    // the public key is not another credential of a password user
+    {
+        let user_name = "pwd_with_key";
+        let auth_info = AuthInfo::new(
+            AuthType::Sha256Password,
+            &Some("password".to_string()),
+            false,
+        )?;
+        let mut user_info = UserInfo::new(user_name, "%", auth_info);
+        user_info.option = user_info.option.with_rsa_public_key(Some(pem.clone()));
+        UserApiProvider::instance()
+            .add_user(&tenant, user_info, &CreateOption::Create)
+            .await?;
+
+        let res = auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Jwt {
+                    token: token(user_name)?,
+                    client_ip: None,
+                },
+                true,
+            )
+            .await;
+        assert!(res.is_err());
+        assert!(res
+            .unwrap_err()
+            .message()
+            .contains("not configured for key-pair authentication"));
+    }
+
+    // locked users can't login with a valid token
+    {
+        let user_name = "locked_key_pair";
+        let mut user_info = UserInfo::new(user_name, "%", AuthInfo::KeyPair);
+        user_info.option = user_info.option.with_rsa_public_key(Some(pem.clone()));
+        user_info.update_lockout_time(Utc::now() + chrono::Duration::minutes(10));
+        UserApiProvider::instance()
+            .add_user(&tenant, user_info, &CreateOption::Create)
+            .await?;
+
+        let res = auth_mgr
+            .auth(
+                &mut session,
+                &Credential::Jwt {
+                    token: token(user_name)?,
+                    client_ip: None,
+                },
+                true,
+            )
+            .await;
+        assert!(res.is_err());
+        assert!(res
+            .unwrap_err()
+            .message()
+            .contains("because of too many password fails"));
+    }
+
+    Ok(())
+}
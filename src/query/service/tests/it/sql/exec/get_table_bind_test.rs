@@ -45,6 +45,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::ColumnId;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
@@ -862,6 +863,17 @@ impl TableContext for CtxDelegation {
         self.get_table(catalog, database, table).await
     }
 
+    async fn get_hidden_column_ids(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &dyn Table,
+    ) -> Result<HashSet<ColumnId>> {
+        self.ctx
+            .get_hidden_column_ids(catalog, database, table)
+            .await
+    }
+
     async fn filter_out_copied_files(
         &self,
         _catalog_name: &str,
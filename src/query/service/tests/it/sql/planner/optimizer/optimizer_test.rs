@@ -390,6 +390,8 @@ impl<'a> SExprVisitor for StatsApplier<'a> {
                     table_stats: Some(table_stats),
                     column_stats,
                     histograms: HashMap::new(),
+                    most_common_values: HashMap::new(),
+                    multi_column_stats: vec![],
                 });
 
                 return Ok(VisitAction::Replace(
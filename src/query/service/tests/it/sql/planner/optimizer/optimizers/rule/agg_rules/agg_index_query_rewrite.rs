@@ -86,6 +86,7 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         table_indexes: None,
         table_constraints: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     }
@@ -342,6 +342,8 @@ fn build_test_segment_info(
         virtual_block_meta: None,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        spatial_stats: None,
+        deletion_vector: None,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -365,6 +367,7 @@ fn build_test_segment_info(
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     Ok(SegmentInfo::new(block_metas, statistics))
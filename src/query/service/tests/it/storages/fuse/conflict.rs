@@ -105,6 +105,7 @@ fn test_resolvable_delete_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let mut latest_snapshot = new_empty_snapshot(TableSchema::default(), None);
@@ -131,6 +132,7 @@ fn test_resolvable_delete_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let removed_statistics = Statistics {
@@ -150,6 +152,7 @@ fn test_resolvable_delete_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let merged_statistics = Statistics {
@@ -169,6 +172,7 @@ fn test_resolvable_delete_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -211,6 +215,7 @@ fn test_resolvable_delete_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
     assert_eq!(actual, expected);
 }
@@ -250,6 +255,7 @@ fn test_resolvable_replace_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let mut latest_snapshot = new_empty_snapshot(TableSchema::default(), None);
@@ -276,6 +282,7 @@ fn test_resolvable_replace_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let removed_statistics = Statistics {
@@ -295,6 +302,7 @@ fn test_resolvable_replace_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let merged_statistics = Statistics {
@@ -314,6 +322,7 @@ fn test_resolvable_replace_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
 
     let ctx = ConflictResolveContext::ModifiedSegmentExistsInLatest(SnapshotChanges {
@@ -361,6 +370,7 @@ fn test_resolvable_replace_conflict() {
         cluster_stats: None,
         virtual_block_count: None,
         additional_stats_meta: None,
+        deleted_row_count: None,
     };
     assert_eq!(actual, expected);
 }
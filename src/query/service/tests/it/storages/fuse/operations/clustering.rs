@@ -55,6 +55,7 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         table_indexes: None,
         table_constraints: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     };
@@ -43,6 +43,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
@@ -725,6 +726,15 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    async fn get_hidden_column_ids(
+        &self,
+        _catalog: &str,
+        _database: &str,
+        _table: &dyn Table,
+    ) -> Result<HashSet<ColumnId>> {
+        todo!()
+    }
+
     async fn filter_out_copied_files(
         &self,
         _catalog_name: &str,
@@ -109,6 +109,7 @@ async fn test_block_pruner() -> Result<()> {
         table_indexes: None,
         table_constraints: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     };
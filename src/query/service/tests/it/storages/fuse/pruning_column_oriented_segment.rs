@@ -180,6 +180,7 @@ async fn test_snapshot_pruner() -> Result<()> {
         table_indexes: None,
         table_constraints: None,
         attached_columns: None,
+        clone_source: None,
         table_partition: None,
         table_properties: None,
     };
@@ -27,3 +27,4 @@ pub use settings_default::SettingRange;
 pub use settings_default::SettingScope;
 pub use settings_getter_setter::FlightCompression;
 pub use settings_getter_setter::OutofMemoryBehavior;
+pub use settings_getter_setter::SkewJoinMode;
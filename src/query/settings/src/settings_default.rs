@@ -36,6 +36,7 @@ static COST_FACTOR_COMPUTE_PER_ROW: u64 = 1;
 static COST_FACTOR_HASH_TABLE_PER_ROW: u64 = 10;
 static COST_FACTOR_AGGREGATE_PER_ROW: u64 = 5;
 static COST_FACTOR_NETWORK_PER_ROW: u64 = 50;
+static COST_FACTOR_SORT_PER_ROW: u64 = 8;
 
 // Settings for readability and writability of tags.
 // we will not be able to safely get its value when set to only write.
@@ -558,6 +559,55 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_adaptive_query_execution", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables adaptive query execution, which materializes the build side stages of distributed joins and re-plans the join distribution with the actual statistics.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("adaptive_broadcast_join_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(32 * 1024 * 1024),
+                    desc: "Sets the maximum size in bytes of a materialized build side that adaptive query execution broadcasts.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("adaptive_max_materialize_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(128 * 1024 * 1024),
+                    desc: "Sets the maximum size in bytes read by the scans of a join side stage that adaptive query execution materializes, larger stages keep the original plan.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("adaptive_skewed_partition_factor", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(5),
+                    desc: "Sets the factor by which a shuffle partition must exceed the average partition to be considered skewed by adaptive query execution.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(2..=u64::MAX)),
+                }),
+                ("adaptive_coalesce_partition_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the number of rows below which adaptive query execution coalesces the shuffle partitions of a node into a single stream.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("skew_join_mode", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("auto")),
+                    desc: "Sets how shuffle joins handle hot keys: auto handles the keys whose frequency in the most common values statistics exceeds skew_join_hot_key_percentage, force handles all the most common values, disable turns it off.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::String(vec![String::from("auto"), String::from("force"), String::from("disable")])),
+                }),
+                ("skew_join_hot_key_percentage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "Sets the percentage of the rows of a join side above which a join key is considered hot.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(1..=100)),
+                }),
                 ("grouping_sets_to_union", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables grouping sets to union.",
@@ -600,6 +650,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_plan_baseline", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reproducing the plan baseline of a query if one exists.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_query_result_cache", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables caching query results to improve performance for identical queries.",
@@ -755,6 +812,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("join_lazy_read_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum estimated percentage of table scan rows kept by a join to read the other columns of the scan after the join. Setting it to 0 disables the optimization.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("parquet_fast_read_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Parquet file with smaller size will be read as a whole file, instead of column by column. Default value: 1MB",
@@ -848,6 +912,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("max_analyze_most_common_values", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(20),
+                    desc: "Sets the maximum number of most common values collected for each column during analyzing table, 0 disables it.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1000)),
+                }),
                 ("enable_auto_analyze", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables automatically analyze after write, 0 for disable, 1 for enable",
@@ -1145,6 +1216,13 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("cost_factor_sort_per_row", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(COST_FACTOR_SORT_PER_ROW),
+                    desc: "Cost factor of sorting a data row",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 // this setting will be removed when geometry type stable.
                 ("enable_geo_create_table", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
@@ -1153,6 +1231,20 @@ impl DefaultSettings {
                     scope: SettingScope::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_spatial_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables spatial join for joins on spatial predicates, e.g. st_contains.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_sort_merge_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables sort-merge join for equi-joins when the cost model prefers it over hash join.",
+                    mode: SettingMode::Both,
+                    scope: SettingScope::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("idle_transaction_timeout_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(4 * 60 * 60),
                     desc: "Set the timeout in seconds for active session without any query",
@@ -48,6 +48,13 @@ pub enum OutofMemoryBehavior {
     Spilling,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SkewJoinMode {
+    Auto,
+    Force,
+    Disable,
+}
+
 impl SpillFileFormat {
     pub fn range() -> Vec<String> {
         ["arrow", "parquet"]
@@ -400,6 +407,45 @@ impl Settings {
         Ok(self.try_get_u64("enforce_shuffle_join")? != 0)
     }
 
+    pub fn get_enable_adaptive_query_execution(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_adaptive_query_execution")? != 0)
+    }
+
+    pub fn get_adaptive_broadcast_join_threshold(&self) -> Result<u64> {
+        self.try_get_u64("adaptive_broadcast_join_threshold")
+    }
+
+    pub fn get_adaptive_max_materialize_bytes(&self) -> Result<u64> {
+        self.try_get_u64("adaptive_max_materialize_bytes")
+    }
+
+    pub fn get_adaptive_skewed_partition_factor(&self) -> Result<u64> {
+        self.try_get_u64("adaptive_skewed_partition_factor")
+    }
+
+    pub fn get_adaptive_coalesce_partition_rows(&self) -> Result<u64> {
+        self.try_get_u64("adaptive_coalesce_partition_rows")
+    }
+
+    pub fn get_skew_join_mode(&self) -> Result<SkewJoinMode> {
+        match self
+            .try_get_string("skew_join_mode")?
+            .to_lowercase()
+            .as_str()
+        {
+            "auto" => Ok(SkewJoinMode::Auto),
+            "force" => Ok(SkewJoinMode::Force),
+            "disable" => Ok(SkewJoinMode::Disable),
+            _ => Err(ErrorCode::BadArguments(
+                "skew_join_mode must be one of auto, force or disable",
+            )),
+        }
+    }
+
+    pub fn get_skew_join_hot_key_percentage(&self) -> Result<u64> {
+        self.try_get_u64("skew_join_hot_key_percentage")
+    }
+
     pub fn get_enable_merge_into_row_fetch(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_merge_into_row_fetch")? != 0)
     }
@@ -461,6 +507,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_planner_cache")? != 0)
     }
 
+    pub fn get_enable_plan_baseline(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_baseline")? != 0)
+    }
+
     pub fn get_enable_experimental_procedure(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_procedure")? != 0)
     }
@@ -557,6 +607,10 @@ impl Settings {
         self.try_get_u64("lazy_read_threshold")
     }
 
+    pub fn get_join_lazy_read_threshold(&self) -> Result<u64> {
+        self.try_get_u64("join_lazy_read_threshold")
+    }
+
     pub fn get_parquet_fast_read_bytes(&self) -> Result<u64> {
         self.try_get_u64("parquet_fast_read_bytes")
     }
@@ -649,6 +703,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_analyze_histogram")? != 0)
     }
 
+    pub fn get_max_analyze_most_common_values(&self) -> Result<u64> {
+        self.try_get_u64("max_analyze_most_common_values")
+    }
+
     pub fn get_enable_auto_analyze(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_auto_analyze")? != 0)
     }
@@ -829,10 +887,22 @@ impl Settings {
         self.try_get_u64("cost_factor_network_per_row")
     }
 
+    pub fn get_cost_factor_sort_per_row(&self) -> Result<u64> {
+        self.try_get_u64("cost_factor_sort_per_row")
+    }
+
     pub fn get_enable_geo_create_table(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_geo_create_table")? != 0)
     }
 
+    pub fn get_enable_spatial_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_spatial_join")? != 0)
+    }
+
+    pub fn get_enable_sort_merge_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_sort_merge_join")? != 0)
+    }
+
     pub fn get_idle_transaction_timeout_secs(&self) -> Result<u64> {
         self.try_get_u64("idle_transaction_timeout_secs")
     }
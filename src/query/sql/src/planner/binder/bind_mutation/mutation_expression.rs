@@ -40,6 +40,7 @@ use crate::optimizer::OptimizerContext;
 use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
 use crate::plans::Join;
+use crate::plans::JoinHint;
 use crate::plans::JoinType;
 use crate::plans::MutationSource;
 use crate::plans::RelOperator;
@@ -323,6 +324,8 @@ impl MutationExpression {
                             is_lateral: false,
                             single_to_inner: None,
                             build_side_cache_info: None,
+                            hint: JoinHint::default(),
+                            skew: None,
                         };
                         s_expr = SExpr::create_binary(
                             Arc::new(join_plan.into()),
@@ -297,6 +297,7 @@ impl Binder {
             engine: Some(engine),
             uri_location: None,
             cluster_by: None,
+            ttl: None,
             table_options: Default::default(),
             iceberg_table_partition: None,
             table_properties: Default::default(),
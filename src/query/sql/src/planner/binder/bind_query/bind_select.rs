@@ -72,6 +72,7 @@ impl Binder {
                     hints, e
                 );
             }
+            self.opt_hints_optimizer(hints);
         }
 
         // whether allow rewrite virtual column and pushdown
@@ -44,6 +44,7 @@ use crate::plans::Filter;
 use crate::plans::HashJoinBuildCacheInfo;
 use crate::plans::Join;
 use crate::plans::JoinEquiCondition;
+use crate::plans::JoinHint;
 use crate::plans::JoinType;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -455,6 +456,8 @@ impl Binder {
             is_lateral,
             single_to_inner: None,
             build_side_cache_info,
+            hint: JoinHint::default(),
+            skew: None,
         };
         Ok(SExpr::create_binary(
             Arc::new(logical_join.into()),
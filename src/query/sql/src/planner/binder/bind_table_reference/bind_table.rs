@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::SampleConfig;
 use databend_common_ast::ast::Statement;
@@ -21,19 +23,32 @@ use databend_common_ast::ast::WithOptions;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_with_options::check_with_opt_valid;
 use databend_common_catalog::table_with_options::get_with_opt_consume;
 use databend_common_catalog::table_with_options::get_with_opt_max_batch_size;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::tag::TaggableObject;
 use databend_common_storages_basic::view_table::QUERY;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_data_mask_feature::get_datamask_handler;
 use databend_storages_common_table_meta::table::get_change_type;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_TAGGED;
 
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
 use crate::optimizer::ir::SExpr;
+use crate::resolve_type_name_by_str;
 use crate::BindContext;
+use crate::IndexType;
 impl Binder {
     /// Bind a base table.
     /// A base table is a table that is not a view or CTE.
@@ -170,7 +185,7 @@ impl Binder {
             let change_type = get_change_type(&table_name_alias);
             if change_type.is_some() {
                 let table_index = self.metadata.write().add_table(
-                    catalog,
+                    catalog.clone(),
                     database.clone(),
                     table_meta.clone(),
                     table_name_alias,
@@ -180,6 +195,8 @@ impl Binder {
                     cte_suffix_name,
                     false,
                 );
+                self.bind_tag_mask_policies(&catalog, &database, table_index)?;
+
                 let (s_expr, mut bind_context) = self.bind_base_table(
                     bind_context,
                     database.as_str(),
@@ -299,6 +316,7 @@ impl Binder {
                     cte_suffix_name,
                     bind_context.allow_virtual_column,
                 );
+                self.bind_tag_mask_policies(&catalog, &database, table_index)?;
 
                 let (s_expr, mut bind_context) = self.bind_base_table(
                     bind_context,
@@ -316,6 +334,139 @@ impl Binder {
         }
     }
 
+    /// Resolve the masking policies the columns of a table get through tags,
+    /// the tags set on a column take precedence over those set on its table and database.
+    fn bind_tag_mask_policies(
+        &self,
+        catalog: &str,
+        database: &str,
+        table_index: IndexType,
+    ) -> Result<()> {
+        if LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::DataMask)
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let table = self.metadata.read().table(table_index).table();
+        let table_info = table.get_table_info();
+        if catalog != CATALOG_DEFAULT
+            || table.is_temp()
+            || table_info.db_type != DatabaseType::NormalDB
+        {
+            return Ok(());
+        }
+        // Only the tables marked when tags are set on them, their columns or their
+        // database can be masked by tags, a stream is marked by its source table.
+        if !table.is_stream() && !table_info.options().contains_key(OPT_KEY_TAGGED) {
+            return Ok(());
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let handler = get_datamask_handler();
+
+        let (tag_policies, policy_metas) = databend_common_base::runtime::block_on(async {
+            let catalog = self.ctx.get_catalog(catalog).await?;
+            let (db_id, table_id) = if table.is_stream() {
+                // A stream is masked by the tags set on its source table.
+                let options = table_info.options();
+                let table_id = options
+                    .get(OPT_KEY_SOURCE_TABLE_ID)
+                    .ok_or_else(|| ErrorCode::Internal("source table id must be set"))?
+                    .parse::<u64>()?;
+                let Some(source_meta) = catalog.get_table_meta_by_id(table_id).await? else {
+                    return Ok((vec![], BTreeMap::new()));
+                };
+                let source_options = &source_meta.data.options;
+                if !source_options.contains_key(OPT_KEY_TAGGED) {
+                    return Ok((vec![], BTreeMap::new()));
+                }
+                let db_id = options
+                    .get(OPT_KEY_SOURCE_DATABASE_ID)
+                    .or_else(|| source_options.get(OPT_KEY_DATABASE_ID))
+                    .ok_or_else(|| ErrorCode::Internal("source database id must be set"))?
+                    .parse::<u64>()?;
+                (db_id, table_id)
+            } else {
+                let db_id = catalog
+                    .get_database(&tenant, database)
+                    .await?
+                    .get_db_info()
+                    .database_id
+                    .db_id;
+                (db_id, table_info.ident.table_id)
+            };
+            let tag_policies = handler
+                .get_tag_data_masks(meta_api.clone(), &tenant, db_id, table_id)
+                .await?;
+
+            let mut policy_metas = BTreeMap::new();
+            for tag_policy in &tag_policies {
+                if policy_metas.contains_key(&tag_policy.policy_id) {
+                    continue;
+                }
+                // A policy dropped after being set on a tag masks nothing.
+                if let Ok(policy) = handler
+                    .get_data_mask_by_id(meta_api.clone(), &tenant, tag_policy.policy_id)
+                    .await
+                {
+                    policy_metas.insert(tag_policy.policy_id, policy.data);
+                }
+            }
+            Ok::<_, ErrorCode>((tag_policies, policy_metas))
+        })?;
+
+        if tag_policies.is_empty() {
+            return Ok(());
+        }
+
+        // Smaller rank wins: column, then table, then database. Ties are broken by tag id.
+        let rank = |object: &TaggableObject| match object {
+            TaggableObject::Column { .. } => 0,
+            TaggableObject::Table { .. } => 1,
+            TaggableObject::Database { .. } => 2,
+        };
+        let mut tag_policies = tag_policies;
+        tag_policies.sort_by_key(|p| (rank(&p.object), p.tag_id));
+
+        let mut policies = BTreeMap::new();
+        for field in table_info.meta.schema.fields() {
+            let column_type = field.data_type().remove_nullable();
+            for tag_policy in &tag_policies {
+                if let TaggableObject::Column { column_id, .. } = tag_policy.object {
+                    if column_id != field.column_id {
+                        continue;
+                    }
+                }
+                let Some(policy) = policy_metas.get(&tag_policy.policy_id) else {
+                    continue;
+                };
+                // A policy replaced by one with more arguments after it was set on the tag
+                // can't mask the column on its own, the read fails instead of leaving it unmasked.
+                let [(_, arg_type)] = policy.args.as_slice() else {
+                    return Err(ErrorCode::InvalidArgument(format!(
+                        "Mask policy (id {}) set on tag (id {}) has {} arguments, only single argument policies can be set on a tag",
+                        tag_policy.policy_id,
+                        tag_policy.tag_id,
+                        policy.args.len()
+                    )));
+                };
+                // A tag masks a column only if the column type matches the policy.
+                if resolve_type_name_by_str(arg_type, false)?.remove_nullable() == column_type {
+                    policies.insert(field.column_id, tag_policy.policy_id);
+                    break;
+                }
+            }
+        }
+
+        self.metadata
+            .write()
+            .set_table_tag_mask_policies(table_index, policies);
+        Ok(())
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
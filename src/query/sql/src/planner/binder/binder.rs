@@ -21,6 +21,7 @@ use std::time::Instant;
 use chrono_tz::Tz;
 use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::OptimizerHint;
 use databend_common_ast::ast::Settings;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::parser::parse_sql;
@@ -75,6 +76,7 @@ use crate::BindContext;
 use crate::ColumnBinding;
 use crate::MetadataRef;
 use crate::NameResolutionContext;
+use crate::OptimizerHints;
 use crate::ScalarExpr;
 use crate::TypeChecker;
 use crate::Visibility;
@@ -331,6 +333,8 @@ impl Binder {
                 self.bind_vacuum_temporary_files(bind_context, stmt).await?
             }
             Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
+            Statement::CreateStatistics(stmt) => self.bind_create_statistics(stmt).await?,
+            Statement::DropStatistics(stmt) => self.bind_drop_statistics(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
             // Dictionaries
             Statement::CreateDictionary(stmt) => self.bind_create_dictionary(stmt).await?,
@@ -687,6 +691,13 @@ impl Binder {
             }
             Statement::DropDatamaskPolicy(stmt) => self.bind_drop_data_mask_policy(stmt).await?,
             Statement::DescDatamaskPolicy(stmt) => self.bind_desc_data_mask_policy(stmt).await?,
+            Statement::CreateTag(stmt) => self.bind_create_tag(stmt).await?,
+            Statement::DropTag(stmt) => self.bind_drop_tag(stmt).await?,
+            Statement::AlterTag(stmt) => self.bind_alter_tag(stmt).await?,
+            Statement::CreatePlanBaseline(stmt) => {
+                self.bind_create_plan_baseline(bind_context, stmt).await?
+            }
+            Statement::DropPlanBaseline(stmt) => self.bind_drop_plan_baseline(stmt).await?,
             Statement::CreateNetworkPolicy(stmt) => self.bind_create_network_policy(stmt).await?,
             Statement::AlterNetworkPolicy(stmt) => self.bind_alter_network_policy(stmt).await?,
             Statement::DropNetworkPolicy(stmt) => self.bind_drop_network_policy(stmt).await?,
@@ -929,6 +940,42 @@ impl Binder {
             .set_batch_settings(&hint_settings, true)
     }
 
+    /// Record join hints such as `LEADING` and `BROADCAST` in the metadata,
+    /// they are applied by the optimizer.
+    pub(crate) fn opt_hints_optimizer(&mut self, hints: &Hint) {
+        if hints.optimizer_hints.is_empty() {
+            return;
+        }
+        let normalize = |tables: &[Identifier]| -> Vec<String> {
+            tables
+                .iter()
+                .map(|table| normalize_identifier(table, &self.name_resolution_ctx).name)
+                .collect()
+        };
+        let mut optimizer_hints = OptimizerHints::default();
+        for hint in &hints.optimizer_hints {
+            match hint {
+                OptimizerHint::Leading(tables) => {
+                    if optimizer_hints.leading.is_empty() {
+                        optimizer_hints.leading = normalize(tables);
+                    }
+                }
+                OptimizerHint::Broadcast(tables) => {
+                    optimizer_hints.broadcast.extend(normalize(tables))
+                }
+                OptimizerHint::Shuffle(tables) => optimizer_hints.shuffle.extend(normalize(tables)),
+                OptimizerHint::HashJoin(tables) => {
+                    optimizer_hints.hash_join.push(normalize(tables))
+                }
+                OptimizerHint::NoRuntimeFilter(tables) => {
+                    optimizer_hints.no_runtime_filter.extend(normalize(tables))
+                }
+                OptimizerHint::NoDecorrelate => optimizer_hints.no_decorrelate = true,
+            }
+        }
+        self.metadata.write().add_optimizer_hints(optimizer_hints);
+    }
+
     pub fn set_bind_recursive_cte(&mut self, val: bool) {
         self.bind_recursive_cte = val;
     }
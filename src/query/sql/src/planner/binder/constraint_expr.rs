@@ -108,6 +108,9 @@ impl ConstraintExprBinder {
         }
         match constraint {
             Constraint::Check(_) => constraint_name.push_str("check"),
+            Constraint::PrimaryKey(_) => constraint_name.push_str("pkey"),
+            Constraint::Unique(_) => constraint_name.push_str("key"),
+            Constraint::ForeignKey(_) => constraint_name.push_str("fkey"),
         }
         constraint_name
     }
@@ -540,6 +540,7 @@ impl Binder {
                         value: Literal::UInt64(1),
                     },
                 }],
+                optimizer_hints: vec![],
             };
             if let Some(e) = self.opt_hints_set_var(&mut output_context, &hints).err() {
                 warn!(
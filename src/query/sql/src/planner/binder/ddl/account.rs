@@ -21,6 +21,7 @@ use databend_common_ast::ast::AlterUserStmt;
 use databend_common_ast::ast::CreateUserStmt;
 use databend_common_ast::ast::GrantObjectName;
 use databend_common_ast::ast::GrantStmt;
+use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::PrincipalIdentity as AstPrincipalIdentity;
 use databend_common_ast::ast::RevokeStmt;
 use databend_common_ast::ast::ShowGranteesOfRoleStmt;
@@ -40,6 +41,7 @@ use databend_common_meta_app::principal::ProcedureNameIdent;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::principal::UserOption;
 use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_users::KeyPairPublicKey;
 use databend_common_users::UserApiProvider;
 
 use crate::binder::show::get_show_options;
@@ -78,7 +80,7 @@ impl Binder {
                 let priv_types = grant_object.available_privileges(false);
                 let plan: GrantPrivilegePlan = GrantPrivilegePlan {
                     principal: principal.clone().into(),
-                    on: grant_object,
+                    on: vec![grant_object],
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -91,7 +93,24 @@ impl Binder {
                 }
                 let plan = GrantPrivilegePlan {
                     principal: principal.clone().into(),
-                    on: grant_object,
+                    on: vec![grant_object],
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let grant_objects = self.convert_to_column_grant_objects(level, columns).await?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(x.clone().into());
+                }
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone().into(),
+                    on: grant_objects,
                     priv_types,
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
@@ -145,6 +164,23 @@ impl Binder {
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privileges,
+                columns,
+                level,
+            } => {
+                let grant_objects = self.convert_to_column_grant_objects(level, columns).await?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                for x in privileges {
+                    priv_types.set_privilege(x.clone().into());
+                }
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone().into(),
+                    on: grant_objects,
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
         }
     }
 
@@ -223,6 +259,63 @@ impl Binder {
         }
     }
 
+    // Column privileges are only allowed on a table, each column is resolved to
+    // its column id so that renaming the column does not lose the grant.
+    async fn convert_to_column_grant_objects(
+        &self,
+        source: &AccountMgrLevel,
+        columns: &[Identifier],
+    ) -> Result<Vec<GrantObject>> {
+        let AccountMgrLevel::Table(database_name, table_name) = source else {
+            return Err(ErrorCode::IllegalGrant(
+                "Illegal GRANT/REVOKE command; column privileges can only be granted on a table",
+            ));
+        };
+        let catalog_name = self.ctx.get_current_catalog();
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&catalog_name).await?;
+        let database_name = database_name
+            .clone()
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        if self
+            .ctx
+            .is_temp_table(&catalog_name, &database_name, table_name)
+        {
+            return Err(ErrorCode::StorageOther(format!(
+                "{}.{}.{} is a temporary table, cannot grant privileges on it",
+                catalog_name, database_name, table_name
+            )));
+        }
+        let db_id = catalog
+            .get_database(&tenant, &database_name)
+            .await?
+            .get_db_info()
+            .database_id
+            .db_id;
+        let table = catalog
+            .get_table(&tenant, &database_name, table_name)
+            .await?;
+        let table_id = table.get_id();
+        let schema = table.schema();
+
+        let mut grant_objects = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column_name = self.normalize_identifier(column).name;
+            let field = schema.field_with_name(&column_name).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "Unknown column {} in table {}.{}",
+                    column_name, database_name, table_name
+                ))
+            })?;
+            let object =
+                GrantObject::ColumnById(catalog_name.clone(), db_id, table_id, field.column_id());
+            if !grant_objects.contains(&object) {
+                grant_objects.push(object);
+            }
+        }
+        Ok(grant_objects)
+    }
+
     // Some old query version use GrantObject::Table store table name.
     // So revoke need compat the old version.
     pub(in crate::planner::binder) async fn convert_to_revoke_grant_object(
@@ -330,6 +423,11 @@ impl Binder {
                 let workload_group = workload_mgr.get_id_by_name(name).await?;
                 user_option.apply(&UserOptionItem::SetWorkloadGroup(workload_group));
             } else {
+                if let UserOptionItem::SetRsaPublicKey(key)
+                | UserOptionItem::SetRsaPublicKey2(key) = &option
+                {
+                    KeyPairPublicKey::from_pem(key)?;
+                }
                 user_option.apply(option);
             }
         }
@@ -405,6 +503,11 @@ impl Binder {
                 let workload_group = workload_mgr.get_id_by_name(name).await?;
                 user_option.apply(&UserOptionItem::SetWorkloadGroup(workload_group));
             } else {
+                if let UserOptionItem::SetRsaPublicKey(key)
+                | UserOptionItem::SetRsaPublicKey2(key) = &option
+                {
+                    KeyPairPublicKey::from_pem(key)?;
+                }
                 user_option.apply(option);
             }
         }
@@ -14,7 +14,9 @@
 
 use databend_common_ast::ast::CreateConnectionStmt;
 use databend_common_ast::ast::UriLocation;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::SECRET_STORAGE_TYPE;
 
 use crate::binder::parse_storage_params_from_uri;
 use crate::plans::CreateConnectionPlan;
@@ -27,13 +29,23 @@ impl Binder {
         &mut self,
         stmt: &CreateConnectionStmt,
     ) -> Result<Plan> {
-        let mut location = UriLocation::new(
-            stmt.storage_type.clone(),
-            "".to_string(),
-            "/".to_string(),
-            stmt.storage_params.clone(),
-        );
-        parse_storage_params_from_uri(&mut location, None, "when CREATE CONNECTION").await?;
+        // A secret connection only holds a secret value (e.g. an encryption key),
+        // which can be referenced by `get_secret('<connection_name>')`.
+        if stmt.storage_type.eq_ignore_ascii_case(SECRET_STORAGE_TYPE) {
+            if stmt.storage_params.len() != 1 || !stmt.storage_params.contains_key("secret") {
+                return Err(ErrorCode::BadArguments(
+                    "Secret connection only accepts the `SECRET` option",
+                ));
+            }
+        } else {
+            let mut location = UriLocation::new(
+                stmt.storage_type.clone(),
+                "".to_string(),
+                "/".to_string(),
+                stmt.storage_params.clone(),
+            );
+            parse_storage_params_from_uri(&mut location, None, "when CREATE CONNECTION").await?;
+        }
         Ok(Plan::CreateConnection(Box::new(CreateConnectionPlan {
             create_option: stmt.create_option.clone().into(),
             name: stmt.name.to_string(),
@@ -36,6 +36,7 @@ use log::debug;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::CloneDatabaseSource;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
@@ -44,6 +45,7 @@ use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowCreateDatabasePlan;
+use crate::plans::TagObject;
 use crate::plans::UndropDatabasePlan;
 use crate::BindContext;
 use crate::SelectBuilder;
@@ -214,6 +216,14 @@ impl Binder {
                     database,
                 },
             ))),
+
+            AlterDatabaseAction::SetTags { tags } => {
+                self.bind_set_object_tags(TagObject::Database { catalog, database }, tags)
+            }
+
+            AlterDatabaseAction::UnsetTags { tags } => {
+                self.bind_unset_object_tags(TagObject::Database { catalog, database }, tags)
+            }
         }
     }
 
@@ -274,6 +284,7 @@ impl Binder {
             database: DatabaseRef { catalog, database },
             engine,
             options,
+            clone_from,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -285,12 +296,22 @@ impl Binder {
 
         let meta = self.database_meta(engine, options)?;
 
+        let clone_source = clone_from.as_ref().map(|source| CloneDatabaseSource {
+            catalog: source
+                .catalog
+                .as_ref()
+                .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| self.ctx.get_current_catalog()),
+            database: normalize_identifier(&source.database, &self.name_resolution_ctx).name,
+        });
+
         Ok(Plan::CreateDatabase(Box::new(CreateDatabasePlan {
             create_option: create_option.clone().into(),
             tenant,
             catalog,
             database,
             meta,
+            clone_source,
         })))
     }
 
@@ -24,6 +24,7 @@ mod index;
 mod network_policy;
 mod notification;
 mod password_policy;
+mod plan_baseline;
 mod procedure;
 mod role;
 mod row_access_policy;
@@ -31,6 +32,7 @@ mod sequence;
 mod stage;
 mod stream;
 mod table;
+mod tag;
 mod task;
 mod view;
 mod warehouse;
@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::CreatePlanBaselineStmt;
+use databend_common_ast::ast::DropPlanBaselineStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::CreatePlanBaselinePlan;
+use crate::plans::DropPlanBaselinePlan;
+use crate::plans::Plan;
+use crate::BindContext;
+use crate::Planner;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_plan_baseline(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &CreatePlanBaselineStmt,
+    ) -> Result<Plan> {
+        let CreatePlanBaselineStmt {
+            create_option,
+            query,
+        } = stmt;
+
+        let plan = self
+            .bind_statement(bind_context, &Statement::Query(query.clone()))
+            .await?;
+        if !matches!(plan, Plan::Query { .. }) {
+            return Err(ErrorCode::SemanticError(
+                "Plan baseline can only be created for a query",
+            ));
+        }
+
+        let plan = CreatePlanBaselinePlan {
+            create_option: create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            sql_hash: Planner::plan_baseline_key(self.ctx.as_ref(), query),
+            query_text: query.to_string(),
+            query: Box::new(plan),
+        };
+        Ok(Plan::CreatePlanBaseline(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_plan_baseline(
+        &mut self,
+        stmt: &DropPlanBaselineStmt,
+    ) -> Result<Plan> {
+        let DropPlanBaselineStmt {
+            if_exists,
+            sql_hash,
+        } = stmt;
+
+        let plan = DropPlanBaselinePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            sql_hash: sql_hash.clone(),
+        };
+        Ok(Plan::DropPlanBaseline(Box::new(plan)))
+    }
+}
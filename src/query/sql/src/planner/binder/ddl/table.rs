@@ -29,12 +29,15 @@ use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CompactTarget;
 use databend_common_ast::ast::ConstraintDefinition;
 use databend_common_ast::ast::ConstraintType as AstConstraintType;
+use databend_common_ast::ast::CreateStatisticsStmt;
 use databend_common_ast::ast::CreateTableSource;
 use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::DescribeTableStmt;
+use databend_common_ast::ast::DropStatisticsStmt;
 use databend_common_ast::ast::DropTableStmt;
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::ExistsTableStmt;
+use databend_common_ast::ast::Expr as AstExpr;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::ModifyColumnAction;
 use databend_common_ast::ast::OptimizeTableAction as AstOptimizeTableAction;
@@ -51,8 +54,10 @@ use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableIndexDefinition;
 use databend_common_ast::ast::TableIndexType as AstTableIndexType;
+use databend_common_ast::ast::TableRefKind as AstTableRefKind;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TableType;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::ast::UndropTableStmt;
@@ -67,6 +72,8 @@ use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::table::CompactionLimits;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -74,6 +81,7 @@ use databend_common_expression::infer_schema_type;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::DataType;
 use databend_common_expression::AutoIncrementExpr;
+use databend_common_expression::ColumnId;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRefExt;
@@ -87,6 +95,8 @@ use databend_common_license::license::Feature;
 use databend_common_license::license_manager::LicenseManagerSwitch;
 use databend_common_meta_app::schema::Constraint;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::ForeignKeyConstraint;
+use databend_common_meta_app::schema::KeyConstraint;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableIndexType;
 use databend_common_meta_app::storage::StorageParams;
@@ -96,14 +106,22 @@ use databend_common_storages_basic::view_table::QUERY;
 use databend_common_storages_basic::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::TableCompression;
+use databend_storages_common_table_meta::table::TableRefKind;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION_FIXED_FLAG;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_REFS;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use derive_visitor::DriveMut;
 use log::debug;
 use opendal::Operator;
@@ -116,6 +134,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::binder::ConstraintExprBinder;
 use crate::binder::Visibility;
 use crate::optimizer::ir::SExpr;
+use crate::parse_computed_expr;
 use crate::parse_computed_expr_to_string;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::resolve_type_name;
@@ -126,21 +145,28 @@ use crate::plans::AddTableConstraintPlan;
 use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
+use crate::plans::CreateStatisticsPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableRefPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropAllTableRowAccessPoliciesPlan;
+use crate::plans::DropStatisticsPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRefPlan;
 use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
 use crate::plans::ModifyTableConnectionPlan;
+use crate::plans::ModifyTableTTLPlan;
 use crate::plans::OptimizeCompactBlock;
 use crate::plans::OptimizeCompactSegmentPlan;
+use crate::plans::OptimizeExpirePlan;
 use crate::plans::OptimizePurgePlan;
 use crate::plans::Plan;
 use crate::plans::ReclusterPlan;
@@ -153,6 +179,7 @@ use crate::plans::RewriteKind;
 use crate::plans::SetOptionsPlan;
 use crate::plans::ShowCreateTablePlan;
 use crate::plans::SwapTablePlan;
+use crate::plans::TagObject;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UndropTablePlan;
 use crate::plans::UnsetOptionsPlan;
@@ -162,6 +189,7 @@ use crate::plans::VacuumTableOption;
 use crate::plans::VacuumTablePlan;
 use crate::plans::VacuumTemporaryFilesPlan;
 use crate::BindContext;
+use crate::ColumnSet;
 use crate::DefaultExprBinder;
 use crate::Planner;
 use crate::SelectBuilder;
@@ -534,6 +562,7 @@ impl Binder {
             source,
             table_options,
             cluster_by,
+            ttl,
             as_query,
             table_type,
             engine,
@@ -656,6 +685,8 @@ impl Binder {
         }
 
         // Build table schema
+        let mut clone_source = None;
+        let mut inherited_cluster_key = None;
         let (
             AnalyzeCreateTableResult {
                 schema,
@@ -665,6 +696,52 @@ impl Binder {
             },
             as_query_plan,
         ) = match (&source, &as_query) {
+            (
+                Some(CreateTableSource::Clone {
+                    catalog: source_catalog,
+                    database: source_database,
+                    table: source_table,
+                    travel_point,
+                }),
+                None,
+            ) => {
+                if engine != Engine::Fuse || *table_type == TableType::Temporary {
+                    return Err(ErrorCode::TableEngineNotSupported(
+                        "CREATE TABLE ... CLONE is only supported for non-temporary FUSE tables",
+                    ));
+                }
+                let (source_table, source) = self
+                    .analyze_clone_table_source(
+                        source_catalog,
+                        source_database,
+                        source_table,
+                        travel_point,
+                    )
+                    .await?;
+                // Inherit the options of the source table, unless they are specified explicitly.
+                for (key, value) in source_table.get_table_info().options() {
+                    if !is_clone_excluded_opt_key(key) {
+                        options.entry(key.clone()).or_insert_with(|| value.clone());
+                    }
+                }
+                if let Some(snapshot_location) = &source.snapshot_location {
+                    options.insert(
+                        OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+                        snapshot_location.clone(),
+                    );
+                }
+                inherited_cluster_key = source_table.get_table_info().meta.cluster_key.clone();
+                clone_source = Some(source);
+                (
+                    AnalyzeCreateTableResult {
+                        schema: source_table.schema(),
+                        field_comments: source_table.field_comments().clone(),
+                        table_indexes: None,
+                        table_constraints: None,
+                    },
+                    None,
+                )
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 let result = self.analyze_create_table_schema(&table, source).await?;
@@ -865,6 +942,20 @@ impl Binder {
                 cluster_key = Some(format!("({})", keys.join(", ")));
             }
         }
+        if cluster_key.is_none() {
+            cluster_key = inherited_cluster_key;
+        }
+
+        if let Some(ttl) = ttl {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "Table engine {} does not support TTL",
+                    engine
+                )));
+            }
+            let ttl = self.analyze_table_ttl(ttl, schema.clone())?;
+            options.insert(OPT_KEY_TTL.to_owned(), ttl);
+        }
 
         let plan = CreateTablePlan {
             create_option: create_option.clone().into(),
@@ -885,6 +976,7 @@ impl Binder {
             table_indexes,
             table_constraints,
             attached_columns: None,
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -954,6 +1046,7 @@ impl Binder {
             table_indexes: None,
             table_constraints: None,
             attached_columns: stmt.columns_opt.clone(),
+            clone_source: None,
         })))
     }
 
@@ -1205,6 +1298,26 @@ impl Binder {
                         let column = self.normalize_object_identifier(column);
                         ModifyColumnActionInPlan::ConvertStoredComputedColumn(column)
                     }
+                    ModifyColumnAction::SetTags(column, tags) => {
+                        let column = self.normalize_object_identifier(column);
+                        let object = TagObject::Column {
+                            catalog,
+                            database,
+                            table,
+                            column,
+                        };
+                        return self.bind_set_object_tags(object, tags);
+                    }
+                    ModifyColumnAction::UnsetTags(column, tags) => {
+                        let column = self.normalize_object_identifier(column);
+                        let object = TagObject::Column {
+                            catalog,
+                            database,
+                            table,
+                            column,
+                        };
+                        return self.bind_unset_object_tags(object, tags);
+                    }
                     ModifyColumnAction::SetDataType(column_def_vec) => {
                         let mut field_and_comment = Vec::with_capacity(column_def_vec.len());
                         // try add lock table.
@@ -1291,6 +1404,30 @@ impl Binder {
                     table,
                 },
             ))),
+            AlterTableAction::ModifyTableTTL { ttl } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let ttl = self.analyze_table_ttl(ttl, schema)?;
+                Ok(Plan::ModifyTableTTL(Box::new(ModifyTableTTLPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    ttl: Some(ttl),
+                })))
+            }
+            AlterTableAction::DropTableTTL => {
+                Ok(Plan::ModifyTableTTL(Box::new(ModifyTableTTLPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    ttl: None,
+                })))
+            }
             AlterTableAction::ReclusterTable {
                 is_final,
                 selection,
@@ -1303,6 +1440,35 @@ impl Binder {
                 selection: selection.clone(),
                 is_final: *is_final,
             }))),
+            AlterTableAction::CreateTableRef {
+                kind,
+                name,
+                travel_point,
+            } => {
+                let point = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point)?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableRef(Box::new(CreateTableRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    kind: table_ref_kind(kind),
+                    name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                    point,
+                })))
+            }
+            AlterTableAction::DropTableRef { kind, name } => {
+                Ok(Plan::DropTableRef(Box::new(DropTableRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    kind: table_ref_kind(kind),
+                    name: normalize_identifier(name, &self.name_resolution_ctx).name,
+                })))
+            }
             AlterTableAction::FlashbackTo { point } => {
                 let point = self.resolve_data_travel_point(bind_context, point)?;
                 Ok(Plan::RevertTable(Box::new(RevertTablePlan {
@@ -1397,6 +1563,22 @@ impl Binder {
                     },
                 )))
             }
+            AlterTableAction::SetTags { tags } => {
+                let object = TagObject::Table {
+                    catalog,
+                    database,
+                    table,
+                };
+                self.bind_set_object_tags(object, tags)
+            }
+            AlterTableAction::UnsetTags { tags } => {
+                let object = TagObject::Table {
+                    catalog,
+                    database,
+                    table,
+                };
+                self.bind_unset_object_tags(object, tags)
+            }
         }
     }
 
@@ -1536,6 +1718,11 @@ impl Binder {
                     }))
                 }
             },
+            AstOptimizeTableAction::Expire => Plan::OptimizeExpire(Box::new(OptimizeExpirePlan {
+                catalog,
+                database,
+                table,
+            })),
         };
 
         Ok(plan)
@@ -1639,6 +1826,91 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_statistics(
+        &mut self,
+        stmt: &CreateStatisticsStmt,
+    ) -> Result<Plan> {
+        let CreateStatisticsStmt {
+            create_option,
+            name,
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let name = self.normalize_object_identifier(name);
+        let table_schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+
+        let mut column_ids = Vec::with_capacity(columns.len());
+        for column in columns {
+            let column_name = self.normalize_object_identifier(column);
+            let field = table_schema.field_with_name(&column_name).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "Statistics column {} does not exist",
+                    column_name
+                ))
+            })?;
+            if column_ids.contains(&field.column_id) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Statistics column {} is duplicated",
+                    column_name
+                )));
+            }
+            column_ids.push(field.column_id);
+        }
+        if column_ids.len() < 2 || column_ids.len() > MAX_STATISTICS_COLUMNS {
+            return Err(ErrorCode::SemanticError(format!(
+                "Statistics {} must be created on 2 to {} columns, got {}",
+                name,
+                MAX_STATISTICS_COLUMNS,
+                column_ids.len()
+            )));
+        }
+
+        Ok(Plan::CreateStatistics(Box::new(CreateStatisticsPlan {
+            create_option: create_option.clone().into(),
+            catalog,
+            database,
+            table,
+            name,
+            column_ids,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_statistics(
+        &mut self,
+        stmt: &DropStatisticsStmt,
+    ) -> Result<Plan> {
+        let DropStatisticsStmt {
+            if_exists,
+            name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let name = self.normalize_object_identifier(name);
+
+        Ok(Plan::DropStatistics(Box::new(DropStatisticsPlan {
+            if_exists: *if_exists,
+            catalog,
+            database,
+            table,
+            name,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_exists_table(
         &mut self,
@@ -1916,6 +2188,58 @@ impl Binder {
                         scalar_expr.used_columns(),
                     )
                 }
+                AstConstraintType::PrimaryKey { columns, rely } => {
+                    if let Some((name, _)) = constraints
+                        .iter()
+                        .find(|(_, c)| matches!(c, Constraint::PrimaryKey(_)))
+                    {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "Table {} already has primary key constraint {}",
+                            table_name, name
+                        )));
+                    }
+                    let (column_ids, used_columns) =
+                        self.analyze_key_constraint_columns(&table_schema, columns)?;
+                    (
+                        Constraint::PrimaryKey(KeyConstraint {
+                            column_ids,
+                            rely: *rely,
+                        }),
+                        used_columns,
+                    )
+                }
+                AstConstraintType::Unique { columns, rely } => {
+                    let (column_ids, used_columns) =
+                        self.analyze_key_constraint_columns(&table_schema, columns)?;
+                    (
+                        Constraint::Unique(KeyConstraint {
+                            column_ids,
+                            rely: *rely,
+                        }),
+                        used_columns,
+                    )
+                }
+                AstConstraintType::ForeignKey {
+                    columns,
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                    rely,
+                } => {
+                    let (column_ids, used_columns) =
+                        self.analyze_key_constraint_columns(&table_schema, columns)?;
+                    let foreign_key = self
+                        .analyze_foreign_key_reference(
+                            &table_schema,
+                            column_ids,
+                            ref_database,
+                            ref_table,
+                            ref_columns,
+                            *rely,
+                        )
+                        .await?;
+                    (Constraint::ForeignKey(foreign_key), used_columns)
+                }
             };
             let name = constraint_def
                 .name
@@ -1938,6 +2262,105 @@ impl Binder {
         Ok(constraints)
     }
 
+    fn analyze_key_constraint_columns(
+        &self,
+        table_schema: &TableSchemaRef,
+        columns: &[Identifier],
+    ) -> Result<(Vec<ColumnId>, ColumnSet)> {
+        let mut column_ids = Vec::with_capacity(columns.len());
+        let mut used_columns = ColumnSet::new();
+        for column in columns {
+            let column_name = self.normalize_object_identifier(column);
+            let index = table_schema.index_of(&column_name).map_err(|_| {
+                ErrorCode::UnknownColumn(format!(
+                    "Key constraint column {} does not exist",
+                    column_name
+                ))
+            })?;
+            if !used_columns.insert(index) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Key constraint column {} is duplicated",
+                    column_name
+                )));
+            }
+            column_ids.push(table_schema.field(index).column_id);
+        }
+        Ok((column_ids, used_columns))
+    }
+
+    /// Resolves the referenced table of a foreign key. The referenced columns
+    /// must match a primary key or unique constraint of that table.
+    #[async_backtrace::framed]
+    async fn analyze_foreign_key_reference(
+        &self,
+        table_schema: &TableSchemaRef,
+        column_ids: Vec<ColumnId>,
+        ref_database: &Option<Identifier>,
+        ref_table: &Identifier,
+        ref_columns: &[Identifier],
+        rely: bool,
+    ) -> Result<ForeignKeyConstraint> {
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(&None, ref_database, ref_table);
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&catalog).await?;
+        let ref_database_id = catalog
+            .get_database(&tenant, &database)
+            .await?
+            .get_db_info()
+            .database_id
+            .db_id;
+        let ref_table = catalog.get_table(&tenant, &database, &table).await?;
+        let ref_schema = ref_table.schema();
+        let (ref_column_ids, _) = self.analyze_key_constraint_columns(&ref_schema, ref_columns)?;
+
+        if column_ids.len() != ref_column_ids.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Foreign key has {} columns but references {} columns of {}.{}",
+                column_ids.len(),
+                ref_column_ids.len(),
+                database,
+                table
+            )));
+        }
+        for (column_id, ref_column_id) in column_ids.iter().zip(ref_column_ids.iter()) {
+            let field = table_schema.field_of_column_id(*column_id)?;
+            let ref_field = ref_schema.field_of_column_id(*ref_column_id)?;
+            if field.data_type().remove_nullable() != ref_field.data_type().remove_nullable() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Foreign key column {} of type {} is incompatible with referenced column {} of type {}",
+                    field.name(),
+                    field.data_type(),
+                    ref_field.name(),
+                    ref_field.data_type()
+                )));
+            }
+        }
+
+        let ref_key = ref_column_ids.iter().collect::<HashSet<_>>();
+        let references_key = ref_table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .filter(|c| matches!(c, Constraint::PrimaryKey(_) | Constraint::Unique(_)))
+            .any(|c| c.key_column_ids().iter().collect::<HashSet<_>>() == ref_key);
+        if !references_key {
+            return Err(ErrorCode::SemanticError(format!(
+                "Referenced columns of {}.{} are not covered by a primary key or unique constraint",
+                database, table
+            )));
+        }
+
+        Ok(ForeignKeyConstraint {
+            column_ids,
+            ref_database_id,
+            ref_table_id: ref_table.get_id(),
+            ref_column_ids,
+            rely,
+        })
+    }
+
     #[async_backtrace::framed]
     async fn analyze_table_indexes(
         &self,
@@ -2079,9 +2502,56 @@ impl Binder {
                     })
                 }
             }
+            CreateTableSource::Clone { .. } => Err(ErrorCode::BadArguments(
+                "Incorrect CREATE query: CLONE can not be used with AS SELECT",
+            )),
         }
     }
 
+    /// Resolve the source table of `CREATE TABLE ... CLONE`, the new table shares the data of
+    /// the source table at the given point instead of copying it.
+    #[async_backtrace::framed]
+    async fn analyze_clone_table_source(
+        &self,
+        catalog: &Option<Identifier>,
+        database: &Option<Identifier>,
+        table: &Identifier,
+        travel_point: &Option<TimeTravelPoint>,
+    ) -> Result<(Arc<dyn Table>, CloneTableSource)> {
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let mut source_table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if source_table.engine() != "FUSE"
+            || source_table.is_temp()
+            || source_table.is_read_only()
+            || source_table.get_table_info().meta.storage_params.is_some()
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Table {database}.{table} can not be cloned, only non-temporary FUSE tables stored in the default storage are supported",
+            )));
+        }
+
+        if let Some(travel_point) = travel_point {
+            let mut bind_context = BindContext::new();
+            let point = self.resolve_data_travel_point(&mut bind_context, travel_point)?;
+            source_table = source_table
+                .navigate_to(&self.ctx, &TimeNavigation::TimeTravel(point))
+                .await?;
+        }
+
+        let snapshot_location = source_table
+            .get_table_info()
+            .options()
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .cloned();
+        Ok((source_table, CloneTableSource {
+            catalog,
+            database,
+            table,
+            snapshot_location,
+        }))
+    }
+
     /// Validate the schema of the table to be created.
     pub(in crate::planner::binder) fn validate_create_table_schema(
         schema: &TableSchemaRef,
@@ -2206,6 +2676,37 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    /// Validates the TTL expression of a table, returns it in the normalized form.
+    pub(in crate::planner::binder) fn analyze_table_ttl(
+        &self,
+        ttl: &AstExpr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        let mut ttl = ttl.clone();
+        let mut normalizer = IdentifierNormalizer::new(&self.name_resolution_ctx);
+        ttl.drive_mut(&mut normalizer);
+        let ttl = format!("{:#}", ttl);
+
+        let expr = parse_computed_expr(self.ctx.clone(), Arc::new(schema.into()), &ttl)?;
+        if expr.column_refs().is_empty() || !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "TTL expression `{}` must be a deterministic expression of the table columns",
+                ttl
+            )));
+        }
+        let data_type = expr.data_type();
+        if !matches!(
+            data_type.remove_nullable(),
+            DataType::Timestamp | DataType::Date
+        ) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "TTL expression `{}` must be of type TIMESTAMP or DATE, but got {}",
+                ttl, data_type
+            )));
+        }
+        Ok(ttl)
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -2228,6 +2729,10 @@ impl Binder {
     }
 }
 
+// The number of distinct combinations explodes with the number of columns,
+// so the columns of multi-column statistics are limited like other databases do.
+const MAX_STATISTICS_COLUMNS: usize = 8;
+
 const VERIFICATION_KEY: &str = "_v_d77aa11285c22e0e1d4593a035c98c0d";
 const VERIFICATION_KEY_DEL: &str = "_v_d77aa11285c22e0e1d4593a035c98c0d_del";
 
@@ -2295,3 +2800,28 @@ fn create_as_select_infer_schema_type(
         (dt, false) => infer_schema_type(&Nullable(Box::new(dt.clone()))),
     }
 }
+
+// Options of the source table that are not inherited by its clones, they describe
+// the storage and the state of the source table itself.
+fn is_clone_excluded_opt_key(key: &str) -> bool {
+    matches!(
+        key,
+        OPT_KEY_SNAPSHOT_LOCATION
+            | OPT_KEY_SNAPSHOT_LOCATION_FIXED_FLAG
+            | OPT_KEY_LEGACY_SNAPSHOT_LOC
+            | OPT_KEY_DATABASE_ID
+            | OPT_KEY_STORAGE_PREFIX
+            | OPT_KEY_TEMP_PREFIX
+            | OPT_KEY_TABLE_ATTACHED_DATA_URI
+            | OPT_KEY_CHANGE_TRACKING
+            | OPT_KEY_CHANGE_TRACKING_BEGIN_VER
+            | OPT_KEY_TABLE_REFS
+    )
+}
+
+fn table_ref_kind(kind: &AstTableRefKind) -> TableRefKind {
+    match kind {
+        AstTableRefKind::Branch => TableRefKind::Branch,
+        AstTableRefKind::Tag => TableRefKind::Tag,
+    }
+}
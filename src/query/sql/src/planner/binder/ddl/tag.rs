@@ -0,0 +1,146 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::AlterTagAction;
+use databend_common_ast::ast::AlterTagStmt;
+use databend_common_ast::ast::CreateTagStmt;
+use databend_common_ast::ast::DropTagStmt;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::TagSetItem;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::Binder;
+use crate::plans::AlterTagPlan;
+use crate::plans::CreateTagPlan;
+use crate::plans::DropTagPlan;
+use crate::plans::Plan;
+use crate::plans::SetObjectTagsPlan;
+use crate::plans::TagObject;
+use crate::plans::UnsetObjectTagsPlan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_tag(
+        &mut self,
+        stmt: &CreateTagStmt,
+    ) -> Result<Plan> {
+        let CreateTagStmt {
+            create_option,
+            name,
+            allowed_values,
+            comment,
+        } = stmt;
+
+        let allowed_values = match allowed_values {
+            Some(values) => {
+                let mut dedup = Vec::with_capacity(values.len());
+                for value in values {
+                    if !dedup.contains(value) {
+                        dedup.push(value.clone());
+                    }
+                }
+                Some(dedup)
+            }
+            None => None,
+        };
+
+        let plan = CreateTagPlan {
+            create_option: create_option.clone().into(),
+            tenant: self.ctx.get_tenant(),
+            name: self.normalize_object_identifier(name),
+            allowed_values,
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_tag(
+        &mut self,
+        stmt: &DropTagStmt,
+    ) -> Result<Plan> {
+        let DropTagStmt { if_exists, name } = stmt;
+
+        let plan = DropTagPlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: self.normalize_object_identifier(name),
+        };
+        Ok(Plan::DropTag(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_tag(
+        &mut self,
+        stmt: &AlterTagStmt,
+    ) -> Result<Plan> {
+        let AlterTagStmt { name, action } = stmt;
+
+        let masking_policy = match action {
+            AlterTagAction::SetMaskingPolicy { policy } => Some(policy.to_string()),
+            AlterTagAction::UnsetMaskingPolicy => None,
+        };
+
+        let plan = AlterTagPlan {
+            tenant: self.ctx.get_tenant(),
+            name: self.normalize_object_identifier(name),
+            masking_policy,
+        };
+        Ok(Plan::AlterTag(Box::new(plan)))
+    }
+
+    pub(in crate::planner::binder) fn bind_set_object_tags(
+        &self,
+        object: TagObject,
+        tags: &[TagSetItem],
+    ) -> Result<Plan> {
+        let mut bound_tags: Vec<(String, String)> = Vec::with_capacity(tags.len());
+        for item in tags {
+            let tag_name = self.normalize_object_identifier(&item.tag_name);
+            if bound_tags.iter().any(|(name, _)| name == &tag_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Tag '{tag_name}' is specified more than once"
+                )));
+            }
+            bound_tags.push((tag_name, item.tag_value.clone()));
+        }
+
+        Ok(Plan::SetObjectTags(Box::new(SetObjectTagsPlan {
+            tenant: self.ctx.get_tenant(),
+            object,
+            tags: bound_tags,
+        })))
+    }
+
+    pub(in crate::planner::binder) fn bind_unset_object_tags(
+        &self,
+        object: TagObject,
+        tags: &[Identifier],
+    ) -> Result<Plan> {
+        let mut bound_tags: Vec<String> = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let tag_name = self.normalize_object_identifier(tag);
+            if !bound_tags.contains(&tag_name) {
+                bound_tags.push(tag_name);
+            }
+        }
+
+        Ok(Plan::UnsetObjectTags(Box::new(UnsetObjectTagsPlan {
+            tenant: self.ctx.get_tenant(),
+            object,
+            tags: bound_tags,
+        })))
+    }
+}
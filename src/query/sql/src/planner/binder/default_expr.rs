@@ -271,7 +271,8 @@ impl DefaultExprBinder {
                     AsyncFunctionArgument::AutoIncrement { .. } => {
                         unreachable!("expect AsyncFunctionArgument::SequenceFunction")
                     }
-                    AsyncFunctionArgument::DictGetFunction(_) => {
+                    AsyncFunctionArgument::DictGetFunction(_)
+                    | AsyncFunctionArgument::SecretFunction(_) => {
                         unreachable!("expect AsyncFunctionArgument::SequenceFunction")
                     }
                 };
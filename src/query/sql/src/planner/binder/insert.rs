@@ -20,6 +20,8 @@ use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
 use databend_common_ast::ast::Statement;
 use databend_common_catalog::session_type::SessionType;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
@@ -105,6 +107,7 @@ impl Binder {
             catalog,
             database,
             table,
+            branch,
             columns,
             source,
             overwrite,
@@ -126,6 +129,26 @@ impl Binder {
             .await
             .map_err(|err| table_identifier.not_found_suggest_error(err))?;
 
+        // The table checked out at the branch is committed to, instead of the table itself.
+        let (table, branch_table_info) = match branch {
+            Some(branch) => {
+                if *overwrite
+                    || matches!(source, InsertSource::LoadFile { .. })
+                    || self.ctx.get_stage_attachment().is_some()
+                {
+                    return Err(ErrorCode::Unimplemented(
+                        "Only INSERT INTO ... VALUES and INSERT INTO ... SELECT are supported for branches",
+                    ));
+                }
+                let branch = normalize_identifier(branch, &self.name_resolution_ctx).name;
+                let navigation = TimeNavigation::TimeTravel(NavigationPoint::Branch(branch));
+                let table = table.navigate_to(&self.ctx, &navigation).await?;
+                let table_info = table.get_table_info().clone();
+                (table, Some(table_info))
+            }
+            None => (table, None),
+        };
+
         let schema = self.schema_project(
             &table.schema(),
             columns,
@@ -267,7 +290,7 @@ impl Binder {
             schema,
             overwrite: *overwrite,
             source: input_source?,
-            table_info: None,
+            table_info: branch_table_info,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
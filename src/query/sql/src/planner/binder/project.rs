@@ -645,10 +645,8 @@ impl Binder {
                 .find(|f| f.name == column_binding.column_name);
 
             match field {
-                Some(field) => table_info_ref
-                    .meta
-                    .column_mask_policy_columns_ids
-                    .get(&field.column_id)
+                Some(field) => metadata
+                    .column_mask_policy(table_index, field.column_id)
                     .map(|policy_info| {
                         (
                             policy_info.policy_id,
@@ -65,6 +65,7 @@ use databend_common_storage::StageFilesInfo;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
 use databend_storages_common_table_meta::table::ChangeType;
+use databend_storages_common_table_meta::table::OPT_KEY_TTL;
 use log::info;
 
 use crate::binder::split_conjunctions;
@@ -77,6 +78,7 @@ use crate::optimizer::ir::SExpr;
 use crate::planner::semantic::normalize_identifier;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::DummyTableScan;
+use crate::plans::Filter;
 use crate::plans::RecursiveCteScan;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
@@ -376,6 +378,12 @@ impl Binder {
             scan_id,
             table
         );
+        // The columns which the current user is not granted SELECT on are not expanded by `*`,
+        // so that `SELECT *` returns the visible columns instead of failing on the privilege check.
+        let hidden_column_ids = databend_common_base::runtime::block_on(
+            self.ctx
+                .get_hidden_column_ids(table.catalog(), database_name, table.table().as_ref()),
+        )?;
         let mut base_column_scan_id = HashMap::new();
         for column in columns.iter() {
             match column {
@@ -387,13 +395,16 @@ impl Binder {
                     table_index,
                     column_position,
                     virtual_expr,
-                    ..
+                    column_id,
                 }) => {
                     let column_binding = ColumnBindingBuilder::new(
                         column_name.clone(),
                         *column_index,
                         Box::new(DataType::from(data_type)),
-                        if path_indices.is_some() || is_stream_column(column_name) {
+                        if path_indices.is_some()
+                            || is_stream_column(column_name)
+                            || column_id.is_some_and(|id| hidden_column_ids.contains(&id))
+                        {
                             Visibility::InVisible
                         } else {
                             Visibility::Visible
@@ -441,6 +452,16 @@ impl Binder {
             .write()
             .add_base_column_scan_id(base_column_scan_id);
 
+        // The changes of a stream are not filtered by the TTL of the table.
+        let ttl = match change_type {
+            None => table
+                .table()
+                .get_table_info()
+                .options()
+                .get(OPT_KEY_TTL)
+                .cloned(),
+            Some(_) => None,
+        };
         let scan_s_expr = SExpr::create_leaf(Arc::new(
             Scan {
                 table_index,
@@ -453,6 +474,10 @@ impl Binder {
             }
             .into(),
         ));
+        let scan_s_expr = match ttl {
+            Some(ttl) => self.bind_ttl_filter(&mut bind_context, scan_s_expr, &ttl)?,
+            None => scan_s_expr,
+        };
 
         if table
             .table()
@@ -488,6 +513,34 @@ impl Binder {
         Ok((final_s_expr, bind_context))
     }
 
+    /// Hides the expired rows of a table with TTL. The filter is pushed down to the scan,
+    /// so the blocks whose rows are all expired are pruned by their min/max statistics.
+    fn bind_ttl_filter(
+        &mut self,
+        bind_context: &mut BindContext,
+        scan_s_expr: SExpr,
+        ttl: &str,
+    ) -> Result<SExpr> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let tokens = tokenize_sql(&format!("({ttl}) > now() OR ({ttl}) IS NULL"))?;
+        let expr = parse_expr(&tokens, sql_dialect)?;
+        let mut scalar_binder = ScalarBinder::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, _) = scalar_binder.bind(&expr)?;
+        let filter = Filter {
+            predicates: split_conjunctions(&scalar),
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(scan_s_expr),
+        ))
+    }
+
     fn bind_row_access_policy(
         &mut self,
         table_index: IndexType,
@@ -719,6 +772,12 @@ impl Binder {
                 database,
                 name,
             } => self.resolve_stream_data_travel_point(catalog, database, name),
+            TimeTravelPoint::Branch(name) => Ok(NavigationPoint::Branch(
+                normalize_identifier(name, &self.name_resolution_ctx).name,
+            )),
+            TimeTravelPoint::Tag(name) => Ok(NavigationPoint::Tag(
+                normalize_identifier(name, &self.name_resolution_ctx).name,
+            )),
         }
     }
 
@@ -94,16 +94,22 @@ impl Plan {
             Plan::DropTableConstraint(_) => Ok("DropTableConstraint".to_string()),
             Plan::AlterTableClusterKey(_) => Ok("AlterTableClusterKey".to_string()),
             Plan::DropTableClusterKey(_) => Ok("DropTableClusterKey".to_string()),
+            Plan::ModifyTableTTL(_) => Ok("ModifyTableTTL".to_string()),
+            Plan::CreateTableRef(_) => Ok("CreateTableRef".to_string()),
+            Plan::DropTableRef(_) => Ok("DropTableRef".to_string()),
             Plan::RefreshTableCache(_) => Ok("RefreshTableCache".to_string()),
             Plan::ReclusterTable(_) => Ok("ReclusterTable".to_string()),
             Plan::TruncateTable(_) => Ok("TruncateTable".to_string()),
             Plan::OptimizePurge(_) => Ok("OptimizePurge".to_string()),
+            Plan::OptimizeExpire(_) => Ok("OptimizeExpire".to_string()),
             Plan::OptimizeCompactSegment(_) => Ok("OptimizeCompactSegment".to_string()),
             Plan::OptimizeCompactBlock { .. } => Ok("OptimizeCompactBlock".to_string()),
             Plan::VacuumTable(_) => Ok("VacuumTable".to_string()),
             Plan::VacuumDropTable(_) => Ok("VacuumDropTable".to_string()),
             Plan::VacuumTemporaryFiles(_) => Ok("VacuumTemporaryFiles".to_string()),
             Plan::AnalyzeTable(_) => Ok("AnalyzeTable".to_string()),
+            Plan::CreateStatistics(_) => Ok("CreateStatistics".to_string()),
+            Plan::DropStatistics(_) => Ok("DropStatistics".to_string()),
             Plan::ExistsTable(_) => Ok("ExistsTable".to_string()),
             Plan::AddTableRowAccessPolicy(_) => Ok("AddTableRowAccessPolicy".to_string()),
             Plan::DropTableRowAccessPolicy(_) => Ok("DropTableRowAccessPolicy".to_string()),
@@ -190,6 +196,17 @@ impl Plan {
             Plan::DropDatamaskPolicy(_) => Ok("DropDatamaskPolicy".to_string()),
             Plan::DescDatamaskPolicy(_) => Ok("DescDatamaskPolicy".to_string()),
 
+            // tag
+            Plan::CreateTag(_) => Ok("CreateTag".to_string()),
+            Plan::DropTag(_) => Ok("DropTag".to_string()),
+            Plan::AlterTag(_) => Ok("AlterTag".to_string()),
+            Plan::SetObjectTags(_) => Ok("SetObjectTags".to_string()),
+            Plan::UnsetObjectTags(_) => Ok("UnsetObjectTags".to_string()),
+
+            // plan baseline
+            Plan::CreatePlanBaseline(_) => Ok("CreatePlanBaseline".to_string()),
+            Plan::DropPlanBaseline(_) => Ok("DropPlanBaseline".to_string()),
+
             // row policy
             Plan::CreateRowAccessPolicy(_) => Ok("CreateRowAccessPolicy".to_string()),
             Plan::DropRowAccessPolicy(_) => Ok("DropRowAccessPolicy".to_string()),
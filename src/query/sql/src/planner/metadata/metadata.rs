@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -28,12 +29,16 @@ use databend_common_catalog::table::Table;
 use databend_common_expression::display::display_tuple_field_name;
 use databend_common_expression::is_stream_column_id;
 use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnId;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
+use databend_common_meta_app::schema::Constraint;
+use databend_common_meta_app::schema::SecurityPolicyColumnMap;
 use parking_lot::RwLock;
 
 use crate::optimizer::ir::SExpr;
+use crate::planner::metadata::OptimizerHints;
 use crate::ScalarExpr;
 
 /// Planner use [`usize`] as it's index type.
@@ -81,6 +86,10 @@ pub struct Metadata {
     /// Mappings from base column index to scan id.
     base_column_scan_id: HashMap<IndexType, usize>,
     next_runtime_filter_id: usize,
+    /// Mappings from table index to the masking policies its columns get through tags.
+    table_tag_mask_policies: HashMap<IndexType, BTreeMap<ColumnId, u64>>,
+    /// Join hints collected from all query blocks.
+    optimizer_hints: OptimizerHints,
 }
 
 impl Metadata {
@@ -197,6 +206,97 @@ impl Metadata {
         self.table_row_id_index.get(&table_index).copied()
     }
 
+    pub fn set_table_tag_mask_policies(
+        &mut self,
+        table_index: IndexType,
+        policies: BTreeMap<ColumnId, u64>,
+    ) {
+        self.table_tag_mask_policies.insert(table_index, policies);
+    }
+
+    /// Returns the masking policy that applies to a column of a table.
+    ///
+    /// A policy set on the column directly takes precedence over one the column gets through a tag.
+    pub fn column_mask_policy(
+        &self,
+        table_index: IndexType,
+        column_id: ColumnId,
+    ) -> Option<SecurityPolicyColumnMap> {
+        let table = self.tables.get(table_index)?.table();
+        if let Some(policy) = table
+            .get_table_info()
+            .meta
+            .column_mask_policy_columns_ids
+            .get(&column_id)
+        {
+            return Some(policy.clone());
+        }
+        self.table_tag_mask_policies
+            .get(&table_index)?
+            .get(&column_id)
+            .map(|policy_id| SecurityPolicyColumnMap::new(*policy_id, vec![column_id]))
+    }
+
+    /// Returns true if any column of the table has a masking policy.
+    pub fn table_has_mask_policy(&self, table_index: IndexType) -> bool {
+        let Some(entry) = self.tables.get(table_index) else {
+            return false;
+        };
+        !entry
+            .table()
+            .get_table_info()
+            .meta
+            .column_mask_policy_columns_ids
+            .is_empty()
+            || self
+                .table_tag_mask_policies
+                .get(&table_index)
+                .is_some_and(|policies| !policies.is_empty())
+    }
+
+    /// Unique keys of a table declared by `RELY` primary key or unique constraints,
+    /// as sets of column indexes. A unique key is only returned if none of its
+    /// columns is nullable, so that the key also holds under `DISTINCT` semantics.
+    pub fn table_unique_keys(&self, table_index: IndexType) -> Vec<ColumnSet> {
+        let Some(entry) = self.tables.get(table_index) else {
+            return vec![];
+        };
+        let table_info = entry.table.get_table_info();
+        let mut keys = Vec::new();
+        for constraint in table_info.meta.constraints.values() {
+            let Some(column_ids) = constraint.unique_key() else {
+                continue;
+            };
+            let primary = matches!(constraint, Constraint::PrimaryKey(_));
+            let key = column_ids
+                .iter()
+                .map(|column_id| {
+                    self.columns.iter().find_map(|column| match column {
+                        ColumnEntry::BaseTableColumn(BaseTableColumn {
+                            table_index: index,
+                            column_index,
+                            column_id: Some(id),
+                            path_indices: None,
+                            virtual_expr: None,
+                            data_type,
+                            ..
+                        }) if *index == table_index
+                            && id == column_id
+                            && (primary || !data_type.is_nullable()) =>
+                        {
+                            Some(*column_index)
+                        }
+                        _ => None,
+                    })
+                })
+                .collect::<Option<ColumnSet>>();
+            if let Some(key) = key {
+                keys.push(key);
+            }
+        }
+        keys
+    }
+
     pub fn row_id_indexes(&self) -> Vec<IndexType> {
         self.table_row_id_index.values().copied().collect()
     }
@@ -528,6 +628,46 @@ impl Metadata {
         self.max_column_position
     }
 
+    pub fn add_optimizer_hints(&mut self, hints: OptimizerHints) {
+        self.optimizer_hints.merge(hints);
+    }
+
+    pub fn optimizer_hints(&self) -> &OptimizerHints {
+        &self.optimizer_hints
+    }
+
+    /// Resolve a table name used in an optimizer hint to table indexes.
+    /// A table is matched by its alias if it has one, otherwise by its name.
+    pub fn hinted_table_indexes(&self, name: &str) -> Vec<IndexType> {
+        self.tables
+            .iter()
+            .filter(|table| match &table.alias_name {
+                Some(alias_name) => alias_name == name,
+                None => table.name == name,
+            })
+            .map(|table| table.index)
+            .collect()
+    }
+
+    /// Resolve the tables of the `LEADING` hint, returns an empty vector if the hint is
+    /// absent or any of its tables does not refer to exactly one distinct table.
+    pub fn leading_table_indexes(&self) -> Vec<IndexType> {
+        let leading = self
+            .optimizer_hints
+            .leading
+            .iter()
+            .map(|table| match self.hinted_table_indexes(table).as_slice() {
+                [index] => Some(*index),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+        if leading.len() < 2 || leading.iter().collect::<HashSet<_>>().len() != leading.len() {
+            return vec![];
+        }
+        leading
+    }
+
     pub fn next_scan_id(&mut self) -> usize {
         let next_scan_id = self.next_scan_id;
         self.next_scan_id += 1;
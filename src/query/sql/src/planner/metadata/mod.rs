@@ -16,7 +16,9 @@ mod bloom_index;
 mod hll_columns;
 #[allow(clippy::module_inception)]
 mod metadata;
+mod optimizer_hints;
 
 pub use bloom_index::BloomIndexColumns;
 pub use hll_columns::ApproxDistinctColumns;
 pub use metadata::*;
+pub use optimizer_hints::OptimizerHints;
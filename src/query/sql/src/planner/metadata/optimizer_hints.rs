@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Optimizer hints written as `/*+ ... */` in a query, with the referenced
+/// table names already normalized. The optimizer resolves the names against
+/// the table aliases (or table names) recorded in the metadata.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OptimizerHints {
+    /// Tables to be joined first, in order.
+    pub leading: Vec<String>,
+    /// Tables to be broadcast as the build side of their joins.
+    pub broadcast: Vec<String>,
+    /// Tables whose joins should shuffle both sides.
+    pub shuffle: Vec<String>,
+    /// Groups of tables whose joins must use hash join.
+    pub hash_join: Vec<Vec<String>>,
+    /// Tables that must not receive runtime filters.
+    pub no_runtime_filter: Vec<String>,
+    /// Whether to keep subqueries from being rewritten into semi/anti joins.
+    pub no_decorrelate: bool,
+}
+
+impl OptimizerHints {
+    pub fn is_empty(&self) -> bool {
+        self == &OptimizerHints::default()
+    }
+
+    /// Merge hints from another query block, e.g. a subquery.
+    /// The first `LEADING` hint wins.
+    pub fn merge(&mut self, other: OptimizerHints) {
+        if self.leading.is_empty() {
+            self.leading = other.leading;
+        }
+        self.broadcast.extend(other.broadcast);
+        self.shuffle.extend(other.shuffle);
+        self.hash_join.extend(other.hash_join);
+        self.no_runtime_filter.extend(other.no_runtime_filter);
+        self.no_decorrelate |= other.no_decorrelate;
+    }
+}
@@ -23,6 +23,7 @@ pub mod dataframe;
 mod execution;
 mod expression;
 pub mod optimizer;
+mod plan_baseline;
 mod planner_cache;
 pub mod plans;
 
@@ -40,6 +41,12 @@ pub use expression::*;
 pub use format::*;
 pub use metadata::*;
 pub use optimizer::optimize;
+pub use plan_baseline::get_plan_baseline_usage;
+pub use plan_baseline::reset_plan_baseline_usage;
+pub use plan_baseline::JoinShape;
+pub use plan_baseline::PlanBaselineUsage;
+pub use plan_baseline::PlanShape;
+pub use plan_baseline::ShapeTable;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
 pub use planner::Planner;
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeSet;
+use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::Mutex;
@@ -30,9 +31,13 @@ use crate::optimizer::ir::StatInfo;
 use crate::optimizer::ir::VisitAction;
 use crate::optimizer::optimizers::rule::AppliedRules;
 use crate::optimizer::optimizers::rule::RuleID;
+use crate::plans::AsyncFunctionArgument;
+use crate::plans::AsyncFunctionCall;
 use crate::plans::Exchange;
 use crate::plans::Operator;
 use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::ColumnSet;
 use crate::IndexType;
 
 /// `SExpr` is abbreviation of single expression, which is a tree of relational operators.
@@ -254,6 +259,78 @@ impl SExpr {
         Ok(udf_ids)
     }
 
+    /// Collect the names of the connections whose secret is read by `get_secret`.
+    #[recursive::recursive]
+    pub fn get_secret_connections(&self) -> BTreeSet<String> {
+        let mut connections = BTreeSet::new();
+        if let RelOperator::AsyncFunction(async_func) = self.plan.as_ref() {
+            for item in async_func.items.iter() {
+                if let ScalarExpr::AsyncFunctionCall(AsyncFunctionCall {
+                    func_arg: AsyncFunctionArgument::SecretFunction(name),
+                    ..
+                }) = &item.scalar
+                {
+                    connections.insert(name.clone());
+                }
+            }
+        }
+        for child in &self.children {
+            connections.extend(child.get_secret_connections());
+        }
+        connections
+    }
+
+    /// Collect the columns read by each table scan, grouped by table index.
+    #[recursive::recursive]
+    pub fn get_scan_columns(&self) -> HashMap<IndexType, ColumnSet> {
+        let mut scan_columns: HashMap<IndexType, ColumnSet> = HashMap::new();
+        if let RelOperator::Scan(scan) = self.plan.as_ref() {
+            scan_columns
+                .entry(scan.table_index)
+                .or_default()
+                .extend(scan.columns.iter().copied());
+        }
+        for child in &self.children {
+            for (table_index, columns) in child.get_scan_columns() {
+                scan_columns.entry(table_index).or_default().extend(columns);
+            }
+        }
+        scan_columns
+    }
+
+    /// Collect the columns referenced by filter and join predicates, including
+    /// the predicates pushed down into table scans.
+    #[recursive::recursive]
+    pub fn get_predicate_columns(&self) -> ColumnSet {
+        let mut columns = ColumnSet::new();
+        match self.plan.as_ref() {
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter() {
+                    columns.extend(predicate.used_columns());
+                }
+            }
+            RelOperator::Join(join) => {
+                for condition in join.equi_conditions.iter() {
+                    columns.extend(condition.left.used_columns());
+                    columns.extend(condition.right.used_columns());
+                }
+                for predicate in join.non_equi_conditions.iter() {
+                    columns.extend(predicate.used_columns());
+                }
+            }
+            RelOperator::Scan(scan) => {
+                for predicate in scan.push_down_predicates.iter().flatten() {
+                    columns.extend(predicate.used_columns());
+                }
+            }
+            _ => {}
+        }
+        for child in &self.children {
+            columns.extend(child.get_predicate_columns());
+        }
+        columns
+    }
+
     // Add column index to Scan nodes that match the given table index
     pub fn add_column_index_to_scans(
         &self,
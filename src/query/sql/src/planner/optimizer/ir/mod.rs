@@ -29,6 +29,7 @@ pub use expr::VisitAction;
 pub use group::Group;
 pub use group::GroupState;
 pub use memo::Memo;
+pub use property::derive_unique_keys;
 pub use property::Distribution;
 pub use property::DistributionEnforcer;
 pub use property::Enforcer;
@@ -42,6 +43,7 @@ pub use property::Statistics;
 pub use stats::ColumnStat;
 pub use stats::ColumnStatSet;
 pub use stats::HistogramBuilder;
+pub use stats::MultiColumnStat;
 pub use stats::NewStatistic;
 pub use stats::SelectivityEstimator;
 pub use stats::UniformSampleSet;
@@ -16,6 +16,7 @@ mod builder;
 mod enforcer;
 #[allow(clippy::module_inception)]
 mod property;
+mod unique_keys;
 
 pub use builder::RelExpr;
 pub use enforcer::DistributionEnforcer;
@@ -27,3 +28,4 @@ pub use property::RelationalProperty;
 pub use property::RequiredProperty;
 pub use property::StatInfo;
 pub use property::Statistics;
+pub use unique_keys::derive_unique_keys;
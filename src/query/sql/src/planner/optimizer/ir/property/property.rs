@@ -16,6 +16,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use crate::optimizer::ir::ColumnStatSet;
+use crate::optimizer::ir::MultiColumnStat;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::SortItem;
@@ -45,6 +46,8 @@ pub struct Statistics {
     pub precise_cardinality: Option<u64>,
     /// Statistics of columns, column index -> column stat
     pub column_stats: ColumnStatSet,
+    /// Statistics of column groups created by `CREATE STATISTICS`
+    pub multi_column_stats: Vec<MultiColumnStat>,
 }
 
 #[derive(Default, Clone, Debug)]
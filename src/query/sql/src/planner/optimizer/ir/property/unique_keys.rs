@@ -0,0 +1,102 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::optimizer::ir::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::ColumnSet;
+use crate::Metadata;
+use crate::ScalarExpr;
+
+/// Derive the unique keys of a relational expression.
+///
+/// Each returned column set is guaranteed to identify at most one output row,
+/// treating NULLs as equal. An empty set means the expression yields at most one row.
+/// Keys originate from declared `RELY` key constraints and from `GROUP BY`, and are
+/// propagated through operators that don't duplicate rows.
+pub fn derive_unique_keys(s_expr: &SExpr, metadata: &Metadata) -> Vec<ColumnSet> {
+    match s_expr.plan() {
+        RelOperator::Scan(scan) if scan.change_type.is_none() => metadata
+            .table_unique_keys(scan.table_index)
+            .into_iter()
+            .filter(|key| key.is_subset(&scan.columns))
+            .collect(),
+        RelOperator::Filter(_)
+        | RelOperator::SecureFilter(_)
+        | RelOperator::Sort(_)
+        | RelOperator::Limit(_)
+        | RelOperator::EvalScalar(_) => match s_expr.child(0) {
+            Ok(child) => derive_unique_keys(child, metadata),
+            Err(_) => vec![],
+        },
+        RelOperator::Aggregate(agg)
+            if matches!(agg.mode, AggregateMode::Initial | AggregateMode::Final)
+                && agg.grouping_sets.is_none() =>
+        {
+            vec![agg.group_items.iter().map(|item| item.index).collect()]
+        }
+        RelOperator::Join(join) => {
+            let (Ok(left), Ok(right)) = (s_expr.child(0), s_expr.child(1)) else {
+                return vec![];
+            };
+            let left_keys = derive_unique_keys(left, metadata);
+            let right_keys = derive_unique_keys(right, metadata);
+            let left_join_columns = join_key_columns(join.equi_conditions.iter().map(|c| &c.left));
+            let right_join_columns =
+                join_key_columns(join.equi_conditions.iter().map(|c| &c.right));
+            // A side keeps its keys if every row of it matches at most one row of the other side.
+            let left_matches_once = right_keys
+                .iter()
+                .any(|key| key.is_subset(&right_join_columns));
+            let right_matches_once = left_keys
+                .iter()
+                .any(|key| key.is_subset(&left_join_columns));
+
+            match join.join_type {
+                JoinType::Inner | JoinType::Cross => {
+                    let mut keys = Vec::new();
+                    if left_matches_once {
+                        keys.extend(left_keys.iter().cloned());
+                    }
+                    if right_matches_once {
+                        keys.extend(right_keys.iter().cloned());
+                    }
+                    for left_key in left_keys.iter() {
+                        for right_key in right_keys.iter() {
+                            keys.push(left_key.union(right_key).cloned().collect());
+                        }
+                    }
+                    keys
+                }
+                JoinType::Left if left_matches_once => left_keys,
+                JoinType::Right if right_matches_once => right_keys,
+                JoinType::LeftSemi | JoinType::LeftAnti => left_keys,
+                JoinType::RightSemi | JoinType::RightAnti => right_keys,
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    }
+}
+
+/// Columns that are directly compared by equi-join conditions.
+fn join_key_columns<'a>(conditions: impl Iterator<Item = &'a ScalarExpr>) -> ColumnSet {
+    conditions
+        .filter_map(|condition| match condition {
+            ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+            _ => None,
+        })
+        .collect()
+}
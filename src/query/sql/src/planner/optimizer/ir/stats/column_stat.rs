@@ -16,6 +16,7 @@ use std::collections::HashMap;
 
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
 
 use crate::IndexType;
 
@@ -38,6 +39,23 @@ pub struct ColumnStat {
 
     /// Histogram of column
     pub histogram: Option<Histogram>,
+
+    /// Most common values of column and their frequencies
+    pub mcv: Option<MostCommonValues>,
+}
+
+#[derive(Debug, Clone)]
+/// Statistics information of a group of columns
+pub struct MultiColumnStat {
+    /// Indexes of the columns in the group
+    pub columns: Vec<IndexType>,
+
+    /// Number of distinct combinations of the columns
+    pub ndv: f64,
+
+    /// Functional dependency degree of each column on the other columns,
+    /// in the same order as `columns`
+    pub dependencies: Vec<f64>,
 }
 
 #[derive(Debug, Clone)]
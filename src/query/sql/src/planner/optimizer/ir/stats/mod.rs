@@ -18,6 +18,7 @@ mod selectivity;
 
 pub use column_stat::ColumnStat;
 pub use column_stat::ColumnStatSet;
+pub use column_stat::MultiColumnStat;
 pub use column_stat::NewStatistic;
 pub use histogram::HistogramBuilder;
 pub use histogram::UniformSampleSet;
@@ -14,6 +14,7 @@
 
 use std::cmp::max;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use databend_common_exception::ErrorCode;
@@ -74,6 +75,55 @@ impl<'a> SelectivityEstimator<'a> {
         }
     }
 
+    /// Compute the selectivity of the conjunction of predicates.
+    ///
+    /// The predicates are assumed to be fully correlated unless a multi-column
+    /// statistics covers the columns of several equal predicates, whose dependency
+    /// degrees are used to combine their selectivities.
+    pub fn compute_conjunction_selectivity(
+        &mut self,
+        predicates: &[ScalarExpr],
+        update: bool,
+    ) -> Result<f64> {
+        let mut selectivity = MAX_SELECTIVITY;
+        let mut equal_selectivities = HashMap::new();
+        for pred in predicates.iter() {
+            // Compute selectivity for each conjunction
+            let pred_selectivity = self.compute_selectivity(pred, update)?;
+            if let Some(index) = equal_predicate_column(pred) {
+                equal_selectivities.insert(index, pred_selectivity);
+            }
+            selectivity = selectivity.min(pred_selectivity);
+        }
+
+        for stat in self.input_stat.multi_column_stats.iter() {
+            let mut selectivities = stat
+                .columns
+                .iter()
+                .zip(stat.dependencies.iter())
+                .filter_map(|(index, dependency)| {
+                    equal_selectivities
+                        .get(index)
+                        .map(|selectivity| (*selectivity, *dependency))
+                })
+                .collect::<Vec<_>>();
+            if selectivities.len() < 2 {
+                continue;
+            }
+            // Start from the most selective predicate, each of the others filters
+            // the rows only as far as its column isn't determined by the group.
+            selectivities.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let combined = selectivities[1..].iter().fold(
+                selectivities[0].0,
+                |acc, (selectivity, dependency)| {
+                    acc * (dependency + (1.0 - dependency) * selectivity)
+                },
+            );
+            selectivity = selectivity.min(combined);
+        }
+        Ok(selectivity)
+    }
+
     /// Compute the selectivity of a predicate.
     pub fn compute_selectivity(&mut self, predicate: &ScalarExpr, update: bool) -> Result<f64> {
         Ok(match predicate {
@@ -341,6 +391,9 @@ impl<'a> SelectivityEstimator<'a> {
 
     // Update other columns' statistic according to selectivity.
     pub fn update_other_statistic_by_selectivity(&mut self, selectivity: f64) {
+        for stat in self.input_stat.multi_column_stats.iter_mut() {
+            stat.ndv = (stat.ndv * selectivity).ceil();
+        }
         for (index, column_stat) in self.input_stat.column_stats.iter_mut() {
             if !self.updated_column_indexes.contains(index) {
                 let new_ndv = (column_stat.ndv * selectivity).ceil();
@@ -553,6 +606,23 @@ impl<'a> SelectivityEstimator<'a> {
     }
 }
 
+// Returns the column index if the predicate is an equal predicate between a column and a constant.
+fn equal_predicate_column(predicate: &ScalarExpr) -> Option<IndexType> {
+    let ScalarExpr::FunctionCall(func) = predicate else {
+        return None;
+    };
+    if ComparisonOp::try_from_func_name(&func.func_name) != Some(ComparisonOp::Equal) {
+        return None;
+    }
+    match (&func.arguments[0], &func.arguments[1]) {
+        (ScalarExpr::BoundColumnRef(column_ref), ScalarExpr::ConstantExpr(_))
+        | (ScalarExpr::ConstantExpr(_), ScalarExpr::BoundColumnRef(column_ref)) => {
+            Some(column_ref.column.index)
+        }
+        _ => None,
+    }
+}
+
 // TODO(andylokandy): match on non-null boolean only once we have constant folding in the optimizer.
 fn is_true_constant_predicate(constant: &ConstantExpr) -> bool {
     match &constant.value {
@@ -606,6 +676,16 @@ fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
                 return 0.0;
             }
         }
+
+        if let Some(mcv) = &column_stat.mcv {
+            if let Some(frequency) = mcv.frequency(constant_datum) {
+                return frequency;
+            }
+            // The value isn't a most common value, spread the rest rows
+            // over the rest distinct values.
+            let rest_ndv = (column_stat.ndv - mcv.len() as f64).max(1.0);
+            return (1.0 - mcv.total_frequency()) / rest_ndv;
+        }
     }
 
     if column_stat.ndv == 0.0 {
@@ -623,6 +703,8 @@ fn update_statistic(
 ) -> Result<()> {
     let new_ndv = (column_stat.ndv * selectivity).ceil();
     column_stat.ndv = new_ndv;
+    // The frequencies are no longer relative to the filtered rows.
+    column_stat.mcv = None;
     if matches!(
         new_min,
         Datum::Bool(_) | Datum::Int(_) | Datum::UInt(_) | Datum::Float(_)
@@ -28,10 +28,13 @@ use crate::optimizer::ir::SExpr;
 use crate::optimizer::optimizers::distributed::BroadcastToShuffleOptimizer;
 use crate::optimizer::optimizers::operator::CleanupUnusedCTEOptimizer;
 use crate::optimizer::optimizers::operator::DeduplicateJoinConditionOptimizer;
+use crate::optimizer::optimizers::operator::EliminateJoinOptimizer;
+use crate::optimizer::optimizers::operator::JoinHintOptimizer;
 use crate::optimizer::optimizers::operator::PullUpFilterOptimizer;
 use crate::optimizer::optimizers::operator::RuleNormalizeAggregateOptimizer;
 use crate::optimizer::optimizers::operator::RuleStatsAggregateOptimizer;
 use crate::optimizer::optimizers::operator::SingleToInnerOptimizer;
+use crate::optimizer::optimizers::operator::SkewJoinOptimizer;
 use crate::optimizer::optimizers::operator::SubqueryDecorrelatorOptimizer;
 use crate::optimizer::optimizers::recursive::RecursiveRuleOptimizer;
 use crate::optimizer::optimizers::rule::RuleID;
@@ -54,6 +57,7 @@ use crate::plans::Plan;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::SetScalarsOrQuery;
+use crate::ColumnSet;
 use crate::InsertInputSource;
 
 #[fastrace::trace]
@@ -68,7 +72,9 @@ pub async fn optimize(opt_ctx: Arc<OptimizerContext>, plan: Plan) -> Result<Plan
             formatted_ast,
             ignore_result,
         } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(opt_ctx, *s_expr).await?),
+            s_expr: Box::new(
+                optimize_query(opt_ctx, *s_expr, Some(bind_context.column_set())).await?,
+            ),
             bind_context,
             metadata,
             rewrite_kind,
@@ -232,12 +238,22 @@ pub async fn optimize(opt_ctx: Arc<OptimizerContext>, plan: Plan) -> Result<Plan
         //     plan.query_plan = Box::new(optimize(opt_ctx.clone(), *plan.query_plan.clone()).await?);
         //     Ok(Plan::RefreshIndex(plan))
         // }
+        Plan::CreatePlanBaseline(mut plan) => {
+            plan.query = Box::new(Box::pin(optimize(opt_ctx, *plan.query)).await?);
+            Ok(Plan::CreatePlanBaseline(plan))
+        }
         // Pass through statements.
         _ => Ok(plan),
     }
 }
 
-pub async fn optimize_query(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Result<SExpr> {
+/// `required` is the set of output columns of the query, all output columns
+/// of `s_expr` are considered required if it's `None`.
+pub async fn optimize_query(
+    opt_ctx: Arc<OptimizerContext>,
+    s_expr: SExpr,
+    required: Option<ColumnSet>,
+) -> Result<SExpr> {
     let mut pipeline = OptimizerPipeline::new(opt_ctx.clone(), s_expr.clone())
         .await?
         // 2. Eliminate subqueries by rewriting them into more efficient form
@@ -255,34 +271,40 @@ pub async fn optimize_query(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Re
             opt_ctx.clone(),
             &DEFAULT_REWRITE_RULES,
         ))
-        // 8. CTE filter pushdown optimization
+        // 8. Eliminate outer joins whose unique side is not used
+        .add(EliminateJoinOptimizer::new(opt_ctx.clone(), required))
+        // 9. CTE filter pushdown optimization
         .add(CTEFilterPushdownOptimizer::new(opt_ctx.clone()))
-        // 9. Run post rewrite rules
+        // 10. Run post rewrite rules
         .add(RecursiveRuleOptimizer::new(opt_ctx.clone(), &[
             RuleID::SplitAggregate,
         ]))
-        // 10. Apply DPhyp algorithm for cost-based join reordering
+        // 11. Apply DPhyp algorithm for cost-based join reordering
         .add(DPhpyOptimizer::new(opt_ctx.clone()))
-        // 11. After join reorder, Convert some single join to inner join.
+        // 12. After join reorder, Convert some single join to inner join.
         .add(SingleToInnerOptimizer::new())
-        // 12. Deduplicate join conditions.
+        // 13. Deduplicate join conditions.
         .add(DeduplicateJoinConditionOptimizer::new())
-        // 13. Apply join commutativity to further optimize join ordering
+        // 14. Attach join hints, hinted joins are not reordered by the following optimizers.
+        .add(JoinHintOptimizer::new(opt_ctx.clone()))
+        // 15. Attach the hot keys of skewed joins from the most common values statistics.
+        .add(SkewJoinOptimizer::new(opt_ctx.clone()))
+        // 16. Apply join commutativity to further optimize join ordering
         .add_if(
             opt_ctx.get_enable_join_reorder(),
             RecursiveRuleOptimizer::new(opt_ctx.clone(), [RuleID::CommuteJoin].as_slice()),
         )
-        // 14. Cascades optimizer may fail due to timeout, fallback to heuristic optimizer in this case.
+        // 17. Cascades optimizer may fail due to timeout, fallback to heuristic optimizer in this case.
         .add(CascadesOptimizer::new(opt_ctx.clone())?)
-        // 15. Eliminate unnecessary scalar calculations to clean up the final plan
+        // 18. Eliminate unnecessary scalar calculations to clean up the final plan
         .add_if(
             !opt_ctx.get_planning_agg_index(),
             RecursiveRuleOptimizer::new(opt_ctx.clone(), [RuleID::EliminateEvalScalar].as_slice()),
         )
-        // 16. Clean up unused CTEs
+        // 19. Clean up unused CTEs
         .add(CleanupUnusedCTEOptimizer);
 
-    // 17. Execute the pipeline
+    // 20. Execute the pipeline
     let s_expr = pipeline.execute().await?;
 
     Ok(s_expr)
@@ -309,6 +331,7 @@ async fn get_optimized_memo(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Re
         ]))
         // Cost based optimization
         .add(DPhpyOptimizer::new(opt_ctx.clone()))
+        .add(JoinHintOptimizer::new(opt_ctx.clone()))
         .add(CascadesOptimizer::new(opt_ctx.clone())?);
 
     let _s_expr = pipeline.execute().await?;
@@ -318,7 +341,7 @@ async fn get_optimized_memo(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Re
 
 async fn optimize_mutation(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Result<Plan> {
     // Optimize the input plan.
-    let mut input_s_expr = optimize_query(opt_ctx.clone(), s_expr.child(0)?.clone()).await?;
+    let mut input_s_expr = optimize_query(opt_ctx.clone(), s_expr.child(0)?.clone(), None).await?;
     input_s_expr = RecursiveRuleOptimizer::new(opt_ctx.clone(), &[RuleID::MergeFilterIntoMutation])
         .optimize_sync(&input_s_expr)?;
 
@@ -330,7 +353,7 @@ async fn optimize_mutation(opt_ctx: Arc<OptimizerContext>, s_expr: SExpr) -> Res
     // optimize the input plan again.
     if input_s_expr.has_merge_exchange() {
         opt_ctx.set_enable_distributed_optimization(false);
-        input_s_expr = optimize_query(opt_ctx.clone(), s_expr.child(0)?.clone()).await?;
+        input_s_expr = optimize_query(opt_ctx.clone(), s_expr.child(0)?.clone(), None).await?;
     }
 
     let mut mutation: Mutation = s_expr.plan().clone().try_into()?;
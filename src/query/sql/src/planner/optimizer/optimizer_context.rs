@@ -24,6 +24,7 @@ use parking_lot::RwLock;
 use crate::optimizer::optimizers::rule::RuleID;
 use crate::planner::QueryExecutor;
 use crate::MetadataRef;
+use crate::PlanShape;
 
 #[derive(Educe)]
 #[educe(Debug)]
@@ -40,6 +41,8 @@ pub struct OptimizerContext {
     planning_agg_index: RwLock<bool>,
     #[educe(Debug(ignore))]
     sample_executor: RwLock<Option<Arc<dyn QueryExecutor>>>,
+    // The plan shape to reproduce, from the plan baseline of the query
+    plan_baseline: RwLock<Option<Arc<PlanShape>>>,
 
     // Optimizer state flags
     #[educe(Debug(ignore))]
@@ -61,6 +64,7 @@ impl OptimizerContext {
             enable_dphyp: RwLock::new(true),
             max_push_down_limit: RwLock::new(10000),
             sample_executor: RwLock::new(None),
+            plan_baseline: RwLock::new(None),
             planning_agg_index: RwLock::new(false),
             flags: RwLock::new(HashMap::new()),
             enable_trace: RwLock::new(false),
@@ -118,6 +122,18 @@ impl OptimizerContext {
         self.sample_executor.read().clone()
     }
 
+    pub fn set_plan_baseline(
+        self: &Arc<Self>,
+        plan_baseline: Option<Arc<PlanShape>>,
+    ) -> &Arc<Self> {
+        *self.plan_baseline.write() = plan_baseline;
+        self
+    }
+
+    pub fn get_plan_baseline(&self) -> Option<Arc<PlanShape>> {
+        self.plan_baseline.read().clone()
+    }
+
     pub fn set_planning_agg_index(self: &Arc<Self>, enable: bool) -> &Arc<Self> {
         *self.planning_agg_index.write() = enable;
         self
@@ -15,3 +15,4 @@
 mod model;
 
 pub use model::DefaultCostModel;
+pub use model::KeyOrder;
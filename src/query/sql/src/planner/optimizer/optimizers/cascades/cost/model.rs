@@ -29,12 +29,23 @@ use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
 
+/// How the input of a sort-merge join is ordered on the join keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyOrder {
+    Unsorted,
+    /// The input is read from a table clustered on the join keys, its blocks are
+    /// mostly in order, but it still needs to be sorted.
+    Clustered,
+    Sorted,
+}
+
 #[derive(Default)]
 pub struct DefaultCostModel {
     compute_per_row: f64,
     hash_table_per_row: f64,
     aggregate_per_row: f64,
     network_per_row: f64,
+    sort_per_row: f64,
 
     /// The number of peers in the cluster to
     /// exchange data with.
@@ -56,11 +67,13 @@ impl DefaultCostModel {
         let hash_table_per_row = settings.get_cost_factor_hash_table_per_row()? as f64;
         let aggregate_per_row = settings.get_cost_factor_aggregate_per_row()? as f64;
         let network_per_row = settings.get_cost_factor_network_per_row()? as f64;
+        let sort_per_row = settings.get_cost_factor_sort_per_row()? as f64;
         Ok(DefaultCostModel {
             compute_per_row: 1.0,
             hash_table_per_row,
             aggregate_per_row,
             network_per_row,
+            sort_per_row,
             cluster_peers: 1,
             degree_of_parallelism: 8,
         })
@@ -76,6 +89,30 @@ impl DefaultCostModel {
         self
     }
 
+    /// Returns true if a sort-merge join over inputs of the given cardinalities
+    /// is cheaper than a hash join that builds the hash table on the right side.
+    /// Inputs that are already sorted on the join keys don't pay the sort cost, and
+    /// inputs clustered on the join keys are sorted at the cost of a merge pass.
+    pub fn prefer_merge_join(
+        &self,
+        left_card: f64,
+        right_card: f64,
+        left_order: KeyOrder,
+        right_order: KeyOrder,
+    ) -> bool {
+        let sort_cost = |card: f64, order: KeyOrder| match order {
+            KeyOrder::Sorted => 0.0,
+            KeyOrder::Clustered => card * self.compute_per_row,
+            KeyOrder::Unsorted => card * self.sort_per_row,
+        };
+
+        let hash_cost = right_card * self.hash_table_per_row + left_card * self.compute_per_row;
+        let merge_cost = sort_cost(left_card, left_order)
+            + sort_cost(right_card, right_order)
+            + (left_card + right_card) * self.compute_per_row;
+        merge_cost < hash_cost
+    }
+
     fn compute_cost_impl(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
         match m_expr.plan.as_ref() {
             RelOperator::Scan(plan) => self.compute_cost_scan(memo, m_expr, plan),
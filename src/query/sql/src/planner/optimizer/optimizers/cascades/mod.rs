@@ -18,3 +18,5 @@ pub mod rule;
 pub mod tasks;
 
 pub use cascade::CascadesOptimizer;
+pub use cost::DefaultCostModel;
+pub use cost::KeyOrder;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -43,6 +44,17 @@ use crate::ScalarExpr;
 const EMIT_THRESHOLD: usize = 10000;
 const RELATION_THRESHOLD: usize = 10;
 
+/// How a csg-cmp-pair is constrained by the plan baseline or the `LEADING` hint.
+enum JoinOrderCheck {
+    /// The pair can be joined in any order.
+    Free,
+    /// The pair is a join of the required join order, e.g. the next hinted relation
+    /// joined to the hinted prefix, `left_is_probe` tells which side is the probe side.
+    Fixed { left_is_probe: bool },
+    /// The pair breaks the required join order.
+    Invalid,
+}
+
 /// The join reorder algorithm follows the paper: Dynamic Programming Strikes Back
 /// See the paper for more details.
 pub struct DPhpyOptimizer {
@@ -57,6 +69,10 @@ pub struct DPhpyOptimizer {
     filters: HashSet<Filter>,
     // The number of times emit_csg_cmp is called
     emit_count: usize,
+    // Join relation indexes in the order of the `LEADING` hint
+    leading: Option<Vec<IndexType>>,
+    // The (probe, build) join relation sets of the joins in the plan baseline
+    baseline: Option<Vec<(Vec<IndexType>, Vec<IndexType>)>>,
 }
 
 impl DPhpyOptimizer {
@@ -70,6 +86,8 @@ impl DPhpyOptimizer {
             relation_set_tree: Default::default(),
             filters: HashSet::new(),
             emit_count: 0,
+            leading: None,
+            baseline: None,
         }
     }
 
@@ -487,6 +505,8 @@ impl DPhpyOptimizer {
         }
 
         // Perform join reordering
+        self.leading = self.resolve_leading();
+        self.baseline = self.resolve_baseline();
         self.join_reorder().await?;
 
         // Get all join relations in `relation_set_tree`
@@ -494,6 +514,19 @@ impl DPhpyOptimizer {
             .relation_set_tree
             .get_relation_set(&(0..self.join_relations.len()).collect())?;
 
+        // The required join order can't be built from the query graph, ignore the
+        // baseline first and then the hint.
+        while (self.baseline.is_some() || self.leading.is_some())
+            && !self.dp_table.contains_key(&all_relations)
+        {
+            if self.baseline.take().is_none() {
+                self.leading = None;
+            }
+            self.dp_table.clear();
+            self.emit_count = 0;
+            self.join_reorder().await?;
+        }
+
         if let Some(final_plan) = self.dp_table.get(&all_relations) {
             let (s_expr, optimized) = self.generate_final_plan(final_plan, &s_expr)?;
             self.opt_ctx.set_flag("dphyp_optimized", optimized);
@@ -505,6 +538,135 @@ impl DPhpyOptimizer {
         }
     }
 
+    /// Map the tables of the `LEADING` hint to join relations, the hint only applies
+    /// if every hinted table is a distinct join relation of this join graph.
+    fn resolve_leading(&self) -> Option<Vec<IndexType>> {
+        let leading = self.metadata().read().leading_table_indexes();
+        if leading.is_empty() {
+            return None;
+        }
+        let relations = leading
+            .iter()
+            .map(|table_index| self.table_index_map.get(table_index).copied())
+            .collect::<Option<Vec<_>>>()?;
+        if relations.iter().collect::<HashSet<_>>().len() != relations.len() {
+            return None;
+        }
+        Some(relations)
+    }
+
+    /// Map the joins of the plan baseline to join relations of this join graph. The baseline
+    /// only applies if it has a join tree over exactly the tables of this join graph, and
+    /// every join of the tree joins two disjoint sets of join relations.
+    fn resolve_baseline(&self) -> Option<Vec<(Vec<IndexType>, Vec<IndexType>)>> {
+        let plan_baseline = self.opt_ctx.get_plan_baseline()?;
+        let graph_tables = self
+            .table_index_map
+            .keys()
+            .copied()
+            .collect::<BTreeSet<_>>();
+
+        let mut joins = vec![];
+        plan_baseline.for_each_join(&mut |_, left, right| joins.push((left, right)));
+        if !joins.iter().any(|(left, right)| {
+            left.union(right).copied().collect::<BTreeSet<_>>() == graph_tables
+        }) {
+            return None;
+        }
+
+        let to_relations = |tables: &BTreeSet<IndexType>| -> Vec<IndexType> {
+            let relations = tables
+                .iter()
+                .map(|table_index| self.table_index_map[table_index])
+                .collect::<BTreeSet<_>>();
+            relations.into_iter().collect()
+        };
+        let mut pairs = vec![];
+        for (left, right) in joins {
+            // Joins of other join trees.
+            if !left.is_subset(&graph_tables) || !right.is_subset(&graph_tables) {
+                continue;
+            }
+            let (left, right) = (to_relations(&left), to_relations(&right));
+            // Joins inside a join relation.
+            if left.len() == 1 && left == right {
+                continue;
+            }
+            if intersect(&left, &right) {
+                return None;
+            }
+            pairs.push((left, right));
+        }
+        Some(pairs)
+    }
+
+    fn check_baseline(&self, left: &[IndexType], right: &[IndexType]) -> JoinOrderCheck {
+        let Some(baseline) = &self.baseline else {
+            return JoinOrderCheck::Free;
+        };
+        let sorted = |nodes: &[IndexType]| {
+            let mut nodes = nodes.to_vec();
+            nodes.sort();
+            nodes
+        };
+        let (left, right) = (sorted(left), sorted(right));
+        for (probe, build) in baseline.iter() {
+            if probe == &left && build == &right {
+                return JoinOrderCheck::Fixed {
+                    left_is_probe: true,
+                };
+            }
+            if probe == &right && build == &left {
+                return JoinOrderCheck::Fixed {
+                    left_is_probe: false,
+                };
+            }
+        }
+        JoinOrderCheck::Invalid
+    }
+
+    fn check_leading(&self, left: &[IndexType], right: &[IndexType]) -> JoinOrderCheck {
+        let Some(leading) = &self.leading else {
+            return JoinOrderCheck::Free;
+        };
+        let left_hinted = left.iter().filter(|r| leading.contains(*r)).count();
+        let right_hinted = right.iter().filter(|r| leading.contains(*r)).count();
+        if left_hinted == 0 && right_hinted == 0 {
+            return JoinOrderCheck::Free;
+        }
+
+        if left_hinted == left.len() && right_hinted == right.len() {
+            // Both sides only contain hinted relations, they must form `prefix JOIN next`.
+            let is_prefix = |nodes: &[IndexType]| {
+                nodes.len() < leading.len()
+                    && leading[..nodes.len()].iter().all(|r| nodes.contains(r))
+            };
+            let is_next = |prefix: &[IndexType], nodes: &[IndexType]| {
+                nodes.len() == 1 && nodes[0] == leading[prefix.len()]
+            };
+            if is_prefix(left) && is_next(left, right) {
+                return JoinOrderCheck::Fixed {
+                    left_is_probe: true,
+                };
+            }
+            if is_prefix(right) && is_next(right, left) {
+                return JoinOrderCheck::Fixed {
+                    left_is_probe: false,
+                };
+            }
+            return JoinOrderCheck::Invalid;
+        }
+
+        // Other relations can only be joined after all hinted relations are joined.
+        if (left_hinted == leading.len() && right_hinted == 0)
+            || (right_hinted == leading.len() && left_hinted == 0)
+        {
+            JoinOrderCheck::Free
+        } else {
+            JoinOrderCheck::Invalid
+        }
+    }
+
     /// Build the query graph from join conditions
     fn build_query_graph(&mut self, join_conditions: &[(ScalarExpr, ScalarExpr)]) -> Result<bool> {
         for (left_condition, right_condition) in join_conditions.iter() {
@@ -730,6 +892,9 @@ impl DPhpyOptimizer {
         if !self.join_reorder_by_dphyp().await? {
             // When DPhpy takes too much time during join ordering, it is necessary to exit the dynamic programming algorithm
             // and switch to a greedy algorithm to minimizes the overall query time.
+            // The greedy algorithm doesn't follow the plan baseline and the `LEADING` hint.
+            self.leading = None;
+            self.baseline = None;
             self.join_reorder_by_greedy().await?;
         }
 
@@ -806,6 +971,7 @@ impl DPhpyOptimizer {
         join_conditions: Vec<(ScalarExpr, ScalarExpr)>,
         left_cardinality: f64,
         right_cardinality: f64,
+        swap_children: bool,
         left_join: JoinNode,
         right_join: JoinNode,
     ) -> Result<JoinNode> {
@@ -815,7 +981,7 @@ impl DPhpyOptimizer {
             let mut join_node = JoinNode {
                 join_type: JoinType::Inner,
                 leaves: Arc::new(parent_set.clone()),
-                children: if left_cardinality < right_cardinality {
+                children: if swap_children {
                     Arc::new(vec![right_join, left_join])
                 } else {
                     Arc::new(vec![left_join, right_join])
@@ -839,7 +1005,7 @@ impl DPhpyOptimizer {
             let join_node = JoinNode {
                 join_type: JoinType::Cross,
                 leaves: Arc::new(parent_set.clone()),
-                children: if left_cardinality < right_cardinality {
+                children: if swap_children {
                     Arc::new(vec![right_join, left_join])
                 } else {
                     Arc::new(vec![left_join, right_join])
@@ -871,8 +1037,17 @@ impl DPhpyOptimizer {
         let left_cardinality = left_join.cardinality(&self.join_relations).await?;
         let right_cardinality = right_join.cardinality(&self.join_relations).await?;
 
-        // Swap join conditions if left cardinality is smaller
-        if left_cardinality < right_cardinality {
+        // Swap join conditions if left cardinality is smaller, unless the order is required
+        let check = match self.check_baseline(left, right) {
+            JoinOrderCheck::Free => self.check_leading(left, right),
+            check => check,
+        };
+        let swap_children = match check {
+            JoinOrderCheck::Free => left_cardinality < right_cardinality,
+            JoinOrderCheck::Fixed { left_is_probe } => !left_is_probe,
+            JoinOrderCheck::Invalid => return Ok(f64::INFINITY),
+        };
+        if swap_children {
             for join_condition in join_conditions.iter_mut() {
                 std::mem::swap(&mut join_condition.0, &mut join_condition.1);
             }
@@ -886,6 +1061,7 @@ impl DPhpyOptimizer {
                 join_conditions,
                 left_cardinality,
                 right_cardinality,
+                swap_children,
                 left_join,
                 right_join,
             )
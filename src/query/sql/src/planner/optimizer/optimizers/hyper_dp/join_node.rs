@@ -21,6 +21,7 @@ use crate::optimizer::ir::SExpr;
 use crate::optimizer::optimizers::hyper_dp::JoinRelation;
 use crate::plans::Join;
 use crate::plans::JoinEquiCondition;
+use crate::plans::JoinHint;
 use crate::plans::JoinType;
 use crate::plans::RelOperator;
 use crate::IndexType;
@@ -95,6 +96,8 @@ impl JoinNode {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            hint: JoinHint::default(),
+            skew: None,
         });
         let children = self
             .children
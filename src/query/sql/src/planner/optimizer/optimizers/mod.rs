@@ -21,6 +21,8 @@ pub mod recursive;
 pub mod rule;
 
 pub use cascades::CascadesOptimizer;
+pub use cascades::DefaultCostModel;
+pub use cascades::KeyOrder;
 pub use cte_filter_pushdown::CTEFilterPushdownOptimizer;
 pub use hyper_dp::DPhpyOptimizer;
 pub use operator::CleanupUnusedCTEOptimizer;
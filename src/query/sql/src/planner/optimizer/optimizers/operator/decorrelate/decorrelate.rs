@@ -42,6 +42,7 @@ use crate::plans::Filter;
 use crate::plans::FunctionCall;
 use crate::plans::Join;
 use crate::plans::JoinEquiCondition;
+use crate::plans::JoinHint;
 use crate::plans::JoinType;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
@@ -185,6 +186,8 @@ impl SubqueryDecorrelatorOptimizer {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            hint: JoinHint::default(),
+            skew: None,
         };
 
         // Rewrite plan to semi-join.
@@ -280,6 +283,8 @@ impl SubqueryDecorrelatorOptimizer {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    hint: JoinHint::default(),
+                    skew: None,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -289,7 +294,10 @@ impl SubqueryDecorrelatorOptimizer {
                 Ok((s_expr, UnnestResult::SingleJoin))
             }
             SubqueryType::Exists | SubqueryType::NotExists => {
-                if is_conjunctive_predicate {
+                // The `NO_DECORRELATE` hint keeps the subquery as a mark join
+                // instead of rewriting it into a semi/anti join.
+                let no_decorrelate = self.metadata.read().optimizer_hints().no_decorrelate;
+                if is_conjunctive_predicate && !no_decorrelate {
                     if let Some(result) = self.try_decorrelate_simple_subquery(outer, subquery)? {
                         return Ok((result, UnnestResult::SimpleJoin { output_index: None }));
                     }
@@ -345,6 +353,8 @@ impl SubqueryDecorrelatorOptimizer {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    hint: JoinHint::default(),
+                    skew: None,
                 };
                 let s_expr = SExpr::create_binary(
                     Arc::new(join_plan.into()),
@@ -428,6 +438,8 @@ impl SubqueryDecorrelatorOptimizer {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    hint: JoinHint::default(),
+                    skew: None,
                 }
                 .into();
                 Ok((
@@ -39,6 +39,7 @@ use crate::plans::ExpressionScan;
 use crate::plans::Filter;
 use crate::plans::Join;
 use crate::plans::JoinEquiCondition;
+use crate::plans::JoinHint;
 use crate::plans::Operator;
 use crate::plans::ProjectSet;
 use crate::plans::RelOperator;
@@ -48,6 +49,7 @@ use crate::plans::Scan;
 use crate::plans::Sort;
 use crate::plans::UnionAll;
 use crate::plans::Window;
+use crate::plans::WindowFuncType;
 use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -448,6 +450,8 @@ impl SubqueryDecorrelatorOptimizer {
                     is_lateral: false,
                     single_to_inner: None,
                     build_side_cache_info: None,
+                    hint: JoinHint::default(),
+                    skew: None,
                 }
                 .into(),
             ),
@@ -815,13 +819,7 @@ impl SubqueryDecorrelatorOptimizer {
             RelOperator::Scan(scan) => self.clone_outer_scan(scan),
             RelOperator::EvalScalar(eval) => self.clone_outer_eval_scalar(eval)?,
             RelOperator::Limit(limit) => limit.clone().into(),
-            RelOperator::Sort(sort) => {
-                let mut sort = sort.clone();
-                for old in sort.used_columns() {
-                    sort.replace_column(old, self.get_derived(old)?);
-                }
-                sort.into()
-            }
+            RelOperator::Sort(sort) => self.clone_outer_sort(sort)?,
             RelOperator::Filter(filter) => {
                 let mut filter = filter.clone();
                 for predicate in &mut filter.predicates {
@@ -847,23 +845,18 @@ impl SubqueryDecorrelatorOptimizer {
                 }
                 join.into()
             }
-            RelOperator::Aggregate(aggregate) => {
-                let mut aggregate = aggregate.clone();
+            RelOperator::Aggregate(aggregate) => self.clone_outer_aggregate(aggregate)?,
+            RelOperator::Window(window) => self.clone_outer_window(window)?,
+            RelOperator::UnionAll(union_all) => self.clone_outer_union_all(union_all)?,
+            RelOperator::ProjectSet(project_set) => {
                 let metadata = self.metadata.clone();
                 let mut metadata = metadata.write();
-                for item in &mut aggregate.group_items {
-                    *item = self.clone_outer_scalar_item(item, &mut metadata)?;
-                }
-                for func in &mut aggregate.aggregate_functions {
-                    *func = self.clone_outer_scalar_item(func, &mut metadata)?;
-                }
-                aggregate.rank_limit = None;
-                if aggregate.grouping_sets.is_some() {
-                    return Err(ErrorCode::Unimplemented(
-                        "join left plan can't contain aggregate with GROUPING SETS to dcorrelated join right plan",
-                   ));
-                }
-                aggregate.into()
+                let srfs = project_set
+                    .srfs
+                    .iter()
+                    .map(|item| self.clone_outer_scalar_item(item, &mut metadata))
+                    .collect::<Result<_>>()?;
+                ProjectSet { srfs }.into()
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
@@ -875,6 +868,141 @@ impl SubqueryDecorrelatorOptimizer {
         Ok(op)
     }
 
+    fn clone_outer_sort(&mut self, sort: &Sort) -> Result<RelOperator> {
+        let mut sort = sort.clone();
+        // The partition items of window top-n are computed by the child, only the
+        // columns need to be replaced.
+        let window_partition = sort.window_partition.take();
+        for old in sort.used_columns() {
+            sort.replace_column(old, self.get_derived(old)?);
+        }
+        if let Some(mut window_partition) = window_partition {
+            for item in &mut window_partition.partition_by {
+                self.replace_outer_columns(&mut item.scalar)?;
+                item.index = self.get_derived(item.index)?;
+            }
+            self.replace_outer_window_func(&mut window_partition.func)?;
+            sort.window_partition = Some(window_partition);
+        }
+        Ok(sort.into())
+    }
+
+    fn clone_outer_aggregate(&mut self, aggregate: &Aggregate) -> Result<RelOperator> {
+        let mut aggregate = aggregate.clone();
+        let metadata = self.metadata.clone();
+        let mut metadata = metadata.write();
+
+        // `_grouping_id` is a group item that refers to itself, and the duplicated
+        // group items are generated by the aggregate, so derive them first.
+        if let Some(grouping_sets) = &mut aggregate.grouping_sets {
+            grouping_sets.grouping_id_index =
+                self.derive_outer_column(grouping_sets.grouping_id_index, &mut metadata);
+            for (index, _) in &mut grouping_sets.dup_group_items {
+                *index = self.derive_outer_column(*index, &mut metadata);
+            }
+        }
+        for item in &mut aggregate.group_items {
+            *item = self.clone_outer_scalar_item(item, &mut metadata)?;
+        }
+        for func in &mut aggregate.aggregate_functions {
+            *func = self.clone_outer_scalar_item(func, &mut metadata)?;
+        }
+        if let Some(grouping_sets) = &mut aggregate.grouping_sets {
+            for set in &mut grouping_sets.sets {
+                for index in set.iter_mut() {
+                    *index = self.get_derived(*index)?;
+                }
+                set.sort();
+            }
+        }
+        aggregate.rank_limit = None;
+        Ok(aggregate.into())
+    }
+
+    fn clone_outer_window(&mut self, window: &Window) -> Result<RelOperator> {
+        let mut window = window.clone();
+        let metadata = self.metadata.clone();
+        let mut metadata = metadata.write();
+        for item in &mut window.arguments {
+            *item = self.clone_outer_scalar_item(item, &mut metadata)?;
+        }
+        for item in &mut window.partition_by {
+            *item = self.clone_outer_scalar_item(item, &mut metadata)?;
+        }
+        for order_by in &mut window.order_by {
+            order_by.order_by_item =
+                self.clone_outer_scalar_item(&order_by.order_by_item, &mut metadata)?;
+        }
+        self.replace_outer_window_func(&mut window.function)?;
+        window.index = self.derive_outer_column(window.index, &mut metadata);
+        Ok(window.into())
+    }
+
+    fn clone_outer_union_all(&mut self, union_all: &UnionAll) -> Result<RelOperator> {
+        if !union_all.cte_scan_names.is_empty() {
+            return Err(ErrorCode::Unimplemented(
+                "join left plan can't contain recursive cte to dcorrelated join right plan",
+            ));
+        }
+
+        let mut union_all = union_all.clone();
+        for (index, cast) in union_all
+            .left_outputs
+            .iter_mut()
+            .chain(union_all.right_outputs.iter_mut())
+        {
+            *index = self.get_derived(*index)?;
+            if let Some(cast) = cast {
+                self.replace_outer_columns(cast)?;
+            }
+        }
+
+        let metadata = self.metadata.clone();
+        let mut metadata = metadata.write();
+        for index in &mut union_all.output_indexes {
+            *index = self.derive_outer_column(*index, &mut metadata);
+        }
+        Ok(union_all.into())
+    }
+
+    fn replace_outer_window_func(&self, func: &mut WindowFuncType) -> Result<()> {
+        match func {
+            WindowFuncType::Aggregate(agg) => {
+                for expr in agg.exprs_mut() {
+                    self.replace_outer_columns(expr)?;
+                }
+            }
+            WindowFuncType::LagLead(lag_lead) => {
+                self.replace_outer_columns(&mut lag_lead.arg)?;
+                if let Some(default) = &mut lag_lead.default {
+                    self.replace_outer_columns(default)?;
+                }
+            }
+            WindowFuncType::NthValue(nth_value) => {
+                self.replace_outer_columns(&mut nth_value.arg)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn replace_outer_columns(&self, scalar: &mut ScalarExpr) -> Result<()> {
+        for old in scalar.used_columns() {
+            scalar.replace_column(old, self.get_derived(old)?)?;
+        }
+        Ok(())
+    }
+
+    // Derive a column for the column generated by an operator of the outer plan.
+    fn derive_outer_column(&mut self, old: IndexType, metadata: &mut Metadata) -> IndexType {
+        let column_entry = metadata.column(old);
+        let name = column_entry.name();
+        let data_type = column_entry.data_type();
+        let index = metadata.add_derived_column(name, data_type, None);
+        self.derived_columns.insert(old, index);
+        index
+    }
+
     fn clone_outer_constant_table_scan(&mut self, scan: &ConstantTableScan) -> Result<RelOperator> {
         let mut metadata = self.metadata.write();
         let ((values, fields), columns) = scan
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::optimizers::rule::Rule;
+use crate::optimizer::optimizers::rule::RuleEliminateJoin;
+use crate::optimizer::optimizers::rule::TransformResult;
+use crate::optimizer::Optimizer;
+use crate::optimizer::OptimizerContext;
+use crate::plans::RelOperator;
+use crate::ColumnSet;
+
+// The EliminateJoinOptimizer collects the columns required by the parents of each join
+// top-down, and applies `RuleEliminateJoin` with them.
+pub struct EliminateJoinOptimizer {
+    opt_ctx: Arc<OptimizerContext>,
+    // Output columns of the plan, all columns of the root are required if not set.
+    required: Option<ColumnSet>,
+}
+
+impl EliminateJoinOptimizer {
+    pub fn new(opt_ctx: Arc<OptimizerContext>, required: Option<ColumnSet>) -> Self {
+        EliminateJoinOptimizer { opt_ctx, required }
+    }
+
+    pub fn optimize_sync(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let required = match &self.required {
+            Some(required) => required.clone(),
+            None => s_expr.derive_relational_prop()?.output_columns.clone(),
+        };
+        self.eliminate_join(s_expr, required)
+    }
+
+    #[recursive::recursive]
+    fn eliminate_join(&self, s_expr: &SExpr, mut required: ColumnSet) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Join(join) => {
+                let rule = RuleEliminateJoin::new(self.opt_ctx.get_metadata())
+                    .with_required_columns(required.clone());
+                if !self.opt_ctx.is_optimizer_disabled(&rule.name())
+                    && rule
+                        .matchers()
+                        .iter()
+                        .any(|matcher| matcher.matches(s_expr))
+                {
+                    let mut state = TransformResult::new();
+                    rule.apply(s_expr, &mut state)?;
+                    if let Some(result) = state.results().first() {
+                        return self.eliminate_join(result, required);
+                    }
+                }
+                required.extend(join.used_columns()?);
+            }
+            RelOperator::EvalScalar(eval_scalar) => required.extend(eval_scalar.used_columns()?),
+            RelOperator::Filter(filter) => required.extend(filter.used_columns()?),
+            RelOperator::SecureFilter(filter) => required.extend(filter.used_columns()?),
+            RelOperator::Sort(sort) if sort.window_partition.is_none() => {
+                required.extend(sort.used_columns())
+            }
+            RelOperator::Limit(limit) => required.extend(limit.lazy_columns.iter()),
+            RelOperator::Aggregate(aggregate) if aggregate.grouping_sets.is_none() => {
+                required.extend(aggregate.used_columns()?)
+            }
+            RelOperator::Window(window) => required.extend(window.used_columns()?),
+            // The columns used by the other operators are not tracked, keep their inputs as is.
+            _ => return Ok(s_expr.clone()),
+        }
+
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let new_child = self.eliminate_join(child, required.clone())?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+        }
+        if children_changed {
+            return Ok(s_expr.replace_children(children));
+        }
+        Ok(s_expr.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl Optimizer for EliminateJoinOptimizer {
+    fn name(&self) -> String {
+        "EliminateJoinOptimizer".to_string()
+    }
+
+    async fn optimize(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        self.optimize_sync(s_expr)
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::Optimizer;
+use crate::optimizer::OptimizerContext;
+use crate::plans::Join;
+use crate::plans::JoinDistributionHint;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::JoinShape;
+use crate::Metadata;
+use crate::PlanShape;
+
+type TableSet = BTreeSet<IndexType>;
+
+// The JoinHintOptimizer attaches the join hints of the query to the join operators,
+// so that the following optimizers keep the hinted join order and distribution.
+// A join matches `LEADING(t1 t2 ... tn)` if its probe side is exactly `t1 ... tk` and its
+// build side is `tk+1`. A join matches `BROADCAST(t)` if its build side only contains
+// hinted tables, and `SHUFFLE(t)` if either side only contains hinted tables.
+// Inner joins are commuted to put the hinted tables on the expected side. The children of
+// an outer, semi or anti join are never commuted, a hint which would need it is ignored
+// with a warning, because it would turn the join into one which can't run in parallel.
+// If the query has a plan baseline, a join whose children have the same tables as a join of
+// the baseline takes its side, distribution and join method from the baseline instead.
+pub struct JoinHintOptimizer {
+    opt_ctx: Arc<OptimizerContext>,
+}
+
+struct BaselineJoin {
+    left: TableSet,
+    right: TableSet,
+    distribution: Option<JoinDistributionHint>,
+    hash_join: bool,
+}
+
+struct ResolvedHints {
+    baseline: Vec<BaselineJoin>,
+    leading: Vec<IndexType>,
+    broadcast: TableSet,
+    shuffle: TableSet,
+    hash_join: Vec<TableSet>,
+}
+
+impl ResolvedHints {
+    fn resolve(metadata: &Metadata, plan_baseline: Option<&PlanShape>) -> Self {
+        let hints = metadata.optimizer_hints();
+        let resolve_tables = |tables: &[String]| -> TableSet {
+            tables
+                .iter()
+                .flat_map(|table| metadata.hinted_table_indexes(table))
+                .collect()
+        };
+
+        let mut baseline = vec![];
+        if let Some(plan_baseline) = plan_baseline {
+            plan_baseline.for_each_join(&mut |join, left, right| {
+                if let JoinShape::Join {
+                    distribution,
+                    hash_join,
+                    ..
+                } = join
+                {
+                    baseline.push(BaselineJoin {
+                        left,
+                        right,
+                        distribution: *distribution,
+                        hash_join: *hash_join,
+                    });
+                }
+            });
+        }
+
+        ResolvedHints {
+            baseline,
+            leading: metadata.leading_table_indexes(),
+            broadcast: resolve_tables(&hints.broadcast),
+            shuffle: resolve_tables(&hints.shuffle),
+            hash_join: hints
+                .hash_join
+                .iter()
+                .map(|tables| resolve_tables(tables))
+                .collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.baseline.is_empty()
+            && self.leading.is_empty()
+            && self.broadcast.is_empty()
+            && self.shuffle.is_empty()
+            && self.hash_join.is_empty()
+    }
+
+    // Returns the join of the baseline with the same children, and whether the children
+    // need to be commuted to match it.
+    fn match_baseline(&self, left: &TableSet, right: &TableSet) -> Option<(&BaselineJoin, bool)> {
+        self.baseline.iter().find_map(|join| {
+            if &join.left == left && &join.right == right {
+                Some((join, false))
+            } else if &join.left == right && &join.right == left {
+                Some((join, true))
+            } else {
+                None
+            }
+        })
+    }
+
+    // Returns `Some(false)` if the join matches the `LEADING` order,
+    // `Some(true)` if it matches after commuting the children.
+    fn match_leading(&self, left: &TableSet, right: &TableSet) -> Option<bool> {
+        for k in 1..self.leading.len() {
+            let prefix = self.leading[..k].iter().copied().collect::<TableSet>();
+            let next = TableSet::from([self.leading[k]]);
+            if left == &prefix && right == &next {
+                return Some(false);
+            }
+            if right == &prefix && left == &next {
+                return Some(true);
+            }
+        }
+        None
+    }
+}
+
+impl JoinHintOptimizer {
+    pub fn new(opt_ctx: Arc<OptimizerContext>) -> Self {
+        JoinHintOptimizer { opt_ctx }
+    }
+
+    pub fn optimize_sync(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let plan_baseline = self.opt_ctx.get_plan_baseline();
+        let hints = ResolvedHints::resolve(
+            &self.opt_ctx.get_metadata().read(),
+            plan_baseline.as_deref(),
+        );
+        if hints.is_empty() {
+            return Ok(s_expr.clone());
+        }
+        let (s_expr, _) = self.apply_hints(s_expr, &hints)?;
+        Ok(s_expr)
+    }
+
+    #[recursive::recursive]
+    fn apply_hints(&self, s_expr: &SExpr, hints: &ResolvedHints) -> Result<(SExpr, TableSet)> {
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(s_expr.arity());
+        let mut children_tables = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let (new_child, tables) = self.apply_hints(child, hints)?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+            children_tables.push(tables);
+        }
+        let mut tables = children_tables
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<TableSet>();
+
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                tables.insert(scan.table_index);
+            }
+            RelOperator::Join(join) if join.build_side_cache_info.is_none() => {
+                let mut join = join.clone();
+                let (mut left, mut right) = (&children_tables[0], &children_tables[1]);
+                let mut commute = false;
+
+                if let Some((baseline, need_commute)) = hints.match_baseline(left, right) {
+                    if !need_commute || Self::can_commute(&join) {
+                        join.hint.baseline = true;
+                        join.hint.distribution = baseline.distribution;
+                        join.hint.hash_join = baseline.hash_join && join.equi_conditions.is_empty();
+                        commute = need_commute;
+                    }
+                }
+                // The baseline takes precedence over the hints of the query.
+                if !join.hint.baseline {
+                    if let Some(need_commute) = hints.match_leading(left, right) {
+                        if !need_commute || Self::can_commute(&join) {
+                            join.hint.leading = true;
+                            commute = need_commute;
+                        } else {
+                            self.warn_ignored_hint("LEADING", &join);
+                        }
+                    }
+                    if !join.hint.leading {
+                        let only_hinted =
+                            |tables: &TableSet, hinted: &TableSet| tables.is_subset(hinted);
+                        if !right.is_empty() && only_hinted(right, &hints.broadcast) {
+                            join.hint.distribution = Some(JoinDistributionHint::Broadcast);
+                        } else if !left.is_empty() && only_hinted(left, &hints.broadcast) {
+                            if Self::can_commute(&join) {
+                                join.hint.distribution = Some(JoinDistributionHint::Broadcast);
+                                commute = true;
+                            } else {
+                                self.warn_ignored_hint("BROADCAST", &join);
+                            }
+                        }
+                    }
+                    if join.hint.distribution.is_none()
+                        && ((!left.is_empty() && left.is_subset(&hints.shuffle))
+                            || (!right.is_empty() && right.is_subset(&hints.shuffle)))
+                    {
+                        join.hint.distribution = Some(JoinDistributionHint::Shuffle);
+                    }
+                }
+
+                if commute {
+                    for condition in join.equi_conditions.iter_mut() {
+                        (condition.left, condition.right) =
+                            (condition.right.clone(), condition.left.clone());
+                    }
+                    join.join_type = join.join_type.opposite();
+                    children.swap(0, 1);
+                    (left, right) = (right, left);
+                }
+
+                if !join.hint.baseline {
+                    join.hint.hash_join = hints
+                        .hash_join
+                        .iter()
+                        .any(|hinted| !hinted.is_disjoint(left) && !hinted.is_disjoint(right));
+                }
+
+                if !join.hint.is_empty() {
+                    let s_expr = SExpr::create_binary(
+                        Arc::new(RelOperator::Join(join)),
+                        children[0].clone(),
+                        children[1].clone(),
+                    );
+                    return Ok((s_expr, tables));
+                }
+            }
+            _ => {}
+        }
+
+        if children_changed {
+            return Ok((s_expr.replace_children(children), tables));
+        }
+        Ok((s_expr.clone(), tables))
+    }
+
+    fn can_commute(join: &Join) -> bool {
+        matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+    }
+
+    fn warn_ignored_hint(&self, hint: &str, join: &Join) {
+        self.opt_ctx.get_table_ctx().push_warning(format!(
+            "{} hint is ignored, the children of a {} JOIN can't be swapped",
+            hint, join.join_type
+        ));
+    }
+}
+
+#[async_trait::async_trait]
+impl Optimizer for JoinHintOptimizer {
+    fn name(&self) -> String {
+        "JoinHintOptimizer".to_string()
+    }
+
+    async fn optimize(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        self.optimize_sync(s_expr)
+    }
+}
@@ -12,6 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod eliminate_join;
+mod join_hint;
 mod single_to_inner;
+mod skew_join;
 
+pub use eliminate_join::EliminateJoinOptimizer;
+pub use join_hint::JoinHintOptimizer;
 pub use single_to_inner::SingleToInnerOptimizer;
+pub use skew_join::SkewJoinOptimizer;
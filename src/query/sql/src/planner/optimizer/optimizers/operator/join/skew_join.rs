@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::cast_scalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_settings::SkewJoinMode;
+use databend_common_storage::Datum;
+
+use crate::optimizer::ir::RelExpr;
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::Optimizer;
+use crate::optimizer::OptimizerContext;
+use crate::plans::BoundColumnRef;
+use crate::plans::Join;
+use crate::plans::JoinDistributionHint;
+use crate::plans::JoinSkew;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+
+// The SkewJoinOptimizer finds the hot keys of single column equi-joins from the most common
+// values statistics of the key columns, and attaches them to the joins, see `JoinSkew`.
+// The hot rows of a skewed side are spread across the nodes and the hot rows of the other
+// side are broadcast, so the other side must not be preserved by the join.
+pub struct SkewJoinOptimizer {
+    opt_ctx: Arc<OptimizerContext>,
+}
+
+impl SkewJoinOptimizer {
+    pub fn new(opt_ctx: Arc<OptimizerContext>) -> Self {
+        SkewJoinOptimizer { opt_ctx }
+    }
+
+    pub fn optimize_sync(&self, s_expr: &SExpr) -> Result<SExpr> {
+        if !self.opt_ctx.get_enable_distributed_optimization() {
+            return Ok(s_expr.clone());
+        }
+
+        let settings = self.opt_ctx.get_table_ctx().get_settings();
+        let min_frequency = match settings.get_skew_join_mode()? {
+            SkewJoinMode::Disable => return Ok(s_expr.clone()),
+            SkewJoinMode::Force => 0.0,
+            SkewJoinMode::Auto => settings.get_skew_join_hot_key_percentage()? as f64 / 100.0,
+        };
+
+        Self::attach_skew(s_expr, min_frequency)
+    }
+
+    #[recursive::recursive]
+    fn attach_skew(s_expr: &SExpr, min_frequency: f64) -> Result<SExpr> {
+        let mut children_changed = false;
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let new_child = Self::attach_skew(child, min_frequency)?;
+            if !new_child.eq(child) {
+                children_changed = true;
+            }
+            children.push(Arc::new(new_child));
+        }
+
+        if let RelOperator::Join(join) = s_expr.plan() {
+            if let Some(skew) = Self::find_skew(s_expr, join, min_frequency)? {
+                let mut join = join.clone();
+                join.skew = Some(skew);
+                return Ok(SExpr::create_binary(
+                    Arc::new(RelOperator::Join(join)),
+                    children[0].clone(),
+                    children[1].clone(),
+                ));
+            }
+        }
+
+        if children_changed {
+            return Ok(s_expr.replace_children(children));
+        }
+        Ok(s_expr.clone())
+    }
+
+    fn find_skew(s_expr: &SExpr, join: &Join, min_frequency: f64) -> Result<Option<JoinSkew>> {
+        if join.equi_conditions.len() != 1
+            || join.need_hold_hash_table
+            || join.build_side_cache_info.is_some()
+            || join.hint.distribution == Some(JoinDistributionHint::Broadcast)
+        {
+            return Ok(None);
+        }
+
+        let condition = &join.equi_conditions[0];
+        let (
+            ScalarExpr::BoundColumnRef(BoundColumnRef { column: left, .. }),
+            ScalarExpr::BoundColumnRef(BoundColumnRef { column: right, .. }),
+        ) = (&condition.left, &condition.right)
+        else {
+            return Ok(None);
+        };
+        if condition.is_null_equal {
+            return Ok(None);
+        }
+
+        let rel_expr = RelExpr::with_s_expr(s_expr);
+        for (child_index, column) in [(0, left), (1, right)] {
+            if !JoinSkew::can_spread(&join.join_type, child_index) {
+                continue;
+            }
+
+            let stat_info = rel_expr.derive_cardinality_child(child_index)?;
+            let Some(mcv) = stat_info
+                .statistics
+                .column_stats
+                .get(&column.index)
+                .and_then(|stat| stat.mcv.as_ref())
+            else {
+                continue;
+            };
+
+            let hot_keys = mcv
+                .values
+                .iter()
+                .filter(|(_, frequency)| *frequency >= min_frequency)
+                .filter_map(|(value, _)| hot_key_scalar(value, &column.data_type))
+                .collect::<Vec<_>>();
+            if !hot_keys.is_empty() {
+                return Ok(Some(JoinSkew {
+                    column: column.index,
+                    hot_keys,
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+// Convert a most common value to a scalar of the key column type. Only exact types are
+// supported, the frequencies of floats and decimals are not reliable for equality.
+fn hot_key_scalar(value: &Datum, data_type: &DataType) -> Option<Scalar> {
+    let data_type = data_type.remove_nullable();
+    let scalar = match (value, &data_type) {
+        (Datum::Int(v), DataType::Number(ty)) if ty.is_integer() => {
+            Scalar::Number(NumberScalar::Int64(*v))
+        }
+        (Datum::UInt(v), DataType::Number(ty)) if ty.is_integer() => {
+            Scalar::Number(NumberScalar::UInt64(*v))
+        }
+        (Datum::Int(v), DataType::Date) => Scalar::Date(*v as i32),
+        (Datum::Int(v), DataType::Timestamp) => Scalar::Timestamp(*v),
+        (Datum::Bytes(v), DataType::String) => Scalar::String(String::from_utf8(v.clone()).ok()?),
+        _ => return None,
+    };
+    cast_scalar(None, scalar, data_type, &BUILTIN_FUNCTIONS).ok()
+}
+
+#[async_trait::async_trait]
+impl Optimizer for SkewJoinOptimizer {
+    fn name(&self) -> String {
+        "SkewJoinOptimizer".to_string()
+    }
+
+    async fn optimize(&mut self, s_expr: &SExpr) -> Result<SExpr> {
+        self.optimize_sync(s_expr)
+    }
+}
@@ -30,4 +30,7 @@ pub use filter::InferFilterOptimizer;
 pub use filter::JoinProperty;
 pub use filter::NormalizeDisjunctiveFilterOptimizer;
 pub use filter::PullUpFilterOptimizer;
+pub use join::EliminateJoinOptimizer;
+pub use join::JoinHintOptimizer;
 pub use join::SingleToInnerOptimizer;
+pub use join::SkewJoinOptimizer;
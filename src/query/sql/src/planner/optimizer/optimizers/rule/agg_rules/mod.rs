@@ -14,6 +14,7 @@
 
 mod agg_index;
 mod rule_eager_aggregation;
+mod rule_eliminate_aggregate;
 mod rule_fold_count_aggregate;
 mod rule_grouping_sets_to_union;
 mod rule_hierarchical_grouping_sets;
@@ -23,6 +24,7 @@ mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
 
 pub use rule_eager_aggregation::RuleEagerAggregation;
+pub use rule_eliminate_aggregate::RuleEliminateAggregate;
 pub use rule_fold_count_aggregate::RuleFoldCountAggregate;
 pub use rule_grouping_sets_to_union::RuleGroupingSetsToUnion;
 pub use rule_hierarchical_grouping_sets::RuleHierarchicalGroupingSetsToUnion;
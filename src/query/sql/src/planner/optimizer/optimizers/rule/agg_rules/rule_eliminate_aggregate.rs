@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::ir::derive_unique_keys;
+use crate::optimizer::ir::Matcher;
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::optimizers::rule::Rule;
+use crate::optimizer::optimizers::rule::RuleID;
+use crate::optimizer::optimizers::rule::TransformResult;
+use crate::plans::Aggregate;
+use crate::plans::AggregateMode;
+use crate::plans::EvalScalar;
+use crate::plans::RelOp;
+use crate::plans::ScalarExpr;
+use crate::ColumnSet;
+use crate::MetadataRef;
+
+/// Eliminate a `DISTINCT` or a `GROUP BY` without aggregate functions
+/// if the group items already cover a unique key of the input, e.g.
+/// `SELECT DISTINCT id, name FROM t` where `id` is the primary key of `t`.
+pub struct RuleEliminateAggregate {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+}
+
+impl RuleEliminateAggregate {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::EliminateAggregate,
+            //  Aggregate
+            //  \
+            //   *
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Aggregate,
+                children: vec![Matcher::Leaf],
+            }],
+            metadata,
+        }
+    }
+}
+
+impl Rule for RuleEliminateAggregate {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let agg: Aggregate = s_expr.plan().clone().try_into()?;
+        // An aggregate without group items always produces one row.
+        if agg.mode != AggregateMode::Initial
+            || agg.group_items.is_empty()
+            || !agg.aggregate_functions.is_empty()
+            || agg.grouping_sets.is_some()
+            || agg.rank_limit.is_some()
+        {
+            return Ok(());
+        }
+
+        let group_columns = agg
+            .group_items
+            .iter()
+            .filter_map(|item| match &item.scalar {
+                ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+                _ => None,
+            })
+            .collect::<ColumnSet>();
+        let input = s_expr.child(0)?;
+        let unique_keys = derive_unique_keys(input, &self.metadata.read());
+        if !unique_keys.iter().any(|key| key.is_subset(&group_columns)) {
+            return Ok(());
+        }
+
+        // Group items that are plain column references pass through the input,
+        // the others are evaluated on top of it.
+        let items = agg
+            .group_items
+            .into_iter()
+            .filter(|item| {
+                !matches!(&item.scalar, ScalarExpr::BoundColumnRef(column_ref)
+                    if column_ref.column.index == item.index)
+            })
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            state.add_result(input.clone());
+        } else {
+            state.add_result(SExpr::create_unary(
+                Arc::new(EvalScalar { items }.into()),
+                Arc::new(input.clone()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
@@ -58,7 +58,8 @@ impl Rule for RuleCommuteJoin {
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let mut join: Join = s_expr.plan().clone().try_into()?;
 
-        if join.build_side_cache_info.is_some() {
+        // Keep the join order fixed by hints.
+        if join.build_side_cache_info.is_some() || join.hint.is_pinned() {
             return Ok(());
         }
 
@@ -56,6 +56,9 @@ impl Rule for RuleCommuteJoinBaseTable {
 
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let mut join: Join = s_expr.plan().clone().try_into()?;
+        if join.hint.is_pinned() {
+            return Ok(());
+        }
         let left_child = s_expr.child(0)?;
         let right_child = s_expr.child(1)?;
 
@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+
+use crate::optimizer::ir::derive_unique_keys;
+use crate::optimizer::ir::Matcher;
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::optimizers::rule::Rule;
+use crate::optimizer::optimizers::rule::RuleID;
+use crate::optimizer::optimizers::rule::TransformResult;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOp;
+use crate::ColumnSet;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// Eliminate an outer join whose non-preserved side contributes no columns
+/// to the parent operators and matches at most one row per row of the preserved side,
+/// e.g. `SELECT f.a FROM f LEFT JOIN d ON f.k = d.id` where `id` is the primary key of `d`.
+///
+/// Whether a column is used can't be told from the join itself, so the rule only
+/// fires after the columns required by the parents are set with `with_required_columns`.
+pub struct RuleEliminateJoin {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+    required: Option<ColumnSet>,
+}
+
+impl RuleEliminateJoin {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::EliminateJoin,
+            // Join
+            // |  \
+            // *   *
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Join,
+                children: vec![Matcher::Leaf, Matcher::Leaf],
+            }],
+            metadata,
+            required: None,
+        }
+    }
+
+    pub fn with_required_columns(mut self, required: ColumnSet) -> Self {
+        self.required = Some(required);
+        self
+    }
+}
+
+impl Rule for RuleEliminateJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let Some(required) = &self.required else {
+            return Ok(());
+        };
+        let join: Join = s_expr.plan().clone().try_into()?;
+        if join.equi_conditions.is_empty()
+            || join.marker_index.is_some()
+            || join.from_correlated_subquery
+            || join.is_lateral
+            || join.single_to_inner.is_some()
+            || join.build_side_cache_info.is_some()
+        {
+            return Ok(());
+        }
+
+        let (preserved, eliminated, keys) = match join.join_type {
+            JoinType::Left => (
+                s_expr.child(0)?,
+                s_expr.child(1)?,
+                join.equi_conditions.iter().map(|c| &c.right),
+            ),
+            JoinType::Right => (
+                s_expr.child(1)?,
+                s_expr.child(0)?,
+                join.equi_conditions.iter().map(|c| &c.left),
+            ),
+            _ => return Ok(()),
+        };
+
+        let eliminated_prop = eliminated.derive_relational_prop()?;
+        if !eliminated_prop.output_columns.is_disjoint(required) {
+            return Ok(());
+        }
+
+        // Extra conditions can only reduce the matched rows, so the equi keys alone decide
+        // whether a preserved row may be duplicated.
+        let key_columns = keys
+            .filter_map(|key| match key {
+                ScalarExpr::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+                _ => None,
+            })
+            .collect::<ColumnSet>();
+        let unique_keys = derive_unique_keys(eliminated, &self.metadata.read());
+        if !unique_keys.iter().any(|key| key.is_subset(&key_columns)) {
+            return Ok(());
+        }
+
+        let mut result = preserved.clone();
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::binder::ColumnBindingBuilder;
+use crate::optimizer::ir::Matcher;
+use crate::optimizer::ir::SExpr;
+use crate::optimizer::optimizers::rule::Rule;
+use crate::optimizer::optimizers::rule::RuleID;
+use crate::optimizer::optimizers::rule::TransformResult;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::FunctionCall;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::plans::Scan;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+use crate::Visibility;
+
+/// Eliminate an inner join of a table with itself on a unique key,
+/// e.g. `SELECT a.x, b.y FROM t a JOIN t b ON a.id = b.id` where `id` is the primary key of `t`.
+///
+/// Every row of the left side matches exactly the same row of the right side, so the
+/// right scan is dropped and its columns are evaluated from the left scan. Filters of the
+/// right side are moved on top of the left side.
+pub struct RuleEliminateSelfJoin {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    metadata: MetadataRef,
+}
+
+impl RuleEliminateSelfJoin {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::EliminateSelfJoin,
+            // Join
+            // |  \
+            // *   *
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Join,
+                children: vec![Matcher::Leaf, Matcher::Leaf],
+            }],
+            metadata,
+        }
+    }
+}
+
+impl Rule for RuleEliminateSelfJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let join: Join = s_expr.plan().clone().try_into()?;
+        if join.join_type != JoinType::Inner
+            || join.equi_conditions.is_empty()
+            || join.from_correlated_subquery
+            || join.is_lateral
+            || join.single_to_inner.is_some()
+            || join.build_side_cache_info.is_some()
+        {
+            return Ok(());
+        }
+
+        let left = s_expr.child(0)?;
+        let Some((left_scan, _)) = scan_with_filters(left) else {
+            return Ok(());
+        };
+        let Some((right_scan, right_filters)) = scan_with_filters(s_expr.child(1)?) else {
+            return Ok(());
+        };
+
+        let metadata = self.metadata.read();
+        let Some(mapping) = map_scan_columns(&metadata, left_scan, right_scan) else {
+            return Ok(());
+        };
+
+        let mut predicates = Vec::new();
+        let mut key_columns = ColumnSet::new();
+        for condition in join.equi_conditions.iter() {
+            let (ScalarExpr::BoundColumnRef(left_column), ScalarExpr::BoundColumnRef(right_column)) =
+                (&condition.left, &condition.right)
+            else {
+                return Ok(());
+            };
+            if mapping.get(&right_column.column.index) != Some(&left_column.column.index) {
+                return Ok(());
+            }
+            key_columns.insert(right_column.column.index);
+            // The inner join drops the rows with NULL keys.
+            if !condition.is_null_equal && left_column.column.data_type.is_nullable_or_null() {
+                predicates.push(ScalarExpr::FunctionCall(FunctionCall {
+                    span: None,
+                    func_name: "is_not_null".to_string(),
+                    params: vec![],
+                    arguments: vec![condition.left.clone()],
+                }));
+            }
+        }
+        if !metadata
+            .table_unique_keys(right_scan.table_index)
+            .iter()
+            .any(|key| key.is_subset(&key_columns))
+        {
+            return Ok(());
+        }
+
+        for predicate in right_filters
+            .into_iter()
+            .chain(join.non_equi_conditions.iter().cloned())
+        {
+            let mut predicate = predicate;
+            for (right_index, left_index) in mapping.iter() {
+                predicate.replace_column(*right_index, *left_index)?;
+            }
+            predicates.push(predicate);
+        }
+
+        let mut input = left.clone();
+        if !predicates.is_empty() {
+            input = SExpr::create_unary(Arc::new(Filter { predicates }.into()), Arc::new(input));
+        }
+        let mut items = mapping
+            .iter()
+            .map(|(right_index, left_index)| {
+                let column = metadata.column(*left_index);
+                ScalarItem {
+                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                        span: None,
+                        column: ColumnBindingBuilder::new(
+                            column.name(),
+                            *left_index,
+                            Box::new(column.data_type()),
+                            Visibility::Visible,
+                        )
+                        .table_index(column.table_index())
+                        .build(),
+                    }),
+                    index: *right_index,
+                }
+            })
+            .collect::<Vec<_>>();
+        items.sort_by_key(|item| item.index);
+
+        let mut result =
+            SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(input));
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
+
+/// Find the scan below a chain of filters, along with the predicates applied to it.
+fn scan_with_filters(s_expr: &SExpr) -> Option<(&Scan, Vec<ScalarExpr>)> {
+    match s_expr.plan() {
+        RelOperator::Filter(filter) => {
+            let (scan, mut predicates) = scan_with_filters(s_expr.child(0).ok()?)?;
+            predicates.extend(filter.predicates.iter().cloned());
+            Some((scan, predicates))
+        }
+        RelOperator::Scan(scan) => {
+            // `push_down_predicates` are copies of the filters above, but `prewhere`
+            // takes the place of the filter it was built from.
+            let predicates = scan
+                .prewhere
+                .as_ref()
+                .map(|prewhere| prewhere.predicates.clone())
+                .unwrap_or_default();
+            Some((scan, predicates))
+        }
+        _ => None,
+    }
+}
+
+/// Map each column of the right scan to the same column of the left scan, if both
+/// scans read the same version of the same table in the same way.
+fn map_scan_columns(
+    metadata: &Metadata,
+    left: &Scan,
+    right: &Scan,
+) -> Option<HashMap<IndexType, IndexType>> {
+    let plain = |scan: &Scan| {
+        scan.limit.is_none()
+            && scan.order_by.is_none()
+            && scan.agg_index.is_none()
+            && scan.change_type.is_none()
+            && !scan.update_stream_columns
+            && scan.inverted_index.is_none()
+            && scan.vector_index.is_none()
+            && !scan.is_lazy_table
+            && scan.sample.is_none()
+    };
+    if !plain(left) || !plain(right) || left.table_index == right.table_index {
+        return None;
+    }
+    let left_table = metadata.table(left.table_index);
+    let right_table = metadata.table(right.table_index);
+    if left_table.catalog() != right_table.catalog()
+        || left_table.is_source_of_stage()
+        || right_table.is_source_of_stage()
+        || left_table.table().get_table_info() != right_table.table().get_table_info()
+    {
+        return None;
+    }
+
+    right
+        .columns
+        .iter()
+        .map(|right_index| {
+            let right_column = metadata.column(*right_index);
+            left.columns
+                .iter()
+                .find(|left_index| same_column(metadata.column(**left_index), right_column))
+                .map(|left_index| (*right_index, *left_index))
+        })
+        .collect()
+}
+
+fn same_column(left: &ColumnEntry, right: &ColumnEntry) -> bool {
+    match (left, right) {
+        (ColumnEntry::BaseTableColumn(left), ColumnEntry::BaseTableColumn(right)) => {
+            left.column_name == right.column_name
+                && left.path_indices == right.path_indices
+                && left.virtual_expr == right.virtual_expr
+        }
+        (ColumnEntry::InternalColumn(_), ColumnEntry::InternalColumn(_))
+        | (ColumnEntry::VirtualColumn(_), ColumnEntry::VirtualColumn(_)) => {
+            left.name() == right.name()
+        }
+        _ => false,
+    }
+}
@@ -109,6 +109,11 @@ impl Rule for RuleLeftExchangeJoin {
             return Ok(());
         }
 
+        // The exchanged joins can not carry the hints of the original joins.
+        if !join1.hint.is_empty() || !join2.hint.is_empty() {
+            return Ok(());
+        }
+
         // Check if original sexpr contains cross join.
         // We will reject the results contain cross join if there is no cross join in original sexpr.
         let contains_cross_join =
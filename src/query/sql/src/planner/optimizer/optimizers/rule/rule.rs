@@ -56,6 +56,8 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::PushDownLimitOuterJoin,
         RuleID::PushDownLimitScan,
         RuleID::SemiToInnerJoin,
+        RuleID::EliminateSelfJoin,
+        RuleID::EliminateAggregate,
         RuleID::FoldCountAggregate,
         RuleID::TryApplyAggIndex,
         RuleID::PushDownFilterScan,
@@ -116,6 +118,8 @@ pub enum RuleID {
     PushDownSortScan,
     PushDownSortFilterScan,
     SemiToInnerJoin,
+    EliminateJoin,
+    EliminateSelfJoin,
     EliminateEvalScalar,
     EliminateFilter,
     EliminateSort,
@@ -125,6 +129,7 @@ pub enum RuleID {
     GroupingSetsToUnion,
     HierarchicalGroupingSetsToUnion,
     SplitAggregate,
+    EliminateAggregate,
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
@@ -175,6 +180,7 @@ impl Display for RuleID {
             RuleID::GroupingSetsToUnion => write!(f, "GroupingSetsToUnion"),
             RuleID::HierarchicalGroupingSetsToUnion => write!(f, "HierarchicalGroupingSetsToUnion"),
             RuleID::SplitAggregate => write!(f, "SplitAggregate"),
+            RuleID::EliminateAggregate => write!(f, "EliminateAggregate"),
             RuleID::FoldCountAggregate => write!(f, "FoldCountAggregate"),
             RuleID::PushDownPrewhere => write!(f, "PushDownPrewhere"),
 
@@ -184,6 +190,8 @@ impl Display for RuleID {
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
+            RuleID::EliminateJoin => write!(f, "EliminateJoin"),
+            RuleID::EliminateSelfJoin => write!(f, "EliminateSelfJoin"),
             RuleID::EliminateUnion => write!(f, "EliminateUnion"),
 
             RuleID::MergeFilterIntoMutation => write!(f, "MergeFilterIntoMutation"),
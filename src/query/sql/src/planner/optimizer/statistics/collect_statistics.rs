@@ -22,6 +22,7 @@ use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 
+use crate::optimizer::ir::MultiColumnStat;
 use crate::optimizer::ir::SExpr;
 use crate::optimizer::Optimizer;
 use crate::optimizer::OptimizerContext;
@@ -73,6 +74,8 @@ impl CollectStatisticsOptimizer {
 
                 let mut column_stats = HashMap::new();
                 let mut histograms = HashMap::new();
+                let mut most_common_values = HashMap::new();
+                let mut column_indexes = HashMap::new();
                 for column in columns.iter() {
                     if let ColumnEntry::BaseTableColumn(BaseTableColumn {
                         column_index,
@@ -89,16 +92,52 @@ impl CollectStatisticsOptimizer {
                                 let histogram =
                                     column_statistics_provider.histogram(column_id as ColumnId);
                                 histograms.insert(*column_index, histogram);
+                                let mcv = column_statistics_provider
+                                    .most_common_values(column_id as ColumnId);
+                                most_common_values.insert(*column_index, mcv);
+                                column_indexes.insert(column_id as ColumnId, *column_index);
                             }
                         }
                     }
                 }
 
+                // A single-column unique key has as many distinct values as non-null rows,
+                // which tightens join cardinality estimation for key/foreign key joins.
+                if let Some(num_rows) = table_stats.as_ref().and_then(|stats| stats.num_rows)
+                    && scan.change_type.is_none()
+                {
+                    let unique_keys = self.metadata.read().table_unique_keys(scan.table_index);
+                    for key in unique_keys.iter().filter(|key| key.len() == 1) {
+                        if let Some(Some(col_stat)) = column_stats.get_mut(key.first().unwrap()) {
+                            col_stat.ndv = Some(num_rows.saturating_sub(col_stat.null_count));
+                        }
+                    }
+                }
+
+                let multi_column_stats = column_statistics_provider
+                    .multi_column_statistics()
+                    .into_iter()
+                    .filter_map(|stat| {
+                        let columns = stat
+                            .column_ids
+                            .iter()
+                            .map(|id| column_indexes.get(id).copied())
+                            .collect::<Option<Vec<_>>>()?;
+                        Some(MultiColumnStat {
+                            columns,
+                            ndv: stat.ndv as f64,
+                            dependencies: stat.dependencies,
+                        })
+                    })
+                    .collect();
+
                 let mut scan = scan.clone();
                 scan.statistics = Arc::new(Statistics {
                     table_stats,
                     column_stats,
                     histograms,
+                    most_common_values,
+                    multi_column_stats,
                 });
                 let mut s_expr = s_expr.replace_plan(Arc::new(RelOperator::Scan(scan.clone())));
                 if let Some(sample) = &scan.sample {
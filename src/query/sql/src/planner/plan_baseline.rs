@@ -0,0 +1,360 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use chrono::DateTime;
+use chrono::Utc;
+use dashmap::DashMap;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::Statement;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_users::UserApiProvider;
+use log::info;
+use log::warn;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::optimizer::ir::SExpr;
+use crate::plans::Exchange;
+use crate::plans::JoinDistributionHint;
+use crate::plans::JoinType;
+use crate::plans::Plan;
+use crate::plans::RelOperator;
+use crate::IndexType;
+use crate::Metadata;
+use crate::Planner;
+
+/// A table scanned by the plan. The table index is stable for the same query text.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ShapeTable {
+    pub index: IndexType,
+    pub name: String,
+}
+
+/// The shape of a join tree: the join order, the distribution and the join method.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum JoinShape {
+    /// A subtree that is not a join, e.g. a table scan or an aggregation.
+    Relation { tables: Vec<ShapeTable> },
+    Join {
+        join_type: JoinType,
+        distribution: Option<JoinDistributionHint>,
+        hash_join: bool,
+        left: Box<JoinShape>,
+        right: Box<JoinShape>,
+    },
+}
+
+/// The shape of an optimized plan that a plan baseline pins, one join tree per
+/// maximal join subtree of the plan.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PlanShape {
+    pub joins: Vec<JoinShape>,
+}
+
+impl PlanShape {
+    pub fn extract(s_expr: &SExpr, metadata: &Metadata) -> PlanShape {
+        let mut joins = vec![];
+        collect_join_trees(s_expr, metadata, &mut joins);
+        PlanShape { joins }
+    }
+
+    /// Visit every join of the shape, the callback receives the join and the
+    /// tables of its left and right children.
+    pub fn for_each_join(
+        &self,
+        f: &mut impl FnMut(&JoinShape, BTreeSet<IndexType>, BTreeSet<IndexType>),
+    ) {
+        for join in self.joins.iter() {
+            join.visit_joins(f);
+        }
+    }
+}
+
+impl JoinShape {
+    pub fn tables(&self) -> BTreeSet<IndexType> {
+        match self {
+            JoinShape::Relation { tables } => tables.iter().map(|table| table.index).collect(),
+            JoinShape::Join { left, right, .. } => {
+                let mut tables = left.tables();
+                tables.extend(right.tables());
+                tables
+            }
+        }
+    }
+
+    fn visit_joins(
+        &self,
+        f: &mut impl FnMut(&JoinShape, BTreeSet<IndexType>, BTreeSet<IndexType>),
+    ) -> BTreeSet<IndexType> {
+        match self {
+            JoinShape::Relation { tables } => tables.iter().map(|table| table.index).collect(),
+            JoinShape::Join { left, right, .. } => {
+                let left_tables = left.visit_joins(f);
+                let right_tables = right.visit_joins(f);
+                let tables = left_tables.union(&right_tables).copied().collect();
+                f(self, left_tables, right_tables);
+                tables
+            }
+        }
+    }
+}
+
+#[recursive::recursive]
+fn collect_join_trees(s_expr: &SExpr, metadata: &Metadata, joins: &mut Vec<JoinShape>) {
+    if let RelOperator::Join(_) = s_expr.plan() {
+        let join = join_shape(s_expr, metadata, joins);
+        joins.push(join);
+        return;
+    }
+    for child in s_expr.children() {
+        collect_join_trees(child, metadata, joins);
+    }
+}
+
+#[recursive::recursive]
+fn join_shape(s_expr: &SExpr, metadata: &Metadata, joins: &mut Vec<JoinShape>) -> JoinShape {
+    let RelOperator::Join(join) = s_expr.plan() else {
+        let mut tables = vec![];
+        collect_tables(s_expr, metadata, &mut tables);
+        tables.sort_by_key(|table| table.index);
+        // Join trees below the relation are recorded separately.
+        collect_join_trees(s_expr, metadata, joins);
+        return JoinShape::Relation { tables };
+    };
+
+    let distribution = match s_expr.children[1].plan() {
+        RelOperator::Exchange(Exchange::Broadcast) => Some(JoinDistributionHint::Broadcast),
+        RelOperator::Exchange(Exchange::Hash(_)) => Some(JoinDistributionHint::Shuffle),
+        _ => None,
+    };
+    let is_asof = matches!(
+        join.join_type,
+        JoinType::Asof | JoinType::LeftAsof | JoinType::RightAsof
+    );
+    let hash_join = !is_asof
+        && (!join.equi_conditions.is_empty()
+            || join.build_side_cache_info.is_some()
+            || join.hint.hash_join);
+
+    JoinShape::Join {
+        join_type: join.join_type.clone(),
+        distribution,
+        hash_join,
+        left: Box::new(join_shape(
+            skip_to_join(&s_expr.children[0]),
+            metadata,
+            joins,
+        )),
+        right: Box::new(join_shape(
+            skip_to_join(&s_expr.children[1]),
+            metadata,
+            joins,
+        )),
+    }
+}
+
+// Exchanges, filters and projections between two joins don't break the join tree.
+fn skip_to_join(s_expr: &SExpr) -> &SExpr {
+    let mut current = s_expr;
+    while matches!(
+        current.plan(),
+        RelOperator::Exchange(_) | RelOperator::Filter(_) | RelOperator::EvalScalar(_)
+    ) && current.arity() == 1
+    {
+        current = current.children[0].as_ref();
+    }
+    match current.plan() {
+        RelOperator::Join(_) => current,
+        _ => s_expr,
+    }
+}
+
+#[recursive::recursive]
+fn collect_tables(s_expr: &SExpr, metadata: &Metadata, tables: &mut Vec<ShapeTable>) {
+    if let RelOperator::Scan(scan) = s_expr.plan() {
+        let table = metadata.table(scan.table_index);
+        let name = match table.alias_name() {
+            Some(alias) => alias.clone(),
+            None => table.name().to_string(),
+        };
+        tables.push(ShapeTable {
+            index: scan.table_index,
+            name,
+        });
+    }
+    for child in s_expr.children() {
+        collect_tables(child, metadata, tables);
+    }
+}
+
+impl Display for PlanShape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.joins.is_empty() {
+            return write!(f, "NO JOIN");
+        }
+        for (i, join) in self.joins.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{join}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for JoinShape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinShape::Relation { tables } if tables.len() == 1 => write!(f, "{}", tables[0].name),
+            JoinShape::Relation { tables } => {
+                let names = tables.iter().map(|table| table.name.as_str());
+                write!(f, "{{{}}}", names.collect::<Vec<_>>().join(", "))
+            }
+            JoinShape::Join {
+                join_type,
+                distribution,
+                hash_join,
+                left,
+                right,
+            } => {
+                write!(f, "({left} {join_type}")?;
+                if *hash_join {
+                    write!(f, " HASH")?;
+                }
+                write!(f, " JOIN")?;
+                match distribution {
+                    Some(JoinDistributionHint::Broadcast) => write!(f, "[BROADCAST]")?,
+                    Some(JoinDistributionHint::Shuffle) => write!(f, "[SHUFFLE]")?,
+                    None => {}
+                }
+                write!(f, " {right})")
+            }
+        }
+    }
+}
+
+/// How often the plan baseline of a query was reproduced on this node.
+#[derive(Clone, Debug, Default)]
+pub struct PlanBaselineUsage {
+    pub hits: u64,
+    pub mismatches: u64,
+    pub last_used_on: Option<DateTime<Utc>>,
+    /// The latest optimized plan that differs from the baseline.
+    pub last_mismatch_plan: Option<String>,
+}
+
+// (tenant, sql hash) -> usage
+static PLAN_BASELINE_USAGE: LazyLock<DashMap<(String, String), PlanBaselineUsage>> =
+    LazyLock::new(DashMap::new);
+
+pub fn get_plan_baseline_usage(tenant: &str, sql_hash: &str) -> PlanBaselineUsage {
+    PLAN_BASELINE_USAGE
+        .get(&(tenant.to_string(), sql_hash.to_string()))
+        .map(|usage| usage.clone())
+        .unwrap_or_default()
+}
+
+pub fn reset_plan_baseline_usage(tenant: &str, sql_hash: &str) {
+    PLAN_BASELINE_USAGE.remove(&(tenant.to_string(), sql_hash.to_string()));
+}
+
+pub(crate) struct LoadedPlanBaseline {
+    sql_hash: String,
+    pub(crate) shape: Arc<PlanShape>,
+}
+
+impl Planner {
+    /// The key of the plan baseline of a query, the hash of the query text qualified by the
+    /// current catalog and database, which the table names of the query are resolved in.
+    pub fn plan_baseline_key(ctx: &dyn TableContext, query: &Query) -> String {
+        Self::planner_cache_key(&format!(
+            "{}.{}:{}",
+            ctx.get_current_catalog(),
+            ctx.get_current_database(),
+            query
+        ))
+    }
+
+    pub(crate) async fn load_plan_baseline(&self, stmt: &Statement) -> Option<LoadedPlanBaseline> {
+        // The baseline of a query also applies when the query is explained.
+        let query = match stmt {
+            Statement::Query(query) => query,
+            Statement::Explain { query, .. } => match query.as_ref() {
+                Statement::Query(query) => query,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if !matches!(self.ctx.get_settings().get_enable_plan_baseline(), Ok(true)) {
+            return None;
+        }
+
+        let sql_hash = Self::plan_baseline_key(self.ctx.as_ref(), query);
+        let tenant = self.ctx.get_tenant();
+        let baseline = match UserApiProvider::instance()
+            .plan_baseline_api(&tenant)
+            .get_baseline(&sql_hash)
+            .await
+        {
+            Ok(baseline) => baseline?,
+            Err(e) => {
+                warn!("[SQL-PLANNER] Failed to load plan baseline {sql_hash}: {e}");
+                return None;
+            }
+        };
+        match serde_json::from_str::<PlanShape>(&baseline.plan) {
+            Ok(shape) => Some(LoadedPlanBaseline {
+                sql_hash,
+                shape: Arc::new(shape),
+            }),
+            Err(e) => {
+                warn!("[SQL-PLANNER] Invalid plan baseline {sql_hash}: {e}");
+                None
+            }
+        }
+    }
+
+    /// Compare the optimized plan with the baseline it was planned with.
+    pub(crate) fn record_plan_baseline_usage(&self, baseline: &LoadedPlanBaseline, plan: &Plan) {
+        let Plan::Query {
+            s_expr, metadata, ..
+        } = plan
+        else {
+            return;
+        };
+        let shape = PlanShape::extract(s_expr, &metadata.read());
+
+        let tenant = self.ctx.get_tenant().tenant_name().to_string();
+        let mut usage = PLAN_BASELINE_USAGE
+            .entry((tenant, baseline.sql_hash.clone()))
+            .or_default();
+        usage.last_used_on = Some(Utc::now());
+        if shape == *baseline.shape {
+            usage.hits += 1;
+        } else {
+            info!(
+                "[SQL-PLANNER] Plan differs from plan baseline {}: {}",
+                baseline.sql_hash, shape
+            );
+            usage.mismatches += 1;
+            usage.last_mismatch_plan = Some(shape.to_string());
+        }
+    }
+}
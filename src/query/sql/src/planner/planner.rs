@@ -234,7 +234,10 @@ impl Planner {
         let settings = self.ctx.get_settings();
         // Step 3: Bind AST with catalog, and generate a pure logical SExpr
         let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-        let mut enable_planner_cache = self.ctx.get_settings().get_enable_planner_cache()?;
+        // A query with a plan baseline is always planned, the plan is checked against the baseline.
+        let plan_baseline = self.load_plan_baseline(stmt).await;
+        let mut enable_planner_cache =
+            self.ctx.get_settings().get_enable_planner_cache()? && plan_baseline.is_none();
         let planner_cache_key = if enable_planner_cache {
             Some(Self::planner_cache_key(&stmt.to_string()))
         } else {
@@ -283,10 +286,19 @@ impl Planner {
                     && !settings.get_enforce_local()?,
             )
             .set_sample_executor(self.query_executor.clone())
+            .set_plan_baseline(
+                plan_baseline
+                    .as_ref()
+                    .map(|baseline| baseline.shape.clone()),
+            )
             .clone();
 
         let optimized_plan = optimize(opt_ctx, plan).await?;
 
+        if let Some(baseline) = &plan_baseline {
+            self.record_plan_baseline_usage(baseline, &optimized_plan);
+        }
+
         if enable_planner_cache {
             self.set_cache(planner_cache_key.clone().unwrap(), optimized_plan.clone());
         }
@@ -154,10 +154,36 @@ impl Aggregate {
             cardinality
         } else {
             // A upper bound
-            let res = self.group_items.iter().fold(1.0, |acc, item| {
-                let item_stat = statistics.column_stats.get(&item.index).unwrap();
-                acc * item_stat.ndv
-            });
+            let group_columns = self
+                .group_items
+                .iter()
+                .map(|item| item.index)
+                .collect::<ColumnSet>();
+            // The number of distinct combinations of a column group bounds
+            // the product of the ndv of its columns.
+            let mut covered = ColumnSet::new();
+            let mut res = 1.0;
+            let mut multi_column_stats = statistics.multi_column_stats.iter().collect::<Vec<_>>();
+            multi_column_stats.sort_by_key(|stat| std::cmp::Reverse(stat.columns.len()));
+            for stat in multi_column_stats {
+                if stat
+                    .columns
+                    .iter()
+                    .all(|col| group_columns.contains(col) && !covered.contains(col))
+                {
+                    let product = stat.columns.iter().fold(1.0, |acc, col| {
+                        acc * statistics.column_stats.get(col).unwrap().ndv
+                    });
+                    res *= f64::min(stat.ndv, product);
+                    covered.extend(stat.columns.iter().copied());
+                }
+            }
+            for item in self.group_items.iter() {
+                if covered.insert(item.index) {
+                    let item_stat = statistics.column_stats.get(&item.index).unwrap();
+                    res *= item_stat.ndv;
+                }
+            }
             for item in self.group_items.iter() {
                 let item_stat = statistics.column_stats.get_mut(&item.index).unwrap();
                 if let Some(histogram) = &mut item_stat.histogram {
@@ -190,6 +216,7 @@ impl Aggregate {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: statistics.column_stats,
+                multi_column_stats: statistics.multi_column_stats,
             },
         }))
     }
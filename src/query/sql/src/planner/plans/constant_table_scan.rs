@@ -226,6 +226,7 @@ impl Operator for ConstantTableScan {
                 ndv: ndv as f64,
                 null_count,
                 histogram,
+                mcv: None,
             };
             column_stats.insert(*index, column_stat);
         }
@@ -234,6 +235,7 @@ impl Operator for ConstantTableScan {
             statistics: Statistics {
                 precise_cardinality: Some(self.num_rows as u64),
                 column_stats,
+                multi_column_stats: vec![],
             },
         }))
     }
@@ -32,6 +32,14 @@ pub struct CreateDatabasePlan {
     pub catalog: String,
     pub database: String,
     pub meta: DatabaseMeta,
+    pub clone_source: Option<CloneDatabaseSource>,
+}
+
+/// The source database of `CREATE DATABASE ... CLONE`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloneDatabaseSource {
+    pub catalog: String,
+    pub database: String,
 }
 
 impl From<CreateDatabasePlan> for CreateDatabaseReq {
@@ -18,8 +18,10 @@ use std::time::Duration;
 
 use databend_common_ast::ast::Engine;
 use databend_common_ast::ast::Identifier;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
@@ -35,6 +37,7 @@ use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::LockGuard;
+use databend_storages_common_table_meta::table::TableRefKind;
 
 use crate::plans::Plan;
 
@@ -62,6 +65,17 @@ pub struct CreateTablePlan {
     pub table_constraints: Option<BTreeMap<String, Constraint>>,
 
     pub attached_columns: Option<Vec<Identifier>>,
+    pub clone_source: Option<CloneTableSource>,
+}
+
+/// The source table of `CREATE TABLE ... CLONE`.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// The snapshot the clone starts from, `None` if the source table has no data.
+    pub snapshot_location: Option<String>,
 }
 
 impl CreateTablePlan {
@@ -225,6 +239,39 @@ impl AnalyzeTablePlan {
     }
 }
 
+/// Create multi-column statistics.
+#[derive(Clone, Debug)]
+pub struct CreateStatisticsPlan {
+    pub create_option: CreateOption,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+    pub column_ids: Vec<ColumnId>,
+}
+
+impl CreateStatisticsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop multi-column statistics.
+#[derive(Clone, Debug)]
+pub struct DropStatisticsPlan {
+    pub if_exists: bool,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub name: String,
+}
+
+impl DropStatisticsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Rename.
 #[derive(Clone, Debug)]
 pub struct RenameTablePlan {
@@ -544,6 +591,57 @@ impl DropTableClusterKeyPlan {
     }
 }
 
+/// Set the TTL expression of the table, or drop it if `ttl` is `None`.
+#[derive(Clone, Debug)]
+pub struct ModifyTableTTLPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub ttl: Option<String>,
+}
+
+impl ModifyTableTTLPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Create a branch or tag of the table.
+#[derive(Clone, Debug)]
+pub struct CreateTableRefPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub kind: TableRefKind,
+    pub name: String,
+    /// Created at the current snapshot of the table if not specified.
+    pub point: Option<NavigationPoint>,
+}
+
+impl CreateTableRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DropTableRefPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub kind: TableRefKind,
+    pub name: String,
+}
+
+impl DropTableRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RefreshTableCachePlan {
     pub tenant: Tenant,
@@ -95,6 +95,7 @@ impl Operator for ExpressionScan {
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats: Default::default(),
+                multi_column_stats: vec![],
             },
         }))
     }
@@ -21,10 +21,12 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::F64;
+use databend_common_expression::Scalar;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 
+use crate::binder::JoinPredicate;
 use crate::optimizer::ir::ColumnStat;
 use crate::optimizer::ir::Distribution;
 use crate::optimizer::ir::HistogramBuilder;
@@ -184,6 +186,16 @@ impl Display for JoinType {
     }
 }
 
+/// Spatial predicates which can be executed by spatial join,
+/// the geometry arguments must come from different sides of the join.
+pub const SPATIAL_JOIN_PREDICATES: [&str; 5] = [
+    "st_contains",
+    "st_within",
+    "st_intersects",
+    "st_equals",
+    "st_dwithin",
+];
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct HashJoinBuildCacheInfo {
     pub cache_idx: usize,
@@ -220,6 +232,86 @@ impl JoinEquiCondition {
     }
 }
 
+/// How a hinted join distributes its children.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum JoinDistributionHint {
+    Broadcast,
+    Shuffle,
+}
+
+/// Optimizer hints attached to a join, see [`crate::OptimizerHints`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct JoinHint {
+    /// The join is part of a `LEADING` prefix, its children must not be reordered.
+    pub leading: bool,
+    /// The distribution required by a `BROADCAST` or `SHUFFLE` hint on the build side.
+    pub distribution: Option<JoinDistributionHint>,
+    /// The join must be executed as hash join.
+    pub hash_join: bool,
+    /// The join follows the plan baseline of the query, its children must not be reordered.
+    pub baseline: bool,
+}
+
+impl JoinHint {
+    pub fn is_empty(&self) -> bool {
+        self == &JoinHint::default()
+    }
+
+    /// Whether the children of the join are fixed by the hint.
+    pub fn is_pinned(&self) -> bool {
+        self.leading || self.baseline || self.distribution.is_some()
+    }
+}
+
+impl Display for JoinHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut hints = vec![];
+        if self.baseline {
+            hints.push("BASELINE");
+        }
+        if self.leading {
+            hints.push("LEADING");
+        }
+        match self.distribution {
+            Some(JoinDistributionHint::Broadcast) => hints.push("BROADCAST"),
+            Some(JoinDistributionHint::Shuffle) => hints.push("SHUFFLE"),
+            None => {}
+        }
+        if self.hash_join {
+            hints.push("HASH_JOIN");
+        }
+        write!(f, "{}", hints.join(", "))
+    }
+}
+
+/// The heavy hitters of the key of a single column equi-join.
+/// A shuffle hash join broadcasts the rows of the hot keys of one side,
+/// and spreads the rows of the hot keys of the other side across the nodes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct JoinSkew {
+    /// The key column whose values are skewed.
+    pub column: IndexType,
+    /// The hot values of the key column.
+    pub hot_keys: Vec<Scalar>,
+}
+
+impl JoinSkew {
+    /// Whether the hot rows of the child `child_index` can be spread across the nodes
+    /// while the hot rows of the other child are broadcast.
+    pub fn can_spread(join_type: &JoinType, child_index: usize) -> bool {
+        match child_index {
+            0 => matches!(
+                join_type,
+                JoinType::Inner | JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti
+            ),
+            _ => matches!(
+                join_type,
+                JoinType::Inner | JoinType::Right | JoinType::RightSemi | JoinType::RightAnti
+            ),
+        }
+    }
+}
+
 /// Join operator. We will choose hash join by default.
 /// In the case that using hash join, the right child
 /// is always the build side, and the left child is always
@@ -241,6 +333,10 @@ pub struct Join {
     pub single_to_inner: Option<JoinType>,
     // Cache info for ExpressionScan.
     pub build_side_cache_info: Option<HashJoinBuildCacheInfo>,
+    // Hints from `/*+ ... */`, e.g. LEADING and BROADCAST.
+    pub hint: JoinHint,
+    // Hot keys of the join, set by `SkewJoinOptimizer`.
+    pub skew: Option<JoinSkew>,
 }
 
 impl Default for Join {
@@ -255,6 +351,8 @@ impl Default for Join {
             is_lateral: false,
             single_to_inner: None,
             build_side_cache_info: None,
+            hint: JoinHint::default(),
+            skew: None,
         }
     }
 }
@@ -444,6 +542,61 @@ impl Join {
         Ok(join_card)
     }
 
+    /// Returns the position of the non-equi condition executed by spatial join,
+    /// both the optimizer and `physical_join` choose spatial join by it.
+    pub fn spatial_join_condition(
+        &self,
+        enable_spatial_join: bool,
+        left_prop: &RelationalProperty,
+        right_prop: &RelationalProperty,
+        right_stat_info: &StatInfo,
+    ) -> Option<usize> {
+        // Hash join is used if the join has equi conditions, a build side cache or the
+        // `HASH_JOIN` hint, and cross join + filter is used if the build side has one row.
+        if !enable_spatial_join
+            || !self.equi_conditions.is_empty()
+            || !matches!(self.join_type, JoinType::Inner)
+            || self.build_side_cache_info.is_some()
+            || self.hint.hash_join
+            || matches!(right_stat_info.statistics.precise_cardinality, Some(1))
+            || right_stat_info.cardinality == 1.0
+        {
+            return None;
+        }
+        self.non_equi_conditions
+            .iter()
+            .position(|condition| is_spatial_condition(condition, left_prop, right_prop))
+    }
+
+    /// Returns true if the join is executed by spatial join.
+    pub fn is_spatial_join(&self, ctx: &dyn TableContext, rel_expr: &RelExpr) -> Result<bool> {
+        let enable_spatial_join = ctx.get_settings().get_enable_spatial_join()?;
+        if !enable_spatial_join || !self.equi_conditions.is_empty() {
+            return Ok(false);
+        }
+        let left_prop = child_relational_prop(rel_expr, 0)?;
+        let right_prop = child_relational_prop(rel_expr, 1)?;
+        let right_stat_info = child_stat_info(rel_expr, 1)?;
+        Ok(self
+            .spatial_join_condition(
+                enable_spatial_join,
+                &left_prop,
+                &right_prop,
+                &right_stat_info,
+            )
+            .is_some())
+    }
+
+    /// Spatial join builds the R-tree with the smaller input,
+    /// returns the index of the build child.
+    pub fn spatial_join_build_child(left_cardinality: f64, right_cardinality: f64) -> usize {
+        if left_cardinality < right_cardinality {
+            0
+        } else {
+            1
+        }
+    }
+
     pub fn has_null_equi_condition(&self) -> bool {
         self.equi_conditions
             .iter()
@@ -492,19 +645,22 @@ impl Join {
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
-        let column_stats = if cardinality == 0.0 {
-            HashMap::new()
+        let (column_stats, multi_column_stats) = if cardinality == 0.0 {
+            (HashMap::new(), vec![])
         } else {
             let mut column_stats = HashMap::new();
             column_stats.extend(left_statistics.column_stats);
             column_stats.extend(right_statistics.column_stats);
-            column_stats
+            let mut multi_column_stats = left_statistics.multi_column_stats;
+            multi_column_stats.extend(right_statistics.multi_column_stats);
+            (column_stats, multi_column_stats)
         };
         Ok(Arc::new(StatInfo {
             cardinality,
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                multi_column_stats,
             },
         }))
     }
@@ -617,7 +773,8 @@ impl Operator for Join {
             });
         }
 
-        if !matches!(self.join_type, JoinType::Inner | JoinType::Asof) {
+        // The rows of the hot keys of a skewed join are not partitioned by the join keys.
+        if !matches!(self.join_type, JoinType::Inner | JoinType::Asof) || self.skew.is_some() {
             return Ok(PhysicalProperty {
                 distribution: Distribution::Random,
             });
@@ -663,11 +820,30 @@ impl Operator for Join {
         let probe_physical_prop = rel_expr.derive_physical_prop_child(0)?;
         let build_physical_prop = rel_expr.derive_physical_prop_child(1)?;
 
-        // if join/probe side is Serial or this is a non-equi join, we use Serial distribution
+        // if join/probe side is Serial, we use Serial distribution
         if probe_physical_prop.distribution == Distribution::Serial
             || build_physical_prop.distribution == Distribution::Serial
-            || (self.equi_conditions.is_empty() && !self.non_equi_conditions.is_empty())
         {
+            required.distribution = Distribution::Serial;
+            return Ok(required);
+        }
+
+        // Spatial join builds an R-tree with the whole build side, so the build side is broadcast.
+        if self.is_spatial_join(ctx.as_ref(), rel_expr)? {
+            let build_child = Self::spatial_join_build_child(
+                rel_expr.derive_cardinality_child(0)?.cardinality,
+                rel_expr.derive_cardinality_child(1)?.cardinality,
+            );
+            if child_index == build_child {
+                required.distribution = Distribution::Broadcast;
+            } else {
+                required.distribution = Distribution::Any;
+            }
+            return Ok(required);
+        }
+
+        // if this is a non-equi join, we use Serial distribution
+        if self.equi_conditions.is_empty() && !self.non_equi_conditions.is_empty() {
             // TODO(leiysky): we can enforce redistribution here
             required.distribution = Distribution::Serial;
             return Ok(required);
@@ -699,10 +875,14 @@ impl Operator for Join {
                 // Use a very large value to prevent broadcast join.
                 1000.0
             };
-            if !settings.get_enforce_shuffle_join()?
+            let enforce_broadcast = settings.get_enforce_broadcast_join()?
+                || self.hint.distribution == Some(JoinDistributionHint::Broadcast);
+            let enforce_shuffle = settings.get_enforce_shuffle_join()?
+                || self.hint.distribution == Some(JoinDistributionHint::Shuffle);
+            if !enforce_shuffle
                 && (right_stat_info.cardinality * broadcast_join_threshold
                     < left_stat_info.cardinality
-                    || settings.get_enforce_broadcast_join()?)
+                    || enforce_broadcast)
             {
                 if child_index == 1 {
                     required.distribution = Distribution::Broadcast;
@@ -736,11 +916,30 @@ impl Operator for Join {
     fn compute_required_prop_children(
         &self,
         ctx: Arc<dyn TableContext>,
-        _rel_expr: &RelExpr,
+        rel_expr: &RelExpr,
         _required: &RequiredProperty,
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
+        // Spatial join broadcasts the smaller side to build the R-tree.
+        if self.is_spatial_join(ctx.as_ref(), rel_expr)? {
+            let mut required = vec![
+                RequiredProperty {
+                    distribution: Distribution::Any,
+                },
+                RequiredProperty {
+                    distribution: Distribution::Any,
+                },
+            ];
+            let build_child = Self::spatial_join_build_child(
+                child_cardinality(rel_expr, 0)?,
+                child_cardinality(rel_expr, 1)?,
+            );
+            required[build_child].distribution = Distribution::Broadcast;
+            children_required.push(required);
+            return Ok(children_required);
+        }
+
         // For mark join with nullable eq comparison, ensure to use broadcast for subquery side
         if self.join_type.is_mark_join()
             && self.equi_conditions.len() == 1
@@ -783,7 +982,11 @@ impl Operator for Join {
         }
 
         let settings = ctx.get_settings();
-        if !matches!(self.join_type, JoinType::Cross) && !settings.get_enforce_broadcast_join()? {
+        let enforce_broadcast = settings.get_enforce_broadcast_join()?
+            || self.hint.distribution == Some(JoinDistributionHint::Broadcast);
+        let enforce_shuffle = settings.get_enforce_shuffle_join()?
+            || self.hint.distribution == Some(JoinDistributionHint::Shuffle);
+        if !matches!(self.join_type, JoinType::Cross) && !enforce_broadcast {
             // (Hash, Hash)
             children_required.extend(self.equi_conditions.iter().map(|condition| {
                 vec![
@@ -811,7 +1014,7 @@ impl Operator for Join {
                 | JoinType::Asof
                 | JoinType::LeftAsof
                 | JoinType::RightAsof
-        ) && !settings.get_enforce_shuffle_join()?
+        ) && !enforce_shuffle
         {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
@@ -842,6 +1045,75 @@ impl Operator for Join {
     }
 }
 
+// The cardinality of the child, the children of `MExpr` are groups of the memo.
+fn child_cardinality(rel_expr: &RelExpr, child_index: usize) -> Result<f64> {
+    Ok(child_stat_info(rel_expr, child_index)?.cardinality)
+}
+
+fn child_stat_info(rel_expr: &RelExpr, child_index: usize) -> Result<Arc<StatInfo>> {
+    match rel_expr {
+        RelExpr::MExpr { expr, memo } | RelExpr::OptContext { expr, memo, .. } => {
+            Ok(expr.child_group(memo, child_index)?.stat_info.clone())
+        }
+        RelExpr::SExpr { .. } => rel_expr.derive_cardinality_child(child_index),
+    }
+}
+
+fn child_relational_prop(
+    rel_expr: &RelExpr,
+    child_index: usize,
+) -> Result<Arc<RelationalProperty>> {
+    match rel_expr {
+        RelExpr::MExpr { expr, memo } | RelExpr::OptContext { expr, memo, .. } => {
+            Ok(expr.child_group(memo, child_index)?.relational_prop.clone())
+        }
+        RelExpr::SExpr { .. } => rel_expr.derive_relational_prop_child(child_index),
+    }
+}
+
+/// Spatial condition is a spatial predicate whose geometry arguments come from
+/// different sides, the distance of `st_dwithin` must be a constant.
+pub fn is_spatial_condition(
+    expr: &ScalarExpr,
+    left_prop: &RelationalProperty,
+    right_prop: &RelationalProperty,
+) -> bool {
+    let ScalarExpr::FunctionCall(func) = expr else {
+        return false;
+    };
+    if !SPATIAL_JOIN_PREDICATES.contains(&func.func_name.as_str()) {
+        return false;
+    }
+    if func.func_name == "st_dwithin" {
+        if func.arguments.len() != 3 || spatial_join_distance(&func.arguments[2]).is_none() {
+            return false;
+        }
+    } else if func.arguments.len() != 2 {
+        return false;
+    }
+
+    let left = JoinPredicate::new(&func.arguments[0], left_prop, right_prop);
+    let right = JoinPredicate::new(&func.arguments[1], left_prop, right_prop);
+    matches!(
+        (left, right),
+        (JoinPredicate::Left(_), JoinPredicate::Right(_))
+            | (JoinPredicate::Right(_), JoinPredicate::Left(_))
+    )
+}
+
+/// Get the constant distance of `st_dwithin`.
+pub fn spatial_join_distance(expr: &ScalarExpr) -> Option<f64> {
+    let ScalarExpr::ConstantExpr(constant) = expr else {
+        return None;
+    };
+    let distance = match &constant.value {
+        Scalar::Number(num) => num.to_f64().into_inner(),
+        Scalar::Decimal(num) => num.to_float64(),
+        _ => return None,
+    };
+    (distance.is_finite() && distance >= 0.0).then_some(distance)
+}
+
 fn evaluate_by_histogram(
     left_hist: &Histogram,
     right_hist: &Histogram,
@@ -53,6 +53,7 @@ impl Limit {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: Default::default(),
+                multi_column_stats: vec![],
             },
         }))
     }
@@ -39,6 +39,7 @@ mod operator;
 mod operator_macros;
 mod optimize;
 mod plan;
+mod plan_baseline;
 mod presign;
 mod project_set;
 mod r_cte_scan;
@@ -54,6 +55,7 @@ mod set;
 mod set_priority;
 mod sort;
 mod system;
+mod tag;
 mod udaf;
 mod udf;
 mod union_all;
@@ -91,6 +93,7 @@ pub use mutation_source::MutationSource;
 pub use operator::*;
 pub use optimize::*;
 pub use plan::*;
+pub use plan_baseline::*;
 pub use presign::*;
 pub use project_set::*;
 pub use r_cte_scan::*;
@@ -106,6 +109,7 @@ pub use set::*;
 pub use set_priority::SetPriorityPlan;
 pub use sort::*;
 pub use system::*;
+pub use tag::*;
 pub use udaf::*;
 pub use udf::*;
 pub use union_all::UnionAll;
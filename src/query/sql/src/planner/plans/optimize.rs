@@ -27,6 +27,14 @@ pub struct OptimizePurgePlan {
     pub num_snapshot_limit: Option<usize>,
 }
 
+/// Deletes the rows of a table with TTL that are expired.
+#[derive(Clone, Debug)]
+pub struct OptimizeExpirePlan {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 #[derive(Clone, Debug)]
 pub struct OptimizeCompactSegmentPlan {
     pub catalog: String,
@@ -43,6 +43,7 @@ use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
 use crate::plans::AlterRolePlan;
 use crate::plans::AlterTableClusterKeyPlan;
+use crate::plans::AlterTagPlan;
 use crate::plans::AlterTaskPlan;
 use crate::plans::AlterUDFPlan;
 use crate::plans::AlterUserPlan;
@@ -62,13 +63,17 @@ use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePlanBaselinePlan;
 use crate::plans::CreateProcedurePlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateSequencePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStatisticsPlan;
 use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTableIndexPlan;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableRefPlan;
+use crate::plans::CreateTagPlan;
 use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
@@ -97,18 +102,22 @@ use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPlanBaselinePlan;
 use crate::plans::DropProcedurePlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropRowAccessPolicyPlan;
 use crate::plans::DropSequencePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStatisticsPlan;
 use crate::plans::DropStreamPlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTableConstraintPlan;
 use crate::plans::DropTableIndexPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRefPlan;
 use crate::plans::DropTableRowAccessPolicyPlan;
+use crate::plans::DropTagPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -128,7 +137,9 @@ use crate::plans::InspectWarehousePlan;
 use crate::plans::KillPlan;
 use crate::plans::ModifyTableColumnPlan;
 use crate::plans::ModifyTableCommentPlan;
+use crate::plans::ModifyTableTTLPlan;
 use crate::plans::OptimizeCompactSegmentPlan;
+use crate::plans::OptimizeExpirePlan;
 use crate::plans::OptimizePurgePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterPlan;
@@ -150,6 +161,7 @@ use crate::plans::ResumeWarehousePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
+use crate::plans::SetObjectTagsPlan;
 use crate::plans::SetOptionsPlan;
 use crate::plans::SetPlan;
 use crate::plans::SetPriorityPlan;
@@ -170,6 +182,7 @@ use crate::plans::TruncateTablePlan;
 use crate::plans::UnassignWarehouseNodesPlan;
 use crate::plans::UndropDatabasePlan;
 use crate::plans::UndropTablePlan;
+use crate::plans::UnsetObjectTagsPlan;
 use crate::plans::UnsetOptionsPlan;
 use crate::plans::UnsetPlan;
 use crate::plans::UnsetWorkloadGroupQuotasPlan;
@@ -279,6 +292,9 @@ pub enum Plan {
     DropTableConstraint(Box<DropTableConstraintPlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    ModifyTableTTL(Box<ModifyTableTTLPlan>),
+    CreateTableRef(Box<CreateTableRefPlan>),
+    DropTableRef(Box<DropTableRefPlan>),
     ReclusterTable(Box<ReclusterPlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
@@ -286,6 +302,8 @@ pub enum Plan {
     VacuumDropTable(Box<VacuumDropTablePlan>),
     VacuumTemporaryFiles(Box<VacuumTemporaryFilesPlan>),
     AnalyzeTable(Box<AnalyzeTablePlan>),
+    CreateStatistics(Box<CreateStatisticsPlan>),
+    DropStatistics(Box<DropStatisticsPlan>),
     ExistsTable(Box<ExistsTablePlan>),
     SetOptions(Box<SetOptionsPlan>),
     UnsetOptions(Box<UnsetOptionsPlan>),
@@ -297,6 +315,7 @@ pub enum Plan {
 
     // Optimize
     OptimizePurge(Box<OptimizePurgePlan>),
+    OptimizeExpire(Box<OptimizeExpirePlan>),
     OptimizeCompactSegment(Box<OptimizeCompactSegmentPlan>),
     OptimizeCompactBlock {
         s_expr: Box<SExpr>,
@@ -395,6 +414,17 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Tag
+    CreateTag(Box<CreateTagPlan>),
+    DropTag(Box<DropTagPlan>),
+    AlterTag(Box<AlterTagPlan>),
+    SetObjectTags(Box<SetObjectTagsPlan>),
+    UnsetObjectTags(Box<UnsetObjectTagsPlan>),
+
+    // Plan baseline
+    CreatePlanBaseline(Box<CreatePlanBaselinePlan>),
+    DropPlanBaseline(Box<DropPlanBaselinePlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -506,6 +536,7 @@ impl Plan {
             Plan::Replace(_)
             | Plan::DataMutation { .. }
             | Plan::OptimizePurge(_)
+            | Plan::OptimizeExpire(_)
             | Plan::OptimizeCompactSegment(_)
             | Plan::OptimizeCompactBlock { .. } => QueryKind::Update,
             _ => QueryKind::Other,
@@ -552,6 +583,13 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::CreateTag(plan) => plan.schema(),
+            Plan::DropTag(plan) => plan.schema(),
+            Plan::AlterTag(plan) => plan.schema(),
+            Plan::SetObjectTags(plan) => plan.schema(),
+            Plan::UnsetObjectTags(plan) => plan.schema(),
+            Plan::CreatePlanBaseline(plan) => plan.schema(),
+            Plan::DropPlanBaseline(plan) => plan.schema(),
             Plan::DescNetworkPolicy(plan) => plan.schema(),
             Plan::ShowNetworkPolicies(plan) => plan.schema(),
             Plan::DescPasswordPolicy(plan) => plan.schema(),
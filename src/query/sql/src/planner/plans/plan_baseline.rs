@@ -0,0 +1,51 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+
+use crate::plans::Plan;
+
+#[derive(Clone, Debug)]
+pub struct CreatePlanBaselinePlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub sql_hash: String,
+    pub query_text: String,
+    /// The query whose optimized plan is captured as the baseline.
+    pub query: Box<Plan>,
+}
+
+impl CreatePlanBaselinePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropPlanBaselinePlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub sql_hash: String,
+}
+
+impl DropPlanBaselinePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
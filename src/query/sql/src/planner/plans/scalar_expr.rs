@@ -1180,6 +1180,10 @@ pub enum AsyncFunctionArgument {
     // The dictionary argument is connection URL of remote source, like Redis, MySQL ...
     // Used by `dict_get` function to connect source and read data.
     DictGetFunction(DictGetFunctionArgument),
+    // The argument of secret function is connection name.
+    // Used by `get_secret` function to read the secret of a connection when the query is executed,
+    // so the secret is not kept in the plan.
+    SecretFunction(String),
 }
 
 #[derive(Clone, Debug, Educe, serde::Serialize, serde::Deserialize)]
@@ -1284,6 +1288,9 @@ impl AsyncFunctionCall {
             AsyncFunctionArgument::DictGetFunction(_dict_get_function_argument) => {
                 Err(ErrorCode::Internal("Cannot generate dict_get function"))
             }
+            AsyncFunctionArgument::SecretFunction(_) => {
+                Err(ErrorCode::Internal("Cannot generate get_secret function"))
+            }
         }
     }
 }
@@ -27,6 +27,7 @@ use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 use databend_storages_common_table_meta::table::ChangeType;
 
@@ -35,6 +36,7 @@ use crate::optimizer::ir::ColumnStat;
 use crate::optimizer::ir::ColumnStatSet;
 use crate::optimizer::ir::Distribution;
 use crate::optimizer::ir::HistogramBuilder;
+use crate::optimizer::ir::MultiColumnStat;
 use crate::optimizer::ir::PhysicalProperty;
 use crate::optimizer::ir::RelExpr;
 use crate::optimizer::ir::RelationalProperty;
@@ -42,7 +44,6 @@ use crate::optimizer::ir::RequiredProperty;
 use crate::optimizer::ir::SelectivityEstimator;
 use crate::optimizer::ir::StatInfo;
 use crate::optimizer::ir::Statistics as OpStatistics;
-use crate::optimizer::ir::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -90,6 +91,8 @@ pub struct Statistics {
     // statistics will be ignored in comparison and hashing
     pub column_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
     pub histograms: HashMap<IndexType, Option<Histogram>>,
+    pub most_common_values: HashMap<IndexType, Option<MostCommonValues>>,
+    pub multi_column_stats: Vec<MultiColumnStat>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -132,6 +135,22 @@ impl Scan {
             .map(|(col, hist)| (*col, hist.clone()))
             .collect();
 
+        let most_common_values = self
+            .statistics
+            .most_common_values
+            .iter()
+            .filter(|(col, _)| columns.contains(*col))
+            .map(|(col, mcv)| (*col, mcv.clone()))
+            .collect();
+
+        let multi_column_stats = self
+            .statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| stat.columns.iter().all(|col| columns.contains(col)))
+            .cloned()
+            .collect();
+
         Scan {
             table_index: self.table_index,
             columns,
@@ -142,6 +161,8 @@ impl Scan {
                 table_stats: self.statistics.table_stats,
                 column_stats,
                 histograms,
+                most_common_values,
+                multi_column_stats,
             }),
             prewhere,
             agg_index: self.agg_index.clone(),
@@ -317,11 +338,20 @@ impl Operator for Scan {
                     ndv: ndv as f64,
                     null_count: col_stat.null_count,
                     histogram,
+                    mcv: self.statistics.most_common_values.get(k).cloned().flatten(),
                 };
                 column_stats.insert(*k as IndexType, column_stat);
             }
         }
 
+        let mut multi_column_stats = self
+            .statistics
+            .multi_column_stats
+            .iter()
+            .filter(|stat| stat.columns.iter().all(|col| used_columns.contains(col)))
+            .cloned()
+            .collect::<Vec<_>>();
+
         let precise_cardinality = self
             .statistics
             .table_stats
@@ -333,6 +363,7 @@ impl Operator for Scan {
                 let mut statistics = OpStatistics {
                     precise_cardinality: Some(precise_cardinality),
                     column_stats,
+                    multi_column_stats,
                 };
                 // Derive cardinality
                 let mut sb = SelectivityEstimator::new(
@@ -340,14 +371,11 @@ impl Operator for Scan {
                     precise_cardinality as f64,
                     HashSet::new(),
                 );
-                let mut selectivity = MAX_SELECTIVITY;
-                for pred in prewhere.predicates.iter() {
-                    // Compute selectivity for each conjunction
-                    selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
-                }
+                let selectivity = sb.compute_conjunction_selectivity(&prewhere.predicates, true)?;
                 // Update other columns's statistic according to selectivity.
                 sb.update_other_statistic_by_selectivity(selectivity);
                 column_stats = statistics.column_stats;
+                multi_column_stats = statistics.multi_column_stats;
                 (precise_cardinality as f64) * selectivity
             }
             (Some(precise_cardinality), None) => precise_cardinality as f64,
@@ -365,6 +393,7 @@ impl Operator for Scan {
             statistics: OpStatistics {
                 precise_cardinality,
                 column_stats,
+                multi_column_stats,
             },
         }))
     }
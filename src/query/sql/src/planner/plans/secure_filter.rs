@@ -23,7 +23,6 @@ use crate::optimizer::ir::RelationalProperty;
 use crate::optimizer::ir::SelectivityEstimator;
 use crate::optimizer::ir::StatInfo;
 use crate::optimizer::ir::Statistics;
-use crate::optimizer::ir::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -98,10 +97,7 @@ impl Operator for SecureFilter {
 
         // Apply selectivity calculation
         let mut sb = SelectivityEstimator::new(&mut statistics, input_cardinality, HashSet::new());
-        let mut selectivity = MAX_SELECTIVITY;
-        for pred in self.predicates.iter() {
-            selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
-        }
+        let selectivity = sb.compute_conjunction_selectivity(&self.predicates, true)?;
         sb.update_other_statistic_by_selectivity(selectivity);
         let cardinality = input_cardinality * selectivity;
 
@@ -115,6 +111,7 @@ impl Operator for SecureFilter {
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                multi_column_stats: vec![],
             },
         }))
     }
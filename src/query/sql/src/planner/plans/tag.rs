@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tag::CreateTagReq;
+use databend_common_meta_app::tag::TagMeta;
+use databend_common_meta_app::tag::TagNameIdent;
+use databend_common_meta_app::tenant::Tenant;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreateTagPlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub allowed_values: Option<Vec<String>>,
+    pub comment: Option<String>,
+}
+
+impl CreateTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+impl From<CreateTagPlan> for CreateTagReq {
+    fn from(p: CreateTagPlan) -> Self {
+        CreateTagReq {
+            create_option: p.create_option,
+            name: TagNameIdent::new(&p.tenant, &p.name),
+            meta: TagMeta {
+                allowed_values: p.allowed_values,
+                comment: p.comment.unwrap_or_default(),
+                masking_policy_id: None,
+                created_on: Utc::now(),
+                updated_on: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DropTagPlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
+
+impl DropTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// `ALTER TAG <name> { SET | UNSET } MASKING POLICY`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlterTagPlan {
+    pub tenant: Tenant,
+    pub name: String,
+    /// `None` unbinds the masking policy currently attached to the tag.
+    pub masking_policy: Option<String>,
+}
+
+impl AlterTagPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// The object a `SET TAG` / `UNSET TAG` statement applies to.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TagObject {
+    Database {
+        catalog: String,
+        database: String,
+    },
+    Table {
+        catalog: String,
+        database: String,
+        table: String,
+    },
+    Column {
+        catalog: String,
+        database: String,
+        table: String,
+        column: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetObjectTagsPlan {
+    pub tenant: Tenant,
+    pub object: TagObject,
+    /// `(tag_name, tag_value)`
+    pub tags: Vec<(String, String)>,
+}
+
+impl SetObjectTagsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsetObjectTagsPlan {
+    pub tenant: Tenant,
+    pub object: TagObject,
+    pub tags: Vec<String>,
+}
+
+impl UnsetObjectTagsPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
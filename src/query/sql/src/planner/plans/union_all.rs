@@ -79,6 +79,7 @@ impl UnionAll {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: Default::default(),
+                multi_column_stats: vec![],
             },
         }))
     }
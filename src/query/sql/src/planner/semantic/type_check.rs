@@ -351,8 +351,7 @@ impl<'a> TypeChecker<'a> {
                                             let table_ref = table_entry.table();
                                             let table_info = table_ref.get_table_info();
 
-                                            if table_info.meta.column_mask_policy_columns_ids.is_empty()
-                                            {
+                                            if !metadata.table_has_mask_policy(idx) {
                                                 return None;
                                             }
                                             table_info
@@ -362,11 +361,9 @@ impl<'a> TypeChecker<'a> {
                                                 .iter()
                                                 .find(|f| f.name == column.column_name)
                                                 .and_then(|field| {
-                                                    table_info
-                                                        .meta
-                                                        .column_mask_policy_columns_ids
-                                                        .contains_key(&field.column_id)
-                                                        .then_some(())
+                                                    metadata
+                                                        .column_mask_policy(idx, field.column_id)
+                                                        .map(|_| ())
                                                 })
                                         })
                                     })
@@ -5513,6 +5510,7 @@ impl<'a> TypeChecker<'a> {
         let result = match func_name {
             "nextval" => self.resolve_nextval_async_function(span, func_name, arguments)?,
             "dict_get" => self.resolve_dict_get_async_function(span, func_name, arguments)?,
+            "get_secret" => self.resolve_get_secret_async_function(span, func_name, arguments)?,
             _ => {
                 return Err(ErrorCode::SemanticError(format!(
                     "cannot find async function {}",
@@ -5603,6 +5601,48 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((async_func.into(), return_type)))
     }
 
+    // The secret is read when the query is executed, so secrets like encryption keys
+    // are neither written in the query text nor kept in the plan.
+    fn resolve_get_secret_async_function(
+        &mut self,
+        span: Span,
+        func_name: &str,
+        arguments: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let [Expr::Literal {
+            value: Literal::String(connection_name),
+            ..
+        }] = arguments
+        else {
+            return Err(ErrorCode::SemanticError(
+                "get_secret function need one string literal argument".to_string(),
+            )
+            .set_span(span));
+        };
+
+        let connection =
+            databend_common_base::runtime::block_on(self.ctx.get_connection(connection_name))
+                .map_err(|e| e.set_span(span))?;
+        if connection.secret().is_none() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Connection '{connection_name}' is not a secret connection"
+            ))
+            .set_span(span));
+        }
+
+        let return_type = DataType::String;
+        let async_func = AsyncFunctionCall {
+            span,
+            func_name: func_name.to_string(),
+            display_name: format!("{}('{}')", func_name, connection_name),
+            return_type: Box::new(return_type.clone()),
+            arguments: vec![],
+            func_arg: AsyncFunctionArgument::SecretFunction(connection_name.clone()),
+        };
+
+        Ok(Box::new((async_func.into(), return_type)))
+    }
+
     fn resolve_dict_get_async_function(
         &mut self,
         span: Span,
@@ -6558,10 +6598,8 @@ impl<'a> TypeChecker<'a> {
                     .iter()
                     .find(|f| f.name == column_binding.column_name)
                 {
-                    if let Some(policy_info) = table_info_ref
-                        .meta
-                        .column_mask_policy_columns_ids
-                        .get(&field.column_id)
+                    if let Some(policy_info) =
+                        metadata.column_mask_policy(table_index, field.column_id)
                     {
                         // Check license
                         if LicenseManagerSwitch::instance()
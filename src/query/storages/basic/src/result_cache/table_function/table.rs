@@ -174,6 +174,7 @@ impl Table for ResultScan {
                     plan.push_downs.clone(),
                     table_schema.clone(),
                     op.clone(),
+                    None,
                 )
             },
             1,
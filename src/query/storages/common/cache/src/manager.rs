@@ -1048,6 +1048,8 @@ mod tests {
             virtual_block_meta: None,
             compression: Compression::Lz4,
             create_on: None,
+            spatial_stats: None,
+            deletion_vector: None,
         });
 
         // ----- POPULATE EXTRA CACHES -----
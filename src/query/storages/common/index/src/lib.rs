@@ -18,6 +18,7 @@
 
 mod bloom_index;
 mod eliminate_cast;
+mod expr_bloom_filter;
 pub mod filters;
 mod hnsw_index;
 mod index;
@@ -32,6 +33,7 @@ pub use bloom_index::BloomIndexResult;
 pub use bloom_index::FilterEvalResult;
 pub use bloom_index::NgramArgs;
 pub use eliminate_cast::eliminate_cast;
+pub use expr_bloom_filter::ExprBloomFilter;
 pub use hnsw_index::DistanceType;
 pub use hnsw_index::FixedLengthPriorityQueue;
 pub use hnsw_index::HNSWIndex;
@@ -369,6 +369,7 @@ impl ColumnOrientedSegmentBuilder {
             cluster_stats,
             virtual_block_count: Some(virtual_block_count),
             additional_stats_meta,
+            deleted_row_count: None,
         })
     }
 }
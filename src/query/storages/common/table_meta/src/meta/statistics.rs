@@ -29,12 +29,14 @@ use crate::meta::format::encode;
 use crate::meta::format::read_and_deserialize;
 use crate::meta::ColumnStatistics;
 use crate::meta::SegmentStatistics;
+use crate::meta::SpatialStatistics;
 
 pub type FormatVersion = u64;
 pub type SnapshotId = Uuid;
 pub type Location = (String, FormatVersion);
 pub type ClusterKey = (u32, String);
 pub type StatisticsOfColumns = HashMap<ColumnId, ColumnStatistics>;
+pub type SpatialStatisticsOfColumns = HashMap<ColumnId, SpatialStatistics>;
 pub type BlockHLL = HashMap<ColumnId, MetaHLL>;
 pub type RawBlockHLL = Vec<u8>;
 
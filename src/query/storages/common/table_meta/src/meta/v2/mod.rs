@@ -20,6 +20,7 @@ mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
 pub use segment::ColumnMeta;
+pub use segment::DeletionVectorMeta;
 pub use segment::DraftVirtualBlockMeta;
 pub use segment::DraftVirtualColumnMeta;
 pub use segment::ExtendedBlockMeta;
@@ -31,5 +32,6 @@ pub use snapshot::TableSnapshot;
 pub use statistics::AdditionalStatsMeta;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
+pub use statistics::SpatialStatistics;
 pub use statistics::Statistics;
 pub use table_snapshot_statistics::TableSnapshotStatistics;
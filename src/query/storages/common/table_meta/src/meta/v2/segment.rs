@@ -40,6 +40,7 @@ use crate::meta::ColumnStatistics;
 use crate::meta::Compression;
 use crate::meta::FormatVersion;
 use crate::meta::Location;
+use crate::meta::SpatialStatisticsOfColumns;
 use crate::meta::Statistics;
 use crate::meta::Versioned;
 
@@ -169,6 +170,18 @@ pub struct DraftVirtualBlockMeta {
     pub virtual_location: Location,
 }
 
+/// The rows of a block deleted by merge-on-read mutations.
+/// The offsets of the deleted rows are stored as a serialized roaring bitmap in a side file.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, FrozenAPI)]
+pub struct DeletionVectorMeta {
+    /// location of the deletion bitmap file
+    pub location: Location,
+    /// the number of deleted rows
+    pub deleted_row_count: u64,
+    /// the file size of the deletion bitmap
+    pub size: u64,
+}
+
 /// Meta information of a block
 /// Part of and kept inside the [SegmentInfo]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, FrozenAPI)]
@@ -197,6 +210,10 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+    /// The bounding boxes of geometry columns.
+    pub spatial_stats: Option<SpatialStatisticsOfColumns>,
+    /// The rows deleted without rewriting the block.
+    pub deletion_vector: Option<DeletionVectorMeta>,
 }
 
 impl BlockMeta {
@@ -236,6 +253,8 @@ impl BlockMeta {
             virtual_block_meta,
             compression,
             create_on,
+            spatial_stats: None,
+            deletion_vector: None,
         }
     }
 
@@ -243,6 +262,18 @@ impl BlockMeta {
         self.compression
     }
 
+    /// The number of rows marked as deleted by the deletion vector.
+    pub fn deleted_row_count(&self) -> u64 {
+        self.deletion_vector
+            .as_ref()
+            .map_or(0, |v| v.deleted_row_count)
+    }
+
+    /// The number of rows that are not deleted.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count()
+    }
+
     /// Get the page size of the block.
     ///
     /// - If the format is parquet, its page size is its row count.
@@ -401,6 +432,8 @@ impl BlockMeta {
             virtual_block_meta: None,
             create_on: None,
             ngram_filter_index_size: None,
+            spatial_stats: None,
+            deletion_vector: None,
         }
     }
 
@@ -429,6 +462,8 @@ impl BlockMeta {
             virtual_block_meta: None,
             create_on: None,
             ngram_filter_index_size: None,
+            spatial_stats: None,
+            deletion_vector: None,
         }
     }
 }
@@ -50,6 +50,61 @@ pub struct ColumnStatistics {
     pub distinct_of_values: Option<u64>,
 }
 
+/// The bounding box of the geometry values of a column in a block,
+/// used to prune blocks by spatial predicates.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, FrozenAPI)]
+pub struct SpatialStatistics {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+// Bounding boxes are only built from finite coordinates.
+impl Eq for SpatialStatistics {}
+
+impl SpatialStatistics {
+    pub fn new(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Self {
+        Self {
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+        }
+    }
+
+    pub fn merge(&mut self, other: &SpatialStatistics) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+    }
+
+    /// Grows the bounding box by `distance` in every direction.
+    pub fn expand(&self, distance: f64) -> Self {
+        Self {
+            min_x: self.min_x - distance,
+            min_y: self.min_y - distance,
+            max_x: self.max_x + distance,
+            max_y: self.max_y + distance,
+        }
+    }
+
+    pub fn intersects(&self, other: &SpatialStatistics) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    pub fn contains(&self, other: &SpatialStatistics) -> bool {
+        self.min_x <= other.min_x
+            && self.min_y <= other.min_y
+            && self.max_x >= other.max_x
+            && self.max_y >= other.max_y
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, FrozenAPI)]
 pub struct ClusterStatistics {
     pub cluster_key_id: u32,
@@ -115,6 +170,8 @@ pub struct Statistics {
     pub virtual_block_count: Option<u64>,
 
     pub additional_stats_meta: Option<AdditionalStatsMeta>,
+    /// The number of rows marked as deleted by deletion vectors, not included in `row_count`.
+    pub deleted_row_count: Option<u64>,
 }
 
 // conversions from old meta data
@@ -282,6 +339,7 @@ impl Statistics {
             cluster_stats: None,
             virtual_block_count: None,
             additional_stats_meta: None,
+            deleted_row_count: None,
         }
     }
 
@@ -68,6 +68,8 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             virtual_block_meta: None,
             compression: value.compression.into(),
             create_on: None,
+            spatial_stats: None,
+            deletion_vector: None,
         }
     }
 }
@@ -56,6 +56,7 @@ impl From<Statistics> for crate::meta::Statistics {
             cluster_stats: None,
             virtual_block_count: None,
             additional_stats_meta: None,
+            deleted_row_count: None,
         }
     }
 }
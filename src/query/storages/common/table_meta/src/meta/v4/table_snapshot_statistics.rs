@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 
 use databend_common_expression::ColumnId;
@@ -19,6 +20,8 @@ use databend_common_frozen_api::frozen_api;
 use databend_common_frozen_api::FrozenAPI;
 use databend_common_storage::Histogram;
 use databend_common_storage::MetaHLL;
+use databend_common_storage::MostCommonValues;
+use databend_common_storage::MultiColumnStatistics;
 
 use crate::meta::v1;
 use crate::meta::v2;
@@ -27,7 +30,7 @@ use crate::meta::FormatVersion;
 use crate::meta::SnapshotId;
 use crate::meta::Versioned;
 
-#[frozen_api("446eb231")]
+#[frozen_api("b0a06924")]
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, FrozenAPI)]
 pub struct TableSnapshotStatistics {
     /// format version of snapshot
@@ -37,6 +40,11 @@ pub struct TableSnapshotStatistics {
     pub row_count: u64,
     pub hll: HashMap<ColumnId, MetaHLL>,
     pub histograms: HashMap<ColumnId, Histogram>,
+    #[serde(default)]
+    pub most_common_values: HashMap<ColumnId, MostCommonValues>,
+    /// Statistics of the column groups created by `CREATE STATISTICS`, keyed by statistics name.
+    #[serde(default)]
+    pub multi_column_statistics: BTreeMap<String, MultiColumnStatistics>,
 }
 
 impl TableSnapshotStatistics {
@@ -46,6 +54,8 @@ impl TableSnapshotStatistics {
             snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            most_common_values: HashMap::new(),
+            multi_column_statistics: BTreeMap::new(),
             row_count: 0,
         }
     }
@@ -53,6 +63,8 @@ impl TableSnapshotStatistics {
     pub fn new(
         hll: HashMap<ColumnId, MetaHLL>,
         histograms: HashMap<ColumnId, Histogram>,
+        most_common_values: HashMap<ColumnId, MostCommonValues>,
+        multi_column_statistics: BTreeMap<String, MultiColumnStatistics>,
         snapshot_id: SnapshotId,
         row_count: u64,
     ) -> Self {
@@ -61,6 +73,8 @@ impl TableSnapshotStatistics {
             snapshot_id,
             hll,
             histograms,
+            most_common_values,
+            multi_column_statistics,
             row_count,
         }
     }
@@ -85,6 +99,8 @@ impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
             row_count: 0,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            most_common_values: HashMap::new(),
+            multi_column_statistics: BTreeMap::new(),
         }
     }
 }
@@ -98,6 +114,8 @@ impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
             row_count: 0,
             hll,
             histograms: HashMap::new(),
+            most_common_values: HashMap::new(),
+            multi_column_statistics: BTreeMap::new(),
         }
     }
 }
@@ -111,6 +129,8 @@ impl From<v3::TableSnapshotStatistics> for TableSnapshotStatistics {
             row_count: 0,
             hll,
             histograms: value.histograms,
+            most_common_values: HashMap::new(),
+            multi_column_statistics: BTreeMap::new(),
         }
     }
 }
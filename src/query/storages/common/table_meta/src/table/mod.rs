@@ -17,9 +17,11 @@ mod stream_keys;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod table_refs;
 
 pub use dynamic_table_keys::*;
 pub use stream_keys::*;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use table_refs::*;
@@ -30,6 +30,15 @@ pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_APPROX_DISTINCT_COLUMNS: &str = "approx_distinct_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// The branches and tags of a table, see `TableRefs`.
+pub const OPT_KEY_TABLE_REFS: &str = "table_refs";
+// Set on the in-memory table info of a table checked out at a branch, never persisted.
+pub const OPT_KEY_BRANCH: &str = "branch";
+// The expiry expression of the rows, rows are expired once it evaluates to a past time.
+pub const OPT_KEY_TTL: &str = "ttl";
+// Set once tags are set on the table, its columns or its database,
+// the tag masking policies are only resolved for the tables with it.
+pub const OPT_KEY_TAGGED: &str = "tagged";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -68,6 +77,10 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_TABLE_REFS);
+    r.insert(OPT_KEY_BRANCH);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_TAGGED);
     r
 });
 
@@ -79,6 +92,10 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_TABLE_REFS);
+    r.insert(OPT_KEY_BRANCH);
+    r.insert(OPT_KEY_TTL);
+    r.insert(OPT_KEY_TAGGED);
     r
 });
 
@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::table::OPT_KEY_TABLE_REFS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableRefKind {
+    /// A writable line of snapshots, forked from a snapshot of the table.
+    Branch,
+    /// An immutable name of a snapshot.
+    Tag,
+}
+
+impl Display for TableRefKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableRefKind::Branch => write!(f, "BRANCH"),
+            TableRefKind::Tag => write!(f, "TAG"),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableRef {
+    /// The head snapshot of the reference, `None` if the table was empty when it's created.
+    pub snapshot_location: Option<String>,
+    pub created_on: DateTime<Utc>,
+}
+
+/// The branches and tags of a table, stored as JSON in the table option `table_refs`.
+///
+/// The snapshots referenced by them are exempt from data retention, the GC of the table
+/// keeps them and all the files they use until the references are dropped.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableRefs {
+    #[serde(default)]
+    pub branches: BTreeMap<String, TableRef>,
+    #[serde(default)]
+    pub tags: BTreeMap<String, TableRef>,
+}
+
+impl TableRefs {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        match options.get(OPT_KEY_TABLE_REFS) {
+            Some(refs) => serde_json::from_str(refs).map_err(|e| {
+                ErrorCode::TableOptionInvalid(format!("invalid table option table_refs: {e}"))
+            }),
+            None => Ok(TableRefs::default()),
+        }
+    }
+
+    pub fn apply_to_options(&self, options: &mut BTreeMap<String, String>) -> Result<()> {
+        if self.is_empty() {
+            options.remove(OPT_KEY_TABLE_REFS);
+        } else {
+            options.insert(OPT_KEY_TABLE_REFS.to_string(), serde_json::to_string(self)?);
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.branches.is_empty() && self.tags.is_empty()
+    }
+
+    pub fn refs(&self, kind: TableRefKind) -> &BTreeMap<String, TableRef> {
+        match kind {
+            TableRefKind::Branch => &self.branches,
+            TableRefKind::Tag => &self.tags,
+        }
+    }
+
+    pub fn refs_mut(&mut self, kind: TableRefKind) -> &mut BTreeMap<String, TableRef> {
+        match kind {
+            TableRefKind::Branch => &mut self.branches,
+            TableRefKind::Tag => &mut self.tags,
+        }
+    }
+
+    pub fn get(&self, kind: TableRefKind, name: &str) -> Result<&TableRef> {
+        self.refs(kind)
+            .get(name)
+            .ok_or_else(|| ErrorCode::UnknownTableRef(format!("Unknown {kind} '{name}'")))
+    }
+
+    /// Returns the locations of all the snapshots referenced by branches and tags.
+    pub fn snapshot_locations(&self) -> impl Iterator<Item = &String> {
+        self.branches
+            .values()
+            .chain(self.tags.values())
+            .filter_map(|r| r.snapshot_location.as_ref())
+    }
+}
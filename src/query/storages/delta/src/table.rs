@@ -46,6 +46,7 @@ use databend_common_storages_parquet::ParquetFilePart;
 use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetPruner;
 use databend_common_storages_parquet::ParquetReaderBuilder;
+use databend_common_storages_parquet::ParquetRuntimeFilter;
 use databend_common_storages_parquet::ParquetSourceType;
 use databend_storages_common_pruner::partition_prunner::FetchPartitionScalars;
 use databend_storages_common_pruner::partition_prunner::PartitionPruner;
@@ -248,12 +249,16 @@ impl DeltaTable {
         } else {
             None
         };
-        let mut builder =
-            ParquetReaderBuilder::create(ctx.clone(), Arc::new(op), table_schema, arrow_schema)?
-                .with_options(read_options)
-                .with_push_downs(push_downs.as_ref())
-                .with_pruner(Some(pruner))
-                .with_partition_columns(self.meta.partition_columns.clone());
+        let mut builder = ParquetReaderBuilder::create(
+            ctx.clone(),
+            Arc::new(op),
+            table_schema.clone(),
+            arrow_schema,
+        )?
+        .with_options(read_options)
+        .with_push_downs(push_downs.as_ref())
+        .with_pruner(Some(pruner))
+        .with_partition_columns(self.meta.partition_columns.clone());
 
         let parquet_reader =
             Arc::new(builder.build_full_reader(ParquetSourceType::DeltaLake, false)?);
@@ -267,6 +272,13 @@ impl DeltaTable {
                     output_schema.clone(),
                     parquet_reader.clone(),
                     self.get_partition_fields()?,
+                    ParquetRuntimeFilter::create(
+                        ctx.clone(),
+                        plan,
+                        table_schema.clone(),
+                        read_options,
+                        self.meta.partition_columns.clone(),
+                    )?,
                 )
             },
             max_threads.max(1),
@@ -19,6 +19,8 @@ use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
+use databend_common_storage::MostCommonValues;
+use databend_common_storage::MultiColumnStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
@@ -28,12 +30,16 @@ pub struct FuseTableColumnStatisticsProvider {
     row_count: u64,
     column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
     histograms: HashMap<ColumnId, Histogram>,
+    most_common_values: HashMap<ColumnId, MostCommonValues>,
+    multi_column_statistics: Vec<MultiColumnStatistics>,
 }
 
 impl FuseTableColumnStatisticsProvider {
     pub fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
         histograms: HashMap<ColumnId, Histogram>,
+        most_common_values: HashMap<ColumnId, MostCommonValues>,
+        multi_column_statistics: Vec<MultiColumnStatistics>,
         column_distinct_values: Option<HashMap<ColumnId, u64>>,
         stats_row_count: u64,
         row_count: u64,
@@ -59,6 +65,8 @@ impl FuseTableColumnStatisticsProvider {
         Self {
             column_stats,
             histograms,
+            most_common_values,
+            multi_column_statistics,
             stats_row_count,
             row_count,
         }
@@ -88,4 +96,12 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn histogram(&self, column_id: ColumnId) -> Option<Histogram> {
         self.histograms.get(&column_id).cloned()
     }
+
+    fn most_common_values(&self, column_id: ColumnId) -> Option<MostCommonValues> {
+        self.most_common_values.get(&column_id).cloned()
+    }
+
+    fn multi_column_statistics(&self) -> Vec<MultiColumnStatistics> {
+        self.multi_column_statistics.clone()
+    }
 }
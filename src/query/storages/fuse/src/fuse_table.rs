@@ -133,6 +133,7 @@ use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_NUM_SNAPSHOTS_TO_KEEP;
 use crate::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
+use crate::FUSE_OPT_KEY_ENABLE_DELETION_VECTOR;
 use crate::FUSE_OPT_KEY_FILE_SIZE;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
@@ -790,6 +791,19 @@ impl FuseTable {
                 .cluster_type()
                 .is_none_or(|v| matches!(v, ClusterType::Hilbert)))
     }
+
+    /// Whether DELETE and UPDATE mark the mutated rows in deletion vectors
+    /// instead of rewriting the blocks.
+    pub fn enable_deletion_vector(&self) -> bool {
+        let enabled = self.get_option(FUSE_OPT_KEY_ENABLE_DELETION_VECTOR, 0u32) != 0
+            || GlobalConfig::instance()
+                .query
+                .internal_merge_on_read_mutation;
+        enabled
+            && matches!(self.storage_format, FuseStorageFormat::Parquet)
+            && !self.is_column_oriented()
+            && !self.change_tracking_enabled()
+    }
 }
 
 #[async_trait::async_trait]
@@ -1035,6 +1049,11 @@ impl Table for FuseTable {
                     stats.insert(*col_id, stat.clone());
                 }
             }
+            // The null counts include the rows deleted by deletion vectors,
+            // only the columns without null values are accurate.
+            if snapshot.summary.deleted_row_count.is_some() {
+                stats.retain(|_, stat| stat.null_count == 0);
+            }
             let table_statistics = self.read_table_snapshot_statistics(Some(&snapshot)).await?;
             let additional_stats_meta = snapshot.summary.additional_stats_meta.as_ref();
             let column_distinct_values = match additional_stats_meta.and_then(|v| v.hll.as_ref()) {
@@ -1048,6 +1067,25 @@ impl Table for FuseTable {
                 .as_ref()
                 .map(|v| v.histograms.clone())
                 .unwrap_or_default();
+            let most_common_values = table_statistics
+                .as_ref()
+                .map(|v| v.most_common_values.clone())
+                .unwrap_or_default();
+            // Statistics dropped or recreated on other columns after analyzing are stale.
+            let defs = &self.table_info.meta.multi_column_statistics;
+            let multi_column_statistics = table_statistics
+                .as_ref()
+                .map(|v| {
+                    v.multi_column_statistics
+                        .iter()
+                        .filter(|(name, stat)| {
+                            defs.get(*name)
+                                .is_some_and(|def| def.column_ids == stat.column_ids)
+                        })
+                        .map(|(_, stat)| stat.clone())
+                        .collect()
+                })
+                .unwrap_or_default();
             let stats_row_count = additional_stats_meta
                 .map(|v| v.row_count)
                 .or(table_statistics.as_ref().map(|v| v.row_count))
@@ -1055,6 +1093,8 @@ impl Table for FuseTable {
             FuseTableColumnStatisticsProvider::new(
                 stats,
                 histograms,
+                most_common_values,
+                multi_column_statistics,
                 column_distinct_values,
                 stats_row_count,
                 snapshot.summary.row_count,
@@ -1142,6 +1182,29 @@ impl Table for FuseTable {
         Ok(Some(r))
     }
 
+    #[async_backtrace::framed]
+    async fn refresh_with_seq_meta(
+        &self,
+        ctx: &dyn TableContext,
+        seq: u64,
+        meta: TableMeta,
+    ) -> Result<Arc<dyn Table>> {
+        let table_info = TableInfo {
+            ident: TableIdent::new(self.table_info.ident.table_id, seq),
+            meta,
+            ..self.table_info.clone()
+        };
+        let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        let table = catalog.get_table_by_info(&table_info)?;
+        match self.current_branch() {
+            // keep the refreshed table checked out at the same branch
+            Some(branch) => Ok(FuseTable::try_from_table(table.as_ref())?
+                .checkout_branch(ctx, branch)
+                .await?),
+            None => Ok(table),
+        }
+    }
+
     #[fastrace::trace]
     #[async_backtrace::framed]
     async fn navigate_to(
@@ -0,0 +1,75 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::types::MutableBitmap;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// A deletion vector records the offsets of the rows deleted from a block,
+/// serialized in the portable roaring bitmap format.
+pub struct DeletionVector;
+
+impl DeletionVector {
+    pub const VERSION: u64 = 0;
+
+    pub fn serialize(deleted_rows: &RoaringBitmap) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(deleted_rows.serialized_size());
+        deleted_rows.serialize_into(&mut data)?;
+        Ok(data)
+    }
+
+    pub fn deserialize(data: &[u8]) -> Result<RoaringBitmap> {
+        RoaringBitmap::deserialize_from(data).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to deserialize deletion vector: {e}"))
+        })
+    }
+
+    pub async fn read(dal: &Operator, meta: &DeletionVectorMeta) -> Result<RoaringBitmap> {
+        let data = dal.read(&meta.location.0).await?;
+        Self::deserialize(&data.to_vec())
+    }
+
+    pub fn sync_read(dal: &Operator, meta: &DeletionVectorMeta) -> Result<RoaringBitmap> {
+        let data = dal.blocking().read(&meta.location.0)?;
+        Self::deserialize(&data.to_vec())
+    }
+
+    /// Writes the deletion vector and returns the size of the written file.
+    pub async fn write(
+        dal: &Operator,
+        location: &str,
+        deleted_rows: &RoaringBitmap,
+    ) -> Result<u64> {
+        let data = Self::serialize(deleted_rows)?;
+        let size = data.len() as u64;
+        dal.write(location, data).await?;
+        Ok(size)
+    }
+
+    /// Builds the filter of a block with `num_rows` rows, in which the deleted rows are unset.
+    pub fn live_rows_filter(num_rows: usize, deleted_rows: &RoaringBitmap) -> Bitmap {
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for offset in deleted_rows.iter() {
+            let offset = offset as usize;
+            if offset < num_rows {
+                bitmap.set(offset, false);
+            }
+        }
+        bitmap.into()
+    }
+}
@@ -36,7 +36,9 @@ use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::index::InvertedIndexFile;
+use crate::io::DeletionVector;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT_V2;
 use crate::FUSE_TBL_SEGMENT_STATISTICS_PREFIX;
@@ -69,6 +71,7 @@ pub struct TableMetaLocationGenerator {
     agg_index_location_prefix: String,
     inverted_index_location_prefix: String,
     vector_index_location_prefix: String,
+    deletion_vector_location_prefix: String,
     segment_statistics_location_prefix: String,
 }
 
@@ -83,6 +86,8 @@ impl TableMetaLocationGenerator {
         let inverted_index_location_prefix =
             format!("{}/{}/", &prefix, FUSE_TBL_INVERTED_INDEX_PREFIX);
         let vector_index_location_prefix = format!("{}/{}/", &prefix, FUSE_TBL_VECTOR_INDEX_PREFIX);
+        let deletion_vector_location_prefix =
+            format!("{}/{}/", &prefix, FUSE_TBL_DELETION_VECTOR_PREFIX);
         let segment_statistics_location_prefix =
             format!("{}/{}/", &prefix, FUSE_TBL_SEGMENT_STATISTICS_PREFIX);
         Self {
@@ -94,6 +99,7 @@ impl TableMetaLocationGenerator {
             agg_index_location_prefix,
             inverted_index_location_prefix,
             vector_index_location_prefix,
+            deletion_vector_location_prefix,
             segment_statistics_location_prefix,
         }
     }
@@ -114,6 +120,10 @@ impl TableMetaLocationGenerator {
         &self.vector_index_location_prefix
     }
 
+    pub fn deletion_vector_location_prefix(&self) -> &str {
+        &self.deletion_vector_location_prefix
+    }
+
     pub fn segment_location_prefix(&self) -> &str {
         &self.segment_info_location_prefix
     }
@@ -167,6 +177,23 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_deletion_vector_location(
+        &self,
+        table_meta_timestamps: TableMetaTimestamps,
+    ) -> Location {
+        let uuid = uuid_from_date_time(table_meta_timestamps.segment_block_timestamp);
+        (
+            format!(
+                "{}{}{}_v{}.bin",
+                self.deletion_vector_location_prefix(),
+                VACUUM2_OBJECT_KEY_PREFIX,
+                uuid.as_simple(),
+                DeletionVector::VERSION,
+            ),
+            DeletionVector::VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(
         &self,
         table_meta_timestamps: TableMetaTimestamps,
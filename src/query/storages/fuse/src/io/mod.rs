@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod deletion_vector;
 mod locations;
 pub mod read;
 mod segments;
 mod snapshots;
 mod write;
 
+pub use deletion_vector::DeletionVector;
 pub use locations::TableMetaLocationGenerator;
 pub use read::AggIndexReader;
 pub use read::BlockReadResult;
@@ -68,6 +68,7 @@ use crate::io::BloomIndexState;
 use crate::io::TableMetaLocationGenerator;
 use crate::operations::column_parquet_metas;
 use crate::statistics::gen_columns_statistics;
+use crate::statistics::gen_spatial_statistics;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseStorageFormat;
 
@@ -244,6 +245,7 @@ impl BlockBuilder {
             Some(column_distinct_count),
             &self.source_schema,
         )?;
+        let spatial_stats = gen_spatial_statistics(&data_block, &self.source_schema)?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let block_size = data_block.estimate_block_size() as u64;
@@ -283,6 +285,8 @@ impl BlockBuilder {
             inverted_index_size,
             virtual_block_meta: None,
             create_on: Some(Utc::now()),
+            spatial_stats,
+            deletion_vector: None,
         };
 
         let column_hlls = column_hlls
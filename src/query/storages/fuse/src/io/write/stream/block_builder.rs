@@ -46,6 +46,7 @@ use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::BlockHLLState;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ColumnMeta;
+use databend_storages_common_table_meta::meta::SpatialStatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use databend_storages_common_table_meta::table::TableCompression;
 use parquet::arrow::ArrowWriter;
@@ -68,6 +69,8 @@ use crate::io::VectorIndexBuilder;
 use crate::io::VirtualColumnBuilder;
 use crate::io::WriteSettings;
 use crate::operations::column_parquet_metas;
+use crate::statistics::gen_spatial_statistics;
+use crate::statistics::merge_spatial_statistics;
 use crate::FuseStorageFormat;
 use crate::FuseTable;
 
@@ -162,6 +165,7 @@ pub struct StreamBlockBuilder {
 
     cluster_stats_state: ClusterStatisticsState,
     column_stats_state: ColumnStatisticsState,
+    spatial_stats: Option<SpatialStatisticsOfColumns>,
 
     row_count: usize,
     block_size: usize,
@@ -247,6 +251,7 @@ impl StreamBlockBuilder {
             block_size: 0,
             column_stats_state,
             cluster_stats_state,
+            spatial_stats: None,
         })
     }
 
@@ -274,6 +279,10 @@ impl StreamBlockBuilder {
         let block = self.cluster_stats_state.add_block(block)?;
         self.column_stats_state
             .add_block(&self.properties.source_schema, &block)?;
+        merge_spatial_statistics(
+            &mut self.spatial_stats,
+            gen_spatial_statistics(&block, &self.properties.source_schema)?,
+        );
         self.bloom_index_builder.add_block(&block)?;
         self.block_stats_builder.add_block(&block)?;
         for writer in self.inverted_index_writers.iter_mut() {
@@ -394,6 +403,8 @@ impl StreamBlockBuilder {
                 .map(|v| v.ngram_size)
                 .unwrap_or_default(),
             virtual_block_meta: None,
+            spatial_stats: self.spatial_stats,
+            deletion_vector: None,
         };
         let serialized = BlockSerialization {
             block_raw_data,
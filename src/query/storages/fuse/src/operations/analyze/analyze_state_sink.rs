@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -29,6 +30,7 @@ use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::Processor;
@@ -38,6 +40,8 @@ use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use databend_common_storage::HistogramBucket;
 use databend_common_storage::MetaHLL;
+use databend_common_storage::MostCommonValues;
+use databend_common_storage::MultiColumnStatistics;
 use databend_storages_common_cache::Partitions;
 use databend_storages_common_table_meta::meta::encode_column_hll;
 use databend_storages_common_table_meta::meta::AdditionalStatsMeta;
@@ -57,13 +61,25 @@ use crate::statistics::reducers::reduce_virtual_column_statistics;
 use crate::FuseLazyPartInfo;
 use crate::FuseTable;
 
+/// Receivers of the statistics computed by SQL queries on the table during analyzing.
+#[derive(Clone, Default)]
+pub struct AnalyzeStatsReceivers {
+    /// Histogram buckets of each column.
+    pub histograms: HashMap<ColumnId, Receiver<DataBlock>>,
+    /// Most common values and their counts of each column.
+    pub most_common_values: HashMap<ColumnId, Receiver<DataBlock>>,
+    /// Distinct combinations and functional dependencies of each column group,
+    /// keyed by statistics name.
+    pub multi_column_statistics: HashMap<String, (Vec<ColumnId>, Receiver<DataBlock>)>,
+}
+
 impl FuseTable {
     pub fn do_analyze(
         &self,
         ctx: Arc<dyn TableContext>,
         snapshot: Arc<TableSnapshot>,
         pipeline: &mut Pipeline,
-        histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        stats_receivers: AnalyzeStatsReceivers,
         no_scan: bool,
     ) -> Result<()> {
         let mut parts = Vec::with_capacity(snapshot.segments.len());
@@ -92,9 +108,9 @@ impl FuseTable {
             SinkAnalyzeState::create(
                 ctx.clone(),
                 self,
-                snapshot.snapshot_id,
+                snapshot.clone(),
                 input,
-                histogram_info_receivers.clone(),
+                stats_receivers.clone(),
             )
         })?;
         Ok(())
@@ -105,6 +121,8 @@ impl FuseTable {
 enum AnalyzeStep {
     CollectNDV,
     CollectHistogram,
+    CollectMostCommonValues,
+    CollectMultiColumnStatistics,
     CommitStatistics,
 }
 
@@ -114,13 +132,17 @@ struct SinkAnalyzeState {
 
     table: Arc<dyn Table>,
     snapshot_id: SnapshotId,
-    histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+    // The number of rows of the analyzed snapshot, used to compute value frequencies.
+    snapshot_row_count: u64,
+    stats_receivers: AnalyzeStatsReceivers,
     input_data: Option<DataBlock>,
     committed: bool,
     row_count: u64,
     unstats_rows: u64,
     ndv_states: HashMap<ColumnId, MetaHLL>,
     histograms: HashMap<ColumnId, Histogram>,
+    most_common_values: HashMap<ColumnId, MostCommonValues>,
+    multi_column_statistics: BTreeMap<String, MultiColumnStatistics>,
     step: AnalyzeStep,
 }
 
@@ -128,22 +150,25 @@ impl SinkAnalyzeState {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         table: &FuseTable,
-        snapshot_id: SnapshotId,
+        snapshot: Arc<TableSnapshot>,
         input_port: Arc<InputPort>,
-        histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        stats_receivers: AnalyzeStatsReceivers,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(SinkAnalyzeState {
             ctx,
             input_port,
             table: Arc::new(table.clone()),
-            snapshot_id,
-            histogram_info_receivers,
+            snapshot_id: snapshot.snapshot_id,
+            snapshot_row_count: snapshot.summary.row_count,
+            stats_receivers,
             input_data: None,
             committed: false,
             row_count: 0,
             unstats_rows: 0,
             ndv_states: Default::default(),
             histograms: Default::default(),
+            most_common_values: Default::default(),
+            multi_column_statistics: Default::default(),
             step: AnalyzeStep::CollectNDV,
         })))
     }
@@ -187,6 +212,53 @@ impl SinkAnalyzeState {
         Ok(())
     }
 
+    /// The data block is the most common values of the column and their counts,
+    /// in descending order of count.
+    fn create_most_common_values(&mut self, col_id: ColumnId, data_block: DataBlock) {
+        if data_block.num_rows() == 0 || self.snapshot_row_count == 0 {
+            return;
+        }
+        let most_common_values = self.most_common_values.entry(col_id).or_default();
+        for row in 0..data_block.num_rows() {
+            let value = data_block
+                .get_by_offset(0)
+                .index(row)
+                .and_then(|v| Datum::from_scalar(v.to_owned()));
+            let count = scalar_to_f64(data_block.get_by_offset(1).index(row));
+            if let (Some(value), Some(count)) = (value, count) {
+                let frequency = (count / self.snapshot_row_count as f64).min(1.0);
+                most_common_values.values.push((value, frequency));
+            }
+        }
+    }
+
+    /// The data block is a single row of the number of distinct combinations of
+    /// the column group, followed by the functional dependency degree of each column.
+    fn create_multi_column_statistics(
+        &mut self,
+        name: &str,
+        column_ids: &[ColumnId],
+        data_block: DataBlock,
+    ) {
+        if data_block.num_rows() == 0 || data_block.num_columns() != column_ids.len() + 1 {
+            return;
+        }
+        let Some(ndv) = scalar_to_f64(data_block.get_by_offset(0).index(0)) else {
+            return;
+        };
+        let dependencies = (1..data_block.num_columns())
+            .map(|i| {
+                scalar_to_f64(data_block.get_by_offset(i).index(0))
+                    .unwrap_or(0.0)
+                    .clamp(0.0, 1.0)
+            })
+            .collect();
+        self.multi_column_statistics.insert(
+            name.to_string(),
+            MultiColumnStatistics::new(column_ids.to_vec(), ndv as u64, dependencies),
+        );
+    }
+
     async fn commit_statistics(&mut self) -> Result<()> {
         let table = self.table.refresh(self.ctx.as_ref()).await?;
         let table = FuseTable::try_from_table(table.as_ref())?;
@@ -197,6 +269,10 @@ impl SinkAnalyzeState {
         let snapshot = snapshot.unwrap();
         let column_ids = snapshot.schema.to_leaf_column_id_set();
         self.ndv_states.retain(|k, _| column_ids.contains(k));
+        self.most_common_values
+            .retain(|k, v| column_ids.contains(k) && !v.is_empty());
+        self.multi_column_statistics
+            .retain(|_, v| v.column_ids.iter().all(|id| column_ids.contains(id)));
 
         let mut new_snapshot = TableSnapshot::try_from_previous(
             snapshot.clone(),
@@ -217,6 +293,8 @@ impl SinkAnalyzeState {
             let stats = TableSnapshotStatistics::new(
                 self.ndv_states.clone(),
                 self.histograms.clone(),
+                self.most_common_values.clone(),
+                self.multi_column_statistics.clone(),
                 self.snapshot_id,
                 self.row_count,
             );
@@ -350,7 +428,9 @@ impl Processor for SinkAnalyzeState {
                     self.step = AnalyzeStep::CollectHistogram;
                     return Ok(Event::Async);
                 }
-                AnalyzeStep::CollectHistogram => {
+                AnalyzeStep::CollectHistogram
+                | AnalyzeStep::CollectMostCommonValues
+                | AnalyzeStep::CollectMultiColumnStatistics => {
                     return Ok(Event::Async);
                 }
                 AnalyzeStep::CommitStatistics => {
@@ -404,7 +484,7 @@ impl Processor for SinkAnalyzeState {
         match self.step {
             AnalyzeStep::CollectHistogram => {
                 let mut finished_count = 0;
-                let receivers = self.histogram_info_receivers.clone();
+                let receivers = self.stats_receivers.histograms.clone();
                 for (id, receiver) in receivers.iter() {
                     if let Ok(res) = receiver.recv().await {
                         self.create_histogram(*id, res).await?;
@@ -412,7 +492,35 @@ impl Processor for SinkAnalyzeState {
                         finished_count += 1;
                     }
                 }
-                if finished_count == self.histogram_info_receivers.len() {
+                if finished_count == receivers.len() {
+                    self.step = AnalyzeStep::CollectMostCommonValues;
+                }
+            }
+            AnalyzeStep::CollectMostCommonValues => {
+                let mut finished_count = 0;
+                let receivers = self.stats_receivers.most_common_values.clone();
+                for (id, receiver) in receivers.iter() {
+                    if let Ok(res) = receiver.recv().await {
+                        self.create_most_common_values(*id, res);
+                    } else {
+                        finished_count += 1;
+                    }
+                }
+                if finished_count == receivers.len() {
+                    self.step = AnalyzeStep::CollectMultiColumnStatistics;
+                }
+            }
+            AnalyzeStep::CollectMultiColumnStatistics => {
+                let mut finished_count = 0;
+                let receivers = self.stats_receivers.multi_column_statistics.clone();
+                for (name, (column_ids, receiver)) in receivers.iter() {
+                    if let Ok(res) = receiver.recv().await {
+                        self.create_multi_column_statistics(name, column_ids, res);
+                    } else {
+                        finished_count += 1;
+                    }
+                }
+                if finished_count == receivers.len() {
                     self.step = AnalyzeStep::CommitStatistics;
                 }
             }
@@ -436,3 +544,11 @@ impl Processor for SinkAnalyzeState {
         Ok(())
     }
 }
+
+fn scalar_to_f64(scalar: Option<ScalarRef>) -> Option<f64> {
+    match scalar? {
+        ScalarRef::Number(number) => Some(number.to_f64().into_inner()),
+        ScalarRef::Decimal(decimal) => Some(decimal.to_float64()),
+        _ => None,
+    }
+}
@@ -18,5 +18,6 @@ mod collect_ndv_source;
 mod histogram_info_sink;
 
 pub use analyze_ndv_meta::AnalyzeNDVMeta;
+pub use analyze_state_sink::AnalyzeStatsReceivers;
 pub use collect_ndv_source::AnalyzeCollectNDVSource;
 pub use histogram_info_sink::HistogramInfoSink;
@@ -65,6 +65,11 @@ impl FuseTable {
         desc: String,
         navigation: Option<&NavigationPoint>,
     ) -> Result<ChangesDesc> {
+        if let Some(NavigationPoint::Branch(_)) = navigation {
+            return Err(ErrorCode::IllegalStream(
+                "Change tracking is not supported on the branches of a table",
+            ));
+        }
         // To support analyze table, we move the change tracking check out of the function.
         let source = if let Some(point) = navigation {
             self.navigate_to_point(ctx, point).await?.as_ref().clone()
@@ -0,0 +1,404 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A table created by `CREATE TABLE ... CLONE` starts from the snapshot of its source
+//! table, and shares the segments and blocks of that snapshot instead of copying them.
+//!
+//! To keep the shared files alive, a reference file `_clone_refs/<clone table id>` is
+//! written under the storage prefix of every table owning files used by the clone, and
+//! the clone keeps the paths of these reference files in `_clone_source`. The GC of a
+//! table never purges files reachable from the snapshots of its clones, and the reference
+//! files are removed when the data of the clone is vacuumed after it's dropped.
+
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use bytes::Buf;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use log::info;
+use opendal::ErrorKind;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::io::MetaReaders;
+use crate::io::SnapshotsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::gc::LocationTuple;
+use crate::FuseTable;
+use crate::RetentionPolicy;
+use crate::FUSE_TBL_CLONE_REFS_PREFIX;
+use crate::FUSE_TBL_CLONE_SOURCE;
+use crate::FUSE_TBL_SNAPSHOT_PREFIX;
+
+/// The content of a reference file, written under the storage prefix of a table whose
+/// files are used by a clone.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CloneReference {
+    pub table_id: u64,
+    /// The storage prefix of the clone.
+    pub storage_prefix: String,
+    /// The snapshot the clone is created from.
+    pub snapshot_location: String,
+}
+
+/// Files of a table that are still used by its clones.
+#[derive(Default)]
+pub struct CloneReferencedLocations {
+    pub snapshots: HashSet<String>,
+    pub segments: HashSet<String>,
+    pub table_statistics: HashSet<String>,
+    pub locations: LocationTuple,
+    /// The reference files these locations are collected from.
+    pub reference_files: HashSet<String>,
+}
+
+impl CloneReferencedLocations {
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty() && self.segments.is_empty()
+    }
+
+    pub fn contains(&self, location: &str) -> bool {
+        self.snapshots.contains(location)
+            || self.segments.contains(location)
+            || self.table_statistics.contains(location)
+            || self.locations.block_location.contains(location)
+            || self.locations.bloom_location.contains(location)
+            || self.locations.hll_location.contains(location)
+            || self.locations.deletion_vector_location.contains(location)
+    }
+
+    pub fn extend(&mut self, other: CloneReferencedLocations) {
+        self.snapshots.extend(other.snapshots);
+        self.segments.extend(other.segments);
+        self.table_statistics.extend(other.table_statistics);
+        self.locations.extend(&other.locations);
+        self.reference_files.extend(other.reference_files);
+    }
+
+    pub fn retain(&mut self, f: impl Fn(&String) -> bool) {
+        self.snapshots.retain(&f);
+        self.segments.retain(&f);
+        self.table_statistics.retain(&f);
+        self.locations.retain(&f);
+    }
+
+    /// Removes the files used by clones from the files to be purged.
+    pub fn retain_unreferenced(
+        &self,
+        segments: &mut HashSet<Location>,
+        table_statistics: &mut HashSet<String>,
+        snapshots: &mut HashSet<String>,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+        segments.retain(|(loc, _)| !self.segments.contains(loc));
+        table_statistics.retain(|loc| !self.table_statistics.contains(loc));
+        snapshots.retain(|loc| !self.snapshots.contains(loc));
+    }
+
+    /// Removes the files used by clones from the given files to be purged.
+    pub fn retain_unreferenced_files<'a>(
+        &self,
+        files: impl IntoIterator<Item = &'a mut HashSet<String>>,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+        for files in files {
+            files.retain(|loc| !self.contains(loc));
+        }
+    }
+}
+
+impl FuseTable {
+    /// Checks that a clone can be created from the snapshot at `snapshot_location`.
+    ///
+    /// The files of snapshots outside the retention period of this table may already be
+    /// purged, so only the snapshots kept by the GC, and those of the branches and tags of
+    /// this table, can be cloned.
+    #[async_backtrace::framed]
+    pub async fn check_clone_snapshot_retained(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_location: &str,
+    ) -> Result<()> {
+        let Some(current_location) = self.snapshot_loc() else {
+            return Ok(());
+        };
+        if current_location == snapshot_location
+            || self
+                .table_refs()?
+                .snapshot_locations()
+                .any(|loc| loc == snapshot_location)
+        {
+            return Ok(());
+        }
+
+        let snapshot = read_snapshot(self.get_operator(), snapshot_location).await?;
+        let retained_since = match self.get_data_retention_policy(ctx.as_ref())? {
+            RetentionPolicy::ByTimePeriod(period) => self
+                .read_table_snapshot()
+                .await?
+                .and_then(|current| current.timestamp)
+                .map(|timestamp| timestamp - period),
+            RetentionPolicy::ByNumOfSnapshotsToKeep(num) => self
+                .navigate_back_with_limit(ctx, current_location, num)
+                .await?
+                .read_table_snapshot()
+                .await?
+                .and_then(|oldest| oldest.timestamp),
+        };
+        match (snapshot.timestamp, retained_since) {
+            (Some(timestamp), Some(retained_since)) if timestamp < retained_since => {
+                Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Can not clone table {} at snapshot {}, it is older than the data retention period of the table, its files may have been purged",
+                    self.table_info.desc, snapshot.snapshot_id
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Registers this table, just cloned at `snapshot_location`, as a user of the files
+    /// owned by other tables.
+    #[async_backtrace::framed]
+    pub async fn register_clone_references(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_location: &str,
+    ) -> Result<()> {
+        let own_prefix = self.meta_location_generator().prefix();
+        let snapshot = read_snapshot(self.get_operator(), snapshot_location).await?;
+        let locations = self
+            .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+            .await?;
+
+        let table_statistics_location = snapshot.table_statistics_location();
+        let referenced = std::iter::once(snapshot_location)
+            .chain(snapshot.segments.iter().map(|(loc, _)| loc.as_str()))
+            .chain(table_statistics_location.as_deref())
+            .chain(locations.block_location.iter().map(String::as_str))
+            .chain(locations.bloom_location.iter().map(String::as_str))
+            .chain(locations.hll_location.iter().map(String::as_str))
+            .chain(
+                locations
+                    .deletion_vector_location
+                    .iter()
+                    .map(String::as_str),
+            );
+        let prefixes = referenced
+            .filter_map(location_prefix)
+            .filter(|prefix| *prefix != own_prefix)
+            .collect::<BTreeSet<_>>();
+
+        let reference = CloneReference {
+            table_id: self.get_id(),
+            storage_prefix: own_prefix.to_string(),
+            snapshot_location: snapshot_location.to_string(),
+        };
+        let reference_files = prefixes
+            .into_iter()
+            .map(|prefix| {
+                format!(
+                    "{}/{}/{}",
+                    prefix,
+                    FUSE_TBL_CLONE_REFS_PREFIX,
+                    self.get_id()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Write the paths of the reference files first, so that they can be removed by
+        // vacuum even if writing some of them fails.
+        let source_path = format!("{}/{}", own_prefix, FUSE_TBL_CLONE_SOURCE);
+        self.operator
+            .write(&source_path, serde_json::to_vec(&reference_files)?)
+            .await?;
+
+        let content = serde_json::to_vec(&reference)?;
+        for path in reference_files.iter() {
+            self.operator.write(path, content.clone()).await?;
+        }
+        info!(
+            "clone: table {} references files of {:?}",
+            self.table_info.desc, reference_files
+        );
+        Ok(())
+    }
+
+    /// Returns the files of this table that are reachable from the snapshots of its clones.
+    #[async_backtrace::framed]
+    pub async fn get_clone_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<CloneReferencedLocations> {
+        let mut referenced = CloneReferencedLocations::default();
+        self.refresh_clone_referenced_locations(ctx, &mut referenced)
+            .await?;
+        Ok(referenced)
+    }
+
+    /// Adds the files used by the clones registered since `referenced` was collected.
+    ///
+    /// A clone may be registered while the table is being purged, so the references are
+    /// checked again right before files are deleted. Returns true if a new clone is found.
+    #[async_backtrace::framed]
+    pub async fn refresh_clone_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        referenced: &mut CloneReferencedLocations,
+    ) -> Result<bool> {
+        let reference_files = list_clone_reference_files(
+            self.get_operator(),
+            self.meta_location_generator().prefix(),
+        )
+        .await?;
+        let mut collected = CloneReferencedLocations::default();
+        for path in reference_files {
+            if referenced.reference_files.contains(&path) {
+                continue;
+            }
+            let reference: CloneReference = match self.operator.read(&path).await {
+                Ok(buf) => serde_json::from_reader(buf.reader())?,
+                // The clone has been vacuumed concurrently.
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+
+            let prefix = format!("{}/{}/", reference.storage_prefix, FUSE_TBL_SNAPSHOT_PREFIX);
+            let mut snapshot_locations =
+                SnapshotsIO::list_files(self.get_operator(), &prefix, None).await?;
+            snapshot_locations.push(reference.snapshot_location);
+            // Segments written by the clone may still reference blocks of this table.
+            self.collect_referenced_locations(ctx, snapshot_locations, &mut collected)
+                .await?;
+            collected.reference_files.insert(path);
+        }
+
+        if collected.reference_files.is_empty() {
+            return Ok(false);
+        }
+        collected.retain(|loc| self.is_own_location(loc));
+        referenced.extend(collected);
+        Ok(true)
+    }
+
+    /// Adds the given snapshots, and the files reachable from them, to `referenced`.
+    ///
+    /// Snapshots that no longer exist are ignored.
+    #[async_backtrace::framed]
+    pub(crate) async fn collect_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_locations: Vec<String>,
+        referenced: &mut CloneReferencedLocations,
+    ) -> Result<()> {
+        let mut segments = HashSet::<Location>::new();
+        for location in snapshot_locations {
+            let snapshot = match read_snapshot(self.get_operator(), &location).await {
+                Ok(snapshot) => snapshot,
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => continue,
+                Err(e) => return Err(e),
+            };
+            if let Some(ts) = snapshot.table_statistics_location() {
+                referenced.table_statistics.insert(ts);
+            }
+            segments.extend(snapshot.segments.iter().cloned());
+            referenced.snapshots.insert(location);
+        }
+
+        let segments = segments.into_iter().collect::<Vec<_>>();
+        let locations = self
+            .get_block_locations(ctx.clone(), &segments, false, true)
+            .await?;
+        referenced
+            .segments
+            .extend(segments.into_iter().map(|(loc, _)| loc));
+        referenced.locations.extend(&locations);
+        Ok(())
+    }
+
+    /// Whether the file is stored under the storage prefix of this table.
+    ///
+    /// Files of other tables may be referenced by a clone, they must never be purged by it.
+    pub fn is_own_location(&self, location: &str) -> bool {
+        location
+            .strip_prefix(self.meta_location_generator().prefix())
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+/// Returns true if files stored under `storage_prefix` are still used by any clone.
+#[async_backtrace::framed]
+pub async fn has_clone_references(operator: &Operator, storage_prefix: &str) -> Result<bool> {
+    let files = list_clone_reference_files(operator.clone(), storage_prefix).await?;
+    Ok(!files.is_empty())
+}
+
+/// Removes the reference files registered by the clone stored at `storage_prefix`, so
+/// that the files it shares with other tables can be purged by them.
+#[async_backtrace::framed]
+pub async fn remove_clone_references(operator: &Operator, storage_prefix: &str) -> Result<()> {
+    let source_path = format!(
+        "{}/{}",
+        storage_prefix.trim_end_matches('/'),
+        FUSE_TBL_CLONE_SOURCE
+    );
+    let reference_files: Vec<String> = match operator.read(&source_path).await {
+        Ok(buf) => serde_json::from_reader(buf.reader())?,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+    for path in reference_files {
+        operator.delete(&path).await?;
+    }
+    Ok(())
+}
+
+async fn list_clone_reference_files(
+    operator: Operator,
+    storage_prefix: &str,
+) -> Result<Vec<String>> {
+    let prefix = format!(
+        "{}/{}/",
+        storage_prefix.trim_end_matches('/'),
+        FUSE_TBL_CLONE_REFS_PREFIX
+    );
+    SnapshotsIO::list_files(operator, &prefix, None).await
+}
+
+async fn read_snapshot(operator: Operator, location: &str) -> Result<Arc<TableSnapshot>> {
+    let reader = MetaReaders::table_snapshot_reader(operator);
+    let params = LoadParams {
+        location: location.to_string(),
+        len_hint: None,
+        ver: TableMetaLocationGenerator::snapshot_version(location),
+        put_cache: false,
+    };
+    reader.read(&params).await
+}
+
+// Files of fuse tables are stored as `<storage prefix>/<kind>/<file name>`.
+fn location_prefix(location: &str) -> Option<&str> {
+    location.rsplitn(3, '/').nth(2)
+}
@@ -54,6 +54,7 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::readers::snapshot_reader::TableSnapshotAccessor;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -249,8 +250,20 @@ impl FuseTable {
         deduplicated_label: Option<String>,
     ) -> Result<()> {
         // 1. prepare table meta
-        let new_table_meta =
-            Self::build_new_table_meta(&table_info.meta, &snapshot_location, &snapshot)?;
+        let branch = table_info.options().get(OPT_KEY_BRANCH);
+        let new_table_meta = match branch {
+            Some(branch) => {
+                Self::build_branch_table_meta(
+                    ctx,
+                    catalog.as_ref(),
+                    table_info,
+                    branch,
+                    &snapshot_location,
+                )
+                .await?
+            }
+            None => Self::build_new_table_meta(&table_info.meta, &snapshot_location, &snapshot)?,
+        };
         // 2. prepare the request
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -293,14 +306,17 @@ impl FuseTable {
 
         // update_table_meta succeed, populate the snapshot cache item and try keeping a hit file of last snapshot
         TableSnapshot::cache().insert(snapshot_location.clone(), snapshot);
-        Self::write_last_snapshot_hint(
-            ctx,
-            operator,
-            location_generator,
-            &snapshot_location,
-            &new_table_meta,
-        )
-        .await;
+        // the hint file always points to the snapshot of the main line of the table
+        if branch.is_none() {
+            Self::write_last_snapshot_hint(
+                ctx,
+                operator,
+                location_generator,
+                &snapshot_location,
+                &new_table_meta,
+            )
+            .await;
+        }
 
         Ok(())
     }
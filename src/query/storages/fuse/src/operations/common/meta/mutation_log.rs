@@ -20,6 +20,7 @@ use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::VirtualDataSchema;
 use databend_storages_common_table_meta::meta::BlockHLL;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::ExtendedBlockMeta;
 use databend_storages_common_table_meta::meta::FormatVersion;
 use databend_storages_common_table_meta::meta::Statistics;
@@ -58,6 +59,10 @@ pub enum MutationLogEntry {
     CompactExtras {
         extras: CompactExtraInfo,
     },
+    DeletionVector {
+        index: BlockMetaIndex,
+        deletion_vector: DeletionVectorMeta,
+    },
     AppendVirtualSchema {
         virtual_schema: VirtualDataSchema,
     },
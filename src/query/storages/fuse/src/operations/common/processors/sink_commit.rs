@@ -187,7 +187,10 @@ where F: SnapshotGenerator + Send + Sync + 'static
         table: &FuseTable,
         snapshot_gen: &F,
     ) -> Result<Option<PurgeMode>> {
-        let mode = if Self::need_to_purge_all_history(table, snapshot_gen) {
+        // The history of a branch is purged along with the table it belongs to.
+        let mode = if table.current_branch().is_some() {
+            None
+        } else if Self::need_to_purge_all_history(table, snapshot_gen) {
             Some(PurgeMode::PurgeAllHistory)
         } else if Self::is_auto_vacuum_enabled(ctx, table)? {
             Some(PurgeMode::PurgeAccordingToRetention)
@@ -37,6 +37,7 @@ use databend_storages_common_table_meta::meta::BlockHLL;
 use databend_storages_common_table_meta::meta::BlockHLLState;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::ExtendedBlockMeta;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::RawBlockHLL;
@@ -264,6 +265,15 @@ impl TableMutationAggregator {
                     .and_modify(|v| v.push_deleted(index.block_idx))
                     .or_insert(ExtendedBlockMutations::new_deletion(index.block_idx));
             }
+            MutationLogEntry::DeletionVector {
+                index,
+                deletion_vector,
+            } => {
+                self.mutations
+                    .entry(index.segment_idx)
+                    .or_default()
+                    .push_deletion_vector(index.block_idx, deletion_vector);
+            }
             MutationLogEntry::DeletedSegment { deleted_segment } => {
                 self.removed_segment_indexes.push(deleted_segment.index);
                 merge_statistics_mut(
@@ -306,6 +316,7 @@ impl TableMutationAggregator {
                         v.insert(BlockMutations {
                             replaced_blocks,
                             deleted_blocks: vec![],
+                            deletion_vectors: vec![],
                         });
                     }
                 }
@@ -527,6 +538,13 @@ impl TableMutationAggregator {
                     for idx in segment_mutation.deleted_blocks {
                         block_editor.remove(&idx);
                     }
+                    for (idx, deletion_vector) in segment_mutation.deletion_vectors {
+                        if let Some((block_meta, _)) = block_editor.get_mut(&idx) {
+                            let mut new_block_meta = block_meta.as_ref().clone();
+                            new_block_meta.deletion_vector = Some(deletion_vector);
+                            *block_meta = Arc::new(new_block_meta);
+                        }
+                    }
 
                     if block_editor.is_empty() {
                         return Ok(SegmentLite {
@@ -766,6 +784,7 @@ impl ExtendedBlockMutations {
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, BlockMetaWithHLL)>,
     deleted_blocks: Vec<BlockIndex>,
+    deletion_vectors: Vec<(BlockIndex, DeletionVectorMeta)>,
 }
 
 impl BlockMutations {
@@ -777,6 +796,7 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![(block_idx, (block_meta, column_hlls))],
             deleted_blocks: vec![],
+            deletion_vectors: vec![],
         }
     }
 
@@ -784,6 +804,7 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            deletion_vectors: vec![],
         }
     }
 
@@ -800,6 +821,10 @@ impl BlockMutations {
     fn push_deleted(&mut self, block_idx: BlockIndex) {
         self.deleted_blocks.push(block_idx)
     }
+
+    fn push_deletion_vector(&mut self, block_idx: BlockIndex, deletion_vector: DeletionVectorMeta) {
+        self.deletion_vectors.push((block_idx, deletion_vector))
+    }
 }
 
 struct SegmentLite {
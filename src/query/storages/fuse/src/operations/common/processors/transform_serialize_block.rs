@@ -35,6 +35,7 @@ use databend_common_sql::executor::physical_plans::MutationKind;
 use databend_common_storage::MutationStatus;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
 use databend_storages_common_table_meta::meta::TableMetaTimestamps;
 use opendal::Operator;
 
@@ -42,6 +43,7 @@ use crate::io::create_inverted_index_builders;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::io::BlockWriter;
+use crate::io::DeletionVector;
 use crate::io::VectorIndexBuilder;
 use crate::io::VirtualColumnBuilder;
 use crate::operations::common::BlockMetaIndex;
@@ -49,6 +51,7 @@ use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -59,11 +62,14 @@ enum State {
         block: DataBlock,
         stats_type: ClusterStatsGenType,
         index: Option<BlockMetaIndex>,
+        deletion_vector: Option<SerializeDeletionVector>,
     },
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        deletion_vector: Option<SerializeDeletionVector>,
     },
+    WriteDeletionVector(SerializeDeletionVector),
 }
 
 pub struct TransformSerializeBlock {
@@ -230,6 +236,26 @@ impl TransformSerializeBlock {
         self.block_builder.clone()
     }
 
+    async fn write_deletion_vector(
+        &self,
+        deletion_vector: SerializeDeletionVector,
+    ) -> Result<MutationLogEntry> {
+        let location = self
+            .block_builder
+            .meta_locations
+            .gen_deletion_vector_location(self.block_builder.table_meta_timestamps);
+        let size =
+            DeletionVector::write(&self.dal, &location.0, &deletion_vector.deleted_rows).await?;
+        Ok(MutationLogEntry::DeletionVector {
+            index: deletion_vector.index,
+            deletion_vector: DeletionVectorMeta {
+                location,
+                deleted_row_count: deletion_vector.deleted_rows.len(),
+                size,
+            },
+        })
+    }
+
     fn mutation_logs(entry: MutationLogEntry) -> DataBlock {
         let meta = MutationLogs {
             entries: vec![entry],
@@ -253,7 +279,10 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(
+            self.state,
+            State::Serialized { .. } | State::WriteDeletionVector(_)
+        ) {
             return Ok(Event::Async);
         }
 
@@ -307,6 +336,23 @@ impl Processor for TransformSerializeBlock {
                             block: input_data,
                             stats_type: serialize_block.stats_type,
                             index: Some(serialize_block.index),
+                            deletion_vector: None,
+                        };
+                        Ok(Event::Sync)
+                    }
+                }
+                SerializeDataMeta::DeletionVector(deletion_vector) => {
+                    if input_data.is_empty() {
+                        // mark the deleted rows of the block
+                        self.state = State::WriteDeletionVector(deletion_vector);
+                        Ok(Event::Async)
+                    } else {
+                        // mark the updated rows of the block as deleted, and append the new rows
+                        self.state = State::NeedSerialize {
+                            block: input_data,
+                            stats_type: ClusterStatsGenType::Generally,
+                            index: None,
+                            deletion_vector: Some(deletion_vector),
                         };
                         Ok(Event::Sync)
                     }
@@ -331,6 +377,7 @@ impl Processor for TransformSerializeBlock {
                 block: input_data,
                 stats_type: ClusterStatsGenType::Generally,
                 index: None,
+                deletion_vector: None,
             };
             Ok(Event::Sync)
         }
@@ -342,6 +389,7 @@ impl Processor for TransformSerializeBlock {
                 block,
                 stats_type,
                 index,
+                deletion_vector,
             } => {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
@@ -357,7 +405,11 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = State::Serialized {
+                    serialized,
+                    index,
+                    deletion_vector,
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -367,7 +419,11 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
+            State::Serialized {
+                serialized,
+                index,
+                deletion_vector,
+            } => {
                 let extended_block_meta = BlockWriter::write_down(&self.dal, serialized).await?;
 
                 let bytes = if let Some(draft_virtual_block_meta) =
@@ -412,6 +468,13 @@ impl Processor for TransformSerializeBlock {
 
                     if matches!(self.kind, MutationKind::Insert) {
                         DataBlock::empty_with_meta(Box::new(extended_block_meta))
+                    } else if let Some(deletion_vector) = deletion_vector {
+                        let entry = self.write_deletion_vector(deletion_vector).await?;
+                        DataBlock::empty_with_meta(Box::new(MutationLogs {
+                            entries: vec![entry, MutationLogEntry::AppendBlock {
+                                block_meta: Arc::new(extended_block_meta),
+                            }],
+                        }))
                     } else {
                         if matches!(self.kind, MutationKind::Recluster) {
                             metrics_inc_recluster_write_block_nums();
@@ -423,6 +486,10 @@ impl Processor for TransformSerializeBlock {
                 };
                 self.output_data = Some(mutation_log_data_block);
             }
+            State::WriteDeletionVector(deletion_vector) => {
+                let entry = self.write_deletion_vector(deletion_vector).await?;
+                self.output_data = Some(Self::mutation_logs(entry));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
@@ -51,6 +51,7 @@ use crate::io::SegmentsIO;
 use crate::io::SnapshotLiteExtended;
 use crate::io::SnapshotsIO;
 use crate::io::TableMetaLocationGenerator;
+use crate::operations::CloneReferencedLocations;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
 
@@ -98,7 +99,7 @@ impl FuseTable {
             }
         }
 
-        let root_snapshot_info = root_snapshot_info_opt.unwrap();
+        let mut root_snapshot_info = root_snapshot_info_opt.unwrap();
 
         if root_snapshot_info.snapshot_lite.timestamp.is_none() {
             return Err(ErrorCode::StorageOther(format!(
@@ -107,6 +108,14 @@ impl FuseTable {
             )));
         }
 
+        // Files used by the clones, branches and tags of this table are kept like the ones used
+        // by the root snapshot.
+        let mut clone_referenced = self.get_clone_referenced_locations(ctx).await?;
+        clone_referenced.extend(self.get_table_ref_referenced_locations(ctx).await?);
+        root_snapshot_info
+            .referenced_locations
+            .extend(&clone_referenced.locations);
+
         let snapshots_io = SnapshotsIO::create(ctx.clone(), self.operator.clone());
         let location_gen = self.meta_location_generator();
         let purged_snapshot_limit = num_snapshot_limit.unwrap_or(snapshot_files.len());
@@ -194,6 +203,12 @@ impl FuseTable {
                 ctx.set_status_info(&status);
             }
 
+            clone_referenced.retain_unreferenced(
+                &mut segments_to_be_purged,
+                &mut ts_to_be_purged,
+                &mut snapshots_to_be_purged,
+            );
+
             if !snapshots_to_be_purged.is_empty() {
                 if dry_run {
                     debug_assert!(num_snapshot_limit.is_some());
@@ -216,6 +231,7 @@ impl FuseTable {
                         ctx,
                         counter,
                         &root_snapshot_info.referenced_locations,
+                        &mut clone_referenced,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -252,6 +268,11 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            clone_referenced.retain_unreferenced(
+                &mut segments_to_be_purged,
+                &mut ts_to_be_purged,
+                &mut snapshots_to_be_purged,
+            );
             if dry_run {
                 self.dry_run_purge(
                     ctx,
@@ -268,6 +289,7 @@ impl FuseTable {
                     ctx,
                     counter,
                     &root_snapshot_info.referenced_locations,
+                    &mut clone_referenced,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -290,6 +312,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &mut clone_referenced,
                 &table_agg_index_ids,
                 inverted_indexes,
             )
@@ -390,10 +413,21 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
         purge_files.extend(snapshots_to_be_purged.iter().map(|loc| loc.to_string()));
+        purge_files.retain(|loc| self.is_own_location(loc));
 
         Ok(())
     }
@@ -404,9 +438,10 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        clone_referenced: &mut CloneReferencedLocations,
         segments_to_be_purged: HashSet<Location>,
-        ts_to_be_purged: HashSet<String>,
-        snapshots_to_be_purged: HashSet<String>,
+        mut ts_to_be_purged: HashSet<String>,
+        mut snapshots_to_be_purged: HashSet<String>,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<()> {
@@ -463,7 +498,18 @@ impl FuseTable {
                 stats_to_be_purged.insert(loc.to_string());
             }
 
-            let segment_locations_to_be_purged = HashSet::from_iter(
+            let mut deletion_vectors_to_be_purged = HashSet::new();
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                deletion_vectors_to_be_purged.insert(loc.to_string());
+            }
+
+            let mut segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
                     .map(|loc| loc.0.clone())
@@ -482,6 +528,20 @@ impl FuseTable {
                 ctx.set_status_info(&status);
             }
 
+            // A clone may have been created from one of the snapshots being purged.
+            if self
+                .refresh_clone_referenced_locations(ctx, clone_referenced)
+                .await?
+            {
+                clone_referenced.retain_unreferenced_files([
+                    &mut blocks_to_be_purged,
+                    &mut blooms_to_be_purged,
+                    &mut stats_to_be_purged,
+                    &mut deletion_vectors_to_be_purged,
+                    &mut segment_locations_to_be_purged,
+                ]);
+            }
+
             self.purge_block_segments(
                 ctx,
                 counter,
@@ -490,11 +550,19 @@ impl FuseTable {
                 inverted_indexes_to_be_purged,
                 blooms_to_be_purged,
                 stats_to_be_purged,
+                deletion_vectors_to_be_purged,
                 segment_locations_to_be_purged,
             )
             .await?;
         }
 
+        if self
+            .refresh_clone_referenced_locations(ctx, clone_referenced)
+            .await?
+        {
+            clone_referenced
+                .retain_unreferenced_files([&mut ts_to_be_purged, &mut snapshots_to_be_purged]);
+        }
         self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
             .await
     }
@@ -504,18 +572,25 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        clone_referenced: &mut CloneReferencedLocations,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<()> {
+        // Files used by the clones created since the purge started are kept as well.
+        self.refresh_clone_referenced_locations(ctx, clone_referenced)
+            .await?;
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
+                .filter(|loc| !clone_referenced.contains(loc))
                 .collect::<Vec<_>>(),
         );
+        // The root location tuple contains the files used by clones, see `execute_purge`.
+        root_location_tuple.retain(|loc| !clone_referenced.contains(loc));
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         let mut inverted_indexes_to_be_purged = HashSet::new();
@@ -551,21 +626,22 @@ impl FuseTable {
             inverted_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             root_location_tuple.hll_location,
+            root_location_tuple.deletion_vector_location,
             segment_locations_to_be_purged,
         )
         .await?;
 
+        self.refresh_clone_referenced_locations(ctx, clone_referenced)
+            .await?;
         let mut ts_to_be_purged = HashSet::new();
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        ts_to_be_purged.retain(|loc| !clone_referenced.contains(loc));
+        let mut snapshots_to_be_purged = HashSet::from([root_snapshot_location]);
+        snapshots_to_be_purged.retain(|loc| !clone_referenced.contains(loc));
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -577,6 +653,7 @@ impl FuseTable {
         inverted_indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         stats_to_be_purged: HashSet<String>,
+        deletion_vectors_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
         // 1. Try to purge block file chunks.
@@ -635,7 +712,15 @@ impl FuseTable {
                 .await?;
         }
 
-        // 4. Try to purge segment file chunks.
+        // 4. Try to purge deletion vector file chunks.
+        let deletion_vectors_count = deletion_vectors_to_be_purged.len();
+        if deletion_vectors_count > 0 {
+            counter.deletion_vectors += deletion_vectors_count;
+            self.try_purge_location_files(ctx.clone(), deletion_vectors_to_be_purged)
+                .await?;
+        }
+
+        // 5. Try to purge segment file chunks.
         let segments_count = segments_to_be_purged.len();
         if segments_count > 0 {
             counter.segments += segments_count;
@@ -701,6 +786,10 @@ impl FuseTable {
         ctx: Arc<dyn TableContext>,
         locations_to_be_purged: HashSet<String>,
     ) -> Result<()> {
+        // A clone references files of other tables, which must be purged by their owners.
+        let locations_to_be_purged = locations_to_be_purged
+            .into_iter()
+            .filter(|loc| self.is_own_location(loc));
         let fuse_file = Files::create(ctx.clone(), self.operator.clone());
         fuse_file.remove_file_in_batch(locations_to_be_purged).await
     }
@@ -735,6 +824,7 @@ impl FuseTable {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
         let mut hlls = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -801,6 +891,7 @@ impl FuseTable {
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
                 hlls.extend(location_tuple.hll_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
@@ -808,6 +899,7 @@ impl FuseTable {
             block_location: blocks,
             bloom_location: blooms,
             hll_location: hlls,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -832,6 +924,26 @@ pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
     pub hll_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
+}
+
+impl LocationTuple {
+    pub fn extend(&mut self, other: &LocationTuple) {
+        self.block_location
+            .extend(other.block_location.iter().cloned());
+        self.bloom_location
+            .extend(other.bloom_location.iter().cloned());
+        self.hll_location.extend(other.hll_location.iter().cloned());
+        self.deletion_vector_location
+            .extend(other.deletion_vector_location.iter().cloned());
+    }
+
+    pub fn retain(&mut self, f: impl Fn(&String) -> bool) {
+        self.block_location.retain(&f);
+        self.bloom_location.retain(&f);
+        self.hll_location.retain(&f);
+        self.deletion_vector_location.retain(&f);
+    }
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -840,12 +952,16 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
         let mut hll_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
         }
         if let Some(loc) = value.as_ref().summary.additional_stats_loc() {
             hll_location.insert(loc.0);
@@ -854,6 +970,7 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
             block_location,
             bloom_location,
             hll_location,
+            deletion_vector_location,
         })
     }
 }
@@ -864,6 +981,8 @@ impl TryFrom<Arc<ColumnOrientedSegment>> for LocationTuple {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
         let mut hll_location = HashSet::new();
+        // Deletion vectors are never enabled for column-oriented segments.
+        let deletion_vector_location = HashSet::new();
 
         let location_path = value.location_path_col();
         for path in location_path.iter() {
@@ -889,6 +1008,7 @@ impl TryFrom<Arc<ColumnOrientedSegment>> for LocationTuple {
             block_location,
             bloom_location,
             hll_location,
+            deletion_vector_location,
         })
     }
 }
@@ -901,6 +1021,7 @@ struct PurgeCounter {
     inverted_indexes: usize,
     blooms: usize,
     hlls: usize,
+    deletion_vectors: usize,
     segments: usize,
     table_statistics: usize,
     snapshots: usize,
@@ -915,6 +1036,7 @@ impl PurgeCounter {
             inverted_indexes: 0,
             blooms: 0,
             hlls: 0,
+            deletion_vectors: 0,
             segments: 0,
             table_statistics: 0,
             snapshots: 0,
@@ -52,6 +52,7 @@ use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::BlockWriter;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::WriteSettings;
 use crate::operations::acquire_task_permit;
@@ -401,10 +402,20 @@ impl AggregationContext {
             origin_data_block.add_entry(row_num);
         }
 
+        // the rows deleted by deletion vector are purged as well.
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(meta) => Some(DeletionVector::read(&self.data_accessor, meta).await?),
+            None => None,
+        };
+
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || deleted_rows
+                    .as_ref()
+                    .is_some_and(|deleted_rows| deleted_rows.contains(row as u32))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
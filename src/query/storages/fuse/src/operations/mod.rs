@@ -16,6 +16,7 @@ mod agg_index_sink;
 mod analyze;
 mod append;
 mod changes;
+mod clone;
 mod commit;
 mod common;
 mod compact;
@@ -33,6 +34,7 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod table_ref;
 mod truncate;
 mod util;
 
@@ -41,8 +43,13 @@ mod snapshot_hint;
 mod vacuum;
 
 pub use agg_index_sink::AggIndexSink;
+pub use analyze::AnalyzeStatsReceivers;
 pub use analyze::HistogramInfoSink;
 pub use changes::ChangesDesc;
+pub use clone::has_clone_references;
+pub use clone::remove_clone_references;
+pub use clone::CloneReference;
+pub use clone::CloneReferencedLocations;
 pub use common::*;
 pub use compact::CompactOptions;
 pub use merge_into::*;
@@ -19,6 +19,7 @@ use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use roaring::RoaringBitmap;
 
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactExtraInfo;
@@ -30,6 +31,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletionVector(SerializeDeletionVector),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -61,9 +63,27 @@ impl SerializeBlock {
     }
 }
 
+/// Marks rows of the block represented by `index` as deleted, instead of rewriting it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SerializeDeletionVector {
+    pub index: BlockMetaIndex,
+    /// All the deleted rows of the block, including the previously deleted ones.
+    pub deleted_rows: RoaringBitmap,
+}
+
+impl SerializeDeletionVector {
+    pub fn create(index: BlockMetaIndex, deleted_rows: RoaringBitmap) -> Self {
+        SerializeDeletionVector {
+            index,
+            deleted_rows,
+        }
+    }
+}
+
 pub enum CompactSourceMeta {
     Concat {
         read_res: Vec<BlockReadResult>,
+        deleted_rows: Vec<Option<RoaringBitmap>>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -362,6 +362,11 @@ impl SegmentCompactChecker {
             return false;
         }
 
+        // The deleted rows need to be purged.
+        if summary.deleted_row_count.is_some() {
+            return false;
+        }
+
         if summary.block_count == 1 {
             return true;
         }
@@ -512,7 +517,7 @@ impl CompactTaskBuilder {
     }
 
     fn add(&mut self, block_meta: &Arc<BlockMeta>, hlls: &Option<RawBlockHLL>) -> (bool, bool) {
-        let total_rows = self.total_rows + block_meta.row_count as usize;
+        let total_rows = self.total_rows + block_meta.live_row_count() as usize;
         let total_size = self.total_size + block_meta.block_size as usize;
         let total_compressed = self.total_compressed + block_meta.file_size as usize;
         if !self.check_large_enough(total_rows, total_size, total_compressed) {
@@ -570,6 +575,10 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &BlockMeta) -> bool {
+        if block.deletion_vector.is_some() {
+            return true;
+        }
+
         // The snapshot schema does not contain stream columns,
         // so the stream columns need to be filtered out.
         let column_ids = block
@@ -684,7 +693,7 @@ impl CompactTaskBuilder {
                     .iter()
                     .chain(tail.iter())
                     .fold((0, 0, 0), |mut acc, x| {
-                        acc.0 += x.0.row_count as usize;
+                        acc.0 += x.0.live_row_count() as usize;
                         acc.1 += x.0.block_size as usize;
                         acc.2 += x.0.file_size as usize;
                         acc
@@ -19,6 +19,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_metrics::storage::*;
@@ -29,6 +30,7 @@ use databend_common_sql::StreamContext;
 use databend_storages_common_io::ReadSettings;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::operations::ClusterStatsGenType;
 use crate::operations::CompactBlockPartInfo;
 use crate::operations::CompactSourceMeta;
@@ -89,14 +91,21 @@ impl PrefetchAsyncSource for CompactSource {
                                 metrics_inc_compact_block_read_bytes(block.block_size);
                             }
 
-                            block_reader
+                            let read_res = block_reader
                                 .read_columns_data_by_merge_io(
                                     &settings,
                                     &block.location.0,
                                     &block.col_metas,
                                     &None,
                                 )
-                                .await
+                                .await?;
+                            let deleted_rows = match &block.deletion_vector {
+                                Some(meta) => {
+                                    Some(DeletionVector::read(&block_reader.operator, meta).await?)
+                                }
+                                None => None,
+                            };
+                            Ok::<_, ErrorCode>((read_res, deleted_rows))
                         })
                         .await
                         .unwrap()
@@ -105,13 +114,17 @@ impl PrefetchAsyncSource for CompactSource {
 
                 let start = Instant::now();
 
-                let read_res = futures::future::try_join_all(task_futures).await?;
+                let (read_res, deleted_rows) = futures::future::try_join_all(task_futures)
+                    .await?
+                    .into_iter()
+                    .unzip();
                 // Perf.
                 {
                     metrics_inc_compact_block_read_milliseconds(start.elapsed().as_millis() as u64);
                 }
                 Box::new(CompactSourceMeta::Concat {
                     read_res,
+                    deleted_rows,
                     metas: task.blocks.clone(),
                     index: task.index.clone(),
                 })
@@ -156,18 +169,26 @@ impl BlockMetaTransform<CompactSourceMeta> for CompactTransform {
         match meta {
             CompactSourceMeta::Concat {
                 read_res,
+                deleted_rows,
                 metas,
                 index,
             } => {
                 let blocks = read_res
                     .into_iter()
+                    .zip(deleted_rows.into_iter())
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, deleted_rows), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta.as_ref().into(),
                             &self.storage_format,
                             data,
                         )?;
+                        // purge the rows deleted by deletion vector.
+                        if let Some(deleted_rows) = deleted_rows {
+                            let live_rows =
+                                DeletionVector::live_rows_filter(block.num_rows(), &deleted_rows);
+                            block = block.filter_with_bitmap(&live_rows)?;
+                        }
 
                         self.scan_progress.incr(&ProgressValues {
                             rows: block.num_rows(),
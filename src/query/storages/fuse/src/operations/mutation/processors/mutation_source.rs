@@ -23,6 +23,8 @@ use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::boolean::TrueIdxIter;
+use databend_common_expression::types::Bitmap;
 use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::BlockMetaInfoPtr;
@@ -38,14 +40,17 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_storage::MutationStatus;
 use databend_storages_common_io::ReadSettings;
+use roaring::RoaringBitmap;
 
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::BlockReadResult;
 use crate::FuseStorageFormat;
 
@@ -57,7 +62,7 @@ pub enum MutationAction {
 
 enum State {
     ReadData(Option<PartInfoPtr>),
-    FilterData(PartInfoPtr, BlockReadResult),
+    FilterData(PartInfoPtr, BlockReadResult, Option<RoaringBitmap>),
     ReadRemain {
         part: PartInfoPtr,
         data_block: DataBlock,
@@ -85,9 +90,12 @@ pub struct MutationSource {
     operators: Vec<BlockOperator>,
     storage_format: FuseStorageFormat,
     action: MutationAction,
+    enable_deletion_vector: bool,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    // The deleted rows of the block being updated, if the updated rows are appended as new block.
+    deleted_rows: Option<RoaringBitmap>,
 }
 
 impl MutationSource {
@@ -101,6 +109,7 @@ impl MutationSource {
         remain_reader: Arc<Option<BlockReader>>,
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
+        enable_deletion_vector: bool,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -112,8 +121,10 @@ impl MutationSource {
             operators,
             storage_format,
             action,
+            enable_deletion_vector,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deleted_rows: None,
         })))
     }
 }
@@ -169,7 +180,7 @@ impl Processor for MutationSource {
 
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Finish) {
-            State::FilterData(part, read_res) => {
+            State::FilterData(part, read_res, deleted_rows) => {
                 let chunks = read_res.columns_chunks()?;
                 let mut data_block = self.block_reader.deserialize_chunks_with_part_info(
                     part.clone(),
@@ -183,17 +194,30 @@ impl Processor for MutationSource {
                 });
 
                 let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // The rows already deleted by deletion vector are invisible to the mutation.
+                let live_rows = deleted_rows
+                    .as_ref()
+                    .map(|deleted_rows| DeletionVector::live_rows_filter(rows, deleted_rows));
+                let num_live_rows = live_rows.as_ref().map_or(rows, |v| v.true_count());
                 if let Some(filter) = self.filter.as_ref() {
                     assert_eq!(filter.data_type(), &DataType::Boolean);
 
                     let func_ctx = self.ctx.get_function_context()?;
                     let evaluator = Evaluator::new(&data_block, &func_ctx, &BUILTIN_FUNCTIONS);
 
-                    let predicates = evaluator
+                    let mut predicates = evaluator
                         .run(filter)
                         .map_err(|e| e.add_message("eval filter failed:"))?
                         .try_downcast::<BooleanType>()
                         .unwrap();
+                    if let Some(live_rows) = &live_rows {
+                        let bitmap = match &predicates {
+                            Value::Scalar(true) => live_rows.clone(),
+                            Value::Scalar(false) => Bitmap::new_zeroed(rows),
+                            Value::Column(bitmap) => bitmap & live_rows,
+                        };
+                        predicates = Value::Column(bitmap);
+                    }
 
                     let affect_rows = match &predicates {
                         Value::Scalar(v) => {
@@ -211,7 +235,7 @@ impl Processor for MutationSource {
 
                         match self.action {
                             MutationAction::Deletion => {
-                                if affect_rows == rows {
+                                if affect_rows == num_live_rows {
                                     // all the rows should be removed.
                                     let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                         SerializeBlock::create(
@@ -223,6 +247,24 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.enable_deletion_vector {
+                                    // mark the rows as deleted, the block is kept as it is.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    let mut deleted_rows = deleted_rows.unwrap_or_default();
+                                    deleted_rows.extend(
+                                        TrueIdxIter::new(predicate_col.len(), Some(&predicate_col))
+                                            .map(|idx| idx as u32),
+                                    );
+                                    let meta = Box::new(SerializeDataMeta::DeletionVector(
+                                        SerializeDeletionVector::create(
+                                            self.index.clone(),
+                                            deleted_rows,
+                                        ),
+                                    ));
+                                    self.state = State::Output(
+                                        self.ctx.get_partition(),
+                                        DataBlock::empty_with_meta(meta),
+                                    );
                                 } else {
                                     if self.block_reader.update_stream_columns {
                                         let row_num = build_origin_block_row_num(rows);
@@ -230,7 +272,10 @@ impl Processor for MutationSource {
                                     }
 
                                     let predicate_col = predicates.into_column().unwrap();
-                                    let filter = predicate_col.not();
+                                    let filter = match &live_rows {
+                                        Some(live_rows) => &predicate_col.not() & live_rows,
+                                        None => predicate_col.not(),
+                                    };
                                     data_block = data_block.filter_with_bitmap(&filter)?;
                                     if self.remain_reader.is_none() {
                                         self.state = State::PerformOperator(
@@ -248,7 +293,26 @@ impl Processor for MutationSource {
                             }
 
                             MutationAction::Update => {
+                                let filter = if self.enable_deletion_vector
+                                    && affect_rows < num_live_rows
+                                {
+                                    // mark the updated rows as deleted, and only the updated rows
+                                    // are appended as new block.
+                                    let predicate_col = predicates.clone().into_column().unwrap();
+                                    let mut deleted_rows = deleted_rows.unwrap_or_default();
+                                    deleted_rows.extend(
+                                        TrueIdxIter::new(predicate_col.len(), Some(&predicate_col))
+                                            .map(|idx| idx as u32),
+                                    );
+                                    self.deleted_rows = Some(deleted_rows);
+                                    Some(predicate_col)
+                                } else {
+                                    live_rows
+                                };
                                 data_block.add_value(predicates.upcast(), DataType::Boolean);
+                                if let Some(filter) = &filter {
+                                    data_block = data_block.filter_with_bitmap(filter)?;
+                                }
                                 if self.remain_reader.is_none() {
                                     self.state = State::PerformOperator(
                                         data_block,
@@ -258,7 +322,7 @@ impl Processor for MutationSource {
                                     self.state = State::ReadRemain {
                                         part,
                                         data_block,
-                                        filter: None,
+                                        filter: filter.map(Value::Column),
                                     };
                                 }
                             }
@@ -268,7 +332,10 @@ impl Processor for MutationSource {
                         self.state = State::Output(self.ctx.get_partition(), DataBlock::empty());
                     }
                 } else {
-                    self.update_mutation_status(rows);
+                    self.update_mutation_status(num_live_rows);
+                    if let Some(live_rows) = &live_rows {
+                        data_block = data_block.filter_with_bitmap(live_rows)?;
+                    }
                     self.state = State::PerformOperator(data_block, fuse_part.location.clone());
                 }
             }
@@ -294,7 +361,7 @@ impl Processor for MutationSource {
                     });
 
                     let remain_block = if let Some(filter) = filter {
-                        // for deletion.
+                        // for deletion, or the rows to be updated.
                         remain_block.filter_boolean_value(&filter)?
                     } else {
                         remain_block
@@ -313,9 +380,16 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
-                let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
-                    SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
-                ));
+                let inner_meta = if let Some(deleted_rows) = self.deleted_rows.take() {
+                    Box::new(SerializeDataMeta::DeletionVector(
+                        SerializeDeletionVector::create(self.index.clone(), deleted_rows),
+                    ))
+                } else {
+                    Box::new(SerializeDataMeta::SerializeBlock(SerializeBlock::create(
+                        self.index.clone(),
+                        self.stats_type.clone(),
+                    )))
+                };
                 let meta: BlockMetaInfoPtr = if self.block_reader.update_stream_columns() {
                     Box::new(gen_mutation_stream_meta(Some(inner_meta), &path)?)
                 } else {
@@ -356,11 +430,19 @@ impl Processor for MutationSource {
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FuseBlockPartInfo::from_part(&inner_part)?;
 
+                        let deleted_rows = match &fuse_part.deletion_vector {
+                            Some(meta) => {
+                                Some(DeletionVector::read(&self.block_reader.operator, meta).await?)
+                            }
+                            None => None,
+                        };
                         if part.whole_block_mutation
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
-                            self.update_mutation_status(fuse_part.nums_rows);
+                            let deleted_row_count =
+                                deleted_rows.as_ref().map_or(0, |v| v.len() as usize);
+                            self.update_mutation_status(fuse_part.nums_rows - deleted_row_count);
                             let meta = Box::new(SerializeDataMeta::SerializeBlock(
                                 SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                             ));
@@ -378,7 +460,7 @@ impl Processor for MutationSource {
                                     &None,
                                 )
                                 .await?;
-                            self.state = State::FilterData(inner_part, read_res);
+                            self.state = State::FilterData(inner_part, read_res, deleted_rows);
                         }
                     }
                 }
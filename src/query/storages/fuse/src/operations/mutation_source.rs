@@ -107,6 +107,7 @@ impl FuseTable {
         projection.sort_by_key(|&i| source_col_indices[i]);
         let ops = vec![BlockOperator::Project { projection }];
 
+        let enable_deletion_vector = self.enable_deletion_vector();
         let max_threads = (ctx.get_settings().get_max_threads()? as usize)
             .min(ctx.partition_num())
             .max(1);
@@ -122,6 +123,7 @@ impl FuseTable {
                     remain_reader.clone(),
                     ops.clone(),
                     self.storage_format,
+                    enable_deletion_vector,
                 )
             },
             max_threads,
@@ -62,6 +62,8 @@ impl FuseTable {
                     .await
             }
             NavigationPoint::StreamInfo(info) => self.navigate_to_stream(ctx, info).await,
+            NavigationPoint::Branch(name) => self.checkout_branch(ctx.as_ref(), name).await,
+            NavigationPoint::Tag(name) => self.navigate_to_tag(ctx.as_ref(), name).await,
         }
     }
 
@@ -209,6 +211,17 @@ impl FuseTable {
         format_version: u64,
         s3_storage_class: S3StorageClass,
     ) -> Result<Arc<FuseTable>> {
+        let table_info = self.table_info_at_snapshot(snapshot, format_version)?;
+        // let's instantiate it
+        let table = FuseTable::create_without_refresh_table_info(table_info, s3_storage_class)?;
+        Ok(table.into())
+    }
+
+    pub(crate) fn table_info_at_snapshot(
+        &self,
+        snapshot: &TableSnapshot,
+        format_version: u64,
+    ) -> Result<TableInfo> {
         // The `seq` of ident that we cloned here is JUST a place holder
         // we should NOT use it other than a pure place holder.
         let mut table_info = self.table_info.clone();
@@ -245,10 +258,7 @@ impl FuseTable {
             number_of_segments: Some(snapshot.segments.len() as u64),
             number_of_blocks: Some(summary.block_count),
         };
-
-        // let's instantiate it
-        let table = FuseTable::create_without_refresh_table_info(table_info, s3_storage_class)?;
-        Ok(table.into())
+        Ok(table_info)
     }
 
     #[async_backtrace::framed]
@@ -284,6 +294,11 @@ impl FuseTable {
                     Some(NavigationPoint::StreamInfo(info)) => {
                         self.list_by_stream(info, time_point).await
                     }
+                    Some(NavigationPoint::Branch(_)) | Some(NavigationPoint::Tag(_)) => {
+                        return Err(ErrorCode::Unimplemented(
+                            "Purging before a branch or tag is not supported, the snapshots referenced by branches and tags are always kept",
+                        ));
+                    }
                     None => self.list_by_time_point(time_point).await,
                 }?;
 
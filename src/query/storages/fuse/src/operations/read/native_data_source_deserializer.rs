@@ -57,6 +57,7 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::IndexType;
+use databend_storages_common_index::ExprBloomFilter;
 use xorf::BinaryFuse16;
 
 use super::native_data_source::NativeDataSource;
@@ -66,7 +67,6 @@ use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
-use crate::pruning::ExprBloomFilter;
 use crate::DEFAULT_ROW_PER_PAGE;
 
 /// A helper struct to store the intermediate state while reading a native partition.
@@ -14,6 +14,7 @@
 
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_expression::BlockMetaInfo;
+use roaring::RoaringBitmap;
 
 use crate::io::BlockReadResult;
 use crate::io::VirtualBlockReadResult;
@@ -21,7 +22,13 @@ use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 
 pub enum ParquetDataSource {
     AggIndex((PartInfoPtr, BlockReadResult)),
-    Normal((BlockReadResult, Option<VirtualBlockReadResult>)),
+    Normal(
+        (
+            BlockReadResult,
+            Option<VirtualBlockReadResult>,
+            Option<RoaringBitmap>,
+        ),
+    ),
 }
 
 #[typetag::serde(name = "fuse_data_source")]
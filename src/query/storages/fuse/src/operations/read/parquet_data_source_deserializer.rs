@@ -43,6 +43,7 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::IndexType;
+use databend_storages_common_index::ExprBloomFilter;
 use xorf::BinaryFuse16;
 
 use super::parquet_data_source::ParquetDataSource;
@@ -51,9 +52,9 @@ use super::util::need_reserve_block_info;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
-use crate::pruning::ExprBloomFilter;
 
 pub struct DeserializeDataTransform {
     ctx: Arc<dyn TableContext>,
@@ -276,7 +277,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                ParquetDataSource::Normal((data, virtual_data)) => {
+                ParquetDataSource::Normal((data, virtual_data, deleted_rows)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FuseBlockPartInfo::from_part(&part)?;
@@ -291,27 +292,35 @@ impl Processor for DeserializeDataTransform {
 
                     let origin_num_rows = data_block.num_rows();
 
-                    let mut filter = None;
+                    // Rows deleted by merge-on-read mutations are filtered out together
+                    // with the bloom runtime filter, so the offsets stay physical.
+                    let mut filter = deleted_rows.as_ref().map(|deleted_rows| {
+                        DeletionVector::live_rows_filter(origin_num_rows, deleted_rows)
+                    });
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
                         let start = Instant::now();
-                        let rows_before = data_block.num_rows();
                         if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
-                            let rows_after = data_block.num_rows();
+                            let rows_filtered = bitmap.null_count();
+                            filter = Some(match filter {
+                                Some(live_rows) => (&live_rows).bitand(&bitmap),
+                                None => bitmap,
+                            });
                             let bloom_duration = start.elapsed();
                             Profile::record_usize_profile(
                                 ProfileStatisticsName::RuntimeFilterBloomTime,
                                 bloom_duration.as_nanos() as usize,
                             );
-                            if rows_before > rows_after {
+                            if rows_filtered > 0 {
                                 Profile::record_usize_profile(
                                     ProfileStatisticsName::RuntimeFilterBloomRowsFiltered,
-                                    rows_before - rows_after,
+                                    rows_filtered,
                                 );
                             }
                         }
                     }
+                    if let Some(bitmap) = &filter {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
+                    }
 
                     // Add optional virtual columns
                     if let Some(virtual_reader) = self.virtual_reader.as_ref() {
@@ -42,6 +42,7 @@ use super::parquet_data_source::ParquetDataSource;
 use crate::fuse_part::FuseBlockPartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::block_partition_meta::BlockPartitionMeta;
@@ -166,8 +167,14 @@ impl Transform for ReadParquetDataTransform<true> {
                     return Ok(DataBlock::empty());
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // The aggregating index knows nothing about the deleted rows.
+                let index_reader = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none());
+                if let Some(index_reader) = index_reader {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -187,7 +194,6 @@ impl Transform for ReadParquetDataTransform<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
                     let virtual_block_meta = fuse_part
                         .block_meta_index
                         .as_ref()
@@ -211,10 +217,19 @@ impl Transform for ReadParquetDataTransform<true> {
                     &part,
                     ignore_column_ids,
                 )?;
+                let deleted_rows = fuse_part
+                    .deletion_vector
+                    .as_ref()
+                    .map(|meta| DeletionVector::sync_read(&self.block_reader.operator, meta))
+                    .transpose()?;
 
                 return Ok(DataBlock::empty_with_meta(DataSourceWithMeta::create(
                     vec![part],
-                    vec![ParquetDataSource::Normal((source, virtual_source))],
+                    vec![ParquetDataSource::Normal((
+                        source,
+                        virtual_source,
+                        deleted_rows,
+                    ))],
                 )));
             }
         }
@@ -282,7 +297,12 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                             databend_common_base::runtime::spawn(async move {
                                 let part = FuseBlockPartInfo::from_part(&part)?;
 
-                                if let Some(index_reader) = index_reader.as_ref() {
+                                // The aggregating index knows nothing about the deleted rows.
+                                let index_reader = index_reader
+                                    .as_ref()
+                                    .as_ref()
+                                    .filter(|_| part.deletion_vector.is_none());
+                                if let Some(index_reader) = index_reader {
                                     let loc =
                                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                                             &part.location,
@@ -321,8 +341,12 @@ impl AsyncTransform for ReadParquetDataTransform<false> {
                                         ignore_column_ids,
                                     )
                                     .await?;
+                                let deleted_rows = match &part.deletion_vector {
+                                    Some(meta) => Some(DeletionVector::read(&block_reader.operator, meta).await?),
+                                    None => None,
+                                };
 
-                                Ok(ParquetDataSource::Normal((source, virtual_source)))
+                                Ok(ParquetDataSource::Normal((source, virtual_source, deleted_rows)))
                             })
                                 .await
                                 .unwrap()
@@ -61,6 +61,7 @@ use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::BlockWriter;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::WriteSettings;
 use crate::operations::acquire_task_permit;
@@ -466,9 +467,20 @@ impl AggregationContext {
         }
         let columns: Vec<_> = columns.iter().collect();
 
+        // the rows deleted by deletion vector are purged as well.
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(meta) => Some(DeletionVector::read(&self.data_accessor, meta).await?),
+            None => None,
+        };
+
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deleted_rows
+                .as_ref()
+                .is_some_and(|deleted_rows| deleted_rows.contains(row as u32))
+            {
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -478,7 +490,7 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.null_count();
+        let delete_nums = bitmap.null_count() - block_meta.deleted_row_count() as usize;
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -490,12 +502,11 @@ impl AggregationContext {
         }
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
-            metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
+            metrics_inc_replace_deleted_blocks_rows(delete_nums as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
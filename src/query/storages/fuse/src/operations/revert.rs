@@ -21,6 +21,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
 
 use crate::operations::SnapshotHintWriter;
 use crate::FuseTable;
@@ -45,7 +46,9 @@ impl FuseTable {
         }
 
         // 2. prepare table meta which being reverted to
-        let table_meta_to_be_committed = table_reverting_to.table_info.meta.clone();
+        let mut table_meta_to_be_committed = table_reverting_to.table_info.meta.clone();
+        // reverting to the head of a branch, which is only a marker of the in-memory table
+        table_meta_to_be_committed.options.remove(OPT_KEY_BRANCH);
 
         // 3. prepare the request
         //  using the CURRENT version as the base table version
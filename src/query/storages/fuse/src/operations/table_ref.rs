@@ -0,0 +1,251 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Branches and tags of fuse tables.
+//!
+//! They are kept in the table option `table_refs`, as the locations of their head snapshots.
+//! A table checked out at a branch carries the in-memory option `branch`, and committing to
+//! it moves the head of the branch instead of the snapshot location of the table.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::table::NavigationPoint;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableStatistics;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_storages_common_table_meta::table::TableRef;
+use databend_storages_common_table_meta::table::TableRefKind;
+use databend_storages_common_table_meta::table::TableRefs;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use log::info;
+
+use crate::io::SnapshotsIO;
+use crate::operations::CloneReferencedLocations;
+use crate::FuseTable;
+
+impl FuseTable {
+    pub fn table_refs(&self) -> Result<TableRefs> {
+        TableRefs::from_options(self.table_info.options())
+    }
+
+    /// The branch this table is checked out at, `None` for the main line of the table.
+    pub fn current_branch(&self) -> Option<&String> {
+        self.table_info.options().get(OPT_KEY_BRANCH)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn checkout_branch(
+        &self,
+        ctx: &dyn TableContext,
+        name: &str,
+    ) -> Result<Arc<FuseTable>> {
+        let refs = self.table_refs()?;
+        let branch = refs.get(TableRefKind::Branch, name)?;
+        // Unlike time travel, the branch keeps following the schema of the table.
+        let schema = self.table_info.meta.schema.clone();
+        let mut table_info = self.table_info_at_ref(branch).await?;
+        table_info.meta.schema = schema;
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_BRANCH.to_string(), name.to_string());
+        let table = FuseTable::create_without_refresh_table_info(
+            table_info,
+            ctx.get_settings().get_s3_storage_class()?,
+        )?;
+        Ok(table.into())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_tag(
+        &self,
+        ctx: &dyn TableContext,
+        name: &str,
+    ) -> Result<Arc<FuseTable>> {
+        let refs = self.table_refs()?;
+        let tag = refs.get(TableRefKind::Tag, name)?;
+        let table_info = self.table_info_at_ref(tag).await?;
+        let table = FuseTable::create_without_refresh_table_info(
+            table_info,
+            ctx.get_settings().get_s3_storage_class()?,
+        )?;
+        Ok(table.into())
+    }
+
+    async fn table_info_at_ref(&self, table_ref: &TableRef) -> Result<TableInfo> {
+        match &table_ref.snapshot_location {
+            Some(location) => {
+                let (snapshot, format_version) =
+                    SnapshotsIO::read_snapshot(location.clone(), self.get_operator()).await?;
+                self.table_info_at_snapshot(snapshot.as_ref(), format_version)
+            }
+            None => {
+                let mut table_info = self.table_info.clone();
+                table_info.meta.options.remove(OPT_KEY_SNAPSHOT_LOCATION);
+                table_info.meta.statistics = TableStatistics::default();
+                Ok(table_info)
+            }
+        }
+    }
+
+    /// Creates a branch or tag at the given point, or at the current snapshot of the table.
+    #[async_backtrace::framed]
+    pub async fn create_table_ref(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        kind: TableRefKind,
+        name: &str,
+        point: Option<&NavigationPoint>,
+    ) -> Result<()> {
+        if self.is_transient() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Can not create {kind} on transient table '{}'",
+                self.table_info.desc
+            )));
+        }
+
+        let mut refs = self.table_refs()?;
+        if refs.refs(kind).contains_key(name) {
+            return Err(ErrorCode::TableRefAlreadyExists(format!(
+                "{kind} '{name}' already exists in table '{}'",
+                self.table_info.desc
+            )));
+        }
+
+        let snapshot_location = match point {
+            Some(point) => self.navigate_to_point(ctx, point).await?.snapshot_loc(),
+            None => self.snapshot_loc(),
+        };
+        info!(
+            "create {} {} of table {} at {:?}",
+            kind, name, self.table_info.desc, snapshot_location
+        );
+        refs.refs_mut(kind).insert(name.to_string(), TableRef {
+            snapshot_location,
+            created_on: Utc::now(),
+        });
+        self.commit_table_refs(ctx, &refs).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn drop_table_ref(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        kind: TableRefKind,
+        name: &str,
+    ) -> Result<()> {
+        let mut refs = self.table_refs()?;
+        if refs.refs_mut(kind).remove(name).is_none() {
+            return Err(ErrorCode::UnknownTableRef(format!(
+                "Unknown {kind} '{name}' of table '{}'",
+                self.table_info.desc
+            )));
+        }
+        info!("drop {} {} of table {}", kind, name, self.table_info.desc);
+        self.commit_table_refs(ctx, &refs).await
+    }
+
+    async fn commit_table_refs(&self, ctx: &Arc<dyn TableContext>, refs: &TableRefs) -> Result<()> {
+        let mut new_table_meta = self.table_info.meta.clone();
+        refs.apply_to_options(&mut new_table_meta.options)?;
+        new_table_meta.updated_on = Utc::now();
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let req = UpdateTableMetaReq {
+            table_id: self.table_info.ident.table_id,
+            seq: MatchSeq::Exact(self.table_info.ident.seq),
+            new_table_meta,
+            base_snapshot_location: self.snapshot_loc(),
+        };
+        catalog
+            .update_single_table_meta(req, &self.table_info)
+            .await?;
+        Ok(())
+    }
+
+    /// Builds the table meta which moves the head of `branch` to `snapshot_location`.
+    ///
+    /// The meta of the table is read again since the in-memory one of a checked out table
+    /// is tweaked, the commit fails with a version mismatch if the table has been changed.
+    #[async_backtrace::framed]
+    pub(crate) async fn build_branch_table_meta(
+        ctx: &dyn TableContext,
+        catalog: &dyn Catalog,
+        table_info: &TableInfo,
+        branch: &str,
+        snapshot_location: &str,
+    ) -> Result<TableMeta> {
+        if ctx.txn_mgr().lock().is_active() {
+            return Err(ErrorCode::Unimplemented(
+                "Writing to a branch in an explicit transaction is not supported",
+            ));
+        }
+
+        let table_id = table_info.ident.table_id;
+        let Some(seq_meta) = catalog.get_table_meta_by_id(table_id).await? else {
+            return Err(ErrorCode::UnknownTable(format!(
+                "Unknown table '{}'",
+                table_info.desc
+            )));
+        };
+        if seq_meta.seq != table_info.ident.seq {
+            return Err(ErrorCode::TableVersionMismatched(format!(
+                "Table '{}' has been changed while committing to branch '{}', expected version {}, got {}",
+                table_info.desc, branch, table_info.ident.seq, seq_meta.seq
+            )));
+        }
+
+        let mut new_table_meta = seq_meta.data;
+        let mut refs = TableRefs::from_options(&new_table_meta.options)?;
+        let Some(head) = refs.branches.get_mut(branch) else {
+            return Err(ErrorCode::UnknownTableRef(format!(
+                "Unknown BRANCH '{branch}' of table '{}'",
+                table_info.desc
+            )));
+        };
+        head.snapshot_location = Some(snapshot_location.to_string());
+        refs.apply_to_options(&mut new_table_meta.options)?;
+        new_table_meta.updated_on = Utc::now();
+        Ok(new_table_meta)
+    }
+
+    /// Returns the files of this table that are reachable from its branches and tags.
+    #[async_backtrace::framed]
+    pub async fn get_table_ref_referenced_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+    ) -> Result<CloneReferencedLocations> {
+        let mut referenced = CloneReferencedLocations::default();
+        let snapshot_locations = self
+            .table_refs()?
+            .snapshot_locations()
+            .cloned()
+            .collect::<Vec<_>>();
+        if snapshot_locations.is_empty() {
+            return Ok(referenced);
+        }
+        self.collect_referenced_locations(ctx, snapshot_locations, &mut referenced)
+            .await?;
+        referenced.retain(|loc| self.is_own_location(loc));
+        Ok(referenced)
+    }
+}
@@ -100,6 +100,7 @@ impl BlockPruner {
         let pruning_semaphore = &self.pruning_ctx.pruning_semaphore;
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let spatial_pruner = self.pruning_ctx.spatial_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
         let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
         let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
@@ -131,8 +132,12 @@ impl BlockPruner {
                     BlockPruneResult::new(block_idx, block_meta.location.0.clone());
                 let block_meta = block_meta.clone();
                 let row_count = block_meta.row_count;
-                prune_result.keep =
-                    range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas));
+                let live_row_count = block_meta.live_row_count();
+                prune_result.keep = range_pruner
+                    .should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
+                    && spatial_pruner
+                        .as_ref()
+                        .is_none_or(|pruner| pruner.should_keep(&block_meta));
                 if prune_result.keep {
                     // Perf.
                     {
@@ -178,7 +183,7 @@ impl BlockPruner {
                                     .await;
 
                                 prune_result.keep =
-                                    keep_by_bloom && limit_pruner.within_limit(row_count);
+                                    keep_by_bloom && limit_pruner.within_limit(live_row_count);
                                 if prune_result.keep {
                                     // Perf.
                                     {
@@ -191,7 +196,7 @@ impl BlockPruner {
                                     }
                                 }
                             } else {
-                                prune_result.keep = limit_pruner.within_limit(row_count);
+                                prune_result.keep = limit_pruner.within_limit(live_row_count);
                             }
                             if prune_result.keep {
                                 let (keep, range) =
@@ -314,6 +319,7 @@ impl BlockPruner {
         let pruning_stats = self.pruning_ctx.pruning_stats.clone();
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
+        let spatial_pruner = self.pruning_ctx.spatial_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
 
         let start = Instant::now();
@@ -333,8 +339,11 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
+                && spatial_pruner
+                    .as_ref()
+                    .is_none_or(|pruner| pruner.should_keep(&block_meta))
                 && limit_pruner.within_limit(row_count)
             {
                 // Perf.
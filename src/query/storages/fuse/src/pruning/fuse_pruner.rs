@@ -64,6 +64,7 @@ use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
 use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::SpatialPruner;
 use crate::pruning::VectorIndexPruner;
 use crate::pruning::VirtualColumnPruner;
 
@@ -77,6 +78,7 @@ pub struct PruningContext {
 
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    pub spatial_pruner: Option<Arc<SpatialPruner>>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
@@ -144,6 +146,11 @@ impl PruningContext {
             default_stats,
         )?;
 
+        // Spatial pruner.
+        // None will be returned, if there are no spatial predicates on geometry columns.
+        let spatial_pruner =
+            SpatialPruner::try_create(&func_ctx, &table_schema, filter_expr.as_ref());
+
         // Bloom pruner.
         // None will be returned, if filter is not applicable (e.g. unsuitable filter expression, index not available, etc.)
         let bloom_pruner = BloomPrunerCreator::create(
@@ -194,6 +201,7 @@ impl PruningContext {
             pruning_semaphore,
             limit_pruner,
             range_pruner,
+            spatial_pruner,
             bloom_pruner,
             page_pruner,
             internal_column_pruner,
@@ -14,20 +14,19 @@
 
 mod block_pruner;
 mod bloom_pruner;
-mod expr_bloom_filter;
 mod expr_runtime_pruner;
 mod fuse_pruner;
 mod inverted_index_pruner;
 mod pruner_location;
 mod pruning_statistics;
 mod segment_pruner;
+mod spatial_pruner;
 mod vector_index_pruner;
 mod virtual_column_pruner;
 
 pub use block_pruner::BlockPruner;
 pub use bloom_pruner::BloomPruner;
 pub use bloom_pruner::BloomPrunerCreator;
-pub use expr_bloom_filter::ExprBloomFilter;
 pub use expr_runtime_pruner::ExprRuntimePruner;
 pub use fuse_pruner::table_sample;
 pub use fuse_pruner::FusePruner;
@@ -38,5 +37,6 @@ pub use pruner_location::create_segment_location_vector;
 pub use pruner_location::SegmentLocation;
 pub use pruning_statistics::FusePruningStatistics;
 pub use segment_pruner::SegmentPruner;
+pub use spatial_pruner::SpatialPruner;
 pub use vector_index_pruner::VectorIndexPruner;
 pub use virtual_column_pruner::VirtualColumnPruner;
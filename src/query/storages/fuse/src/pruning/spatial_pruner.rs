@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnId;
+use databend_common_expression::ColumnRef;
+use databend_common_expression::Constant;
+use databend_common_expression::ConstantFolder;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionCall;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::read_bounding_box;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SpatialStatistics;
+use geozero::wkb::Ewkb;
+
+/// Prunes blocks by comparing the bounding boxes of geometry columns with
+/// spatial predicates like `st_intersects(geom, <const>)`.
+///
+/// All supported predicates can only be true if the bounding box of the column
+/// value intersects the (expanded) bounding box of the constant geometry,
+/// so a block whose bounding box is disjoint from it can be skipped.
+pub struct SpatialPruner {
+    predicates: Vec<(ColumnId, SpatialStatistics)>,
+}
+
+impl SpatialPruner {
+    pub fn try_create(
+        func_ctx: &FunctionContext,
+        schema: &TableSchemaRef,
+        expr: Option<&Expr<String>>,
+    ) -> Option<Arc<Self>> {
+        let expr = expr?;
+        let (expr, _) = ConstantFolder::fold(expr, func_ctx, &BUILTIN_FUNCTIONS);
+
+        let mut conjunctions = vec![];
+        collect_conjunctions(&expr, &mut conjunctions);
+
+        let predicates = conjunctions
+            .into_iter()
+            .filter_map(|expr| Self::spatial_predicate(schema, expr))
+            .collect::<Vec<_>>();
+        if predicates.is_empty() {
+            None
+        } else {
+            Some(Arc::new(SpatialPruner { predicates }))
+        }
+    }
+
+    pub fn should_keep(&self, block_meta: &BlockMeta) -> bool {
+        let Some(spatial_stats) = &block_meta.spatial_stats else {
+            return true;
+        };
+        self.predicates.iter().all(|(column_id, bbox)| {
+            spatial_stats
+                .get(column_id)
+                .is_none_or(|stats| stats.intersects(bbox))
+        })
+    }
+
+    fn spatial_predicate(
+        schema: &TableSchemaRef,
+        expr: &Expr<String>,
+    ) -> Option<(ColumnId, SpatialStatistics)> {
+        let Expr::FunctionCall(FunctionCall { function, args, .. }) = expr else {
+            return None;
+        };
+        let distance = match (function.signature.name.as_str(), args.len()) {
+            ("st_intersects" | "st_contains" | "st_within" | "st_equals", 2) => 0.0,
+            ("st_dwithin", 3) => match &args[2] {
+                Expr::Constant(Constant {
+                    scalar: Scalar::Number(num),
+                    ..
+                }) => num.to_f64().into_inner(),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if !distance.is_finite() || distance < 0.0 {
+            return None;
+        }
+
+        let (column_name, geometry) = match (&args[0], &args[1]) {
+            (
+                Expr::ColumnRef(ColumnRef { id, data_type, .. }),
+                Expr::Constant(Constant {
+                    scalar: Scalar::Geometry(geometry),
+                    ..
+                }),
+            )
+            | (
+                Expr::Constant(Constant {
+                    scalar: Scalar::Geometry(geometry),
+                    ..
+                }),
+                Expr::ColumnRef(ColumnRef { id, data_type, .. }),
+            ) if data_type.remove_nullable() == DataType::Geometry => (id, geometry),
+            _ => return None,
+        };
+
+        let column_id = schema.field_with_name(column_name).ok()?.column_id();
+        let (min_x, min_y, max_x, max_y) = read_bounding_box(&mut Ewkb(geometry)).ok()??;
+        let bbox = SpatialStatistics::new(min_x, min_y, max_x, max_y).expand(distance);
+        Some((column_id, bbox))
+    }
+}
+
+fn collect_conjunctions<'a>(expr: &'a Expr<String>, conjunctions: &mut Vec<&'a Expr<String>>) {
+    match expr {
+        Expr::FunctionCall(FunctionCall { function, args, .. })
+            if matches!(
+                function.signature.name.as_str(),
+                "and" | "and_filters" | "is_true"
+            ) =>
+        {
+            for arg in args {
+                collect_conjunctions(arg, conjunctions);
+            }
+        }
+        _ => conjunctions.push(expr),
+    }
+}
@@ -16,6 +16,7 @@ pub mod accumulator;
 mod cluster_statistics;
 mod column_statistic;
 pub mod reducers;
+mod spatial_statistic;
 mod table_statistics;
 mod traverse;
 
@@ -34,5 +35,7 @@ pub use reducers::merge_statistics;
 pub use reducers::reduce_block_metas;
 pub use reducers::reduce_block_statistics;
 pub use reducers::reduce_cluster_statistics;
+pub use spatial_statistic::gen_spatial_statistics;
+pub use spatial_statistic::merge_spatial_statistics;
 pub use table_statistics::TableStatsGenerator;
 pub use traverse::traverse_values_dfs;
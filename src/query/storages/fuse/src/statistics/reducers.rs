@@ -307,6 +307,10 @@ pub fn merge_statistics_mut(
     let virtual_block_count =
         l.virtual_block_count.unwrap_or_default() + r.virtual_block_count.unwrap_or_default();
     l.virtual_block_count = Option::from(virtual_block_count).filter(|&x| x > 0);
+
+    let deleted_row_count =
+        l.deleted_row_count.unwrap_or_default() + r.deleted_row_count.unwrap_or_default();
+    l.deleted_row_count = Option::from(deleted_row_count).filter(|&x| x > 0);
 }
 
 // Deduct statistics, only be used for calculate snapshot summary.
@@ -358,6 +362,10 @@ pub fn deduct_statistics_mut(l: &mut Statistics, r: &Statistics) {
     let virtual_block_count =
         l.virtual_block_count.unwrap_or_default() - r.virtual_block_count.unwrap_or_default();
     l.virtual_block_count = Option::from(virtual_block_count).filter(|&x| x > 0);
+
+    let deleted_row_count =
+        l.deleted_row_count.unwrap_or_default() - r.deleted_row_count.unwrap_or_default();
+    l.deleted_row_count = Option::from(deleted_row_count).filter(|&x| x > 0);
 }
 
 pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
@@ -377,6 +385,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let mut virtual_column_size: u64 = 0;
     let mut perfect_block_count: u64 = 0;
     let mut virtual_block_count: u64 = 0;
+    let mut deleted_row_count: u64 = 0;
 
     let len = block_metas.len();
     let mut col_stats = Vec::with_capacity(len);
@@ -385,7 +394,8 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
+        deleted_row_count += b.deleted_row_count();
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
@@ -410,11 +420,13 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
             virtual_block_count += 1;
             virtual_col_stats.push(&virtual_block_meta.virtual_column_metas);
         }
-        if thresholds.check_perfect_block(
-            b.row_count as usize,
-            b.block_size as usize,
-            b.file_size as usize,
-        ) || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0)
+        // Blocks with deleted rows are never perfect, so that compaction purges them.
+        if b.deletion_vector.is_none()
+            && (thresholds.check_perfect_block(
+                b.row_count as usize,
+                b.block_size as usize,
+                b.file_size as usize,
+            ) || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0))
         {
             perfect_block_count += 1;
         }
@@ -437,6 +449,7 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
     let inverted_index_size = Option::from(inverted_index_size).filter(|&x| x > 0);
     let vector_index_size = Option::from(vector_index_size).filter(|&x| x > 0);
     let virtual_column_size = Option::from(virtual_column_size).filter(|&x| x > 0);
+    let deleted_row_count = Option::from(deleted_row_count).filter(|&x| x > 0);
 
     Statistics {
         row_count,
@@ -455,5 +468,6 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
         cluster_stats: merged_cluster_stats,
         virtual_block_count: merged_virtual_block_count,
         additional_stats_meta: None,
+        deleted_row_count,
     }
 }
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableSchemaRef;
+use databend_common_io::read_bounding_box;
+use databend_storages_common_table_meta::meta::SpatialStatistics;
+use databend_storages_common_table_meta::meta::SpatialStatisticsOfColumns;
+use geozero::wkb::Ewkb;
+
+/// Generates the bounding boxes of the top level geometry columns of the block.
+///
+/// Returns `None` if the block has no geometry column with non-empty values.
+pub fn gen_spatial_statistics(
+    data_block: &DataBlock,
+    schema: &TableSchemaRef,
+) -> Result<Option<SpatialStatisticsOfColumns>> {
+    let mut statistics = SpatialStatisticsOfColumns::new();
+    for (entry, field) in data_block.columns().iter().zip(schema.fields()) {
+        if !matches!(
+            DataType::from(field.data_type()).remove_nullable(),
+            DataType::Geometry
+        ) {
+            continue;
+        }
+
+        let mut bbox: Option<SpatialStatistics> = None;
+        let mut merge_value = |value: ScalarRef| -> Result<()> {
+            let ScalarRef::Geometry(buf) = value else {
+                return Ok(());
+            };
+            if let Some((min_x, min_y, max_x, max_y)) = read_bounding_box(&mut Ewkb(buf))? {
+                let value_bbox = SpatialStatistics::new(min_x, min_y, max_x, max_y);
+                match bbox.as_mut() {
+                    Some(bbox) => bbox.merge(&value_bbox),
+                    None => bbox = Some(value_bbox),
+                }
+            }
+            Ok(())
+        };
+        match entry {
+            BlockEntry::Const(s, _, _) => merge_value(s.as_ref())?,
+            BlockEntry::Column(c) => {
+                for value in c.iter() {
+                    merge_value(value)?;
+                }
+            }
+        }
+
+        if let Some(bbox) = bbox {
+            statistics.insert(field.column_id, bbox);
+        }
+    }
+
+    if statistics.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(statistics))
+    }
+}
+
+/// Merges the bounding boxes of `other` into `target`.
+pub fn merge_spatial_statistics(
+    target: &mut Option<SpatialStatisticsOfColumns>,
+    other: Option<SpatialStatisticsOfColumns>,
+) {
+    let Some(other) = other else {
+        return;
+    };
+    let Some(target) = target.as_mut() else {
+        *target = Some(other);
+        return;
+    };
+    for (column_id, bbox) in other {
+        target
+            .entry(column_id)
+            .and_modify(|v| v.merge(&bbox))
+            .or_insert(bbox);
+    }
+}
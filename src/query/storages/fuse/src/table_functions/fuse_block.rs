@@ -75,6 +75,14 @@ impl TableMetaFunc for FuseBlock {
                 "virtual_column_size",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
             ),
+            TableField::new(
+                "deletion_vector_location",
+                TableDataType::String.wrap_nullable(),
+            ),
+            TableField::new(
+                "deleted_row_count",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
         ])
     }
 
@@ -99,6 +107,8 @@ impl TableMetaFunc for FuseBlock {
         let mut ngram_index_size = Vec::with_capacity(len);
         let mut vector_index_size = Vec::with_capacity(len);
         let mut virtual_column_size = Vec::with_capacity(len);
+        let mut deletion_vector_location = Vec::with_capacity(len);
+        let mut deleted_row_count = Vec::with_capacity(len);
 
         let segments_io = SegmentsIO::create(ctx.clone(), tbl.operator.clone(), tbl.schema());
 
@@ -134,6 +144,18 @@ impl TableMetaFunc for FuseBlock {
                             .as_ref()
                             .map(|m| m.virtual_column_size),
                     );
+                    deletion_vector_location.push(
+                        block
+                            .deletion_vector
+                            .as_ref()
+                            .map(|dv| dv.location.0.clone()),
+                    );
+                    deleted_row_count.push(
+                        block
+                            .deletion_vector
+                            .as_ref()
+                            .map(|dv| dv.deleted_row_count),
+                    );
 
                     num_rows += 1;
                     if num_rows >= limit {
@@ -157,6 +179,8 @@ impl TableMetaFunc for FuseBlock {
                 UInt64Type::from_opt_data(ngram_index_size).into(),
                 UInt64Type::from_opt_data(vector_index_size).into(),
                 UInt64Type::from_opt_data(virtual_column_size).into(),
+                StringType::from_opt_data(deletion_vector_location).into(),
+                UInt64Type::from_opt_data(deleted_row_count).into(),
             ],
             num_rows,
         ))
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_storages_common_table_meta::table::TableRefKind;
+
+use crate::sessions::TableContext;
+use crate::table_functions::parse_db_tb_args;
+use crate::table_functions::string_literal;
+use crate::table_functions::SimpleTableFunc;
+use crate::FuseTable;
+
+pub struct FuseTableRefsArgs {
+    database_name: String,
+    table_name: String,
+}
+
+/// Lists the branches and tags of a fuse table.
+pub struct FuseTableRefsFunc {
+    args: FuseTableRefsArgs,
+}
+
+impl From<&FuseTableRefsArgs> for TableArgs {
+    fn from(args: &FuseTableRefsArgs) -> Self {
+        TableArgs::new_positioned(vec![
+            string_literal(args.database_name.as_str()),
+            string_literal(args.table_name.as_str()),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl SimpleTableFunc for FuseTableRefsFunc {
+    fn table_args(&self) -> Option<TableArgs> {
+        Some((&self.args).into())
+    }
+
+    fn schema(&self) -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("type", TableDataType::String),
+            TableField::new(
+                "snapshot_location",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new("created_on", TableDataType::Timestamp),
+        ])
+    }
+
+    async fn apply(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+    ) -> Result<Option<DataBlock>> {
+        let tenant_id = ctx.get_tenant();
+        let tbl = ctx
+            .get_catalog(CATALOG_DEFAULT)
+            .await?
+            .get_table(
+                &tenant_id,
+                self.args.database_name.as_str(),
+                self.args.table_name.as_str(),
+            )
+            .await?;
+
+        let table = FuseTable::try_from_table(tbl.as_ref()).map_err(|_| {
+            ErrorCode::StorageOther(
+                "Invalid table engine, only FUSE table supports fuse_table_refs",
+            )
+        })?;
+
+        let refs = table.table_refs()?;
+        let mut names = vec![];
+        let mut kinds = vec![];
+        let mut locations = vec![];
+        let mut created_on = vec![];
+        for kind in [TableRefKind::Branch, TableRefKind::Tag] {
+            for (name, table_ref) in refs.refs(kind) {
+                names.push(name.clone());
+                kinds.push(kind.to_string());
+                locations.push(table_ref.snapshot_location.clone());
+                created_on.push(table_ref.created_on.timestamp_micros());
+            }
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(kinds),
+            StringType::from_opt_data(locations),
+            TimestampType::from_data(created_on),
+        ])))
+    }
+
+    fn create(func_name: &str, table_args: TableArgs) -> Result<Self>
+    where Self: Sized {
+        let (arg_database_name, arg_table_name) = parse_db_tb_args(&table_args, func_name)?;
+        Ok(Self {
+            args: FuseTableRefsArgs {
+                database_name: arg_database_name,
+                table_name: arg_table_name,
+            },
+        })
+    }
+}
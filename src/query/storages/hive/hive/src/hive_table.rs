@@ -53,6 +53,7 @@ use databend_common_storage::init_operator;
 use databend_common_storage::DataOperator;
 use databend_common_storages_parquet::ParquetPruner;
 use databend_common_storages_parquet::ParquetReaderBuilder;
+use databend_common_storages_parquet::ParquetRuntimeFilter;
 use databend_common_storages_parquet::ParquetSourceType;
 use databend_storages_common_pruner::partition_prunner::PartitionPruner;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -190,12 +191,16 @@ impl HiveTable {
         } else {
             None
         };
-        let mut builder =
-            ParquetReaderBuilder::create(ctx.clone(), Arc::new(op), table_schema, arrow_schema)?
-                .with_options(read_options)
-                .with_push_downs(push_downs.as_ref())
-                .with_pruner(Some(pruner))
-                .with_partition_columns(partition_keys);
+        let mut builder = ParquetReaderBuilder::create(
+            ctx.clone(),
+            Arc::new(op),
+            table_schema.clone(),
+            arrow_schema,
+        )?
+        .with_options(read_options)
+        .with_push_downs(push_downs.as_ref())
+        .with_pruner(Some(pruner))
+        .with_partition_columns(partition_keys.clone());
 
         let parquet_reader = Arc::new(builder.build_full_reader(ParquetSourceType::Hive, false)?);
 
@@ -208,6 +213,13 @@ impl HiveTable {
                     output_schema.clone(),
                     parquet_reader.clone(),
                     self.partition_fields(),
+                    ParquetRuntimeFilter::create(
+                        ctx.clone(),
+                        plan,
+                        table_schema.clone(),
+                        read_options,
+                        partition_keys.clone(),
+                    )?,
                 )
             },
             max_threads.max(1),
@@ -35,6 +35,7 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_storages_parquet::ParquetFileReader;
+use databend_common_storages_parquet::ParquetRuntimeFilter;
 use databend_common_storages_parquet::ParquetWholeFileReader;
 use parquet::arrow::async_reader::ParquetRecordBatchStream;
 
@@ -63,6 +64,9 @@ pub struct HiveTableSource {
     // Used to check schema
     output_schema: DataSchemaRef,
 
+    // Join runtime filters pushed down to this scan.
+    runtime_filter: ParquetRuntimeFilter,
+
     // Per partition
     stream: Option<ParquetRecordBatchStream<ParquetFileReader>>,
     partition_block_scalars: Vec<(DataType, Scalar)>,
@@ -75,6 +79,7 @@ impl HiveTableSource {
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetWholeFileReader>,
         partition_fields: Vec<TableField>,
+        runtime_filter: ParquetRuntimeFilter,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
             .fields()
@@ -96,6 +101,7 @@ impl HiveTableSource {
             output_schema,
             partition_fields,
             output_partition_columns,
+            runtime_filter,
             stream: None,
             generated_data: None,
             is_finished: false,
@@ -168,7 +174,7 @@ impl Processor for HiveTableSource {
                 .map(|b| check_block_schema(&self.output_schema, b))
                 .transpose()?
             {
-                self.generated_data = Some(block);
+                self.generated_data = Some(self.runtime_filter.filter_block(block)?);
                 self.stream = Some(stream);
             }
             // else:
@@ -182,13 +188,28 @@ impl Processor for HiveTableSource {
                 .cloned()
                 .zip(part.partitions.iter().cloned())
                 .collect::<Vec<_>>();
+
+            self.runtime_filter.refresh()?;
+            let partition_values = partition_fields
+                .iter()
+                .map(|(f, v)| (f.name().to_string(), v.clone()))
+                .collect();
+            if self.runtime_filter.prune_partition(&partition_values) {
+                // The whole file is pruned by runtime filter, try the next partition.
+                return Ok(());
+            }
             self.partition_block_scalars = partition_fields
                 .iter()
                 .map(|(f, v)| (f.data_type().into(), v.clone()))
                 .collect();
             let stream = self
                 .parquet_reader
-                .prepare_data_stream(&part.filename, part.filesize, Some(&partition_fields))
+                .prepare_data_stream(
+                    &part.filename,
+                    part.filesize,
+                    Some(&partition_fields),
+                    Some(&self.runtime_filter),
+                )
                 .await?;
             self.stream = Some(stream);
         } else {
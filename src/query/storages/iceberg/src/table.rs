@@ -51,6 +51,7 @@ use databend_common_pipeline_transforms::processors::TransformPipelineHelper;
 use databend_common_storages_orc::ORCSource;
 use databend_common_storages_orc::StripeDecoder;
 use databend_common_storages_parquet::ParquetReaderBuilder;
+use databend_common_storages_parquet::ParquetRuntimeFilter;
 use databend_common_storages_parquet::ParquetSource;
 use databend_common_storages_parquet::ParquetSourceType;
 use databend_storages_common_table_meta::table::ChangeType;
@@ -382,6 +383,13 @@ impl IcebergTable {
                         plan.push_downs.clone(),
                         table_schema.clone(),
                         op.clone(),
+                        Some(ParquetRuntimeFilter::create(
+                            ctx.clone(),
+                            plan,
+                            table_schema.clone(),
+                            read_options,
+                            vec![],
+                        )?),
                     )
                 },
                 max_threads,
@@ -36,6 +36,7 @@ mod parquet_reader;
 mod parquet_table;
 mod partition;
 mod pruning;
+mod runtime_filter;
 mod source;
 mod statistics;
 mod transformer;
@@ -60,5 +61,6 @@ pub use parquet_variant_table::ParquetVariantTable;
 // for it test
 pub use pruning::ParquetPruner;
 pub use read_settings::ReadSettings;
+pub use runtime_filter::ParquetRuntimeFilter;
 pub use source::ParquetSource;
 pub use source::ParquetSourceType;
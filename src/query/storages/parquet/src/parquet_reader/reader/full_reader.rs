@@ -52,6 +52,7 @@ use crate::parquet_reader::utils::FieldPaths;
 use crate::parquet_reader::DataBlockIterator;
 use crate::transformer::RecordBatchTransformer;
 use crate::ParquetPruner;
+use crate::ParquetRuntimeFilter;
 
 /// The reader to read a whole parquet file.
 pub struct ParquetWholeFileReader {
@@ -82,12 +83,13 @@ pub struct ParquetWholeFileReader {
 }
 
 impl ParquetWholeFileReader {
-    // partition_fields is only used for delta table engine.
+    // partition_fields is only used for delta and hive table engines.
     pub async fn prepare_data_stream(
         &self,
         loc: &str,
         size: u64,
         partition_fields: Option<&[(TableField, Scalar)]>,
+        runtime_filter: Option<&ParquetRuntimeFilter>,
     ) -> Result<ParquetRecordBatchStream<ParquetFileReader>> {
         let partition_values_map = partition_fields.map(|arr| {
             arr.iter()
@@ -110,25 +112,57 @@ impl ParquetWholeFileReader {
         let file_meta = builder.metadata().clone();
 
         // Prune row groups.
+        let mut selected_row_groups = None;
         if let Some(pruner) = &self.pruner {
-            let (selected_row_groups, omits, _) =
+            let (selected, omits, _) =
                 pruner.prune_row_groups(&file_meta, None, partition_values_map.as_ref())?;
             all_pruned = omits.iter().all(|x| *x);
+            selected_row_groups = Some(selected);
+
+            if all_pruned {
+                metrics_inc_omit_filter_rowgroups(file_meta.num_row_groups() as u64);
+                metrics_inc_omit_filter_rows(file_meta.file_metadata().num_rows() as u64);
+            }
+        }
+        if let Some(runtime_filter) = runtime_filter {
+            let row_groups =
+                selected_row_groups.unwrap_or_else(|| (0..file_meta.num_row_groups()).collect());
+            selected_row_groups = Some(runtime_filter.prune_row_groups(
+                &file_meta,
+                row_groups,
+                partition_values_map.as_ref(),
+            )?);
+        }
+
+        // Prune pages of the selected row groups.
+        if let Some(selected_row_groups) = selected_row_groups {
             builder = builder.with_row_groups(selected_row_groups.clone());
 
-            if !all_pruned {
-                let row_selection = pruner.prune_pages(
+            let mut row_selection = None;
+            if let Some(pruner) = &self.pruner
+                && !all_pruned
+            {
+                row_selection = pruner.prune_pages(
                     &file_meta,
                     &selected_row_groups,
                     partition_values_map.as_ref(),
                 )?;
+            }
+            if let Some(runtime_filter) = runtime_filter
+                && let Some(runtime_selection) = runtime_filter.prune_pages(
+                    &file_meta,
+                    &selected_row_groups,
+                    partition_values_map.as_ref(),
+                )?
+            {
+                row_selection = Some(match row_selection {
+                    Some(selection) => selection.intersection(&runtime_selection),
+                    None => runtime_selection,
+                });
+            }
 
-                if let Some(row_selection) = row_selection {
-                    builder = builder.with_row_selection(row_selection);
-                }
-            } else {
-                metrics_inc_omit_filter_rowgroups(file_meta.num_row_groups() as u64);
-                metrics_inc_omit_filter_rows(file_meta.file_metadata().num_rows() as u64);
+            if let Some(row_selection) = row_selection {
+                builder = builder.with_row_selection(row_selection);
             }
         }
 
@@ -178,7 +212,12 @@ impl ParquetWholeFileReader {
     }
 
     /// Read a [`DataBlock`] from bytes.
-    pub fn read_blocks_from_binary(&self, bytes: Bytes, path: &str) -> Result<DataBlockIterator> {
+    pub fn read_blocks_from_binary(
+        &self,
+        bytes: Bytes,
+        path: &str,
+        runtime_filter: Option<&ParquetRuntimeFilter>,
+    ) -> Result<DataBlockIterator> {
         let mut builder =
             ParquetRecordBatchReaderBuilder::try_new_with_options(bytes, ArrowReaderOptions::new())
                 .map_err(|e| format!("Invalid Parquet file '{path}': {e}"))?
@@ -197,24 +236,48 @@ impl ParquetWholeFileReader {
         }
 
         let mut full_match = false;
+        let mut selected_row_groups = None;
         if let Some(pruner) = &self.pruner {
-            let (selected_row_groups, omits, _) =
-                pruner.prune_row_groups(&file_meta, None, None)?;
+            let (selected, omits, _) = pruner.prune_row_groups(&file_meta, None, None)?;
 
             full_match = omits.iter().all(|x| *x);
-            builder = builder.with_row_groups(selected_row_groups.clone());
-
-            if !full_match {
-                let row_selection = pruner.prune_pages(&file_meta, &selected_row_groups, None)?;
+            selected_row_groups = Some(selected);
 
-                if let Some(row_selection) = row_selection {
-                    builder = builder.with_row_selection(row_selection);
-                }
-            } else {
+            if full_match {
                 metrics_inc_omit_filter_rowgroups(file_meta.num_row_groups() as u64);
                 metrics_inc_omit_filter_rows(file_meta.file_metadata().num_rows() as u64);
             }
         }
+        if let Some(runtime_filter) = runtime_filter {
+            let row_groups =
+                selected_row_groups.unwrap_or_else(|| (0..file_meta.num_row_groups()).collect());
+            selected_row_groups =
+                Some(runtime_filter.prune_row_groups(&file_meta, row_groups, None)?);
+        }
+
+        if let Some(selected_row_groups) = selected_row_groups {
+            builder = builder.with_row_groups(selected_row_groups.clone());
+
+            let mut row_selection = None;
+            if let Some(pruner) = &self.pruner
+                && !full_match
+            {
+                row_selection = pruner.prune_pages(&file_meta, &selected_row_groups, None)?;
+            }
+            if let Some(runtime_filter) = runtime_filter
+                && let Some(runtime_selection) =
+                    runtime_filter.prune_pages(&file_meta, &selected_row_groups, None)?
+            {
+                row_selection = Some(match row_selection {
+                    Some(selection) => selection.intersection(&runtime_selection),
+                    None => runtime_selection,
+                });
+            }
+
+            if let Some(row_selection) = row_selection {
+                builder = builder.with_row_selection(row_selection);
+            }
+        }
 
         if !full_match {
             if let Some(predicate) = self.predicate.as_ref() {
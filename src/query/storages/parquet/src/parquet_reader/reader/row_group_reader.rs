@@ -317,7 +317,7 @@ impl RowGroupReader {
         .with_push_downs(Some(info));
         let reader = builder.build_full_reader(ParquetSourceType::Iceberg, false)?;
         let mut stream = reader
-            .prepare_data_stream(path, meta.content_length(), None)
+            .prepare_data_stream(path, meta.content_length(), None, None)
             .await?;
         let mut positional_deletes = Vec::new();
 
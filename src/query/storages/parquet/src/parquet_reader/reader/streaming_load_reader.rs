@@ -121,8 +121,11 @@ impl InmMemoryFile {
 
         let whole_file_reader =
             reader_builder.build_full_reader(ParquetSourceType::StreamingLoad, false)?;
-        let it =
-            whole_file_reader.read_blocks_from_binary(self.file_data.clone(), &self.location)?;
+        let it = whole_file_reader.read_blocks_from_binary(
+            self.file_data.clone(),
+            &self.location,
+            None,
+        )?;
         Ok(Box::new(it.into_iter().map(move |result_block| {
             result_block.map(|block| project_eval.project(&block, &output_projection).unwrap())
         })))
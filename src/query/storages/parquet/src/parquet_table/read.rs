@@ -28,6 +28,7 @@ use crate::source::ParquetSource;
 use crate::utils::calc_parallelism;
 use crate::ParquetPart;
 use crate::ParquetPruner;
+use crate::ParquetRuntimeFilter;
 use crate::ParquetSourceType;
 
 impl ParquetTable {
@@ -122,6 +123,13 @@ impl ParquetTable {
                     plan.push_downs.clone(),
                     table_schema.clone(),
                     op.clone(),
+                    Some(ParquetRuntimeFilter::create(
+                        ctx.clone(),
+                        plan,
+                        table_schema.clone(),
+                        self.read_options,
+                        vec![],
+                    )?),
                 )
             },
             num_threads,
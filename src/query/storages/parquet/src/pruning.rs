@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
+use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableField;
@@ -29,6 +30,7 @@ use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use parquet::arrow::arrow_reader::RowSelection;
 use parquet::arrow::arrow_reader::RowSelector;
 use parquet::file::metadata::ParquetMetaData;
+use parquet::file::metadata::RowGroupMetaData;
 use parquet::format::PageLocation;
 
 use super::statistics::collect_row_group_stats;
@@ -42,7 +44,7 @@ pub struct ParquetPruner {
     leaf_fields: Arc<Vec<TableField>>,
     range_pruner: Option<(
         Arc<dyn RangePruner + Send + Sync>,
-        Arc<dyn RangePruner + Send + Sync>,
+        Option<Arc<dyn RangePruner + Send + Sync>>,
     )>,
     prune_row_groups: bool,
     prune_pages: bool,
@@ -60,19 +62,42 @@ impl ParquetPruner {
         options: ParquetReadOptions,
         partition_columns: Vec<String>,
     ) -> Result<Self> {
-        // Build `RangePruner` by `filter`.
-        let filter = push_down.as_ref().and_then(|p| p.filters.as_ref());
+        let filter = push_down
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| {
+                (
+                    f.filter.as_expr(&BUILTIN_FUNCTIONS),
+                    Some(f.inverted_filter.as_expr(&BUILTIN_FUNCTIONS)),
+                )
+            });
+        Self::try_create_with_filter(
+            func_ctx,
+            schema,
+            leaf_fields,
+            filter,
+            options,
+            partition_columns,
+        )
+    }
 
+    /// Create a pruner from a filter expression.
+    ///
+    /// Without the inverted filter, row groups are never marked as fully matched.
+    pub fn try_create_with_filter(
+        func_ctx: FunctionContext,
+        schema: TableSchemaRef,
+        leaf_fields: Arc<Vec<TableField>>,
+        filter: Option<(Expr<String>, Option<Expr<String>>)>,
+        options: ParquetReadOptions,
+        partition_columns: Vec<String>,
+    ) -> Result<Self> {
+        // Build `RangePruner` by `filter`.
         let mut predicate_columns = vec![];
-        let range_pruner =
-            if filter.is_some() && (options.prune_row_groups() || options.prune_pages()) {
-                let filter_expr = filter.as_ref().unwrap().filter.as_expr(&BUILTIN_FUNCTIONS);
-                let inverted_filter_expr = filter
-                    .as_ref()
-                    .unwrap()
-                    .inverted_filter
-                    .as_expr(&BUILTIN_FUNCTIONS);
-
+        let range_pruner = match filter {
+            Some((filter_expr, inverted_filter_expr))
+                if options.prune_row_groups() || options.prune_pages() =>
+            {
                 predicate_columns = filter_expr
                     .column_refs()
                     .into_keys()
@@ -91,12 +116,13 @@ impl ParquetPruner {
                 predicate_columns.sort();
                 let pruner =
                     RangePrunerCreator::try_create(func_ctx.clone(), &schema, Some(&filter_expr))?;
-                let inverted_pruner =
-                    RangePrunerCreator::try_create(func_ctx, &schema, Some(&inverted_filter_expr))?;
+                let inverted_pruner = inverted_filter_expr
+                    .map(|expr| RangePrunerCreator::try_create(func_ctx, &schema, Some(&expr)))
+                    .transpose()?;
                 Some((pruner, inverted_pruner))
-            } else {
-                None
-            };
+            }
+            _ => None,
+        };
 
         Ok(ParquetPruner {
             leaf_fields,
@@ -152,8 +178,9 @@ impl ParquetPruner {
                             selection.push(i);
                             start_rows.push(default_start_rows[i]);
 
-                            let omit = !inverted_pruner
-                                .should_keep_with_partition_columns(row_group, partition_values);
+                            let omit = inverted_pruner.as_ref().is_some_and(|p| {
+                                !p.should_keep_with_partition_columns(row_group, partition_values)
+                            });
                             omits.push(omit);
                         }
                     }
@@ -168,8 +195,9 @@ impl ParquetPruner {
                             selection.push(i);
                             start_rows.push(default_start_rows[i]);
 
-                            let omit = !inverted_pruner
-                                .should_keep_with_partition_columns(row_group, partition_values);
+                            let omit = inverted_pruner.as_ref().is_some_and(|p| {
+                                !p.should_keep_with_partition_columns(row_group, partition_values)
+                            });
                             omits.push(omit);
                         }
                     }
@@ -181,6 +209,39 @@ impl ParquetPruner {
         }
     }
 
+    /// Check if a single row group may contain rows that pass the filter.
+    pub fn should_keep_row_group(
+        &self,
+        row_group: &RowGroupMetaData,
+        partition_values: Option<&HashMap<String, Scalar>>,
+    ) -> bool {
+        if !self.prune_row_groups {
+            return true;
+        }
+        let Some((pruner, _)) = &self.range_pruner else {
+            return true;
+        };
+        match collect_row_group_stats(
+            std::slice::from_ref(row_group),
+            &self.leaf_fields,
+            Some(&self.predicate_columns),
+        ) {
+            Some(stats) => pruner.should_keep_with_partition_columns(&stats[0], partition_values),
+            None => true,
+        }
+    }
+
+    /// Check if a file with the partition values may contain rows that pass the filter.
+    pub fn should_keep_partition(&self, partition_values: &HashMap<String, Scalar>) -> bool {
+        match &self.range_pruner {
+            None => true,
+            Some((pruner, _)) => pruner.should_keep_with_partition_columns(
+                &StatisticsOfColumns::default(),
+                Some(partition_values),
+            ),
+        }
+    }
+
     /// Prune pages of a parquet file.
     ///
     /// Return a vector of [`RowSelection`] to represent rows to read.
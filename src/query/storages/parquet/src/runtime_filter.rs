@@ -0,0 +1,267 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::ops::BitAnd;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_base::runtime::profile::Profile;
+use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::ParquetReadOptions;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Bitmap;
+use databend_common_expression::types::MutableBitmap;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::FieldIndex;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_index::ExprBloomFilter;
+use parquet::arrow::arrow_reader::RowSelection;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::metadata::RowGroupMetaData;
+use xorf::BinaryFuse16;
+
+use crate::ParquetPruner;
+
+/// Join runtime filters applied to the parquet files of a scanned table.
+///
+/// The build side of a hash join publishes filters on the probe keys under the
+/// scan id of the probe side table. They may arrive after the scan has started,
+/// so [`ParquetRuntimeFilter::refresh`] is called before each file or row group
+/// to pick up newly published filters.
+///
+/// Inlist and min-max filters prune files by partition values, and row groups and
+/// pages by statistics. Bloom filters drop rows from the read blocks.
+pub struct ParquetRuntimeFilter {
+    ctx: Arc<dyn TableContext>,
+    scan_id: usize,
+    func_ctx: FunctionContext,
+
+    /// Schema of the columns stored in the parquet files.
+    schema: TableSchemaRef,
+    leaf_fields: Arc<Vec<TableField>>,
+    partition_columns: Vec<String>,
+    options: ParquetReadOptions,
+    /// Schema of the blocks produced by the scan.
+    output_schema: DataSchema,
+
+    num_exprs: usize,
+    pruners: Vec<ParquetPruner>,
+    num_blooms: usize,
+    blooms: Vec<(FieldIndex, BinaryFuse16)>,
+}
+
+impl ParquetRuntimeFilter {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        schema: TableSchemaRef,
+        options: ParquetReadOptions,
+        partition_columns: Vec<String>,
+    ) -> Result<Self> {
+        let func_ctx = ctx.get_function_context()?;
+        let leaf_fields = Arc::new(schema.leaf_fields());
+        Ok(Self {
+            ctx,
+            scan_id: plan.scan_id,
+            func_ctx,
+            schema,
+            leaf_fields,
+            partition_columns,
+            options,
+            output_schema: DataSchema::from(plan.schema()),
+            num_exprs: 0,
+            pruners: vec![],
+            num_blooms: 0,
+            blooms: vec![],
+        })
+    }
+
+    /// Fetch the filters published since the last refresh.
+    pub fn refresh(&mut self) -> Result<()> {
+        let mut exprs = self.ctx.get_inlist_runtime_filter_with_id(self.scan_id);
+        exprs.extend(self.ctx.get_min_max_runtime_filter_with_id(self.scan_id));
+        // Filters are only appended, so a changed count means new filters.
+        if exprs.len() != self.num_exprs {
+            self.num_exprs = exprs.len();
+            self.pruners = exprs
+                .into_iter()
+                .map(|expr| {
+                    ParquetPruner::try_create_with_filter(
+                        self.func_ctx.clone(),
+                        self.schema.clone(),
+                        self.leaf_fields.clone(),
+                        Some((expr, None)),
+                        self.options,
+                        self.partition_columns.clone(),
+                    )
+                })
+                .collect::<Result<_>>()?;
+        }
+
+        let blooms = self.ctx.get_bloom_runtime_filter_with_id(self.scan_id);
+        if blooms.len() != self.num_blooms {
+            self.num_blooms = blooms.len();
+            self.blooms = blooms
+                .into_iter()
+                .filter_map(|(name, filter)| {
+                    // Some probe keys are not in the schema, they are derived from expressions.
+                    self.output_schema
+                        .index_of(&name)
+                        .ok()
+                        .map(|idx| (idx, filter))
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    /// Returns true if no row of a file with the partition values can pass the filters.
+    pub fn prune_partition(&self, partition_values: &HashMap<String, Scalar>) -> bool {
+        if self.pruners.is_empty() {
+            return false;
+        }
+        let start = Instant::now();
+        let pruned = self
+            .pruners
+            .iter()
+            .any(|pruner| !pruner.should_keep_partition(partition_values));
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterInlistMinMaxTime,
+            start.elapsed().as_nanos() as usize,
+        );
+        if pruned {
+            Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, 1);
+        }
+        pruned
+    }
+
+    /// Keep the row groups that may contain rows passing the filters.
+    pub fn prune_row_groups(
+        &self,
+        meta: &ParquetMetaData,
+        mut row_groups: Vec<usize>,
+        partition_values: Option<&HashMap<String, Scalar>>,
+    ) -> Result<Vec<usize>> {
+        if self.pruners.is_empty() || !self.match_file_schema(meta.row_groups()) {
+            return Ok(row_groups);
+        }
+        let start = Instant::now();
+        let num_row_groups = row_groups.len();
+        for pruner in self.pruners.iter() {
+            let (selected, _, _) = pruner.prune_row_groups(meta, None, partition_values)?;
+            row_groups.retain(|idx| selected.binary_search(idx).is_ok());
+        }
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterInlistMinMaxTime,
+            start.elapsed().as_nanos() as usize,
+        );
+        let pruned = num_row_groups - row_groups.len();
+        if pruned > 0 {
+            Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, pruned);
+        }
+        Ok(row_groups)
+    }
+
+    /// Select the rows of pages that may pass the filters in the given row groups.
+    pub fn prune_pages(
+        &self,
+        meta: &ParquetMetaData,
+        row_groups: &[usize],
+        partition_values: Option<&HashMap<String, Scalar>>,
+    ) -> Result<Option<RowSelection>> {
+        if self.pruners.is_empty() || !self.match_file_schema(meta.row_groups()) {
+            return Ok(None);
+        }
+        let mut selection: Option<RowSelection> = None;
+        for pruner in self.pruners.iter() {
+            if let Some(s) = pruner.prune_pages(meta, row_groups, partition_values)? {
+                selection = Some(match selection {
+                    Some(prev) => prev.intersection(&s),
+                    None => s,
+                });
+            }
+        }
+        Ok(selection)
+    }
+
+    /// Check if a single row group may contain rows passing the filters.
+    pub fn should_keep_row_group(&self, row_group: &RowGroupMetaData) -> bool {
+        if self.pruners.is_empty() || !self.match_file_schema(std::slice::from_ref(row_group)) {
+            return true;
+        }
+        let start = Instant::now();
+        let keep = self
+            .pruners
+            .iter()
+            .all(|pruner| pruner.should_keep_row_group(row_group, None));
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterInlistMinMaxTime,
+            start.elapsed().as_nanos() as usize,
+        );
+        if !keep {
+            Profile::record_usize_profile(ProfileStatisticsName::RuntimeFilterPruneParts, 1);
+        }
+        keep
+    }
+
+    /// Drop the rows of the block rejected by the bloom filters.
+    pub fn filter_block(&self, block: DataBlock) -> Result<DataBlock> {
+        if self.blooms.is_empty() || block.is_empty() {
+            return Ok(block);
+        }
+
+        let start = Instant::now();
+        let mut bitmap: Option<Bitmap> = None;
+        for (idx, filter) in self.blooms.iter() {
+            let mut filter_bitmap = MutableBitmap::from_len_zeroed(block.num_rows());
+            let probe_column = block.get_by_offset(*idx).to_column();
+            ExprBloomFilter::new(filter.clone()).apply(probe_column, &mut filter_bitmap)?;
+            let filter_bitmap: Bitmap = filter_bitmap.into();
+            bitmap = Some(match bitmap {
+                Some(prev) => (&prev).bitand(&filter_bitmap),
+                None => filter_bitmap,
+            });
+        }
+        let bitmap = bitmap.unwrap();
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterBloomTime,
+            start.elapsed().as_nanos() as usize,
+        );
+
+        let rows_filtered = bitmap.null_count();
+        if rows_filtered == 0 {
+            return Ok(block);
+        }
+        Profile::record_usize_profile(
+            ProfileStatisticsName::RuntimeFilterBloomRowsFiltered,
+            rows_filtered,
+        );
+        block.filter_with_bitmap(&bitmap)
+    }
+
+    // Files whose schema differs from the table (e.g. after schema evolution)
+    // have no statistics aligned with the leaf fields, so they are not pruned.
+    fn match_file_schema(&self, row_groups: &[RowGroupMetaData]) -> bool {
+        row_groups
+            .iter()
+            .all(|rg| rg.num_columns() == self.leaf_fields.len())
+    }
+}
@@ -55,6 +55,7 @@ use crate::read_settings::ReadSettings;
 use crate::ParquetFilePart;
 use crate::ParquetPart;
 use crate::ParquetReaderBuilder;
+use crate::ParquetRuntimeFilter;
 
 enum State {
     Init,
@@ -115,6 +116,9 @@ pub struct ParquetSource {
     push_downs: Option<PushDownInfo>,
     topk: Arc<Option<TopK>>,
     op_registry: Arc<dyn OperatorRegistry>,
+
+    /// Join runtime filters pushed down to this scan.
+    runtime_filter: Option<ParquetRuntimeFilter>,
 }
 
 impl ParquetSource {
@@ -130,6 +134,7 @@ impl ParquetSource {
         push_downs: Option<PushDownInfo>,
         table_schema: TableSchemaRef,
         op_registry: Arc<dyn OperatorRegistry>,
+        runtime_filter: Option<ParquetRuntimeFilter>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         let is_copy = matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable);
@@ -158,6 +163,7 @@ impl ParquetSource {
             push_downs,
             topk,
             op_registry,
+            runtime_filter,
         })))
     }
 }
@@ -229,6 +235,9 @@ impl Processor for ParquetSource {
                                 error: None,
                             });
                         }
+                        if let Some(runtime_filter) = &self.runtime_filter {
+                            block = runtime_filter.filter_block(block)?;
+                        }
                         self.generated_data = Some(block);
                     } else {
                         vs.pop_front();
@@ -247,7 +256,7 @@ impl Processor for ParquetSource {
                         .whole_file_reader
                         .as_ref()
                         .unwrap()
-                        .read_blocks_from_binary(buffer, &path)?
+                        .read_blocks_from_binary(buffer, &path, self.runtime_filter.as_ref())?
                         .collect();
                     let mut bs = bs?;
 
@@ -271,7 +280,11 @@ impl Processor for ParquetSource {
                 }
 
                 if !blocks.is_empty() {
-                    self.generated_data = Some(DataBlock::concat(&blocks)?);
+                    let mut block = DataBlock::concat(&blocks)?;
+                    if let Some(runtime_filter) = &self.runtime_filter {
+                        block = runtime_filter.filter_block(block)?;
+                    }
+                    self.generated_data = Some(block);
                 }
                 // Else: no output data is generated.
             }
@@ -285,9 +298,20 @@ impl Processor for ParquetSource {
         match std::mem::replace(&mut self.state, State::Init) {
             State::Init => {
                 if let Some(part) = self.ctx.get_partition() {
+                    if let Some(runtime_filter) = &mut self.runtime_filter {
+                        runtime_filter.refresh()?;
+                    }
                     match ParquetPart::from_part(&part)? {
                         // From Copy Table from Stage, we don't enable cache
                         ParquetPart::RowGroup(part) => {
+                            if self
+                                .runtime_filter
+                                .as_ref()
+                                .is_some_and(|f| !f.should_keep_row_group(&part.meta))
+                            {
+                                // Pruned by runtime filter, keep in init state.
+                                return Ok(());
+                            }
                             if let Some(reader) = self
                                 .row_group_reader
                                 .create_read_policy(
@@ -432,6 +456,13 @@ impl ParquetSource {
             if !should_read(rowgroup_idx, part.bucket_option) {
                 continue;
             }
+            if self
+                .runtime_filter
+                .as_ref()
+                .is_some_and(|f| !f.should_keep_row_group(rg))
+            {
+                continue;
+            }
 
             let part = ParquetRowGroupPart {
                 location: part.file.clone(),
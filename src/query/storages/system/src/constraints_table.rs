@@ -19,14 +19,18 @@ use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::Constraint;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -88,6 +92,7 @@ impl AsyncSystemTable for ConstraintsTable {
             )
             .await?;
 
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
         let len = table_constraint_tables.len();
         let mut names = Vec::with_capacity(len);
         let mut types = Vec::with_capacity(len);
@@ -98,6 +103,11 @@ impl AsyncSystemTable for ConstraintsTable {
         let mut tables = Vec::with_capacity(len);
         let mut created_on = Vec::with_capacity(len);
         let mut updated_on = Vec::with_capacity(len);
+        let mut enforced = Vec::with_capacity(len);
+        let mut rely = Vec::with_capacity(len);
+        let mut referenced_databases = Vec::with_capacity(len);
+        let mut referenced_tables = Vec::with_capacity(len);
+        let mut referenced_column_names = Vec::with_capacity(len);
 
         for table in table_constraint_tables {
             let table_schema = table.schema();
@@ -105,19 +115,17 @@ impl AsyncSystemTable for ConstraintsTable {
                 ConstraintExprBinder::try_new(ctx.clone(), Arc::new(table_schema.clone().into()))?;
 
             for (name, constraint) in &table.meta.constraints {
-                let expr = constraint.expr();
-
-                names.push(name.clone());
-                types.push(constraint.type_name());
-                databases.push(table.database_name()?.to_string());
-                tables.push(Some(table.name.to_string()));
-
-                let scalar_expr = binder.parse_and_bind(name, &expr)?;
-                let columns = scalar_expr
-                    .used_columns()
-                    .iter()
-                    .map(|i| binder.bind_context.columns[*i].column_name.clone())
-                    .collect::<Vec<_>>();
+                let columns = match constraint.check_expr() {
+                    Some(expr) => {
+                        let scalar_expr = binder.parse_and_bind(name, expr)?;
+                        scalar_expr
+                            .used_columns()
+                            .iter()
+                            .map(|i| binder.bind_context.columns[*i].column_name.clone())
+                            .collect::<Vec<_>>()
+                    }
+                    None => column_names_of(&table_schema, constraint.key_column_ids())?,
+                };
 
                 let mut indexes = String::new();
 
@@ -127,11 +135,62 @@ impl AsyncSystemTable for ConstraintsTable {
                     }
                     indexes.push_str(table_schema.index_of(column_name)?.to_string().as_str());
                 }
+
+                let (ref_database, ref_table, ref_columns) = match constraint {
+                    Constraint::ForeignKey(fk) => {
+                        let ref_database = catalog.get_db_name_by_id(fk.ref_database_id).await.ok();
+                        let ref_table = catalog
+                            .get_table_name_by_id(fk.ref_table_id)
+                            .await
+                            .ok()
+                            .flatten();
+                        // The referenced table may have been dropped or altered since.
+                        let ref_columns = match catalog.get_table_meta_by_id(fk.ref_table_id).await
+                        {
+                            Ok(Some(meta)) => {
+                                column_names_of(&meta.data.schema, &fk.ref_column_ids).ok()
+                            }
+                            _ => None,
+                        };
+                        (ref_database, ref_table, ref_columns)
+                    }
+                    _ => (None, None, None),
+                };
+
+                let expr = match constraint {
+                    Constraint::Check(expr) => expr.clone(),
+                    Constraint::PrimaryKey(_) => format!("PRIMARY KEY ({})", columns.join(", ")),
+                    Constraint::Unique(_) => format!("UNIQUE ({})", columns.join(", ")),
+                    Constraint::ForeignKey(_) => format!(
+                        "FOREIGN KEY ({}) REFERENCES {}.{} ({})",
+                        columns.join(", "),
+                        ref_database.as_deref().unwrap_or("?"),
+                        ref_table.as_deref().unwrap_or("?"),
+                        ref_columns
+                            .as_ref()
+                            .map(|c| c.join(", "))
+                            .unwrap_or_else(|| "?".to_string()),
+                    ),
+                };
+
+                names.push(name.clone());
+                types.push(constraint.type_name());
+                databases.push(table.database_name()?.to_string());
+                tables.push(Some(table.name.to_string()));
                 constraint_column_indexes.push(indexes);
                 constraint_column_names.push(columns.join(", "));
                 created_on.push(table.meta.created_on.timestamp_micros());
                 updated_on.push(None);
                 expression.push(expr);
+                enforced.push(if constraint.check_expr().is_some() {
+                    "YES"
+                } else {
+                    "NO"
+                });
+                rely.push(constraint.rely());
+                referenced_databases.push(ref_database);
+                referenced_tables.push(ref_table);
+                referenced_column_names.push(ref_columns.map(|c| c.join(", ")));
             }
         }
 
@@ -145,10 +204,22 @@ impl AsyncSystemTable for ConstraintsTable {
             StringType::from_opt_data(tables),
             TimestampType::from_data(created_on),
             TimestampType::from_opt_data(updated_on),
+            StringType::from_data(enforced),
+            BooleanType::from_data(rely),
+            StringType::from_opt_data(referenced_databases),
+            StringType::from_opt_data(referenced_tables),
+            StringType::from_opt_data(referenced_column_names),
         ]))
     }
 }
 
+fn column_names_of(schema: &TableSchema, column_ids: &[ColumnId]) -> Result<Vec<String>> {
+    column_ids
+        .iter()
+        .map(|id| Ok(schema.field_of_column_id(*id)?.name().clone()))
+        .collect()
+}
+
 impl ConstraintsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
@@ -167,6 +238,20 @@ impl ConstraintsTable {
                 "updated_on",
                 TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
             ),
+            TableField::new("enforced", TableDataType::String),
+            TableField::new("rely", TableDataType::Boolean),
+            TableField::new(
+                "referenced_database",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "referenced_table",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "referenced_column_names",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -47,6 +47,7 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod plan_baselines_table;
 mod private_task_history_table;
 mod private_tasks_table;
 mod procedures_table;
@@ -62,6 +63,7 @@ mod streams_table;
 mod table;
 mod table_functions_table;
 mod tables_table;
+mod tag_references_table;
 mod task_history_table;
 mod tasks_table;
 mod temp_files_table;
@@ -105,6 +107,7 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use plan_baselines_table::PlanBaselinesTable;
 pub use private_task_history_table::PrivateTaskHistoryTable;
 pub use private_tasks_table::PrivateTasksTable;
 pub use procedures_table::ProceduresTable;
@@ -128,6 +131,7 @@ pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tables_table::ViewsTableWithHistory;
 pub use tables_table::ViewsTableWithoutHistory;
+pub use tag_references_table::TagReferencesTable;
 pub use task_history_table::parse_task_runs_to_datablock;
 pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
@@ -0,0 +1,129 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::get_plan_baseline_usage;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Plan baselines of the tenant, with how often the optimizer reproduced them on this node.
+pub struct PlanBaselinesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PlanBaselinesTable {
+    const NAME: &'static str = "system.plan_baselines";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let baselines = UserApiProvider::instance()
+            .plan_baseline_api(&tenant)
+            .list_baselines()
+            .await?;
+
+        let mut sql_hashes = Vec::with_capacity(baselines.len());
+        let mut query_texts = Vec::with_capacity(baselines.len());
+        let mut plans = Vec::with_capacity(baselines.len());
+        let mut created_ons = Vec::with_capacity(baselines.len());
+        let mut hits = Vec::with_capacity(baselines.len());
+        let mut mismatches = Vec::with_capacity(baselines.len());
+        let mut last_used_ons = Vec::with_capacity(baselines.len());
+        let mut last_mismatch_plans = Vec::with_capacity(baselines.len());
+        for baseline in baselines {
+            let usage = get_plan_baseline_usage(tenant.tenant_name(), &baseline.sql_hash);
+            sql_hashes.push(baseline.sql_hash);
+            query_texts.push(baseline.query_text);
+            plans.push(baseline.plan_display);
+            created_ons.push(baseline.created_on.timestamp_micros());
+            hits.push(usage.hits);
+            mismatches.push(usage.mismatches);
+            last_used_ons.push(usage.last_used_on.map(|t| t.timestamp_micros()));
+            last_mismatch_plans.push(usage.last_mismatch_plan);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(sql_hashes),
+            StringType::from_data(query_texts),
+            StringType::from_data(plans),
+            TimestampType::from_data(created_ons),
+            UInt64Type::from_data(hits),
+            UInt64Type::from_data(mismatches),
+            TimestampType::from_opt_data(last_used_ons),
+            StringType::from_opt_data(last_mismatch_plans),
+        ]))
+    }
+}
+
+impl PlanBaselinesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("sql_hash", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("plan", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("hits", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("mismatches", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "last_used_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "last_mismatch_plan",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'plan_baselines'".to_string(),
+            name: "plan_baselines".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanBaselines".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PlanBaselinesTable { table_info })
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
+use databend_common_meta_api::TagApi;
+use databend_common_meta_app::schema::TableIdToName;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::tag::TaggableObject;
+use databend_common_users::Object;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct TagReferencesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TagReferencesTable {
+    const NAME: &'static str = "system.tag_references";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+
+        let tag_names = meta_api
+            .list_tags(&tenant)
+            .await?
+            .into_iter()
+            .map(|(name, id, _)| (*id, name))
+            .collect::<BTreeMap<_, _>>();
+
+        // References to dropped or replaced tags are left behind, skip them.
+        let references = meta_api
+            .list_tag_references(&tenant)
+            .await?
+            .into_iter()
+            .filter(|r| tag_names.contains_key(&r.tag_id))
+            .collect::<Vec<_>>();
+
+        let mut table_ids = BTreeSet::new();
+        let mut db_ids = BTreeSet::new();
+        for reference in &references {
+            match reference.object {
+                TaggableObject::Database { db_id } => {
+                    db_ids.insert(db_id);
+                }
+                TaggableObject::Table { table_id } | TaggableObject::Column { table_id, .. } => {
+                    table_ids.insert(table_id);
+                }
+            }
+        }
+        let table_ids = table_ids.into_iter().collect::<Vec<_>>();
+
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+        let ctl_name = catalog.name();
+
+        // table_id -> (db_id, table_name, table_meta), dropped tables are skipped.
+        let mut tables = BTreeMap::new();
+        let live_names = catalog
+            .mget_table_names_by_ids(&tenant, &table_ids, false)
+            .await?;
+        let id_to_names = meta_api
+            .get_pb_values_vec(table_ids.iter().map(|id| TableIdToName { table_id: *id }))
+            .await?;
+        for ((table_id, live_name), id_to_name) in table_ids.iter().zip(live_names).zip(id_to_names)
+        {
+            let (Some(table_name), Some(id_to_name)) = (live_name, id_to_name) else {
+                continue;
+            };
+            let Some(table_meta) = catalog.get_table_meta_by_id(*table_id).await? else {
+                continue;
+            };
+            db_ids.insert(id_to_name.data.db_id);
+            tables.insert(
+                *table_id,
+                (id_to_name.data.db_id, table_name, table_meta.data),
+            );
+        }
+
+        let db_ids = db_ids.into_iter().collect::<Vec<_>>();
+        let db_names = catalog
+            .mget_database_names_by_ids(&tenant, &db_ids)
+            .await?
+            .into_iter()
+            .zip(db_ids)
+            .filter_map(|(name, id)| name.map(|name| (id, name)))
+            .collect::<BTreeMap<_, _>>();
+
+        let visibility_checker = ctx.get_visibility_checker(false, Object::All).await?;
+
+        let len = references.len();
+        let mut tag_name_col = Vec::with_capacity(len);
+        let mut tag_value_col = Vec::with_capacity(len);
+        let mut domain_col = Vec::with_capacity(len);
+        let mut database_col = Vec::with_capacity(len);
+        let mut table_col = Vec::with_capacity(len);
+        let mut column_col = Vec::with_capacity(len);
+
+        for reference in references {
+            let (db_name, table_name, column_name) = match reference.object {
+                TaggableObject::Database { db_id } => {
+                    let Some(db_name) = db_names.get(&db_id) else {
+                        continue;
+                    };
+                    if !visibility_checker.check_database_visibility(&ctl_name, db_name, db_id) {
+                        continue;
+                    }
+                    (db_name.clone(), None, None)
+                }
+                TaggableObject::Table { table_id } | TaggableObject::Column { table_id, .. } => {
+                    let Some((db_id, table_name, table_meta)) = tables.get(&table_id) else {
+                        continue;
+                    };
+                    let Some(db_name) = db_names.get(db_id) else {
+                        continue;
+                    };
+                    if !visibility_checker
+                        .check_table_visibility(&ctl_name, db_name, table_name, *db_id, table_id)
+                    {
+                        continue;
+                    }
+                    let column_name = match reference.object {
+                        TaggableObject::Column { column_id, .. } => {
+                            // The column has been dropped.
+                            let Some(field) = table_meta
+                                .schema
+                                .fields()
+                                .iter()
+                                .find(|f| f.column_id == column_id)
+                            else {
+                                continue;
+                            };
+                            Some(field.name.clone())
+                        }
+                        _ => None,
+                    };
+                    (db_name.clone(), Some(table_name.clone()), column_name)
+                }
+            };
+
+            tag_name_col.push(tag_names[&reference.tag_id].clone());
+            tag_value_col.push(reference.tag_value);
+            domain_col.push(reference.object.domain().to_string());
+            database_col.push(db_name);
+            table_col.push(table_name);
+            column_col.push(column_name);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(tag_name_col),
+            StringType::from_data(tag_value_col),
+            StringType::from_data(domain_col),
+            StringType::from_data(database_col),
+            StringType::from_opt_data(table_col),
+            StringType::from_opt_data(column_col),
+        ]))
+    }
+}
+
+impl TagReferencesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("tag_name", TableDataType::String),
+            TableField::new("tag_value", TableDataType::String),
+            TableField::new("domain", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new(
+                "table",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+            TableField::new(
+                "column_name",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'tag_references'".to_string(),
+            name: "tag_references".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTagReferences".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
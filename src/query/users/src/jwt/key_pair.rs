@@ -0,0 +1,166 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64::prelude::*;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserOption;
+use jwt_simple::algorithms::Ed25519PublicKey;
+use jwt_simple::algorithms::EdDSAPublicKeyLike;
+use jwt_simple::algorithms::RS256PublicKey;
+use jwt_simple::algorithms::RSAPublicKeyLike;
+use jwt_simple::prelude::Clock;
+use jwt_simple::prelude::Duration;
+use jwt_simple::prelude::JWTClaims;
+use serde::Deserialize;
+use sha2::Digest;
+use sha2::Sha256;
+
+use super::CustomClaims;
+
+const FINGERPRINT_PREFIX: &str = "SHA256:";
+
+/// Key-pair tokens are signed by the client itself, so they must be short-lived.
+const MAX_TOKEN_LIFETIME_HOURS: u64 = 1;
+
+/// A public key registered on a user with `RSA_PUBLIC_KEY` / `RSA_PUBLIC_KEY_2`.
+#[derive(Debug, Clone)]
+pub enum KeyPairPublicKey {
+    RSA256(Box<RS256PublicKey>),
+    Ed25519(Ed25519PublicKey),
+}
+
+impl KeyPairPublicKey {
+    /// Parse a PEM encoded RSA or Ed25519 public key.
+    ///
+    /// The `-----BEGIN PUBLIC KEY-----` armor is optional, so the base64 body alone
+    /// is also accepted.
+    pub fn from_pem(pem: &str) -> Result<Self> {
+        let pem = pem.trim();
+        let pem = if pem.starts_with("-----BEGIN") {
+            pem.to_string()
+        } else {
+            let body: String = pem.split_whitespace().collect();
+            format!("-----BEGIN PUBLIC KEY-----\n{body}\n-----END PUBLIC KEY-----")
+        };
+
+        if let Ok(key) = RS256PublicKey::from_pem(&pem) {
+            return Ok(KeyPairPublicKey::RSA256(Box::new(key)));
+        }
+        if let Ok(key) = Ed25519PublicKey::from_pem(&pem) {
+            return Ok(KeyPairPublicKey::Ed25519(key));
+        }
+        Err(ErrorCode::InvalidArgument(
+            "invalid public key, expect a PEM encoded RSA or Ed25519 public key",
+        ))
+    }
+
+    /// The fingerprint of the key, in the form of `SHA256:<base64 of sha256(der)>`.
+    pub fn fingerprint(&self) -> Result<String> {
+        let der = match self {
+            KeyPairPublicKey::RSA256(key) => key
+                .to_der()
+                .map_err(|e| ErrorCode::InvalidArgument(e.to_string()))?,
+            KeyPairPublicKey::Ed25519(key) => key.to_der(),
+        };
+        let digest = Sha256::digest(der);
+        Ok(format!(
+            "{FINGERPRINT_PREFIX}{}",
+            BASE64_STANDARD.encode(digest)
+        ))
+    }
+
+    fn verify_token(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
+        let result = match self {
+            KeyPairPublicKey::RSA256(key) => key.verify_token::<CustomClaims>(token, None),
+            KeyPairPublicKey::Ed25519(key) => key.verify_token::<CustomClaims>(token, None),
+        };
+        result.map_err(|e| ErrorCode::AuthenticateFailure(e.to_string()))
+    }
+}
+
+#[derive(Deserialize)]
+struct UnverifiedClaims {
+    #[serde(rename = "iss")]
+    issuer: Option<String>,
+}
+
+/// The issuer of a key-pair token, `<user>.SHA256:<fingerprint>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyPairIssuer {
+    pub user: String,
+    pub fingerprint: String,
+}
+
+impl KeyPairIssuer {
+    /// Returns the issuer if the token is signed by a user's private key,
+    /// which is told apart from tokens issued by an identity provider by the
+    /// fingerprint suffix of the `iss` claim.
+    pub fn from_token(token: &str) -> Option<Self> {
+        let payload = token.split('.').nth(1)?;
+        let payload = BASE64_URL_SAFE_NO_PAD.decode(payload).ok()?;
+        let claims: UnverifiedClaims = serde_json::from_slice(&payload).ok()?;
+        let issuer = claims.issuer?;
+        let pos = issuer.rfind(&format!(".{FINGERPRINT_PREFIX}"))?;
+        let (user, fingerprint) = (&issuer[..pos], &issuer[pos + 1..]);
+        if user.is_empty() || fingerprint.len() == FINGERPRINT_PREFIX.len() {
+            return None;
+        }
+        Some(KeyPairIssuer {
+            user: user.to_string(),
+            fingerprint: fingerprint.to_string(),
+        })
+    }
+
+    /// Verify the token with the user's public key which matches the fingerprint.
+    pub fn verify_token(
+        &self,
+        token: &str,
+        user_option: &UserOption,
+    ) -> Result<JWTClaims<CustomClaims>> {
+        let mut key = None;
+        for pem in user_option.rsa_public_keys() {
+            let candidate = KeyPairPublicKey::from_pem(pem)?;
+            if candidate.fingerprint()? == self.fingerprint {
+                key = Some(candidate);
+                break;
+            }
+        }
+        let key = key.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(format!(
+                "[AUTH] Key-pair authentication failed: no public key of user {} matches fingerprint {}",
+                self.user, self.fingerprint
+            ))
+        })?;
+
+        let claims = key.verify_token(token)?;
+        if claims.subject.as_deref() != Some(self.user.as_str()) {
+            return Err(ErrorCode::AuthenticateFailure(
+                "[AUTH] Key-pair authentication failed: subject claim does not match the issuer",
+            ));
+        }
+        let expires_at = claims.expires_at.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(
+                "[AUTH] Key-pair authentication failed: expiration claim is missing in the token",
+            )
+        })?;
+        let issued_at = claims.issued_at.unwrap_or_else(Clock::now_since_epoch);
+        if expires_at > issued_at + Duration::from_hours(MAX_TOKEN_LIFETIME_HOURS) {
+            return Err(ErrorCode::AuthenticateFailure(
+                "[AUTH] Key-pair authentication failed: token lifetime must not exceed 1 hour",
+            ));
+        }
+        Ok(claims)
+    }
+}
@@ -14,6 +14,7 @@
 
 mod authenticator;
 mod jwk;
+mod key_pair;
 
 pub use authenticator::CustomClaims;
 pub use authenticator::EnsureUser;
@@ -21,3 +22,5 @@ pub use authenticator::JwtAuthenticator;
 pub use authenticator::PubKey;
 pub use jwk::JwkKey;
 pub use jwk::JwkKeyStore;
+pub use key_pair::KeyPairIssuer;
+pub use key_pair::KeyPairPublicKey;
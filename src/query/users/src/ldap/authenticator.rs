@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_config::LdapConfig;
+use databend_common_config::QueryConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::tenant::Tenant;
+use ldap3::dn_escape;
+use ldap3::ldap_escape;
+use log::info;
+use log::warn;
+
+use super::client::Ldap3Connector;
+use crate::UserApiProvider;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LdapScope {
+    Base,
+    Subtree,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LdapEntry {
+    pub dn: String,
+    pub attrs: HashMap<String, Vec<String>>,
+}
+
+/// A connection to the LDAP server.
+#[async_trait::async_trait]
+pub trait LdapConnection: Send {
+    async fn simple_bind(&mut self, dn: &str, password: &str) -> Result<()>;
+
+    async fn search(
+        &mut self,
+        base: &str,
+        scope: LdapScope,
+        filter: &str,
+        attrs: &[&str],
+    ) -> Result<Vec<LdapEntry>>;
+
+    async fn unbind(&mut self);
+}
+
+#[async_trait::async_trait]
+pub trait LdapConnector: Send + Sync {
+    async fn connect(&self, config: &LdapConfig) -> Result<Box<dyn LdapConnection>>;
+}
+
+/// The user authenticated by the LDAP server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapUser {
+    pub dn: String,
+    /// Roles mapped from the groups of the user.
+    pub roles: BTreeSet<String>,
+}
+
+pub struct LdapAuthenticator {
+    config: LdapConfig,
+    connector: Arc<dyn LdapConnector>,
+}
+
+impl LdapAuthenticator {
+    pub fn create(cfg: &QueryConfig) -> Option<Self> {
+        let config = cfg.ldap.clone()?;
+        if config.url.is_empty() {
+            return None;
+        }
+        Some(Self::with_connector(config, Arc::new(Ldap3Connector)))
+    }
+
+    pub fn with_connector(config: LdapConfig, connector: Arc<dyn LdapConnector>) -> Self {
+        LdapAuthenticator { config, connector }
+    }
+
+    pub fn auto_create_user(&self) -> bool {
+        self.config.auto_create_user
+    }
+
+    /// Verify the password by binding to the LDAP server as the user.
+    #[async_backtrace::framed]
+    pub async fn authenticate(&self, user_name: &str, password: &[u8]) -> Result<LdapUser> {
+        // An empty password makes an unauthenticated bind, which most servers accept.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure(
+                "[AUTH] LDAP authentication failed: password is required but was not provided",
+            ));
+        }
+        let password = std::str::from_utf8(password).map_err(|_| {
+            ErrorCode::AuthenticateFailure(
+                "[AUTH] LDAP authentication failed: password is not valid utf-8",
+            )
+        })?;
+
+        let mut conn = self.connector.connect(&self.config).await?;
+        let res = self.bind_user(conn.as_mut(), user_name, password).await;
+        conn.unbind().await;
+        let entry = res?;
+
+        let groups = entry
+            .attrs
+            .iter()
+            .filter(|(k, _)| k.eq_ignore_ascii_case(&self.config.group_attribute))
+            .flat_map(|(_, v)| v.iter())
+            .collect::<Vec<_>>();
+        let roles = self.map_roles(&groups);
+        info!(
+            "[AUTH] LDAP authenticated user {} as {}, mapped roles: {:?}",
+            user_name, entry.dn, roles
+        );
+        Ok(LdapUser {
+            dn: entry.dn,
+            roles,
+        })
+    }
+
+    async fn bind_user(
+        &self,
+        conn: &mut dyn LdapConnection,
+        user_name: &str,
+        password: &str,
+    ) -> Result<LdapEntry> {
+        let attrs = [self.config.group_attribute.as_str()];
+        if !self.config.bind_dn_template.is_empty() {
+            let dn = self
+                .config
+                .bind_dn_template
+                .replace("{username}", &dn_escape(user_name));
+            conn.simple_bind(&dn, password)
+                .await
+                .map_err(|e| Self::bind_failure(user_name, e))?;
+
+            // Groups are optional, the login is not rejected if the entry is not readable.
+            let entry = match conn
+                .search(&dn, LdapScope::Base, "(objectClass=*)", &attrs)
+                .await
+            {
+                Ok(mut entries) => entries.pop(),
+                Err(e) => {
+                    warn!("[AUTH] LDAP failed to read the entry of {}: {}", dn, e);
+                    None
+                }
+            };
+            Ok(entry.unwrap_or(LdapEntry {
+                dn,
+                attrs: HashMap::new(),
+            }))
+        } else {
+            conn.simple_bind(
+                &self.config.search_bind_dn,
+                &self.config.search_bind_password,
+            )
+            .await
+            .map_err(|e| {
+                ErrorCode::AuthenticateFailure(format!(
+                    "[AUTH] LDAP authentication failed: search bind failed: {}",
+                    e.message()
+                ))
+            })?;
+            let filter = self
+                .config
+                .search_filter
+                .replace("{username}", &ldap_escape(user_name));
+            let mut entries = conn
+                .search(
+                    &self.config.search_base_dn,
+                    LdapScope::Subtree,
+                    &filter,
+                    &attrs,
+                )
+                .await?;
+            if entries.len() != 1 {
+                return Err(ErrorCode::AuthenticateFailure(format!(
+                    "[AUTH] LDAP authentication failed: expect one entry for user {}, but found {}",
+                    user_name,
+                    entries.len()
+                )));
+            }
+            let entry = entries.pop().unwrap();
+            conn.simple_bind(&entry.dn, password)
+                .await
+                .map_err(|e| Self::bind_failure(user_name, e))?;
+            Ok(entry)
+        }
+    }
+
+    fn bind_failure(user_name: &str, e: ErrorCode) -> ErrorCode {
+        ErrorCode::AuthenticateFailure(format!(
+            "[AUTH] LDAP authentication failed: bind as user {} failed: {}",
+            user_name,
+            e.message()
+        ))
+    }
+
+    /// A group matches a mapping key by either its DN or the value of its first RDN.
+    fn map_roles(&self, groups: &[&String]) -> BTreeSet<String> {
+        let mut roles = BTreeSet::new();
+        for group in groups {
+            let cn = group
+                .split(',')
+                .next()
+                .and_then(|rdn| rdn.split_once('='))
+                .map(|(_, v)| v.trim());
+            for (key, role) in &self.config.group_role_mapping {
+                if key.eq_ignore_ascii_case(group)
+                    || cn.is_some_and(|cn| key.eq_ignore_ascii_case(cn))
+                {
+                    roles.insert(role.clone());
+                }
+            }
+        }
+        roles
+    }
+
+    /// Grant the roles mapped from the groups of the user, and revoke the mapped
+    /// roles of the groups the user no longer belongs to.
+    /// Roles not in the mapping are left as they are.
+    #[async_backtrace::framed]
+    pub async fn sync_roles(
+        &self,
+        tenant: &Tenant,
+        user_info: &mut UserInfo,
+        ldap_user: &LdapUser,
+    ) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let mapped_roles = self
+            .config
+            .group_role_mapping
+            .values()
+            .map(|role| role.as_str())
+            .collect::<HashSet<_>>();
+        let current_roles = user_info.grants.roles();
+
+        for role in &ldap_user.roles {
+            if !current_roles.contains(role) {
+                info!(
+                    "[AUTH] LDAP grant role to user: {} -> {}",
+                    user_info.name, role
+                );
+                user_api
+                    .grant_role_to_user(tenant, user_info.identity(), role.clone())
+                    .await?;
+                user_info.grants.grant_role(role.clone());
+            }
+        }
+        for role in current_roles {
+            if mapped_roles.contains(role.as_str()) && !ldap_user.roles.contains(&role) {
+                info!(
+                    "[AUTH] LDAP revoke role from user: {} -> {}",
+                    user_info.name, role
+                );
+                user_api
+                    .revoke_role_from_user(tenant, user_info.identity(), role.clone())
+                    .await?;
+                user_info.grants.revoke_role(&role);
+            }
+        }
+        Ok(())
+    }
+}
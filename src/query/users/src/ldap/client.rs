@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_config::LdapConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use ldap3::Ldap;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+use ldap3::LdapError;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+use log::warn;
+
+use super::authenticator::LdapConnection;
+use super::authenticator::LdapConnector;
+use super::authenticator::LdapEntry;
+use super::authenticator::LdapScope;
+
+/// Connects to the LDAP server with `ldap3`.
+pub struct Ldap3Connector;
+
+#[async_trait::async_trait]
+impl LdapConnector for Ldap3Connector {
+    async fn connect(&self, config: &LdapConfig) -> Result<Box<dyn LdapConnection>> {
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(Duration::from_secs(config.connect_timeout_secs));
+        let (conn, ldap) = LdapConnAsync::with_settings(settings, &config.url)
+            .await
+            .map_err(ldap_error)?;
+        databend_common_base::runtime::spawn(async move {
+            if let Err(e) = conn.drive().await {
+                warn!("[AUTH] LDAP connection error: {}", e);
+            }
+        });
+        Ok(Box::new(Ldap3Connection { ldap }))
+    }
+}
+
+struct Ldap3Connection {
+    ldap: Ldap,
+}
+
+#[async_trait::async_trait]
+impl LdapConnection for Ldap3Connection {
+    async fn simple_bind(&mut self, dn: &str, password: &str) -> Result<()> {
+        self.ldap
+            .simple_bind(dn, password)
+            .await
+            .and_then(|res| res.success())
+            .map_err(ldap_error)?;
+        Ok(())
+    }
+
+    async fn search(
+        &mut self,
+        base: &str,
+        scope: LdapScope,
+        filter: &str,
+        attrs: &[&str],
+    ) -> Result<Vec<LdapEntry>> {
+        let scope = match scope {
+            LdapScope::Base => Scope::Base,
+            LdapScope::Subtree => Scope::Subtree,
+        };
+        let (entries, _) = self
+            .ldap
+            .search(base, scope, filter, attrs.to_vec())
+            .await
+            .and_then(|res| res.success())
+            .map_err(ldap_error)?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| {
+                let entry = SearchEntry::construct(entry);
+                LdapEntry {
+                    dn: entry.dn,
+                    attrs: entry.attrs,
+                }
+            })
+            .collect())
+    }
+
+    async fn unbind(&mut self) {
+        if let Err(e) = self.ldap.unbind().await {
+            warn!("[AUTH] LDAP unbind failed: {}", e);
+        }
+    }
+}
+
+fn ldap_error(e: LdapError) -> ErrorCode {
+    ErrorCode::AuthenticateFailure(format!("[AUTH] LDAP error: {}", e))
+}
@@ -18,6 +18,7 @@
 extern crate core;
 
 mod jwt;
+mod ldap;
 mod network_policy;
 mod password_policy;
 mod role_mgr;
@@ -35,6 +36,7 @@ pub mod role_cache_mgr;
 pub mod role_util;
 
 pub use jwt::*;
+pub use ldap::*;
 pub use password_policy::*;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
@@ -30,6 +30,7 @@ use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PlanBaselineMgr;
 use databend_common_management::ProcedureMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
@@ -212,6 +213,10 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn plan_baseline_api(&self, tenant: &Tenant) -> PlanBaselineMgr {
+        PlanBaselineMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn client_session_api(&self, tenant: &Tenant) -> ClientSessionMgr {
         ClientSessionMgr::create(self.client.clone(), tenant)
     }
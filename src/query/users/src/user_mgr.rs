@@ -217,9 +217,10 @@ impl UserApiProvider {
                 | GrantObject::Connection(_)
                 | GrantObject::Sequence(_)
                 | GrantObject::Procedure(_)
+                | GrantObject::ColumnById(..)
         ) {
             return Err(ErrorCode::IllegalUser(format!(
-                "Cannot grant warehouse|connection|Sequence|Procedure privileges to user `{}`",
+                "Cannot grant warehouse|connection|Sequence|Procedure|column privileges to user `{}`",
                 user.username
             )));
         }
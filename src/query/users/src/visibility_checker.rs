@@ -209,6 +209,11 @@ impl GrantObjectVisibilityChecker {
                         // if table is visible, the table's database is also treated as visible
                         extra_databases_id.insert((catalog.to_string(), *db));
                     }
+                    GrantObject::ColumnById(catalog, db, table, _) => {
+                        // a table with some granted columns is visible as well
+                        granted_tables_id.insert((catalog.to_string(), *db, *table));
+                        extra_databases_id.insert((catalog.to_string(), *db));
+                    }
                     GrantObject::UDF(udf) => {
                         granted_udfs.insert(udf.to_string());
                     }
@@ -0,0 +1,260 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_config::LdapConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_grpc::RpcClientConf;
+use databend_common_meta_app::principal::AuthInfo;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::LdapAuthenticator;
+use databend_common_users::LdapConnection;
+use databend_common_users::LdapConnector;
+use databend_common_users::LdapEntry;
+use databend_common_users::LdapScope;
+use databend_common_users::UserApiProvider;
+use databend_common_version::BUILD_INFO;
+use pretty_assertions::assert_eq;
+
+const SERVICE_DN: &str = "cn=admin,dc=example,dc=org";
+const SERVICE_PASSWORD: &str = "admin";
+
+/// An in-process stand-in of a LDAP directory.
+#[derive(Clone, Default)]
+struct MockDirectory {
+    // dn -> (password, entry)
+    entries: BTreeMap<String, (String, LdapEntry)>,
+}
+
+impl MockDirectory {
+    fn new() -> Self {
+        let mut directory = MockDirectory::default();
+        directory.add(SERVICE_DN, SERVICE_PASSWORD, &[]);
+        directory.add("uid=alice,ou=people,dc=example,dc=org", "alice-pwd", &[
+            "cn=analysts,ou=groups,dc=example,dc=org",
+            "cn=staff,ou=groups,dc=example,dc=org",
+        ]);
+        directory.add("uid=bob,ou=people,dc=example,dc=org", "bob-pwd", &[
+            "cn=staff,ou=groups,dc=example,dc=org",
+        ]);
+        directory
+    }
+
+    fn add(&mut self, dn: &str, password: &str, groups: &[&str]) {
+        let attrs = HashMap::from([(
+            "memberOf".to_string(),
+            groups.iter().map(|g| g.to_string()).collect(),
+        )]);
+        self.entries.insert(
+            dn.to_string(),
+            (password.to_string(), LdapEntry {
+                dn: dn.to_string(),
+                attrs,
+            }),
+        );
+    }
+}
+
+struct MockConnection {
+    directory: MockDirectory,
+    bound: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl LdapConnection for MockConnection {
+    async fn simple_bind(&mut self, dn: &str, password: &str) -> Result<()> {
+        match self.directory.entries.get(dn) {
+            Some((p, _)) if p == password => {
+                self.bound = Some(dn.to_string());
+                Ok(())
+            }
+            _ => Err(ErrorCode::AuthenticateFailure("rc=49 (invalidCredentials)")),
+        }
+    }
+
+    async fn search(
+        &mut self,
+        base: &str,
+        scope: LdapScope,
+        filter: &str,
+        _attrs: &[&str],
+    ) -> Result<Vec<LdapEntry>> {
+        assert!(self.bound.is_some());
+        let entries = self.directory.entries.values().map(|(_, e)| e);
+        Ok(match scope {
+            LdapScope::Base => entries.filter(|e| e.dn == base).cloned().collect(),
+            // only supports filters like `(uid=xxx)`
+            LdapScope::Subtree => {
+                let rdn = filter.trim_start_matches('(').trim_end_matches(')');
+                entries
+                    .filter(|e| e.dn.ends_with(base) && e.dn.starts_with(&format!("{rdn},")))
+                    .cloned()
+                    .collect()
+            }
+        })
+    }
+
+    async fn unbind(&mut self) {
+        self.bound = None;
+    }
+}
+
+struct MockConnector(MockDirectory);
+
+#[async_trait::async_trait]
+impl LdapConnector for MockConnector {
+    async fn connect(&self, _config: &LdapConfig) -> Result<Box<dyn LdapConnection>> {
+        Ok(Box::new(MockConnection {
+            directory: self.0.clone(),
+            bound: None,
+        }))
+    }
+}
+
+fn ldap_config() -> LdapConfig {
+    LdapConfig {
+        url: "ldap://127.0.0.1:389".to_string(),
+        group_role_mapping: BTreeMap::from([
+            ("analysts".to_string(), "analyst".to_string()),
+            (
+                "cn=staff,ou=groups,dc=example,dc=org".to_string(),
+                "staff".to_string(),
+            ),
+            ("admins".to_string(), "admin".to_string()),
+        ]),
+        ..Default::default()
+    }
+}
+
+fn authenticator(config: LdapConfig) -> LdapAuthenticator {
+    LdapAuthenticator::with_connector(config, Arc::new(MockConnector(MockDirectory::new())))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_simple_bind() -> Result<()> {
+    let auth = authenticator(LdapConfig {
+        bind_dn_template: "uid={username},ou=people,dc=example,dc=org".to_string(),
+        ..ldap_config()
+    });
+
+    let user = auth.authenticate("alice", b"alice-pwd").await?;
+    assert_eq!(user.dn, "uid=alice,ou=people,dc=example,dc=org");
+    assert_eq!(
+        user.roles,
+        BTreeSet::from(["analyst".to_string(), "staff".to_string()])
+    );
+
+    let res = auth.authenticate("alice", b"wrong").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // empty password must not fall back to an unauthenticated bind
+    let res = auth.authenticate("alice", b"").await;
+    assert_eq!(res.unwrap_err().code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // the user name is escaped in the DN
+    let res = auth.authenticate("alice,ou=people", b"alice-pwd").await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_search_bind() -> Result<()> {
+    let auth = authenticator(LdapConfig {
+        search_bind_dn: SERVICE_DN.to_string(),
+        search_bind_password: SERVICE_PASSWORD.to_string(),
+        search_base_dn: "ou=people,dc=example,dc=org".to_string(),
+        ..ldap_config()
+    });
+
+    let user = auth.authenticate("bob", b"bob-pwd").await?;
+    assert_eq!(user.dn, "uid=bob,ou=people,dc=example,dc=org");
+    assert_eq!(user.roles, BTreeSet::from(["staff".to_string()]));
+
+    let res = auth.authenticate("bob", b"alice-pwd").await;
+    assert!(res.is_err());
+
+    let res = auth.authenticate("carol", b"carol-pwd").await;
+    assert!(res.unwrap_err().message().contains("found 0"));
+
+    // wrong search bind password
+    let auth = authenticator(LdapConfig {
+        search_bind_dn: SERVICE_DN.to_string(),
+        search_bind_password: "wrong".to_string(),
+        search_base_dn: "ou=people,dc=example,dc=org".to_string(),
+        ..ldap_config()
+    });
+    let res = auth.authenticate("bob", b"bob-pwd").await;
+    assert!(res.unwrap_err().message().contains("search bind failed"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_sync_roles() -> Result<()> {
+    let thread_name = std::thread::current().name().unwrap().to_string();
+    GlobalInstance::init_testing(&thread_name);
+    GlobalConfig::init(&InnerConfig::default(), &BUILD_INFO).unwrap();
+
+    let conf = RpcClientConf::empty(&BUILD_INFO);
+    let tenant = Tenant::new_literal("test");
+    let user_mgr = UserApiProvider::try_create_simple(conf, &tenant).await?;
+    GlobalInstance::set(user_mgr.clone());
+
+    let mut user_info = UserInfo::new("alice", "%", AuthInfo::Ldap);
+    // `admin` is mapped from a group alice is not in, `manual` is not mapped
+    user_info.grants.grant_role("admin".to_string());
+    user_info.grants.grant_role("manual".to_string());
+    user_mgr
+        .add_user(&tenant, user_info.clone(), &CreateOption::Create)
+        .await?;
+
+    let auth = authenticator(LdapConfig {
+        bind_dn_template: "uid={username},ou=people,dc=example,dc=org".to_string(),
+        ..ldap_config()
+    });
+    let ldap_user = auth.authenticate("alice", b"alice-pwd").await?;
+    auth.sync_roles(&tenant, &mut user_info, &ldap_user).await?;
+
+    let mut expect = vec![
+        "analyst".to_string(),
+        "manual".to_string(),
+        "staff".to_string(),
+    ];
+    let mut roles = user_info.grants.roles();
+    roles.sort();
+    assert_eq!(roles, expect);
+
+    let stored = user_mgr
+        .get_user(&tenant, UserIdentity::new("alice", "%"))
+        .await?;
+    let mut roles = stored.grants.roles();
+    roles.sort();
+    expect.sort();
+    assert_eq!(roles, expect);
+
+    Ok(())
+}
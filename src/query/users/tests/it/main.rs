@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod jwt;
+mod ldap;
 mod network_policy;
 mod password_policy;
 mod role_cache_mgr;
@@ -101,6 +101,7 @@ impl<R: Rng> SqlGenerator<'_, R> {
                 engine: Some(Engine::Fuse),
                 uri_location: None,
                 cluster_by: None,
+                ttl: None,
                 table_options: table_options.clone(),
                 iceberg_table_partition: None,
                 as_query: None,
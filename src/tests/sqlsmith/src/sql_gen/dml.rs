@@ -86,6 +86,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 catalog: None,
                 database: table.db_name.clone(),
                 table: table.name.clone(),
+                branch: None,
                 // TODO
                 columns: vec![],
                 source,
@@ -325,7 +326,10 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 };
                 hints_list.push(hint);
             }
-            Some(Hint { hints_list })
+            Some(Hint {
+                hints_list,
+                optimizer_hints: vec![],
+            })
         } else {
             None
         }
@@ -531,6 +535,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 catalog: None,
                 database: table.db_name.clone(),
                 table: table.name.clone(),
+                branch: None,
                 columns,
                 source,
                 overwrite: false,
@@ -57,4 +57,20 @@ spark.sql(
     f"""INSERT INTO iceberg.test.t1_orc VALUES (0, 0, 'a'), (1, 1, 'b'), (2, 2, 'c'), (3, 3, 'd'), (4, null, null);"""
 )
 
+# Runtime filter, each insert writes a data file with a disjoint range of id
+spark.sql(f"DROP TABLE IF EXISTS iceberg.test.t_rf;")
+
+spark.sql(f"""
+CREATE TABLE iceberg.test.t_rf (
+  id INT,
+  v STRING
+)
+USING iceberg;
+""")
+
+for i in range(4):
+    spark.sql(
+        f"""INSERT INTO iceberg.test.t_rf SELECT CAST(id AS INT), CAST(id AS STRING) FROM range({i * 10}, {i * 10 + 10});"""
+    )
+
 spark.stop()
@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "DROP DATABASE IF EXISTS test_table_ttl_pruning" | $BENDSQL_CLIENT_CONNECT
+echo "CREATE DATABASE test_table_ttl_pruning" | $BENDSQL_CLIENT_CONNECT
+
+echo "CREATE TABLE test_table_ttl_pruning.t(id int, ts timestamp) TTL add_days(ts, 30)" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_table_ttl_pruning.t VALUES (1, '2000-01-01 00:00:00')" | $BENDSQL_CLIENT_CONNECT
+echo "INSERT INTO test_table_ttl_pruning.t VALUES (2, '2100-01-01 00:00:00'), (3, NULL)" | $BENDSQL_CLIENT_CONNECT
+
+echo "--- the segment whose rows are all expired is pruned ---"
+echo "EXPLAIN SELECT * FROM test_table_ttl_pruning.t" | $BENDSQL_CLIENT_CONNECT | grep -o "partitions scanned: [0-9]*"
+echo "EXPLAIN SELECT * FROM test_table_ttl_pruning.t" | $BENDSQL_CLIENT_CONNECT | grep -o "segments: <range pruning: [0-9]* to [0-9]*>"
+echo "SELECT id FROM test_table_ttl_pruning.t ORDER BY id" | $BENDSQL_CLIENT_CONNECT
+
+echo "--- nothing is pruned without TTL ---"
+echo "ALTER TABLE test_table_ttl_pruning.t DROP TTL" | $BENDSQL_CLIENT_CONNECT
+echo "EXPLAIN SELECT * FROM test_table_ttl_pruning.t" | $BENDSQL_CLIENT_CONNECT | grep -o "partitions scanned: [0-9]*"
+echo "SELECT id FROM test_table_ttl_pruning.t ORDER BY id" | $BENDSQL_CLIENT_CONNECT
+
+echo "DROP DATABASE test_table_ttl_pruning" | $BENDSQL_CLIENT_CONNECT
@@ -0,0 +1,53 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export USER_U_COL_CONNECT="bendsql --user=u_col --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== prepare ==="
+echo "drop user if exists u_col" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r_col" | $BENDSQL_CLIENT_CONNECT
+echo "create or replace database col_priv_db" | $BENDSQL_CLIENT_CONNECT
+echo "create table col_priv_db.t(a int, b int, c varchar)" | $BENDSQL_CLIENT_CONNECT
+echo "insert into col_priv_db.t values(1, 2, 'x')" | $BENDSQL_CLIENT_CONNECT
+echo "create role r_col" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_col identified by '123' with default_role='r_col'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role r_col to u_col" | $BENDSQL_CLIENT_CONNECT
+echo "grant select (a, b) on col_priv_db.t to role r_col" | $BENDSQL_CLIENT_CONNECT
+echo "grant update (b) on col_priv_db.t to role r_col" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== show grants ==="
+echo "show grants for role r_col" | $BENDSQL_CLIENT_CONNECT | awk -F ' ' '{$3=""; print $0}'
+
+echo "=== select ==="
+echo "select * from col_priv_db.t" | $USER_U_COL_CONNECT
+echo "select b, a from col_priv_db.t" | $USER_U_COL_CONNECT
+echo "select c from col_priv_db.t" | $USER_U_COL_CONNECT
+echo "select a from col_priv_db.t where c = 'x'" | $USER_U_COL_CONNECT
+
+echo "=== update ==="
+echo "update col_priv_db.t set b = 3 where a = 1" | $USER_U_COL_CONNECT
+echo "select a, b from col_priv_db.t" | $USER_U_COL_CONNECT
+echo "update col_priv_db.t set c = 'y'" | $USER_U_COL_CONNECT
+echo "update col_priv_db.t set b = 4 where c = 'x'" | $USER_U_COL_CONNECT
+echo "update col_priv_db.t set b = length(c)" | $USER_U_COL_CONNECT
+echo "select a, b from col_priv_db.t" | $USER_U_COL_CONNECT
+
+echo "=== illegal grants ==="
+echo "grant select (a) on col_priv_db.* to role r_col" | $BENDSQL_CLIENT_CONNECT
+echo "grant select (d) on col_priv_db.t to role r_col" | $BENDSQL_CLIENT_CONNECT
+echo "grant select (a) on col_priv_db.t to u_col" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== revoke ==="
+echo "revoke select (a) on col_priv_db.t from role r_col" | $BENDSQL_CLIENT_CONNECT
+echo "select * from col_priv_db.t" | $USER_U_COL_CONNECT
+echo "show grants for role r_col" | $BENDSQL_CLIENT_CONNECT | awk -F ' ' '{$3=""; print $0}'
+
+echo "=== table privilege shows all columns ==="
+echo "grant select on col_priv_db.t to role r_col" | $BENDSQL_CLIENT_CONNECT
+echo "select * from col_priv_db.t" | $USER_U_COL_CONNECT
+
+echo "drop database col_priv_db" | $BENDSQL_CLIENT_CONNECT
+echo "drop user u_col" | $BENDSQL_CLIENT_CONNECT
+echo "drop role r_col" | $BENDSQL_CLIENT_CONNECT
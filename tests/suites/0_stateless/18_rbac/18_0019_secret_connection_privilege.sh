@@ -0,0 +1,31 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+export USER_U_SECRET_CONNECT="bendsql --user=u_secret --password=123 --host=${QUERY_MYSQL_HANDLER_HOST} --port ${QUERY_HTTP_HANDLER_PORT}"
+
+echo "=== prepare ==="
+echo "set global enable_experimental_connection_privilege_check=1" | $BENDSQL_CLIENT_CONNECT
+echo "drop user if exists u_secret" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r_secret" | $BENDSQL_CLIENT_CONNECT
+echo "drop connection if exists secret_key" | $BENDSQL_CLIENT_CONNECT
+echo "create connection secret_key storage_type = 'SECRET' secret = '0123456789abcdef'" | $BENDSQL_CLIENT_CONNECT
+echo "create role r_secret" | $BENDSQL_CLIENT_CONNECT
+echo "create user u_secret identified by '123' with default_role='r_secret'" | $BENDSQL_CLIENT_CONNECT
+echo "grant role r_secret to u_secret" | $BENDSQL_CLIENT_CONNECT
+
+echo "=== get_secret requires access connection ==="
+echo "select to_string(decrypt(encrypt('databend', get_secret('secret_key')), get_secret('secret_key')))" | $USER_U_SECRET_CONNECT
+echo "grant access connection on connection secret_key to role r_secret" | $BENDSQL_CLIENT_CONNECT
+echo "select to_string(decrypt(encrypt('databend', get_secret('secret_key')), get_secret('secret_key')))" | $USER_U_SECRET_CONNECT
+
+echo "=== the secret is not kept in the plan ==="
+echo "explain select encrypt('databend', get_secret('secret_key'))" | $BENDSQL_CLIENT_CONNECT | grep -c "0123456789abcdef"
+echo "explain select encrypt('databend', get_secret('secret_key'))" | $BENDSQL_CLIENT_CONNECT | grep -c "AsyncFunction"
+
+echo "=== clean up ==="
+echo "drop user if exists u_secret" | $BENDSQL_CLIENT_CONNECT
+echo "drop role if exists r_secret" | $BENDSQL_CLIENT_CONNECT
+echo "drop connection if exists secret_key" | $BENDSQL_CLIENT_CONNECT
+echo "unset global enable_experimental_connection_privilege_check" | $BENDSQL_CLIENT_CONNECT
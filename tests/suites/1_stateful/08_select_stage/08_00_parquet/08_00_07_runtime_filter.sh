@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../../shell_env.sh
+
+echo "drop stage if exists s_rf;" | $BENDSQL_CLIENT_CONNECT
+echo "create stage s_rf FILE_FORMAT = (type = PARQUET);" | $BENDSQL_CLIENT_CONNECT
+
+## 4 files with disjoint ranges of id
+for i in 0 1 2 3; do
+	echo "copy into @s_rf from (select number + ${i} * 10 as id from numbers(10)) file_format = (type = parquet);" | $BENDSQL_CLIENT_CONNECT | cut -d$'\t' -f1
+done
+
+echo "create or replace table rf_dim as select id as k from @s_rf where id = 15;" | $BENDSQL_CLIENT_CONNECT
+
+echo '--- the files without the build keys are pruned by the join runtime filter'
+echo """
+set disable_join_reorder = 1;
+explain analyze select * from @s_rf, rf_dim where id = k;
+""" | $BENDSQL_CLIENT_CONNECT | grep 'parts pruned by runtime filter' | sed 's/^.*── //'
+
+echo "select * from @s_rf, rf_dim where id = k;" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop table rf_dim;" | $BENDSQL_CLIENT_CONNECT
+echo "remove @s_rf;" | $BENDSQL_CLIENT_CONNECT
+echo "drop stage s_rf;" | $BENDSQL_CLIENT_CONNECT
@@ -25,5 +25,12 @@ query "select count() from test_delta where p0 = 10 and p2 = 12;"
 
 query "select c5, p4 from test_delta where c1 - p0 = 11 order by c5;"
 
+## join runtime filters on partition column and normal column
+stmt "create or replace table test_delta_dim(k int, tag string);"
+stmt "insert into test_delta_dim values (24, 'a'), (43, 'b'), (99, 'c');"
+query "select c1, p4 from test_delta join test_delta_dim on test_delta.p4 = test_delta_dim.k where tag = 'a' order by c1;"
+query "select c1, c3 from test_delta join test_delta_dim on test_delta.c3 = test_delta_dim.k where tag = 'b' order by c1;"
+stmt "drop table test_delta_dim;"
+
 stmt "drop table test_delta;"
 
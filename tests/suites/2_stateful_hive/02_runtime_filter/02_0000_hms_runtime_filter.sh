@@ -0,0 +1,16 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "create or replace table default.hive_rf_dim as select c_nation as nation from hive.default.customer_p2 where c_nation = 'CHINA' and foo = 'foo';" | $BENDSQL_CLIENT_CONNECT
+
+## customer_p2 has a file in each of the 5 partitions, the 4 files of the other nations are pruned
+echo """
+set disable_join_reorder = 1;
+explain analyze select foo, c_nation from hive.default.customer_p2, default.hive_rf_dim where c_nation = nation;
+""" | $BENDSQL_CLIENT_CONNECT | grep 'parts pruned by runtime filter' | sed 's/^.*── //'
+
+echo "select foo, c_nation from hive.default.customer_p2, default.hive_rf_dim where c_nation = nation order by foo;" | $BENDSQL_CLIENT_CONNECT
+
+echo "drop table default.hive_rf_dim;" | $BENDSQL_CLIENT_CONNECT